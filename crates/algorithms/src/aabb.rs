@@ -1,8 +1,12 @@
 //! Bounding rectangle computation for paths.
 
 use crate::geom::{CubicBezierSegment, QuadraticBezierSegment};
+use crate::hit_test::hit_test_path;
 use crate::math::{point, Box2D, Point};
-use crate::path::PathEvent;
+use crate::path::{FillRule, PathEvent, PathSlice};
+use crate::raycast::{ray_intersects_box, raycast_path, Hit, Ray};
+
+use core::cell::Cell;
 
 /// Computes a conservative axis-aligned rectangle that contains the path.
 ///
@@ -126,6 +130,80 @@ impl TightBoundingBox for PathEvent {
     }
 }
 
+/// A path along with its bounding box, computed on demand and cached.
+///
+/// Hit testing and ray casting first check the bounding box, which avoids iterating
+/// over the edges of paths that obviously can't be hit. This is useful when testing
+/// many paths, most of which are far from the point or ray.
+pub struct PathWithBounds<'l> {
+    path: PathSlice<'l>,
+    bounds: Cell<Option<Box2D>>,
+}
+
+impl<'l> PathWithBounds<'l> {
+    /// Creates a `PathWithBounds`, the bounding box is computed when first needed.
+    pub fn new(path: impl Into<PathSlice<'l>>) -> Self {
+        PathWithBounds {
+            path: path.into(),
+            bounds: Cell::new(None),
+        }
+    }
+
+    /// Creates a `PathWithBounds` from a precomputed bounding box.
+    ///
+    /// The bounding box must contain the path, including the control points of its
+    /// curves. Otherwise hits may be missed.
+    pub fn with_bounds(path: impl Into<PathSlice<'l>>, bounds: Box2D) -> Self {
+        PathWithBounds {
+            path: path.into(),
+            bounds: Cell::new(Some(bounds)),
+        }
+    }
+
+    /// Returns the path.
+    pub fn path(&self) -> PathSlice<'l> {
+        self.path
+    }
+
+    /// Returns the bounding box of the path, computing it with
+    /// [`fast_bounding_box`](fn.fast_bounding_box.html) if needed.
+    pub fn bounds(&self) -> Box2D {
+        if let Some(bounds) = self.bounds.get() {
+            return bounds;
+        }
+
+        let bounds = fast_bounding_box(self.path.iter());
+        self.bounds.set(Some(bounds));
+
+        bounds
+    }
+
+    /// Returns whether the point is inside of the path, see
+    /// [`hit_test_path`](../hit_test/fn.hit_test_path.html).
+    pub fn hit_test(&self, point: &Point, fill_rule: FillRule, tolerance: f32) -> bool {
+        let bounds = self.bounds();
+        if point.x < bounds.min.x
+            || point.x > bounds.max.x
+            || point.y < bounds.min.y
+            || point.y > bounds.max.y
+        {
+            return false;
+        }
+
+        hit_test_path(point, self.path.iter(), fill_rule, tolerance)
+    }
+
+    /// Finds the closest collision between a ray and the path, see
+    /// [`raycast_path`](../raycast/fn.raycast_path.html).
+    pub fn raycast(&self, ray: &Ray, tolerance: f32) -> Option<Hit> {
+        if !ray_intersects_box(ray, &self.bounds()) {
+            return None;
+        }
+
+        raycast_path(ray, self.path.iter(), tolerance)
+    }
+}
+
 #[test]
 fn simple_bounding_box() {
     use crate::path::Path;
@@ -159,3 +237,62 @@ fn simple_bounding_box() {
         },
     );
 }
+
+#[test]
+fn path_with_bounds() {
+    use crate::math::vector;
+    use crate::path::Path;
+
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.quadratic_bezier_to(point(12.0, 5.0), point(10.0, 10.0));
+    builder.line_to(point(0.0, 10.0));
+    builder.end(true);
+    let path = builder.build();
+
+    let p = PathWithBounds::new(&path);
+    assert_eq!(
+        p.bounds(),
+        Box2D {
+            min: point(0.0, 0.0),
+            max: point(12.0, 10.0)
+        }
+    );
+
+    assert!(p.hit_test(&point(5.0, 5.0), FillRule::EvenOdd, 0.1));
+    assert!(p.hit_test(&point(10.5, 5.0), FillRule::EvenOdd, 0.1));
+    assert!(!p.hit_test(&point(11.5, 5.0), FillRule::EvenOdd, 0.1));
+    assert!(!p.hit_test(&point(20.0, 5.0), FillRule::EvenOdd, 0.1));
+
+    let hit = p
+        .raycast(
+            &Ray {
+                origin: point(-5.0, 5.0),
+                direction: vector(1.0, 0.0),
+            },
+            0.1,
+        )
+        .unwrap();
+    assert_eq!(hit.position, point(0.0, 5.0));
+
+    assert!(p
+        .raycast(
+            &Ray {
+                origin: point(-5.0, 5.0),
+                direction: vector(-1.0, 0.0),
+            },
+            0.1,
+        )
+        .is_none());
+
+    // A precomputed bounding box is used as is.
+    let p = PathWithBounds::with_bounds(
+        &path,
+        Box2D {
+            min: point(0.0, 0.0),
+            max: point(1.0, 1.0),
+        },
+    );
+    assert!(!p.hit_test(&point(5.0, 5.0), FillRule::EvenOdd, 0.1));
+}
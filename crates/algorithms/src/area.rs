@@ -1,6 +1,7 @@
-//! Approximate the area of a path.
+//! Approximate the area and centroid of a path.
 
 use crate::geom::vector;
+use crate::math::{point, Point};
 use crate::path::{iterator::PathIterator, PathEvent};
 
 /// Compute the signed area of a path by summing the signed areas of its sub-paths.
@@ -60,6 +61,74 @@ where
     None
 }
 
+/// Compute the centroid (center of mass) of a path.
+///
+/// Each sub-path contributes proportionally to its signed area, so sub-paths with an
+/// orientation opposite to the others, like holes, are subtracted.
+///
+/// If the area of the path is zero, for example if all of its points are aligned, the
+/// average of its points is returned instead. The origin is returned for empty paths.
+pub fn approximate_centroid<Iter>(tolerance: f32, path: Iter) -> Point
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let mut double_area: f32 = 0.0;
+    let mut abs_double_area = 0.0;
+    // Accumulated relative to the first point of the path to limit precision issues.
+    let mut weighted_sum = vector(0.0, 0.0);
+    let mut point_sum = vector(0.0, 0.0);
+    let mut num_points = 0;
+    let mut origin = None;
+
+    let mut first = vector(0.0, 0.0);
+    let mut v0 = vector(0.0, 0.0);
+
+    for evt in path.into_iter().flattened(tolerance) {
+        match evt {
+            PathEvent::Begin { at } => {
+                let origin = *origin.get_or_insert(at);
+                first = at - origin;
+                v0 = vector(0.0, 0.0);
+                point_sum += first;
+                num_points += 1;
+            }
+            PathEvent::Line { to, .. } => {
+                let to = to - origin.unwrap();
+                let v1 = to - first;
+                // Twice the signed area of the triangle formed with the first point of
+                // the sub-path.
+                let a = v0.cross(v1);
+                double_area += a;
+                abs_double_area += a.abs();
+                weighted_sum += (first * 3.0 + v0 + v1) * a;
+                v0 = v1;
+                point_sum += to;
+                num_points += 1;
+            }
+            PathEvent::End { .. } => {
+                // The closing edge goes back to the first point which doesn't add area
+                // relative to it.
+            }
+            PathEvent::Quadratic { .. } | PathEvent::Cubic { .. } => {
+                debug_assert!(false, "Unexpected curve in a flattened path");
+            }
+        }
+    }
+
+    let origin = match origin {
+        Some(origin) => origin,
+        None => {
+            return point(0.0, 0.0);
+        }
+    };
+
+    if double_area.abs() <= abs_double_area * 1e-5 {
+        return origin + point_sum / num_points as f32;
+    }
+
+    origin + weighted_sum / (3.0 * double_area)
+}
+
 /// Iterator over the sub-path areas of a path.
 pub struct SignedAreas<Iter = PathEvent>(pub Iter, f32);
 
@@ -116,3 +185,56 @@ fn sub_path_signed_area() {
 
     assert_eq!(approximate_signed_area(0.01, path.build().iter()), 5.0);
 }
+
+#[test]
+fn centroid() {
+    use crate::math::Box2D;
+    use crate::path::{Path, Winding};
+
+    fn assert_near(a: Point, b: Point) {
+        assert!((a - b).length() < 0.001, "{:?} != {:?}", a, b);
+    }
+
+    let rect = |min: Point, max: Point| Box2D { min, max };
+
+    let mut path = Path::builder();
+    path.add_rectangle(&rect(point(1.0, 1.0), point(3.0, 5.0)), Winding::Positive);
+    let path = path.build();
+    assert_near(approximate_centroid(0.01, &path), point(2.0, 3.0));
+
+    // A triangle.
+    let mut path = Path::builder();
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(3.0, 0.0));
+    path.line_to(point(0.0, 3.0));
+    path.end(true);
+    let path = path.build();
+    assert_near(approximate_centroid(0.01, &path), point(1.0, 1.0));
+
+    // A 4x4 square with a 2x2 hole in its right half.
+    let mut path = Path::builder();
+    path.add_rectangle(&rect(point(0.0, 0.0), point(4.0, 4.0)), Winding::Positive);
+    path.add_rectangle(&rect(point(2.0, 1.0), point(4.0, 3.0)), Winding::Negative);
+    let path = path.build();
+    // (16 * 2 - 4 * 3) / 12
+    assert_near(approximate_centroid(0.01, &path), point(5.0 / 3.0, 2.0));
+
+    // Curves are flattened.
+    let mut path = Path::builder();
+    path.add_circle(point(10.0, -5.0), 3.0, Winding::Negative);
+    let path = path.build();
+    assert_near(approximate_centroid(0.001, &path), point(10.0, -5.0));
+
+    // Degenerate paths.
+    let mut path = Path::builder();
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(1.0, 1.0));
+    path.line_to(point(2.0, 2.0));
+    path.line_to(point(5.0, 5.0));
+    path.end(true);
+    let path = path.build();
+    assert_near(approximate_centroid(0.01, &path), point(2.0, 2.0));
+
+    let path = Path::new();
+    assert_near(approximate_centroid(0.01, &path), point(0.0, 0.0));
+}
@@ -0,0 +1,234 @@
+//! Clip polygons against convex regions.
+//!
+//! This implements the Sutherland–Hodgman algorithm, which clips the subject polygon
+//! against each edge of the clip region in turn. The subject polygon doesn't need to be
+//! convex, but if it is concave the result may contain degenerate zero-area parts along
+//! the boundary of the clip region.
+//!
+//! # Example
+//!
+//! ```
+//! use lyon_algorithms::clip::clip_polygon_rect;
+//! use lyon_algorithms::math::{point, Box2D};
+//!
+//! let triangle = [point(0.0, 0.0), point(10.0, 0.0), point(0.0, 10.0)];
+//! let clipped = clip_polygon_rect(&triangle, &Box2D::new(point(-1.0, -1.0), point(4.0, 4.0)));
+//!
+//! assert_eq!(
+//!     clipped,
+//!     &[point(0.0, 4.0), point(0.0, 0.0), point(4.0, 0.0), point(4.0, 4.0)],
+//! );
+//! ```
+
+use crate::geom::LineEquation;
+use crate::math::{point, Box2D, Point};
+
+use alloc::vec::Vec;
+
+/// Clips a polygon against a convex polygon.
+///
+/// The clip polygon must be convex and can have either winding. The result contains the
+/// vertices of the clipped polygon, and is empty if the polygons don't overlap or if the
+/// clip polygon has less than three vertices.
+pub fn clip_polygon(subject: &[Point], clip: &[Point]) -> Vec<Point> {
+    if clip.len() < 3 {
+        return Vec::new();
+    }
+
+    // The interior of polygons with a positive winding is on the positive side of
+    // their edges.
+    let mut double_area = 0.0;
+    let mut prev = clip[clip.len() - 1];
+    for &p in clip {
+        double_area += prev.to_vector().cross(p.to_vector());
+        prev = p;
+    }
+    let sign = if double_area < 0.0 { -1.0 } else { 1.0 };
+
+    let mut result = subject.to_vec();
+    let mut tmp = Vec::with_capacity(subject.len());
+    let mut prev = clip[clip.len() - 1];
+    for &p in clip {
+        if prev != p {
+            let edge = LineEquation::from_points(prev, p);
+            clip_half_plane(
+                &result,
+                &mut tmp,
+                &|p| sign * edge.signed_distance_to_point(&p),
+                &|p| p,
+            );
+            core::mem::swap(&mut result, &mut tmp);
+        }
+        prev = p;
+    }
+
+    result
+}
+
+/// Clips a polygon against an axis-aligned rectangle.
+///
+/// This is a faster equivalent of `clip_polygon` with the corners of the rectangle. The
+/// result contains the vertices of the clipped polygon, and is empty if the polygon
+/// and the rectangle don't overlap.
+pub fn clip_polygon_rect(subject: &[Point], rect: &Box2D) -> Vec<Point> {
+    let mut result = subject.to_vec();
+    let mut tmp = Vec::with_capacity(subject.len());
+
+    // The intersections are snapped to the sides of the rectangle, so that they are
+    // exactly on them.
+    clip_half_plane(&result, &mut tmp, &|p| p.x - rect.min.x, &|p| {
+        point(rect.min.x, p.y)
+    });
+    clip_half_plane(&tmp, &mut result, &|p| rect.max.x - p.x, &|p| {
+        point(rect.max.x, p.y)
+    });
+    clip_half_plane(&result, &mut tmp, &|p| p.y - rect.min.y, &|p| {
+        point(p.x, rect.min.y)
+    });
+    clip_half_plane(&tmp, &mut result, &|p| rect.max.y - p.y, &|p| {
+        point(p.x, rect.max.y)
+    });
+
+    result
+}
+
+// Writes into `output` the part of the polygon where `distance` is positive.
+//
+// `snap` is applied to the intersections with the boundary of the half-plane.
+fn clip_half_plane(
+    input: &[Point],
+    output: &mut Vec<Point>,
+    distance: &dyn Fn(Point) -> f32,
+    snap: &dyn Fn(Point) -> Point,
+) {
+    output.clear();
+
+    let mut prev = match input.last() {
+        Some(p) => *p,
+        None => return,
+    };
+    let mut prev_d = distance(prev);
+    for &p in input {
+        let d = distance(p);
+        if (prev_d > 0.0 && d < 0.0) || (prev_d < 0.0 && d > 0.0) {
+            // The edge crosses the boundary. Points on the boundary are considered
+            // inside and don't produce intersections.
+            let t = prev_d / (prev_d - d);
+            output.push(snap(prev.lerp(p, t)));
+        }
+        if d >= 0.0 {
+            output.push(p);
+        }
+        prev = p;
+        prev_d = d;
+    }
+}
+
+#[test]
+fn clip_against_rectangle() {
+    let rect = Box2D::new(point(0.0, 0.0), point(10.0, 10.0));
+
+    // Entirely inside.
+    let square = [
+        point(1.0, 1.0),
+        point(2.0, 1.0),
+        point(2.0, 2.0),
+        point(1.0, 2.0),
+    ];
+    assert_eq!(clip_polygon_rect(&square, &rect), &square);
+
+    // Entirely outside.
+    let outside = [point(20.0, 1.0), point(30.0, 1.0), point(30.0, 5.0)];
+    assert!(clip_polygon_rect(&outside, &rect).is_empty());
+    assert!(clip_polygon_rect(&[], &rect).is_empty());
+
+    // Covering the whole rectangle.
+    let big = [
+        point(-5.0, -5.0),
+        point(15.0, -5.0),
+        point(15.0, 15.0),
+        point(-5.0, 15.0),
+    ];
+    assert_eq!(
+        clip_polygon_rect(&big, &rect),
+        &[
+            point(0.0, 10.0),
+            point(0.0, 0.0),
+            point(10.0, 0.0),
+            point(10.0, 10.0)
+        ]
+    );
+
+    // Crossing the right side.
+    let triangle = [point(5.0, 2.0), point(15.0, 2.0), point(5.0, 8.0)];
+    assert_eq!(
+        clip_polygon_rect(&triangle, &rect),
+        &[
+            point(5.0, 2.0),
+            point(10.0, 2.0),
+            point(10.0, 5.0),
+            point(5.0, 8.0)
+        ]
+    );
+}
+
+#[test]
+fn clip_against_convex_polygon() {
+    use crate::geom::euclid::approxeq::ApproxEq;
+
+    let area = |polygon: &[Point]| {
+        let mut double_area = 0.0;
+        let mut prev = polygon[polygon.len() - 1];
+        for &p in polygon {
+            double_area += prev.to_vector().cross(p.to_vector());
+            prev = p;
+        }
+        double_area * 0.5
+    };
+
+    let rect = Box2D::new(point(0.0, 0.0), point(10.0, 10.0));
+    let subject = [
+        point(-3.0, 4.0),
+        point(6.0, -2.0),
+        point(12.0, 7.0),
+        point(5.0, 5.0),
+        point(4.0, 13.0),
+    ];
+
+    // The rectangle fast path and the generic version agree, regardless of the
+    // winding of the clip polygon.
+    let mut corners = [
+        point(0.0, 0.0),
+        point(10.0, 0.0),
+        point(10.0, 10.0),
+        point(0.0, 10.0),
+    ];
+    let expected = clip_polygon_rect(&subject, &rect);
+    let clipped = clip_polygon(&subject, &corners);
+    assert_eq!(clipped.len(), expected.len());
+    for (a, b) in clipped.iter().zip(expected.iter()) {
+        assert!(a.approx_eq_eps(b, &point(1e-5, 1e-5)), "{:?} {:?}", a, b);
+    }
+    corners.reverse();
+    let clipped = clip_polygon(&subject, &corners);
+    assert_eq!(clipped.len(), expected.len());
+    assert!(area(&clipped).approx_eq_eps(&area(&expected), &1e-4));
+
+    // A triangle clipped by a diamond.
+    let diamond = [
+        point(5.0, 0.0),
+        point(10.0, 5.0),
+        point(5.0, 10.0),
+        point(0.0, 5.0),
+    ];
+    let triangle = [point(0.0, 0.0), point(10.0, 0.0), point(10.0, 10.0)];
+    let clipped = clip_polygon(&triangle, &diamond);
+    // Half of the diamond, below the diagonal.
+    assert_eq!(clipped.len(), 4);
+    assert!(area(&clipped).approx_eq(&25.0));
+    assert!(clipped.iter().any(|p| p.approx_eq(&point(7.5, 7.5))));
+    assert!(clipped.iter().any(|p| p.approx_eq(&point(2.5, 2.5))));
+
+    // Degenerate clip polygons produce nothing.
+    assert!(clip_polygon(&triangle, &diamond[..2]).is_empty());
+}
@@ -0,0 +1,223 @@
+//! Apply dash patterns to paths.
+//!
+//! The dashed path is made of the visible portions of the input path. Curves are split at
+//! the dash boundaries rather than flattened, so the output preserves the geometry of the
+//! input. It is typically stroked afterwards.
+//!
+//! # Example
+//!
+//! ```
+//! use lyon_algorithms::dash::dash;
+//! use lyon_algorithms::path::{Path, math::point};
+//!
+//! let mut builder = Path::builder();
+//! builder.begin(point(0.0, 0.0));
+//! builder.line_to(point(10.0, 0.0));
+//! builder.end(false);
+//! let path = builder.build();
+//!
+//! // Dashes of 3 units separated by gaps of 1 unit.
+//! let dashed = dash(&path, &[3.0, 1.0], 0.0, 0.01);
+//!
+//! // Dashes [0, 3], [4, 7] and [8, 10].
+//! assert_eq!(dashed.iter().count(), 9);
+//! ```
+
+use crate::path::{Path, PathEvent};
+
+/// Splits a path into the dashes described by a pattern.
+///
+/// The pattern alternates the lengths of the dashes and of the gaps between them,
+/// starting with a dash, and is repeated along the path. Like in SVG, a pattern with
+/// an odd number of values is repeated twice to obtain an even number of values.
+///
+/// The `offset` shifts the phase at which the pattern starts. The pattern restarts at
+/// the beginning of each sub-path. Closed sub-paths are dashed continuously: if a dash
+/// overlaps the start of a closed sub-path it is not split in two.
+///
+/// Curves are split at the dash boundaries. The tolerance is used to approximate the
+/// length of the curves.
+///
+/// If the pattern is empty, contains negative values or only zeros, the path is
+/// copied as is. Sub-paths along which the pattern would repeat more than 2^20
+/// times are also copied without dashes.
+pub fn dash<Iter>(path: Iter, pattern: &[f32], offset: f32, tolerance: f32) -> Path
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let mut output = Path::builder();
+    crate::path::private::dash_into(path, pattern, offset, tolerance, &mut output);
+
+    output.build()
+}
+
+#[cfg(test)]
+use crate::math::{point, Point};
+#[cfg(test)]
+use alloc::vec::Vec;
+
+#[cfg(test)]
+fn dash_endpoints(path: &Path) -> Vec<(Point, Point)> {
+    let mut result = Vec::new();
+    let mut start = point(0.0, 0.0);
+    for evt in path.iter() {
+        match evt {
+            PathEvent::Begin { at } => {
+                start = at;
+            }
+            PathEvent::End { last, .. } => {
+                result.push((start, last));
+            }
+            _ => {}
+        }
+    }
+
+    result
+}
+
+#[test]
+fn dash_line() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(5.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let dashed = dash(&path, &[3.0, 1.0], 0.0, 0.01);
+    assert_eq!(
+        dash_endpoints(&dashed),
+        &[
+            (point(0.0, 0.0), point(3.0, 0.0)),
+            (point(4.0, 0.0), point(7.0, 0.0)),
+            (point(8.0, 0.0), point(10.0, 0.0)),
+        ]
+    );
+    // The second dash goes through the vertex at x = 5.
+    assert_eq!(
+        dashed
+            .iter()
+            .filter(|evt| matches!(evt, PathEvent::Line { .. }))
+            .count(),
+        4
+    );
+
+    // The offset shifts the pattern.
+    let dashed = dash(&path, &[3.0, 1.0], 2.0, 0.01);
+    assert_eq!(
+        dash_endpoints(&dashed),
+        &[
+            (point(0.0, 0.0), point(1.0, 0.0)),
+            (point(2.0, 0.0), point(5.0, 0.0)),
+            (point(6.0, 0.0), point(9.0, 0.0)),
+        ]
+    );
+
+    // Negative offsets wrap around.
+    let dashed = dash(&path, &[3.0, 1.0], -1.0, 0.01);
+    assert_eq!(
+        dash_endpoints(&dashed)[0],
+        (point(1.0, 0.0), point(4.0, 0.0))
+    );
+
+    // Odd patterns are repeated.
+    let dashed = dash(&path, &[2.0], 0.0, 0.01);
+    assert_eq!(
+        dash_endpoints(&dashed),
+        &[
+            (point(0.0, 0.0), point(2.0, 0.0)),
+            (point(4.0, 0.0), point(6.0, 0.0)),
+            (point(8.0, 0.0), point(10.0, 0.0)),
+        ]
+    );
+
+    // Invalid patterns leave the path untouched.
+    let dashed = dash(&path, &[], 0.0, 0.01);
+    assert_eq!(
+        dashed.iter().collect::<Vec<_>>(),
+        path.iter().collect::<Vec<_>>()
+    );
+    let dashed = dash(&path, &[1.0, -1.0], 0.0, 0.01);
+    assert_eq!(dashed.iter().count(), path.iter().count());
+}
+
+#[test]
+fn dash_closed() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(4.0, 0.0));
+    builder.line_to(point(4.0, 4.0));
+    builder.line_to(point(0.0, 4.0));
+    builder.close();
+    let path = builder.build();
+
+    // The last dash continues across the start of the sub-path.
+    let dashed = dash(&path, &[3.0, 2.0], 0.0, 0.01);
+    assert_eq!(
+        dash_endpoints(&dashed),
+        &[
+            (point(0.0, 1.0), point(3.0, 0.0)),
+            (point(4.0, 1.0), point(4.0, 4.0)),
+            (point(2.0, 4.0), point(0.0, 3.0)),
+        ]
+    );
+
+    // A pattern that never has gaps on the path produces a closed sub-path.
+    let dashed = dash(&path, &[20.0, 1.0], 0.0, 0.01);
+    let events: Vec<PathEvent> = dashed.iter().collect();
+    assert_eq!(events.len(), 6);
+    assert!(matches!(events[5], PathEvent::End { close: true, .. }));
+}
+
+#[test]
+fn dash_curve() {
+    use crate::length::approximate_length;
+
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.cubic_bezier_to(point(0.0, 10.0), point(10.0, 10.0), point(10.0, 0.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let length = approximate_length(&path, 0.001);
+    let dashed = dash(&path, &[1.0, 1.0], 0.0, 0.001);
+
+    // Curves are split, not flattened.
+    assert!(dashed
+        .iter()
+        .all(|evt| !matches!(evt, PathEvent::Line { .. })));
+
+    let num_dashes = dash_endpoints(&dashed).len();
+    assert_eq!(num_dashes, (length / 2.0).ceil() as usize);
+
+    // Each dash but the last one has the length of the pattern's dash.
+    let mut builder = Path::builder();
+    for evt in dashed.iter().take(3) {
+        builder.path_event(evt);
+    }
+    let first_dash = builder.build();
+    assert!((approximate_length(&first_dash, 0.001) - 1.0).abs() < 0.01);
+}
+
+#[test]
+fn dash_far_from_the_origin() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(300000.0, 0.0));
+    builder.end(false);
+    let path = builder.build();
+
+    // The dashes are smaller than the precision of the coordinates at the end of the
+    // line, the dashing must still terminate.
+    let dashed = dash(&path, &[0.01, 0.5], 0.0, 0.01);
+    let num_dashes = dash_endpoints(&dashed).len();
+    let expected = (300000.0f64 / 0.51).ceil() as usize;
+    assert!(num_dashes.abs_diff(expected) <= 1);
+
+    // Too many repetitions of the pattern, the line is not dashed.
+    let dashed = dash(&path, &[0.01, 0.01], 0.0, 0.01);
+    assert_eq!(
+        dash_endpoints(&dashed),
+        &[(point(0.0, 0.0), point(300000.0, 0.0))]
+    );
+}
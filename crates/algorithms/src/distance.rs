@@ -0,0 +1,257 @@
+//! Compute the signed distance between points and the boundary of a path.
+//!
+//! This can be used to generate signed distance fields on the CPU.
+//!
+//! # Example
+//!
+//! ```
+//! use lyon_algorithms::distance::signed_distance;
+//! use lyon_algorithms::path::{FillRule, Path, Winding, math::{point, Box2D}};
+//!
+//! let mut builder = Path::builder();
+//! builder.add_rectangle(&Box2D::new(point(0.0, 0.0), point(10.0, 10.0)), Winding::Positive);
+//! let path = builder.build();
+//!
+//! let d = signed_distance(&path, point(2.0, 5.0), FillRule::NonZero, 0.01);
+//! assert!((d + 2.0).abs() < 1e-5);
+//! ```
+
+use crate::geom::{CubicBezierSegment, LineSegment, QuadraticBezierSegment};
+use crate::hit_test::{path_winding_number_at_position, path_winding_numbers_at_positions};
+use crate::math::{point, Box2D, Point};
+use crate::path::{FillRule, PathEvent};
+
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Computes the signed distance between a point and the boundary of a path.
+///
+/// The distance is negative inside of the path according to the fill rule, and positive
+/// outside. Like when filling, open sub-paths are considered closed by a straight line.
+///
+/// The distance to the boundary is computed exactly, using analytic closest-point queries
+/// on each segment, so it is accurate near corners and thin features. The tolerance is
+/// only used to flatten the curves when determining the sign, so that the sign of points
+/// closer than the tolerance to a curve corresponds to the flattened path.
+///
+/// Returns positive infinity if the path is empty.
+pub fn signed_distance<Iter>(path: Iter, point: Point, fill_rule: FillRule, tolerance: f32) -> f32
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let events: Vec<PathEvent> = path.into_iter().collect();
+    let edges = collect_edges(&events);
+
+    let winding = path_winding_number_at_position(&point, events.iter().copied(), tolerance);
+
+    apply_sign(distance(&edges, point), winding, fill_rule)
+}
+
+/// Computes the signed distance to the boundary of a path over a grid of points.
+///
+/// The rectangle is divided into `columns` by `rows` cells, and the distance is evaluated
+/// at the center of each cell, like the texels of a texture covering the rectangle. The
+/// result is stored in row-major order, starting at the minimum corner of the rectangle.
+///
+/// This is equivalent to calling `signed_distance` for each point, except that the path
+/// is traversed and its curves are flattened only once.
+pub fn signed_distance_grid<Iter>(
+    path: Iter,
+    rect: &Box2D,
+    columns: usize,
+    rows: usize,
+    fill_rule: FillRule,
+    tolerance: f32,
+) -> Vec<f32>
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let events: Vec<PathEvent> = path.into_iter().collect();
+    let edges = collect_edges(&events);
+
+    let cell_width = rect.width() / columns as f32;
+    let cell_height = rect.height() / rows as f32;
+    let mut points = Vec::with_capacity(columns * rows);
+    for row in 0..rows {
+        let y = rect.min.y + (row as f32 + 0.5) * cell_height;
+        for column in 0..columns {
+            let x = rect.min.x + (column as f32 + 0.5) * cell_width;
+            points.push(point(x, y));
+        }
+    }
+
+    let windings = path_winding_numbers_at_positions(&points, events.iter().copied(), tolerance);
+
+    points
+        .iter()
+        .zip(windings)
+        .map(|(p, winding)| apply_sign(distance(&edges, *p), winding, fill_rule))
+        .collect()
+}
+
+fn apply_sign(distance: f32, winding: i32, fill_rule: FillRule) -> f32 {
+    let inside = match fill_rule {
+        FillRule::EvenOdd => winding % 2 != 0,
+        FillRule::NonZero => winding != 0,
+    };
+
+    if inside {
+        -distance
+    } else {
+        distance
+    }
+}
+
+enum Edge {
+    Line(LineSegment<f32>),
+    Quadratic(QuadraticBezierSegment<f32>),
+    Cubic(CubicBezierSegment<f32>),
+}
+
+// The edges of the path along with a bounding box of each edge.
+fn collect_edges(events: &[PathEvent]) -> Vec<(Box2D, Edge)> {
+    let mut edges = Vec::with_capacity(events.len());
+    for evt in events {
+        match *evt {
+            PathEvent::Begin { .. } => {}
+            PathEvent::Line { from, to } => {
+                let segment = LineSegment { from, to };
+                edges.push((segment.bounding_box(), Edge::Line(segment)));
+            }
+            PathEvent::Quadratic { from, ctrl, to } => {
+                let segment = QuadraticBezierSegment { from, ctrl, to };
+                edges.push((segment.fast_bounding_box(), Edge::Quadratic(segment)));
+            }
+            PathEvent::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => {
+                let segment = CubicBezierSegment {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                };
+                edges.push((segment.fast_bounding_box(), Edge::Cubic(segment)));
+            }
+            PathEvent::End { last, first, .. } => {
+                // Sub-paths are implicitly closed when filled, so the closing edge is
+                // part of the boundary even if the sub-path is open.
+                let segment = LineSegment {
+                    from: last,
+                    to: first,
+                };
+                edges.push((segment.bounding_box(), Edge::Line(segment)));
+            }
+        }
+    }
+
+    edges
+}
+
+fn distance(edges: &[(Box2D, Edge)], p: Point) -> f32 {
+    let mut min_sq_dist = f32::INFINITY;
+    for (bounds, edge) in edges {
+        // Skip the edges that can't be closer than the closest one so far.
+        let dx = (bounds.min.x - p.x).max(p.x - bounds.max.x).max(0.0);
+        let dy = (bounds.min.y - p.y).max(p.y - bounds.max.y).max(0.0);
+        if dx * dx + dy * dy >= min_sq_dist {
+            continue;
+        }
+
+        let sq_dist = match edge {
+            Edge::Line(segment) => segment.square_distance_to_point(p),
+            Edge::Quadratic(segment) => segment.square_distance_to_point(p),
+            Edge::Cubic(segment) => segment.square_distance_to_point(p),
+        };
+        min_sq_dist = min_sq_dist.min(sq_dist);
+    }
+
+    min_sq_dist.sqrt()
+}
+
+#[test]
+fn signed_distance_to_rectangle() {
+    use crate::path::{Path, Winding};
+
+    let mut builder = Path::builder();
+    builder.add_rectangle(
+        &Box2D::new(point(0.0, 0.0), point(10.0, 10.0)),
+        Winding::Positive,
+    );
+    // A hole.
+    builder.add_rectangle(
+        &Box2D::new(point(4.0, 4.0), point(6.0, 6.0)),
+        Winding::Negative,
+    );
+    let path = builder.build();
+
+    let d = |p: Point, fill_rule| signed_distance(&path, p, fill_rule, 0.01);
+
+    assert!((d(point(1.0, 5.0), FillRule::NonZero) + 1.0).abs() < 1e-5);
+    assert!((d(point(5.0, 1.0), FillRule::EvenOdd) + 1.0).abs() < 1e-5);
+    assert!((d(point(5.0, 12.0), FillRule::NonZero) - 2.0).abs() < 1e-5);
+    // Corners are at the euclidean distance.
+    assert!((d(point(13.0, 14.0), FillRule::NonZero) - 5.0).abs() < 1e-5);
+    assert!((d(point(5.0, 5.0), FillRule::NonZero) - 1.0).abs() < 1e-5);
+    assert!(d(point(3.5, 3.5), FillRule::NonZero) < 0.0);
+
+    let grid = signed_distance_grid(
+        &path,
+        &Box2D::new(point(0.0, 0.0), point(10.0, 10.0)),
+        10,
+        10,
+        FillRule::NonZero,
+        0.01,
+    );
+    assert_eq!(grid.len(), 100);
+    for row in 0..10 {
+        for column in 0..10 {
+            let p = point(column as f32 + 0.5, row as f32 + 0.5);
+            let expected = d(p, FillRule::NonZero);
+            let value = grid[row * 10 + column];
+            assert!((value - expected).abs() < 1e-5, "{:?} {:?}", p, value);
+        }
+    }
+
+    let empty = Path::new();
+    assert_eq!(
+        signed_distance(&empty, point(0.0, 0.0), FillRule::NonZero, 0.01),
+        f32::INFINITY
+    );
+}
+
+#[test]
+fn signed_distance_to_curves() {
+    use crate::path::Path;
+
+    // An open half disc, closed by its diameter.
+    let mut builder = Path::builder();
+    builder.begin(point(-10.0, 0.0));
+    builder.cubic_bezier_to(point(-10.0, 13.3), point(10.0, 13.3), point(10.0, 0.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let curve = CubicBezierSegment {
+        from: point(-10.0, 0.0),
+        ctrl1: point(-10.0, 13.3),
+        ctrl2: point(10.0, 13.3),
+        to: point(10.0, 0.0),
+    };
+
+    for p in [point(0.0, 20.0), point(0.0, 5.0), point(-12.0, 3.0)] {
+        let d = signed_distance(&path, p, FillRule::NonZero, 0.01);
+        let expected = curve.distance_to_point(p);
+        assert!((d.abs() - expected).abs() < 1e-4, "{:?} {:?}", p, d);
+    }
+
+    assert!(signed_distance(&path, point(0.0, 5.0), FillRule::NonZero, 0.01) < 0.0);
+    assert!(signed_distance(&path, point(0.0, 20.0), FillRule::NonZero, 0.01) > 0.0);
+    // Close to the closing edge.
+    let d = signed_distance(&path, point(0.0, -1.0), FillRule::NonZero, 0.01);
+    assert!((d - 1.0).abs() < 1e-5, "{:?}", d);
+}
@@ -1,10 +1,16 @@
-//! Fit paths into rectangles.
+//! Fit paths into rectangles and bézier curves to sequences of points.
 
 use crate::aabb::bounding_box;
+use crate::geom::CubicBezierSegment;
 use crate::math::*;
 use crate::path::iterator::*;
 use crate::path::Path;
 
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
 /// The strategy to use when fitting (stretching, overflow, etc.)
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum FitStyle {
@@ -62,6 +68,245 @@ pub fn fit_path(path: &Path, output_rect: &Box2D, style: FitStyle) -> Path {
     builder.build()
 }
 
+/// Approximates a sequence of points with a path made of as few cubic bézier curves
+/// as possible.
+///
+/// This is the inverse of flattening, useful for example to convert sampled pen
+/// input back into curves. The curves are fitted using Philip J. Schneider's least
+/// squares algorithm ("An Algorithm for Automatically Fitting Digitized Curves",
+/// Graphics Gems, 1990). Each point is within `tolerance` of the point of the curve
+/// it is associated to, which in practice is very close to the distance to the curve.
+///
+/// If `corner_threshold` is provided, the points where the direction of the sequence
+/// changes by more than this angle are treated as corners: the curves are fitted
+/// separately on each side instead of smoothing through them.
+///
+/// The resulting path has a single open sub-path, or no sub-path at all if there
+/// are fewer than two distinct points.
+pub fn fit_cubics(points: &[Point], tolerance: f32, corner_threshold: Option<Angle>) -> Path {
+    let mut pts: Vec<Point> = Vec::with_capacity(points.len());
+    for p in points {
+        if pts.last() != Some(p) {
+            pts.push(*p);
+        }
+    }
+
+    let mut builder = Path::builder();
+    if pts.len() < 2 {
+        return builder.build();
+    }
+
+    let mut start = 0;
+    let mut corners = Vec::new();
+    if let Some(threshold) = corner_threshold {
+        for i in 1..pts.len() - 1 {
+            let angle = (pts[i] - pts[i - 1]).angle_to(pts[i + 1] - pts[i]);
+            if angle.radians.abs() > threshold.radians {
+                corners.push(i);
+            }
+        }
+    }
+    corners.push(pts.len() - 1);
+
+    let tolerance_sq = tolerance * tolerance;
+    builder.begin(pts[0]);
+    for end in corners {
+        let run = &pts[start..=end];
+        let left_tangent = (run[1] - run[0]).normalize();
+        let right_tangent = (run[run.len() - 2] - run[run.len() - 1]).normalize();
+        fit_cubic(
+            run,
+            left_tangent,
+            right_tangent,
+            tolerance_sq,
+            &mut |curve| {
+                builder.cubic_bezier_to(curve.ctrl1, curve.ctrl2, curve.to);
+            },
+        );
+        start = end;
+    }
+    builder.end(false);
+
+    builder.build()
+}
+
+const MAX_REPARAMETERIZATIONS: usize = 8;
+
+// Fits the points with one curve if possible, otherwise splits them at the point
+// with the largest error and recurses.
+fn fit_cubic(
+    points: &[Point],
+    left_tangent: Vector,
+    right_tangent: Vector,
+    tolerance_sq: f32,
+    output: &mut dyn FnMut(&CubicBezierSegment<f32>),
+) {
+    let first = points[0];
+    let last = points[points.len() - 1];
+
+    if points.len() == 2 {
+        let d = (last - first).length() / 3.0;
+        output(&CubicBezierSegment {
+            from: first,
+            ctrl1: first + left_tangent * d,
+            ctrl2: last + right_tangent * d,
+            to: last,
+        });
+        return;
+    }
+
+    let mut params = chord_length_parameters(points);
+    let mut curve = generate_bezier(points, &params, left_tangent, right_tangent);
+    let (mut error, mut split) = max_error(points, &params, &curve);
+    if error <= tolerance_sq {
+        output(&curve);
+        return;
+    }
+
+    // Try to improve the parameterization as long as it reduces the error.
+    for _ in 0..MAX_REPARAMETERIZATIONS {
+        reparameterize(points, &mut params, &curve);
+        curve = generate_bezier(points, &params, left_tangent, right_tangent);
+        let (new_error, idx) = max_error(points, &params, &curve);
+        if new_error <= tolerance_sq {
+            output(&curve);
+            return;
+        }
+        let converging = new_error < error * 0.95;
+        error = new_error;
+        split = idx;
+        if !converging {
+            break;
+        }
+    }
+
+    let mut center_tangent = points[split - 1] - points[split + 1];
+    if center_tangent.square_length() == 0.0 {
+        center_tangent = points[split - 1] - points[split];
+    }
+    let center_tangent = center_tangent.normalize();
+    fit_cubic(
+        &points[..=split],
+        left_tangent,
+        center_tangent,
+        tolerance_sq,
+        output,
+    );
+    fit_cubic(
+        &points[split..],
+        -center_tangent,
+        right_tangent,
+        tolerance_sq,
+        output,
+    );
+}
+
+fn chord_length_parameters(points: &[Point]) -> Vec<f32> {
+    let mut params = Vec::with_capacity(points.len());
+    params.push(0.0);
+    for i in 1..points.len() {
+        let length = params[i - 1] + (points[i] - points[i - 1]).length();
+        params.push(length);
+    }
+    let total = params[points.len() - 1];
+    for t in &mut params {
+        *t /= total;
+    }
+
+    params
+}
+
+// Finds the control points that minimize the squared distance between the points and
+// the curve at the provided parameters, with the tangents at the endpoints fixed.
+fn generate_bezier(
+    points: &[Point],
+    params: &[f32],
+    left_tangent: Vector,
+    right_tangent: Vector,
+) -> CubicBezierSegment<f32> {
+    let first = points[0];
+    let last = points[points.len() - 1];
+
+    let mut c = [[0.0; 2]; 2];
+    let mut x = [0.0; 2];
+    for (p, &t) in points.iter().zip(params) {
+        let mt = 1.0 - t;
+        let b0 = mt * mt * mt;
+        let b1 = 3.0 * t * mt * mt;
+        let b2 = 3.0 * t * t * mt;
+        let b3 = t * t * t;
+        let a0 = left_tangent * b1;
+        let a1 = right_tangent * b2;
+
+        c[0][0] += a0.dot(a0);
+        c[0][1] += a0.dot(a1);
+        c[1][1] += a1.dot(a1);
+
+        let tmp = *p - (first.to_vector() * (b0 + b1) + last.to_vector() * (b2 + b3));
+        x[0] += a0.dot(tmp.to_vector());
+        x[1] += a1.dot(tmp.to_vector());
+    }
+    c[1][0] = c[0][1];
+
+    let det_c0_c1 = c[0][0] * c[1][1] - c[1][0] * c[0][1];
+    let det_c0_x = c[0][0] * x[1] - c[1][0] * x[0];
+    let det_x_c1 = x[0] * c[1][1] - x[1] * c[0][1];
+    let (alpha_l, alpha_r) = if det_c0_c1 == 0.0 {
+        (0.0, 0.0)
+    } else {
+        (det_x_c1 / det_c0_c1, det_c0_x / det_c0_c1)
+    };
+
+    // Fall back to a simple heuristic if the solution is degenerate or places the
+    // control points on the wrong side.
+    let length = (last - first).length();
+    let epsilon = 1.0e-6 * length;
+    let (alpha_l, alpha_r) = if alpha_l < epsilon || alpha_r < epsilon {
+        (length / 3.0, length / 3.0)
+    } else {
+        (alpha_l, alpha_r)
+    };
+
+    CubicBezierSegment {
+        from: first,
+        ctrl1: first + left_tangent * alpha_l,
+        ctrl2: last + right_tangent * alpha_r,
+        to: last,
+    }
+}
+
+// Improves the parameters with a Newton-Raphson iteration on the distance between
+// each point and the curve.
+fn reparameterize(points: &[Point], params: &mut [f32], curve: &CubicBezierSegment<f32>) {
+    for (p, t) in points.iter().zip(params.iter_mut()) {
+        let d = curve.sample(*t) - *p;
+        let d1 = curve.derivative(*t);
+        let mt = 1.0 - *t;
+        let d2 = ((curve.ctrl2 - curve.ctrl1) - (curve.ctrl1 - curve.from)) * (6.0 * mt)
+            + ((curve.to - curve.ctrl2) - (curve.ctrl2 - curve.ctrl1)) * (6.0 * *t);
+        let denominator = d1.dot(d1) + d.dot(d2);
+        if denominator != 0.0 {
+            *t = (*t - d.dot(d1) / denominator).clamp(0.0, 1.0);
+        }
+    }
+}
+
+// Returns the largest squared distance between the points and the curve and the index
+// of the corresponding point.
+fn max_error(points: &[Point], params: &[f32], curve: &CubicBezierSegment<f32>) -> (f32, usize) {
+    let mut max = 0.0;
+    let mut split = points.len() / 2;
+    for i in 1..points.len() - 1 {
+        let d = (curve.sample(params[i]) - points[i]).square_length();
+        if d > max {
+            max = d;
+            split = i;
+        }
+    }
+
+    (max, split)
+}
+
 #[test]
 fn simple_fit() {
     fn approx_eq(a: &Box2D, b: &Box2D) -> bool {
@@ -165,3 +410,90 @@ fn simple_fit() {
         },
     ));
 }
+
+#[test]
+fn fit_cubics_to_points() {
+    use crate::geom::Arc;
+    use crate::path::PathEvent;
+
+    fn num_curves(path: &Path) -> usize {
+        path.iter()
+            .filter(|evt| matches!(evt, PathEvent::Cubic { .. }))
+            .count()
+    }
+
+    // Checks that each point is close to the flattened path.
+    fn check_distance(path: &Path, points: &[Point], tolerance: f32) {
+        let mut edges = Vec::new();
+        for evt in path.iter().flattened(0.001) {
+            if let PathEvent::Line { from, to } = evt {
+                edges.push(crate::geom::LineSegment { from, to });
+            }
+        }
+        for p in points {
+            let d = edges
+                .iter()
+                .map(|e| e.distance_to_point(*p))
+                .fold(f32::MAX, f32::min);
+            assert!(d <= tolerance * 1.01, "{:?} is at distance {}", p, d);
+        }
+    }
+
+    assert_eq!(num_curves(&fit_cubics(&[], 0.1, None)), 0);
+    assert_eq!(num_curves(&fit_cubics(&[point(1.0, 1.0)], 0.1, None)), 0);
+
+    // Points sampled on a cubic bézier curve are fitted with very few curves.
+    let curve = CubicBezierSegment {
+        from: point(0.0, 0.0),
+        ctrl1: point(10.0, 20.0),
+        ctrl2: point(30.0, 20.0),
+        to: point(40.0, 0.0),
+    };
+    let points: Vec<Point> = (0..=20).map(|i| curve.sample(i as f32 / 20.0)).collect();
+    let path = fit_cubics(&points, 0.05, None);
+    assert!(num_curves(&path) <= 3);
+    check_distance(&path, &points, 0.05);
+
+    // A circle needs a few curves.
+    let arc = Arc {
+        center: point(0.0, 0.0),
+        radii: vector(50.0, 50.0),
+        start_angle: Angle::radians(0.0),
+        sweep_angle: Angle::two_pi(),
+        x_rotation: Angle::radians(0.0),
+    };
+    let points: Vec<Point> = (0..=100).map(|i| arc.sample(i as f32 / 100.0)).collect();
+    let path = fit_cubics(&points, 0.1, None);
+    assert!(num_curves(&path) >= 2);
+    assert!(num_curves(&path) <= 8);
+    check_distance(&path, &points, 0.1);
+
+    // Noisy-ish sine wave.
+    let points: Vec<Point> = (0..200)
+        .map(|i| {
+            let x = i as f32 * 0.5;
+            point(
+                x,
+                (x * 0.2).sin() * 10.0 + if i % 2 == 0 { 0.02 } else { -0.02 },
+            )
+        })
+        .collect();
+    let path = fit_cubics(&points, 0.5, None);
+    assert!(num_curves(&path) < 20);
+    check_distance(&path, &points, 0.5);
+
+    // With corner detection, the corner of a "V" is preserved.
+    let mut points = Vec::new();
+    for i in 0..=10 {
+        points.push(point(i as f32, i as f32));
+    }
+    for i in 11..=20 {
+        points.push(point(i as f32, 20.0 - i as f32));
+    }
+    let path = fit_cubics(&points, 0.01, Some(Angle::degrees(45.0)));
+    assert_eq!(num_curves(&path), 2);
+    assert!(path
+        .iter()
+        .any(|evt| matches!(evt, PathEvent::Cubic { to, .. } if to == point(10.0, 10.0))));
+    check_distance(&path, &points, 0.01);
+}
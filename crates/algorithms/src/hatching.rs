@@ -38,6 +38,9 @@ use core::mem;
 
 use alloc::vec::Vec;
 
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
 /// Parameters for the hatcher.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(
@@ -63,6 +66,24 @@ pub struct HatchingOptions {
 
     /// The origin of the rotated uv coordinates.
     pub uv_origin: Point,
+
+    /// If specified, a second set of hatches is generated at this angle.
+    ///
+    /// The second set of hatches is generated after the first one and its rows are
+    /// numbered from zero.
+    ///
+    /// This only applies to `Hatcher::hatch_path`. Dot patterns are generated at a
+    /// single angle.
+    ///
+    /// Default value: `None`.
+    pub cross_angle: Option<Angle>,
+
+    /// If specified, the hatch lines are dashed.
+    ///
+    /// This only applies to `Hatcher::hatch_path`.
+    ///
+    /// Default value: `None`.
+    pub dash_pattern: Option<HatchDashPattern>,
 }
 
 impl Default for HatchingOptions {
@@ -87,6 +108,8 @@ impl HatchingOptions {
         angle: Self::DEFAULT_ANGLE,
         compute_tangents: true,
         uv_origin: Self::DEFAULT_UV_ORIGIN,
+        cross_angle: None,
+        dash_pattern: None,
     };
 
     #[inline]
@@ -116,6 +139,43 @@ impl HatchingOptions {
         self.compute_tangents = compute_tangents;
         self
     }
+
+    #[inline]
+    pub fn with_cross_angle(mut self, cross_angle: Angle) -> Self {
+        self.cross_angle = Some(cross_angle);
+        self
+    }
+
+    #[inline]
+    pub fn with_dash_pattern(mut self, dash_pattern: HatchDashPattern) -> Self {
+        self.dash_pattern = Some(dash_pattern);
+        self
+    }
+}
+
+/// A dash pattern applied to each hatch line.
+///
+/// The pattern is aligned with the `u` coordinate of the hatch segments, so that the
+/// dashes of consecutive rows line up regardless of the shape of the path. Endpoints
+/// that are not on the outline of the path have a `NaN` tangent.
+///
+/// Patterns with a non-positive dash or gap length are ignored. Hatch lines that would
+/// be split into more than `MAX_DASHES_PER_SEGMENT` dashes are not dashed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct HatchDashPattern {
+    /// Length of the dashes.
+    pub dash: f32,
+    /// Length of the gaps between dashes.
+    pub gap: f32,
+}
+
+impl HatchDashPattern {
+    /// Maximum number of dashes generated for a single hatch segment.
+    pub const MAX_DASHES_PER_SEGMENT: u32 = 1 << 20;
 }
 
 /// Parameters for generating dot patterns.
@@ -274,6 +334,7 @@ pub struct Hatcher {
     compute_tangents: bool,
     segment: HatchSegment,
     uv_origin: Point,
+    dash_pattern: Option<HatchDashPattern>,
 }
 
 impl Default for Hatcher {
@@ -305,6 +366,7 @@ impl Hatcher {
                 v: 0.0,
             },
             uv_origin: point(0.0, 0.0),
+            dash_pattern: None,
         }
     }
 
@@ -320,7 +382,12 @@ impl Hatcher {
         let mut events = mem::replace(&mut self.events, HatchingEvents::new());
         events.set_path(options.tolerance, options.angle, it);
 
-        self.hatch(&events, options, output);
+        self.hatch(&events, options.angle, options, output);
+
+        if let Some(cross_angle) = options.cross_angle {
+            events.rotate(cross_angle - options.angle);
+            self.hatch(&events, cross_angle, options, output);
+        }
 
         self.events = events;
     }
@@ -341,16 +408,20 @@ impl Hatcher {
     fn hatch(
         &mut self,
         events: &HatchingEvents,
+        angle: Angle,
         options: &HatchingOptions,
         output: &mut dyn HatchBuilder,
     ) {
-        self.transform = Rotation::new(-options.angle);
-        self.uv_origin = Rotation::new(options.angle).transform_point(options.uv_origin);
+        self.transform = Rotation::new(-angle);
+        self.uv_origin = Rotation::new(angle).transform_point(options.uv_origin);
         self.active_edges.clear();
         self.segment.row = 0;
         self.segment.a.tangent = vector(f32::NAN, f32::NAN);
         self.segment.b.tangent = vector(f32::NAN, f32::NAN);
         self.compute_tangents = options.compute_tangents;
+        self.dash_pattern = options
+            .dash_pattern
+            .filter(|p| p.dash > 0.0 && p.gap > 0.0 && (p.dash + p.gap).is_finite());
 
         let mut y = events.edges.first().unwrap().from.y + output.next_offset(0);
         let mut y_max = y;
@@ -390,9 +461,11 @@ impl Hatcher {
             angle: options.angle,
             uv_origin: options.uv_origin,
             compute_tangents: false,
+            cross_angle: None,
+            dash_pattern: None,
         };
 
-        self.hatch(events, &options, &mut dotted);
+        self.hatch(events, options.angle, &options, &mut dotted);
     }
 
     fn update_sweep_line(&mut self, edge: &Edge) {
@@ -411,7 +484,8 @@ impl Hatcher {
         let mut tangent = vector(f32::NAN, f32::NAN);
         self.segment.v = y - self.uv_origin.y;
 
-        for active_edge in &self.active_edges {
+        let active_edges = mem::take(&mut self.active_edges);
+        for active_edge in &active_edges {
             if active_edge.to.y <= y {
                 // TODO: we don't remove the edges during merge events so we can
                 // end up with extra edges that end above the sweep line and have
@@ -428,25 +502,62 @@ impl Hatcher {
             }
 
             if inside {
-                self.segment.a.position = self.transform.transform_point(point(prev_x, y));
-                self.segment.b.position = self.transform.transform_point(point(x, y));
-                self.segment.a.u = prev_x - self.uv_origin.x;
-                self.segment.b.u = x - self.uv_origin.x;
-                if self.compute_tangents {
-                    self.segment.a.tangent = prev_tangent;
-                    self.segment.b.tangent = tangent;
+                let u0 = prev_x - self.uv_origin.x;
+                let u1 = x - self.uv_origin.x;
+                if let Some(dashes) = self.dash_pattern {
+                    let no_tangent = vector(f32::NAN, f32::NAN);
+                    // Use an integer number of periods computed up front rather than
+                    // accumulating floats, which stop increasing with large coordinates.
+                    let period = (dashes.dash + dashes.gap) as f64;
+                    let first = (u0 as f64 / period).floor();
+                    let count = (u1 as f64 / period).ceil() - first;
+                    if count > HatchDashPattern::MAX_DASHES_PER_SEGMENT as f64 {
+                        self.add_segment(y, (u0, prev_tangent), (u1, tangent), output);
+                    } else {
+                        for i in 0..count as u32 {
+                            let dash_start = (first + i as f64) * period;
+                            let a = (dash_start as f32).max(u0);
+                            let b = ((dash_start + dashes.dash as f64) as f32).min(u1);
+                            if a < b {
+                                let ta = if a == u0 { prev_tangent } else { no_tangent };
+                                let tb = if b == u1 { tangent } else { no_tangent };
+                                self.add_segment(y, (a, ta), (b, tb), output);
+                            }
+                        }
+                    }
+                } else {
+                    self.add_segment(y, (u0, prev_tangent), (u1, tangent), output);
                 }
-
-                output.add_segment(&self.segment);
             }
 
             inside = !inside;
             prev_x = x;
             prev_tangent = tangent;
         }
+        self.active_edges = active_edges;
 
         self.segment.row += 1;
     }
+
+    fn add_segment(
+        &mut self,
+        y: f32,
+        a: (f32, Vector),
+        b: (f32, Vector),
+        output: &mut dyn HatchBuilder,
+    ) {
+        let origin = self.uv_origin;
+        self.segment.a.position = self.transform.transform_point(point(a.0 + origin.x, y));
+        self.segment.b.position = self.transform.transform_point(point(b.0 + origin.x, y));
+        self.segment.a.u = a.0;
+        self.segment.b.u = b.0;
+        if self.compute_tangents {
+            self.segment.a.tangent = a.1;
+            self.segment.b.tangent = b.1;
+        }
+
+        output.add_segment(&self.segment);
+    }
 }
 
 struct HatchingEvents {
@@ -591,6 +702,22 @@ impl HatchingEvents {
         }
         mem::swap(self, &mut builder.build());
     }
+
+    // Rotates the edges of the events, for example to generate hatches at a different angle
+    // without going through the path again.
+    fn rotate(&mut self, angle: Angle) {
+        let rotation = Rotation::new(angle);
+        for edge in &mut self.edges {
+            let mut from = rotation.transform_point(edge.from);
+            let mut to = rotation.transform_point(edge.to);
+            if compare_positions(from, to) == Ordering::Greater {
+                mem::swap(&mut from, &mut to);
+            }
+            *edge = Edge { from, to };
+        }
+
+        self.edges.sort_by(|a, b| compare_positions(a.from, b.from));
+    }
 }
 
 #[derive(PartialEq)]
@@ -753,3 +880,102 @@ fn simple_hatching() {
     );
     let _ = hatches.build();
 }
+
+#[test]
+fn cross_hatching_and_dashes() {
+    use crate::math::Box2D;
+    use lyon_path::Path;
+
+    let mut builder = Path::builder();
+    builder.add_rectangle(
+        &Box2D {
+            min: point(0.0, 0.0),
+            max: point(10.0, 10.0),
+        },
+        lyon_path::Winding::Positive,
+    );
+    let path = builder.build();
+
+    let mut hatcher = Hatcher::new();
+
+    let mut segments = Vec::new();
+    hatcher.hatch_path(
+        path.iter(),
+        &HatchingOptions::DEFAULT.with_cross_angle(Angle::degrees(90.0)),
+        &mut RegularHatchingPattern {
+            interval: 1.0,
+            callback: |segment: &HatchSegment| {
+                segments.push((segment.a.position, segment.b.position));
+            },
+        },
+    );
+
+    let horizontal = segments.iter().filter(|(a, b)| (a.y - b.y).abs() < 1e-4);
+    let vertical = segments.iter().filter(|(a, b)| (a.x - b.x).abs() < 1e-4);
+    assert_eq!(horizontal.count(), 9);
+    assert_eq!(vertical.count(), 9);
+    assert_eq!(segments.len(), 18);
+    for (a, b) in &segments {
+        assert!(((*b - *a).length() - 10.0).abs() < 1e-3);
+    }
+
+    let mut segments = Vec::new();
+    let dashes = HatchDashPattern {
+        dash: 1.0,
+        gap: 1.5,
+    };
+    hatcher.hatch_path(
+        path.iter(),
+        &HatchingOptions::DEFAULT.with_dash_pattern(dashes),
+        &mut RegularHatchingPattern {
+            interval: 1.0,
+            callback: |segment: &HatchSegment| {
+                segments.push((segment.a.u, segment.b.u, segment.row));
+            },
+        },
+    );
+
+    // Dashes [0, 1], [2.5, 3.5], [5, 6] and [7.5, 8.5] on each row.
+    assert_eq!(segments.len(), 4 * 9);
+    for (i, (u0, u1, row)) in segments.iter().enumerate() {
+        let expected = (i % 4) as f32 * 2.5;
+        assert_eq!(*row, (i / 4) as u32);
+        assert!((u0 - expected).abs() < 1e-4);
+        assert!((u1 - expected - 1.0).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn dashes_far_from_the_origin() {
+    use crate::math::Box2D;
+    use lyon_path::Path;
+
+    let mut builder = Path::builder();
+    builder.add_rectangle(
+        &Box2D {
+            min: point(40000.0, 0.0),
+            max: point(40001.0, 2.0),
+        },
+        lyon_path::Winding::Positive,
+    );
+    let path = builder.build();
+
+    let mut hatcher = Hatcher::new();
+    let mut num_segments = 0;
+    hatcher.hatch_path(
+        path.iter(),
+        &HatchingOptions::DEFAULT.with_dash_pattern(HatchDashPattern {
+            dash: 0.001,
+            gap: 0.001,
+        }),
+        &mut RegularHatchingPattern {
+            interval: 1.0,
+            callback: |_: &HatchSegment| {
+                num_segments += 1;
+            },
+        },
+    );
+
+    // The dashes are below the precision of the coordinates, but the hatcher terminates.
+    assert!(num_segments > 0);
+}
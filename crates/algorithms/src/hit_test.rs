@@ -4,6 +4,9 @@ use crate::geom::{CubicBezierSegment, LineSegment, QuadraticBezierSegment};
 use crate::math::Point;
 use crate::path::{FillRule, PathEvent};
 
+use alloc::vec;
+use alloc::vec::Vec;
+
 /// Returns whether the point is inside the path.
 pub fn hit_test_path<Iter>(point: &Point, path: Iter, fill_rule: FillRule, tolerance: f32) -> bool
 where
@@ -27,34 +30,96 @@ where
     // left of it.
     let mut winding = 0;
 
+    for_each_edge(path, point.y, point.y, tolerance, &mut |edge| {
+        test_segment(*point, edge, &mut winding);
+    });
+
+    winding
+}
+
+/// Returns whether each of the points is inside the path.
+///
+/// This is equivalent to calling `hit_test_path` for each point, except that the
+/// path is traversed and its curves are flattened only once.
+pub fn hit_test_points<Iter>(
+    points: &[Point],
+    path: Iter,
+    fill_rule: FillRule,
+    tolerance: f32,
+) -> Vec<bool>
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    path_winding_numbers_at_positions(points, path, tolerance)
+        .into_iter()
+        .map(|winding| match fill_rule {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+        })
+        .collect()
+}
+
+/// Compute the winding numbers of several positions with respect to the path.
+pub fn path_winding_numbers_at_positions<Iter>(
+    points: &[Point],
+    path: Iter,
+    tolerance: f32,
+) -> Vec<i32>
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let mut windings = vec![0; points.len()];
+    if points.is_empty() {
+        return windings;
+    }
+
+    let mut min_y = points[0].y;
+    let mut max_y = points[0].y;
+    for point in &points[1..] {
+        min_y = f32::min(min_y, point.y);
+        max_y = f32::max(max_y, point.y);
+    }
+
+    for_each_edge(path, min_y, max_y, tolerance, &mut |edge| {
+        for (point, winding) in points.iter().zip(windings.iter_mut()) {
+            test_segment(*point, edge, winding);
+        }
+    });
+
+    windings
+}
+
+// Calls the callback for each edge of the path, flattening the curves that overlap
+// the vertical range between min_y and max_y.
+fn for_each_edge<Iter>(
+    path: Iter,
+    min_y: f32,
+    max_y: f32,
+    tolerance: f32,
+    callback: &mut dyn FnMut(&LineSegment<f32>),
+) where
+    Iter: IntoIterator<Item = PathEvent>,
+{
     for evt in path {
         match evt {
             PathEvent::Begin { .. } => {}
             PathEvent::Line { from, to } => {
-                test_segment(
-                    *point,
-                    &LineSegment { from, to },
-                    &mut winding,
-                );
+                callback(&LineSegment { from, to });
             }
             PathEvent::End { last, first, .. } => {
-                test_segment(
-                    *point,
-                    &LineSegment {
-                        from: last,
-                        to: first,
-                    },
-                    &mut winding,
-                );
+                callback(&LineSegment {
+                    from: last,
+                    to: first,
+                });
             }
             PathEvent::Quadratic { from, ctrl, to } => {
                 let segment = QuadraticBezierSegment { from, ctrl, to };
                 let (min, max) = segment.fast_bounding_range_y();
-                if min > point.y || max < point.y {
+                if min > max_y || max < min_y {
                     continue;
                 }
                 segment.for_each_flattened(tolerance, &mut |line| {
-                    test_segment(*point, line, &mut winding);
+                    callback(line);
                 });
             }
             PathEvent::Cubic {
@@ -70,17 +135,15 @@ where
                     to,
                 };
                 let (min, max) = segment.fast_bounding_range_y();
-                if min > point.y || max < point.y {
+                if min > max_y || max < min_y {
                     continue;
                 }
                 segment.for_each_flattened(tolerance, &mut |line| {
-                    test_segment(*point, line, &mut winding);
+                    callback(line);
                 });
             }
         }
     }
-
-    winding
 }
 
 fn test_segment(
@@ -295,4 +358,51 @@ fn issue_882() {
     let x = point(55.0, 50.0);
 
     assert!(hit_test_path(&x, p.iter(), FillRule::EvenOdd, 1.0))
+}
+
+#[test]
+fn hit_test_many_points() {
+    use crate::math::point;
+    use crate::path::Path;
+
+    // Two overlapping circles made of cubic béziers, and a quadratic bump.
+    let mut builder = Path::builder();
+    builder.add_circle(point(0.0, 0.0), 2.0, crate::path::Winding::Positive);
+    builder.add_circle(point(1.0, 0.0), 2.0, crate::path::Winding::Positive);
+    builder.begin(point(10.0, 0.0));
+    builder.quadratic_bezier_to(point(12.0, 4.0), point(14.0, 0.0));
+    builder.end(true);
+    let path = builder.build();
+
+    let points = [
+        point(0.5, 0.0),
+        point(-1.8, 0.0),
+        point(2.8, 0.0),
+        point(5.0, 0.0),
+        point(12.0, 1.5),
+        point(12.0, 2.5),
+        point(0.5, 1.9),
+    ];
+
+    for fill_rule in [FillRule::EvenOdd, FillRule::NonZero] {
+        let results = hit_test_points(&points, path.iter(), fill_rule, 0.01);
+        assert_eq!(results.len(), points.len());
+        for (point, result) in points.iter().zip(results.iter()) {
+            assert_eq!(
+                *result,
+                hit_test_path(point, path.iter(), fill_rule, 0.01),
+                "{point:?} {fill_rule:?}"
+            );
+        }
+    }
+
+    assert_eq!(
+        hit_test_points(&points, path.iter(), FillRule::EvenOdd, 0.01),
+        [false, true, true, false, true, false, false]
+    );
+    assert_eq!(
+        hit_test_points(&points, path.iter(), FillRule::NonZero, 0.01),
+        [true, true, true, false, true, false, true]
+    );
+    assert!(hit_test_points(&[], path.iter(), FillRule::NonZero, 0.01).is_empty());
 }
\ No newline at end of file
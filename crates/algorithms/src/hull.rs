@@ -0,0 +1,202 @@
+//! Convex hull computation for paths.
+//!
+//! The hull is computed with the monotone chain algorithm and returned as a list of points
+//! that can be turned into a `Polygon`.
+//!
+//! # Example
+//!
+//! ```
+//! use lyon_algorithms::hull::convex_hull;
+//! use lyon_algorithms::path::{Path, Polygon, math::point};
+//!
+//! let mut builder = Path::builder();
+//! builder.begin(point(0.0, 0.0));
+//! builder.line_to(point(1.0, 0.5));
+//! builder.line_to(point(2.0, 0.0));
+//! builder.quadratic_bezier_to(point(2.0, 2.0), point(0.0, 2.0));
+//! builder.end(true);
+//! let path = builder.build();
+//!
+//! let hull = convex_hull(&path);
+//! assert_eq!(hull, &[point(0.0, 0.0), point(2.0, 0.0), point(2.0, 2.0), point(0.0, 2.0)]);
+//!
+//! // Feed the hull back into a path builder.
+//! let mut builder = Path::builder();
+//! builder.add_polygon(Polygon { points: &hull, closed: true });
+//! ```
+
+use crate::math::Point;
+use crate::path::{iterator::PathIterator, PathEvent};
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Computes the convex hull of a path, including the control points of its curves.
+///
+/// Since bézier curves are contained in the convex hull of their control points, the
+/// result contains the whole path but may be larger than the hull of the curves.
+/// See also [`flattened_convex_hull`](fn.flattened_convex_hull.html).
+///
+/// The points of the hull are returned in counter-clockwise order in a y-up coordinate
+/// system (clockwise with y pointing down), starting with the point with the smallest
+/// x coordinate.
+pub fn convex_hull<Iter>(path: Iter) -> Vec<Point>
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let mut points = Vec::new();
+    for evt in path {
+        match evt {
+            PathEvent::Begin { at } => {
+                points.push(at);
+            }
+            PathEvent::Line { to, .. } => {
+                points.push(to);
+            }
+            PathEvent::Quadratic { ctrl, to, .. } => {
+                points.push(ctrl);
+                points.push(to);
+            }
+            PathEvent::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => {
+                points.push(ctrl1);
+                points.push(ctrl2);
+                points.push(to);
+            }
+            PathEvent::End { .. } => {}
+        }
+    }
+
+    convex_hull_in_place(&mut points);
+
+    points
+}
+
+/// Computes the convex hull of an approximation of a path where curves are flattened.
+///
+/// The result follows the curves more closely than [`convex_hull`](fn.convex_hull.html),
+/// however curves can be outside of the hull by up to the tolerance threshold.
+pub fn flattened_convex_hull<Iter>(path: Iter, tolerance: f32) -> Vec<Point>
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    convex_hull(path.into_iter().flattened(tolerance))
+}
+
+/// Computes the convex hull of a set of points.
+///
+/// See [`convex_hull`](fn.convex_hull.html) for the order of the points.
+pub fn convex_hull_of_points(points: &[Point]) -> Vec<Point> {
+    let mut output = points.to_vec();
+    convex_hull_in_place(&mut output);
+
+    output
+}
+
+fn convex_hull_in_place(points: &mut Vec<Point>) {
+    points.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+    });
+    points.dedup();
+
+    if points.len() < 3 {
+        return;
+    }
+
+    // Points that make a clockwise turn (or are aligned) are removed.
+    let turns_left = |hull: &[Point], p: Point| {
+        let n = hull.len();
+        (hull[n - 1] - hull[n - 2]).cross(p - hull[n - 1]) > 0.0
+    };
+
+    let mut hull: Vec<Point> = Vec::with_capacity(points.len() + 1);
+
+    // Lower hull.
+    for p in points.iter() {
+        while hull.len() >= 2 && !turns_left(&hull, *p) {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+
+    // Upper hull.
+    let lower_len = hull.len() + 1;
+    for p in points.iter().rev().skip(1) {
+        while hull.len() >= lower_len && !turns_left(&hull, *p) {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+
+    // The last point is the same as the first one.
+    hull.pop();
+
+    *points = hull;
+}
+
+#[cfg(test)]
+use crate::math::point;
+
+#[test]
+fn hull_of_points() {
+    let points = [
+        point(1.0, 1.0),
+        point(0.0, 0.0),
+        point(2.0, 0.0),
+        point(1.0, 0.0),
+        point(1.0, 0.5),
+        point(2.0, 2.0),
+        point(0.0, 2.0),
+        point(2.0, 2.0),
+        point(1.0, 2.0),
+    ];
+
+    assert_eq!(
+        convex_hull_of_points(&points),
+        &[
+            point(0.0, 0.0),
+            point(2.0, 0.0),
+            point(2.0, 2.0),
+            point(0.0, 2.0)
+        ]
+    );
+
+    // Degenerate cases.
+    assert!(convex_hull_of_points(&[]).is_empty());
+    assert_eq!(
+        convex_hull_of_points(&[point(1.0, 1.0), point(1.0, 1.0)]),
+        &[point(1.0, 1.0)]
+    );
+    assert_eq!(
+        convex_hull_of_points(&[point(0.0, 0.0), point(2.0, 2.0), point(1.0, 1.0)]),
+        &[point(0.0, 0.0), point(2.0, 2.0)]
+    );
+}
+
+#[test]
+fn hull_of_curves() {
+    use crate::path::Path;
+
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.quadratic_bezier_to(point(1.0, 2.0), point(2.0, 0.0));
+    builder.end(false);
+    let path = builder.build();
+
+    // The control point is part of the hull.
+    assert_eq!(
+        convex_hull(&path),
+        &[point(0.0, 0.0), point(2.0, 0.0), point(1.0, 2.0)]
+    );
+
+    // The flattened hull follows the curve, which peaks at y = 1.0.
+    let hull = flattened_convex_hull(&path, 0.01);
+    assert!(hull.len() > 3);
+    for p in &hull {
+        assert!(p.y >= 0.0 && p.y <= 1.0);
+    }
+    assert!(hull.iter().any(|p| (p.y - 1.0).abs() < 0.01));
+}
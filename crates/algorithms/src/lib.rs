@@ -16,14 +16,22 @@ pub extern crate lyon_path as path;
 
 pub mod aabb;
 pub mod area;
+pub mod clip;
+pub mod dash;
+pub mod distance;
 pub mod fit;
 pub mod hatching;
 pub mod hit_test;
+pub mod hull;
 pub mod length;
 pub mod measure;
+pub mod morph;
 pub mod raycast;
 pub mod rect;
 pub mod rounded_polygon;
+pub mod simplify;
+pub mod smooth;
+pub mod stroke;
 pub mod walk;
 pub mod winding;
 
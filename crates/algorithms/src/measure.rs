@@ -285,6 +285,20 @@ impl PathMeasurements {
         }
     }
 
+    /// Samples the positions and tangents of `count` points evenly spaced along the path,
+    /// including both of its endpoints.
+    ///
+    /// The provided positions must be the ones used when initializing the path measurements.
+    /// See `PathSampler::sample_uniform`.
+    pub fn sample_uniform<PS: PositionStore>(
+        &self,
+        positions: &PS,
+        count: usize,
+    ) -> Vec<(Point, Vector)> {
+        self.create_sampler(positions, SampleType::Distance)
+            .sample_uniform(count)
+    }
+
     /// Create an object that can perform fast sample queries on a path using the cached measurements.
     ///
     /// The returned sampler does not compute interpolated attributes.
@@ -359,6 +373,34 @@ impl<'l, PS: PositionStore, AS: AttributeStore> PathSampler<'l, PS, AS> {
         self.sample_impl(dist, self.sample_type)
     }
 
+    /// Samples the positions and tangents of `count` points evenly spaced along the path,
+    /// including both of its endpoints.
+    ///
+    /// The samples are taken in order, so the path is only walked once regardless of
+    /// the number of samples. If `count` is one, the start of the path is sampled.
+    /// If the path is empty, the samples contain NaNs.
+    pub fn sample_uniform(&mut self, count: usize) -> Vec<(Point, Vector)> {
+        let mut samples = Vec::with_capacity(count);
+        let length = self.length();
+        let step = if count > 1 {
+            length / (count - 1) as f32
+        } else {
+            0.0
+        };
+
+        for i in 0..count {
+            let dist = if i + 1 == count && count > 1 {
+                length
+            } else {
+                step * i as f32
+            };
+            let sample = self.sample_impl(dist, SampleType::Distance);
+            samples.push((sample.position, sample.tangent));
+        }
+
+        samples
+    }
+
     /// Construct a path for a specific sub-range of the measured path.
     ///
     /// The path measurements must have been initialized with the same path.
@@ -415,6 +457,29 @@ impl<'l, PS: PositionStore, AS: AttributeStore> PathSampler<'l, PS, AS> {
         output.end(false);
     }
 
+    /// Split the measured path at a given distance, returning the parts before and after it.
+    ///
+    /// Curves are split without being flattened and custom attributes are interpolated at
+    /// the split point. The distance is clamped to the beginning and end of the path, so
+    /// one of the two parts is empty if the distance is outside of the path.
+    ///
+    /// See also `split_range`.
+    pub fn split_at(&mut self, dist: f32) -> (Path, Path) {
+        let end = match self.sample_type {
+            SampleType::Distance => self.length(),
+            SampleType::Normalized => 1.0,
+        };
+        let num_attributes = self.attributes.num_attributes();
+
+        let mut before = Path::builder_with_attributes(num_attributes);
+        self.split_range(0.0..dist, &mut before);
+
+        let mut after = Path::builder_with_attributes(num_attributes);
+        self.split_range(dist..end, &mut after);
+
+        (before.build(), after.build())
+    }
+
     /// Returns the approximate length of the path.
     pub fn length(&self) -> f32 {
         if self.edges.is_empty() {
@@ -795,6 +860,58 @@ fn measure_bezier_curve() {
     }
 }
 
+#[test]
+fn sample_uniform() {
+    let mut path = Path::builder();
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(1.0, 0.0));
+    path.line_to(point(1.0, 1.0));
+    path.line_to(point(0.0, 1.0));
+    path.close();
+    let path = path.build();
+    let measure = PathMeasurements::from_path(&path, 0.01);
+
+    let samples = measure.sample_uniform(&path, 9);
+    assert_eq!(samples.len(), 9);
+    let expected = [
+        (point(0.0, 0.0), vector(1.0, 0.0)),
+        (point(0.5, 0.0), vector(1.0, 0.0)),
+        (point(1.0, 0.0), vector(1.0, 0.0)),
+        (point(1.0, 0.5), vector(0.0, 1.0)),
+        (point(1.0, 1.0), vector(0.0, 1.0)),
+        (point(0.5, 1.0), vector(-1.0, 0.0)),
+        (point(0.0, 1.0), vector(-1.0, 0.0)),
+        (point(0.0, 0.5), vector(0.0, -1.0)),
+        (point(0.0, 0.0), vector(0.0, -1.0)),
+    ];
+    for (sample, expected) in samples.iter().zip(expected.iter()) {
+        assert!((sample.0 - expected.0).length() < 1e-5);
+        assert_eq!(sample.1, expected.1);
+    }
+
+    assert!(measure.sample_uniform(&path, 0).is_empty());
+    assert_eq!(
+        measure.sample_uniform(&path, 1),
+        &[(point(0.0, 0.0), vector(1.0, 0.0))]
+    );
+
+    let mut path = Path::builder();
+    path.begin(point(0.0, 0.0));
+    path.cubic_bezier_to(point(1.0, 2.0), point(2.0, -2.0), point(3.0, 0.0));
+    path.end(false);
+    let path = path.build();
+    let measure = PathMeasurements::from_path(&path, 0.001);
+
+    let samples = measure.sample_uniform(&path, 100);
+    assert_eq!(samples[0].0, point(0.0, 0.0));
+    assert_eq!(samples[99].0, point(3.0, 0.0));
+    // Consecutive samples are evenly spaced, up to the precision of the measurements.
+    let step = measure.length() / 99.0;
+    for pair in samples.windows(2) {
+        assert!(((pair[1].0 - pair[0].0).length() - step).abs() < 0.002);
+    }
+}
+
 #[test]
 fn split_square() {
     use crate::path::Event;
@@ -923,6 +1040,80 @@ fn split_attributes() {
     );
 }
 
+#[test]
+fn split_at() {
+    use crate::path::Event;
+
+    let mut path = Path::builder_with_attributes(1);
+    path.begin(point(0.0, 0.0), &[0.0]);
+    path.line_to(point(10.0, 0.0), &[1.0]);
+    path.quadratic_bezier_to(point(20.0, 0.0), point(20.0, 10.0), &[2.0]);
+    path.end(false);
+    let path = path.build();
+    let measure = PathMeasurements::from_path(&path, 0.01);
+    let mut sampler = measure.create_sampler_with_attributes(&path, &path, SampleType::Distance);
+
+    let (before, after) = sampler.split_at(5.0);
+    assert_eq!(
+        before.iter_with_attributes().collect::<Vec<_>>(),
+        alloc::vec![
+            Event::Begin {
+                at: (point(0.0, 0.0), slice(&[0.0]))
+            },
+            Event::Line {
+                from: (point(0.0, 0.0), slice(&[0.0])),
+                to: (point(5.0, 0.0), slice(&[0.5])),
+            },
+            Event::End {
+                last: (point(5.0, 0.0), slice(&[0.5])),
+                first: (point(0.0, 0.0), slice(&[0.0])),
+                close: false
+            }
+        ]
+    );
+    let mut after_events = after.iter_with_attributes();
+    assert_eq!(
+        after_events.next(),
+        Some(Event::Begin {
+            at: (point(5.0, 0.0), slice(&[0.5]))
+        })
+    );
+    after_events.next();
+    // The curve is preserved.
+    match after_events.next() {
+        Some(Event::Quadratic { ctrl, .. }) => assert_eq!(ctrl, point(20.0, 0.0)),
+        evt => panic!("Unexpected event {:?}", evt),
+    }
+
+    // Splitting in the middle of the curve.
+    let length = sampler.length();
+    let (before, after) = sampler.split_at(length - 1.0);
+    let before_length = PathMeasurements::from_path(&before, 0.01).length();
+    let after_length = PathMeasurements::from_path(&after, 0.01).length();
+    assert!((before_length - (length - 1.0)).abs() < 0.01);
+    assert!((after_length - 1.0).abs() < 0.01);
+    assert!(before
+        .iter()
+        .any(|evt| matches!(evt, crate::path::PathEvent::Quadratic { .. })));
+    assert!(after
+        .iter()
+        .all(|evt| !matches!(evt, crate::path::PathEvent::Line { .. })));
+
+    // Out of range distances.
+    let (before, after) = sampler.split_at(-1.0);
+    assert_eq!(before.iter().count(), 0);
+    assert_eq!(after.iter().count(), path.iter().count());
+    let (before, after) = sampler.split_at(length + 1.0);
+    assert_eq!(before.iter().count(), path.iter().count());
+    assert_eq!(after.iter().count(), 0);
+
+    // Normalized distances.
+    let mut sampler = measure.create_sampler(&path, SampleType::Normalized);
+    let (before, _) = sampler.split_at(0.5);
+    let before_length = PathMeasurements::from_path(&before, 0.01).length();
+    assert!((before_length - length * 0.5).abs() < 0.01);
+}
+
 #[test]
 fn zero_length() {
     fn expect_nans(sample: PathSample, num_attribs: usize) {
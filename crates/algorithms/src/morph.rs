@@ -0,0 +1,261 @@
+//! Interpolate between two paths with the same structure.
+//!
+//! Two paths are compatible if they have the same sequence of events and the same number
+//! of custom attributes. The interpolation is applied to the endpoints, control points and
+//! custom attributes of the paths.
+//!
+//! # Example
+//!
+//! ```
+//! use lyon_algorithms::morph::lerp_paths;
+//! use lyon_algorithms::path::{Path, math::point};
+//!
+//! let mut a = Path::builder();
+//! a.begin(point(0.0, 0.0));
+//! a.quadratic_bezier_to(point(1.0, 0.0), point(1.0, 1.0));
+//! a.end(false);
+//! let a = a.build();
+//!
+//! let mut b = Path::builder();
+//! b.begin(point(2.0, 0.0));
+//! b.quadratic_bezier_to(point(3.0, 2.0), point(1.0, 3.0));
+//! b.end(false);
+//! let b = b.build();
+//!
+//! let halfway = lerp_paths(&a, &b, 0.5).unwrap();
+//! assert_eq!(halfway.first_endpoint().unwrap().0, point(1.0, 0.0));
+//! ```
+
+use crate::math::Point;
+use crate::path::{AttributeStore, Attributes, Event, Path, PathSlice};
+
+/// Returns true if the two paths have the same sequence of events and the same number of
+/// custom attributes, which means that they can be interpolated with `lerp_paths`.
+pub fn paths_are_compatible<'a, 'b>(
+    a: impl Into<PathSlice<'a>>,
+    b: impl Into<PathSlice<'b>>,
+) -> bool {
+    let a = a.into();
+    let b = b.into();
+    if a.num_attributes() != b.num_attributes() {
+        return false;
+    }
+
+    let mut b_events = b.iter();
+    for a_evt in a.iter() {
+        let compatible = match (a_evt, b_events.next()) {
+            (Event::Begin { .. }, Some(Event::Begin { .. }))
+            | (Event::Line { .. }, Some(Event::Line { .. }))
+            | (Event::Quadratic { .. }, Some(Event::Quadratic { .. }))
+            | (Event::Cubic { .. }, Some(Event::Cubic { .. })) => true,
+            (Event::End { close: c1, .. }, Some(Event::End { close: c2, .. })) => c1 == c2,
+            _ => false,
+        };
+
+        if !compatible {
+            return false;
+        }
+    }
+
+    b_events.next().is_none()
+}
+
+/// Linearly interpolates the endpoints, control points and custom attributes of two
+/// paths.
+///
+/// Returns `a` if `t` is equal to zero and `b` if `t` is equal to one.
+/// Returns `None` if the paths are not compatible (see `paths_are_compatible`).
+pub fn lerp_paths<'a, 'b>(
+    a: impl Into<PathSlice<'a>>,
+    b: impl Into<PathSlice<'b>>,
+    t: f32,
+) -> Option<Path> {
+    let a = a.into();
+    let b = b.into();
+    if !paths_are_compatible(a, b) {
+        return None;
+    }
+
+    let num_attributes = a.num_attributes();
+    let mut attributes = alloc::vec![0.0; num_attributes];
+    let mut output = Path::builder_with_attributes(num_attributes);
+
+    for (a_evt, b_evt) in a.iter_with_attributes().zip(b.iter_with_attributes()) {
+        match (a_evt, b_evt) {
+            (Event::Begin { at: a_at }, Event::Begin { at: b_at }) => {
+                let at = lerp_endpoint(a_at, b_at, t, &mut attributes);
+                output.begin(at, &attributes);
+            }
+            (Event::Line { to: a_to, .. }, Event::Line { to: b_to, .. }) => {
+                let to = lerp_endpoint(a_to, b_to, t, &mut attributes);
+                output.line_to(to, &attributes);
+            }
+            (
+                Event::Quadratic {
+                    ctrl: a_ctrl,
+                    to: a_to,
+                    ..
+                },
+                Event::Quadratic {
+                    ctrl: b_ctrl,
+                    to: b_to,
+                    ..
+                },
+            ) => {
+                let to = lerp_endpoint(a_to, b_to, t, &mut attributes);
+                output.quadratic_bezier_to(a_ctrl.lerp(b_ctrl, t), to, &attributes);
+            }
+            (
+                Event::Cubic {
+                    ctrl1: a_ctrl1,
+                    ctrl2: a_ctrl2,
+                    to: a_to,
+                    ..
+                },
+                Event::Cubic {
+                    ctrl1: b_ctrl1,
+                    ctrl2: b_ctrl2,
+                    to: b_to,
+                    ..
+                },
+            ) => {
+                let to = lerp_endpoint(a_to, b_to, t, &mut attributes);
+                output.cubic_bezier_to(
+                    a_ctrl1.lerp(b_ctrl1, t),
+                    a_ctrl2.lerp(b_ctrl2, t),
+                    to,
+                    &attributes,
+                );
+            }
+            (Event::End { close, .. }, Event::End { .. }) => {
+                output.end(close);
+            }
+            _ => {
+                // Checked by paths_are_compatible.
+                unreachable!();
+            }
+        }
+    }
+
+    Some(output.build())
+}
+
+fn lerp_endpoint(
+    a: (Point, Attributes),
+    b: (Point, Attributes),
+    t: f32,
+    attributes: &mut [f32],
+) -> Point {
+    for (i, attr) in attributes.iter_mut().enumerate() {
+        *attr = a.1[i] * (1.0 - t) + b.1[i] * t;
+    }
+
+    a.0.lerp(b.0, t)
+}
+
+#[cfg(test)]
+use crate::math::point;
+#[cfg(test)]
+use alloc::vec::Vec;
+
+#[test]
+fn lerp_compatible_paths() {
+    let mut a = Path::builder_with_attributes(1);
+    a.begin(point(0.0, 0.0), &[0.0]);
+    a.line_to(point(10.0, 0.0), &[1.0]);
+    a.cubic_bezier_to(point(10.0, 5.0), point(5.0, 10.0), point(0.0, 10.0), &[2.0]);
+    a.end(true);
+    let a = a.build();
+
+    let mut b = Path::builder_with_attributes(1);
+    b.begin(point(0.0, 10.0), &[2.0]);
+    b.line_to(point(20.0, 10.0), &[3.0]);
+    b.cubic_bezier_to(
+        point(20.0, 15.0),
+        point(15.0, 20.0),
+        point(10.0, 20.0),
+        &[4.0],
+    );
+    b.end(true);
+    let b = b.build();
+
+    assert!(paths_are_compatible(&a, &b));
+
+    let path = lerp_paths(&a, &b, 0.5).unwrap();
+    let mut expected = Path::builder_with_attributes(1);
+    expected.begin(point(0.0, 5.0), &[1.0]);
+    expected.line_to(point(15.0, 5.0), &[2.0]);
+    expected.cubic_bezier_to(
+        point(15.0, 10.0),
+        point(10.0, 15.0),
+        point(5.0, 15.0),
+        &[3.0],
+    );
+    expected.end(true);
+    let expected = expected.build();
+    assert_eq!(
+        path.iter_with_attributes().collect::<Vec<_>>(),
+        expected.iter_with_attributes().collect::<Vec<_>>()
+    );
+
+    let path = lerp_paths(&a, &b, 0.0).unwrap();
+    assert_eq!(
+        path.iter_with_attributes().collect::<Vec<_>>(),
+        a.iter_with_attributes().collect::<Vec<_>>()
+    );
+    let path = lerp_paths(&a, &b, 1.0).unwrap();
+    assert_eq!(
+        path.iter_with_attributes().collect::<Vec<_>>(),
+        b.iter_with_attributes().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn lerp_incompatible_paths() {
+    let mut a = Path::builder();
+    a.begin(point(0.0, 0.0));
+    a.line_to(point(10.0, 0.0));
+    a.line_to(point(10.0, 10.0));
+    a.end(true);
+    let a = a.build();
+
+    // Different event types.
+    let mut b = Path::builder();
+    b.begin(point(0.0, 0.0));
+    b.line_to(point(10.0, 0.0));
+    b.quadratic_bezier_to(point(10.0, 5.0), point(10.0, 10.0));
+    b.end(true);
+    let b = b.build();
+    assert!(!paths_are_compatible(&a, &b));
+    assert!(lerp_paths(&a, &b, 0.5).is_none());
+
+    // Different lengths.
+    let mut b = Path::builder();
+    b.begin(point(0.0, 0.0));
+    b.line_to(point(10.0, 0.0));
+    b.end(true);
+    let b = b.build();
+    assert!(!paths_are_compatible(&a, &b));
+    assert!(!paths_are_compatible(&b, &a));
+
+    // Open and closed.
+    let mut b = Path::builder();
+    b.begin(point(0.0, 0.0));
+    b.line_to(point(10.0, 0.0));
+    b.line_to(point(10.0, 10.0));
+    b.end(false);
+    let b = b.build();
+    assert!(!paths_are_compatible(&a, &b));
+
+    // Different number of attributes.
+    let mut b = Path::builder_with_attributes(1);
+    b.begin(point(0.0, 0.0), &[0.0]);
+    b.line_to(point(10.0, 0.0), &[0.0]);
+    b.line_to(point(10.0, 10.0), &[0.0]);
+    b.end(true);
+    let b = b.build();
+    assert!(!paths_are_compatible(&a, &b));
+
+    assert!(paths_are_compatible(&a, &a));
+    assert!(paths_are_compatible(&Path::new(), &Path::new()));
+}
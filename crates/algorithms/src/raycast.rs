@@ -1,18 +1,32 @@
-//! Find the first collision between a ray and a path.
+//! Find the collisions between a ray and a path.
 
 use crate::geom::{CubicBezierSegment, Line, LineSegment, QuadraticBezierSegment};
-use crate::math::{point, vector, Point, Vector};
+use crate::math::{point, vector, Box2D, Point, Vector};
 use crate::path::PathEvent;
 
+use core::cmp::Ordering;
+use core::ops::Range;
+
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
 pub struct Ray {
     pub origin: Point,
     pub direction: Vector,
 }
 
 // Position and normal at the point of contact between a ray and a shape.
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Hit {
     pub position: Point,
+    /// The normal of the path at the point of contact, facing the origin of the ray.
     pub normal: Vector,
+    /// The parameter of the point of contact on the path segment it belongs to.
+    pub t: f32,
+    /// The distance between the origin of the ray and the point of contact.
+    pub distance: f32,
 }
 
 // TODO: early out in the bézier/arc cases using bounding rect or circle
@@ -36,13 +50,14 @@ where
         min_dot: f32::MAX,
         result: point(0.0, 0.0),
         normal: vector(0.0, 0.0),
+        t: 0.0,
     };
 
     for evt in path {
         match evt {
             PathEvent::Begin { .. } => {}
             PathEvent::Line { from, to } => {
-                test_segment(&mut state, &LineSegment { from, to });
+                test_segment(&mut state, &LineSegment { from, to }, 0.0..1.0);
             }
             PathEvent::End { last, first, .. } => {
                 test_segment(
@@ -51,13 +66,14 @@ where
                         from: last,
                         to: first,
                     },
+                    0.0..1.0,
                 );
             }
             PathEvent::Quadratic { from, ctrl, to } => {
-                QuadraticBezierSegment { from, ctrl, to }.for_each_flattened(
+                QuadraticBezierSegment { from, ctrl, to }.for_each_flattened_with_t(
                     tolerance,
-                    &mut |line| {
-                        test_segment(&mut state, line);
+                    &mut |line, t| {
+                        test_segment(&mut state, line, t);
                     },
                 );
             }
@@ -73,8 +89,8 @@ where
                     ctrl2,
                     to,
                 }
-                .for_each_flattened(tolerance, &mut |line| {
-                    test_segment(&mut state, line);
+                .for_each_flattened_with_t(tolerance, &mut |line, t| {
+                    test_segment(&mut state, line, t);
                 });
             }
         }
@@ -91,22 +107,170 @@ where
     Some(Hit {
         position: state.result,
         normal: state.normal.normalize(),
+        t: state.t,
+        distance: state.min_dot / ray_len.sqrt(),
     })
 }
 
+/// Find all collisions between a ray and the path, sorted by increasing distance to
+/// the origin of the ray.
+///
+/// Curves are intersected analytically rather than flattened. Like in `raycast_path`,
+/// sub-paths are treated as if they were closed.
+///
+/// An intersection at the boundary between two consecutive segments is only reported once,
+/// so that the number of hits can be used to determine whether the origin of the ray
+/// is inside of the path using the even-odd fill rule, as long as the ray does not
+/// graze the path.
+pub fn raycast_all<Iter>(ray: &Ray, path: Iter) -> Vec<Hit>
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let mut hits = Vec::new();
+
+    let ray_len = ray.direction.square_length();
+    if ray_len == 0.0 || ray_len.is_nan() {
+        return hits;
+    }
+
+    let line = Line {
+        point: ray.origin,
+        vector: ray.direction,
+    };
+    let ray_len = ray_len.sqrt();
+
+    let mut add_hit = |position: Point, tangent: Vector, t: f32| {
+        if t >= 1.0 {
+            return;
+        }
+        let dot = (position - ray.origin).dot(ray.direction);
+        if dot < 0.0 {
+            return;
+        }
+        let mut normal = vector(-tangent.y, tangent.x);
+        if normal.dot(ray.direction) > 0.0 {
+            normal = -normal;
+        }
+        hits.push(Hit {
+            position,
+            normal: normal.normalize(),
+            t,
+            distance: dot / ray_len,
+        });
+    };
+
+    for evt in path {
+        match evt {
+            PathEvent::Begin { .. } => {}
+            PathEvent::Line { from, to } => {
+                let segment = LineSegment { from, to };
+                if let Some(t) = segment.line_intersection_t(&line) {
+                    add_hit(segment.sample(t), segment.to_vector(), t);
+                }
+            }
+            PathEvent::End { last, first, .. } => {
+                if last == first {
+                    continue;
+                }
+                let segment = LineSegment {
+                    from: last,
+                    to: first,
+                };
+                if let Some(t) = segment.line_intersection_t(&line) {
+                    add_hit(segment.sample(t), segment.to_vector(), t);
+                }
+            }
+            PathEvent::Quadratic { from, ctrl, to } => {
+                let curve = QuadraticBezierSegment { from, ctrl, to };
+                for t in curve.line_intersections_t(&line) {
+                    let mut tangent = curve.derivative(t);
+                    if tangent == vector(0.0, 0.0) {
+                        tangent = to - from;
+                    }
+                    add_hit(curve.sample(t), tangent, t);
+                }
+            }
+            PathEvent::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => {
+                let curve = CubicBezierSegment {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                };
+                for t in curve.line_intersections_t(&line) {
+                    let mut tangent = curve.derivative(t);
+                    if tangent == vector(0.0, 0.0) {
+                        tangent = to - from;
+                    }
+                    add_hit(curve.sample(t), tangent, t);
+                }
+            }
+        }
+    }
+
+    hits.sort_by(|a, b| {
+        a.distance
+            .partial_cmp(&b.distance)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    hits
+}
+
+/// Returns whether a ray intersects or touches an axis-aligned rectangle.
+///
+/// This is a cheap test that can be used to skip `raycast_path` for paths whose
+/// bounding box is not hit by the ray.
+pub fn ray_intersects_box(ray: &Ray, rect: &Box2D) -> bool {
+    let mut t_min = 0.0;
+    let mut t_max = f32::MAX;
+    let axes = [
+        (ray.origin.x, ray.direction.x, rect.min.x, rect.max.x),
+        (ray.origin.y, ray.direction.y, rect.min.y, rect.max.y),
+    ];
+
+    for &(origin, direction, min, max) in &axes {
+        if direction == 0.0 {
+            if origin < min || origin > max {
+                return false;
+            }
+            continue;
+        }
+
+        let inv = 1.0 / direction;
+        let t0 = (min - origin) * inv;
+        let t1 = (max - origin) * inv;
+        t_min = f32::max(t_min, f32::min(t0, t1));
+        t_max = f32::min(t_max, f32::max(t0, t1));
+        if t_min > t_max {
+            return false;
+        }
+    }
+
+    true
+}
+
 struct RayCastInner {
     ray: Line<f32>,
     min_dot: f32,
     result: Point,
     normal: Vector,
+    t: f32,
 }
 
-fn test_segment(state: &mut RayCastInner, segment: &LineSegment<f32>) {
-    if let Some(pos) = segment.line_intersection(&state.ray) {
+fn test_segment(state: &mut RayCastInner, segment: &LineSegment<f32>, t_range: Range<f32>) {
+    if let Some(t) = segment.line_intersection_t(&state.ray) {
+        let pos = segment.sample(t);
         let dot = (pos - state.ray.point).dot(state.ray.vector);
         if dot >= 0.0 && dot < state.min_dot {
             state.min_dot = dot;
             state.result = pos;
+            state.t = t_range.start + (t_range.end - t_range.start) * t;
             let v = segment.to_vector();
             state.normal = vector(-v.y, v.x);
         }
@@ -182,3 +346,97 @@ fn test_raycast() {
     .unwrap();
     assert!(hit.position.approx_eq(&point(1.0, 0.0)));
 }
+
+#[test]
+fn test_raycast_all() {
+    use crate::geom::euclid::approxeq::ApproxEq;
+    use crate::path::{Path, Winding};
+
+    let mut builder = Path::builder();
+    builder.add_circle(point(0.0, 0.0), 1.0, Winding::Positive);
+    builder.begin(point(-3.0, -1.0));
+    builder.line_to(point(-2.0, -1.0));
+    builder.line_to(point(-2.0, 1.0));
+    builder.line_to(point(-3.0, 1.0));
+    builder.end(true);
+    let path = builder.build();
+
+    let hits = raycast_all(
+        &Ray {
+            origin: point(-5.0, 0.0),
+            direction: vector(2.0, 0.0),
+        },
+        path.iter(),
+    );
+
+    assert_eq!(hits.len(), 4);
+    let expected = [-3.0, -2.0, -1.0, 1.0];
+    for (hit, x) in hits.iter().zip(expected.iter()) {
+        assert!(hit
+            .position
+            .approx_eq_eps(&point(*x, 0.0), &point(1e-4, 1e-4)));
+        assert!((hit.distance - (x + 5.0)).abs() < 1e-4);
+        assert!(hit.t >= 0.0 && hit.t < 1.0);
+    }
+    // Points on the circle are exactly on the curve, not a flattened approximation.
+    assert!(hits[2]
+        .normal
+        .approx_eq_eps(&vector(-1.0, 0.0), &vector(1e-4, 1e-4)));
+    assert!(hits[3]
+        .normal
+        .approx_eq_eps(&vector(-1.0, 0.0), &vector(1e-4, 1e-4)));
+
+    // From inside of the circle there is an odd number of hits.
+    let hits = raycast_all(
+        &Ray {
+            origin: point(0.5, 0.0),
+            direction: vector(1.0, 0.0),
+        },
+        path.iter(),
+    );
+    assert_eq!(hits.len(), 1);
+
+    // Crossing a vertex between two edges is reported once.
+    let hits = raycast_all(
+        &Ray {
+            origin: point(-2.5, 0.0),
+            direction: vector(1.0, 2.0),
+        },
+        path.iter(),
+    );
+    assert_eq!(hits.len(), 1);
+    assert!(hits[0].position.approx_eq(&point(-2.0, 1.0)));
+
+    let first = raycast_path(
+        &Ray {
+            origin: point(-5.0, 0.0),
+            direction: vector(2.0, 0.0),
+        },
+        path.iter(),
+        0.01,
+    )
+    .unwrap();
+    assert!(first.position.approx_eq(&point(-3.0, 0.0)));
+    assert!((first.distance - 2.0).abs() < 1e-5);
+    assert!((first.t - 0.5).abs() < 1e-5);
+}
+
+#[test]
+fn test_ray_intersects_box() {
+    let rect = Box2D {
+        min: point(0.0, 0.0),
+        max: point(1.0, 1.0),
+    };
+    let ray = |x, y, dx, dy| Ray {
+        origin: point(x, y),
+        direction: vector(dx, dy),
+    };
+
+    assert!(ray_intersects_box(&ray(-1.0, 0.5, 1.0, 0.0), &rect));
+    assert!(ray_intersects_box(&ray(0.5, 0.5, 1.0, 1.0), &rect));
+    assert!(ray_intersects_box(&ray(-1.0, -1.0, 1.0, 1.0), &rect));
+    assert!(ray_intersects_box(&ray(-1.0, 1.0, 1.0, 0.0), &rect));
+    assert!(!ray_intersects_box(&ray(-1.0, 0.5, -1.0, 0.0), &rect));
+    assert!(!ray_intersects_box(&ray(-1.0, 2.0, 1.0, 0.0), &rect));
+    assert!(!ray_intersects_box(&ray(-1.0, 0.0, 1.0, 2.0), &rect));
+}
@@ -1,11 +1,114 @@
 use lyon_path::{
-    geom::{euclid, Angle, Vector},
+    geom::{euclid, Angle, SvgArc, Vector},
     traits::PathBuilder,
     ArcFlags, Attributes, Polygon, Winding,
 };
 
 pub type Point = euclid::default::Point2D<f32>;
 
+/// A rounded corner of a polygon.
+///
+/// The outline of a rounded polygon is made of straight lines joining consecutive
+/// corners and of an arc at each corner, going from `from` to `to`.
+///
+/// This is the information used by `add_rounded_polygon` and
+/// `add_flattened_rounded_polygon`, which makes it possible to generate other
+/// representations of the same shape.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RoundedCorner {
+    /// The corner of the original polygon.
+    pub corner: Point,
+    /// The start of the arc, on the edge leading to the corner.
+    pub from: Point,
+    /// The end of the arc, on the edge leaving the corner.
+    pub to: Point,
+    /// The radius of the corner, clamped to half of the length of the shortest
+    /// adjacent edge.
+    pub radius: f32,
+    /// Whether the polygon turns in the positive or negative direction at this corner.
+    pub winding: Winding,
+}
+
+impl RoundedCorner {
+    /// Returns the arc of this corner.
+    ///
+    /// If `is_straight_line` returns true for the arc, the corner should be
+    /// rendered as a line segment.
+    pub fn arc(&self) -> SvgArc<f32> {
+        SvgArc {
+            from: self.from,
+            to: self.to,
+            radii: Vector::new(self.radius, self.radius),
+            x_rotation: Angle { radians: 0.0 },
+            flags: ArcFlags {
+                large_arc: false,
+                sweep: self.winding == Winding::Negative,
+            },
+        }
+    }
+}
+
+/// An iterator over the rounded corners of a polygon.
+///
+/// See `rounded_polygon_corners`.
+pub struct RoundedCorners<'l> {
+    points: &'l [Point],
+    radius: f32,
+    index: usize,
+}
+
+impl<'l> Iterator for RoundedCorners<'l> {
+    type Item = RoundedCorner;
+
+    fn next(&mut self) -> Option<RoundedCorner> {
+        let n = self.points.len();
+        if n < 2 || self.index >= n {
+            return None;
+        }
+
+        let p_current = self.points[self.index];
+        let p_next = self.points[(self.index + 1) % n];
+        let p_after_next = self.points[(self.index + 2) % n];
+        self.index += 1;
+
+        let radius = clamp_radius(self.radius, p_current, p_next, p_after_next);
+
+        Some(RoundedCorner {
+            corner: p_next,
+            //from is the second point on the line between p_current and p_next
+            from: get_point_between(p_next, p_current, radius),
+            //to is the first point on the line between p_next and p_after_next
+            to: get_point_between(p_next, p_after_next, radius),
+            radius,
+            winding: get_winding(p_current, p_next, p_after_next),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.points.len();
+        let remaining = if n < 2 { 0 } else { n - self.index };
+        (remaining, Some(remaining))
+    }
+}
+
+/// Returns an iterator over the rounded corners of a polygon.
+///
+/// The first corner is the one at the second point of the polygon, the last corner
+/// is the one at the first point, so that the outline starts at the end of the last
+/// corner's arc. All corners are rounded, even if the polygon is not closed.
+///
+/// No corner is produced if the polygon has less than two points.
+pub fn rounded_polygon_corners<'l>(
+    polygon: &Polygon<'l, Point>,
+    radius: f32,
+) -> RoundedCorners<'l> {
+    RoundedCorners {
+        points: polygon.points,
+        radius,
+        index: 0,
+    }
+}
+
 /// Adds a sub-path from a polygon but rounds the corners.
 ///
 /// There must be no sub-path in progress when this method is called.
@@ -20,47 +123,62 @@ pub fn add_rounded_polygon<B: PathBuilder>(
         return;
     }
 
-    //p points are original polygon points
-    //q points are the actual points we will draw lines and arcs between
-    let clamped_radius = clamp_radius(
-        radius,
-        polygon.points[polygon.points.len() - 1],
-        polygon.points[0],
-        polygon.points[1],
-    );
-    let q_first = get_point_between(polygon.points[0], polygon.points[1], clamped_radius);
+    let last = rounded_polygon_corners(&polygon, radius).last().unwrap();
 
     //We begin on the line just after the first point
-    builder.begin(q_first, attributes);
+    builder.begin(last.to, attributes);
+
+    for corner in rounded_polygon_corners(&polygon, radius) {
+        builder.line_to(corner.from, attributes);
+
+        //Draw the arc near the corner
+        let arc = corner.arc();
+        if arc.is_straight_line() {
+            builder.line_to(arc.to, attributes);
+        } else {
+            arc.for_each_quadratic_bezier(&mut |curve| {
+                builder.quadratic_bezier_to(curve.ctrl, curve.to, attributes);
+            });
+        }
+    }
 
-    for index in 0..polygon.points.len() {
-        let p_current = polygon.points[index];
-        let p_next = polygon.points[(index + 1) % polygon.points.len()];
-        let p_after_next = polygon.points[(index + 2) % polygon.points.len()];
+    builder.end(polygon.closed);
+}
 
-        let clamped_radius = clamp_radius(radius, p_current, p_next, p_after_next);
+/// Adds a sub-path from a polygon with rounded corners, approximating the corners
+/// with line segments.
+///
+/// The generated outline is the same as the one produced by `add_rounded_polygon`,
+/// flattened with the provided tolerance.
+///
+/// This is useful when the same shape is both filled and stroked: the path only
+/// contains line segments, so the fill and stroke tessellators produce exactly
+/// matching geometry regardless of their own tolerance, and the stroke tessellator
+/// only has to join nearly collinear segments at the corners instead of adding its
+/// own rounding to the arcs.
+///
+/// There must be no sub-path in progress when this method is called.
+/// No sub-path is in progress after the method is called.
+pub fn add_flattened_rounded_polygon<B: PathBuilder>(
+    builder: &mut B,
+    polygon: Polygon<Point>,
+    radius: f32,
+    tolerance: f32,
+    attributes: Attributes,
+) {
+    if polygon.points.len() < 2 {
+        return;
+    }
 
-        //q1 is the second point on the line between p_current and p_next
-        let q1 = get_point_between(p_next, p_current, clamped_radius);
-        //q2 is the first point on the line between p_next and p_after_next
-        let q2 = get_point_between(p_next, p_after_next, clamped_radius);
+    let last = rounded_polygon_corners(&polygon, radius).last().unwrap();
 
-        builder.line_to(q1, attributes);
-        let turn_winding = get_winding(p_current, p_next, p_after_next);
+    builder.begin(last.to, attributes);
 
-        //Draw the arc near p_next
-        arc(
-            builder,
-            Vector::new(clamped_radius, clamped_radius),
-            Angle { radians: 0.0 },
-            ArcFlags {
-                large_arc: false,
-                sweep: turn_winding == Winding::Negative,
-            },
-            q1,
-            q2,
-            attributes,
-        );
+    for corner in rounded_polygon_corners(&polygon, radius) {
+        builder.line_to(corner.from, attributes);
+        corner.arc().for_each_flattened(tolerance, &mut |segment| {
+            builder.line_to(segment.to, attributes);
+        });
     }
 
     builder.end(polygon.closed);
@@ -88,33 +206,6 @@ fn get_winding(p0: Point, p1: Point, p2: Point) -> Winding {
     }
 }
 
-fn arc<B: PathBuilder>(
-    builder: &mut B,
-    radii: Vector<f32>,
-    x_rotation: Angle<f32>,
-    flags: ArcFlags,
-    from: Point,
-    to: Point,
-    attributes: Attributes,
-) {
-    let svg_arc = lyon_path::geom::SvgArc {
-        from,
-        to,
-        radii,
-        x_rotation,
-        flags,
-    };
-
-    if svg_arc.is_straight_line() {
-        builder.line_to(to, attributes);
-    } else {
-        let geom_arc = svg_arc.to_arc();
-        geom_arc.for_each_quadratic_bezier(&mut |curve| {
-            builder.quadratic_bezier_to(curve.ctrl, curve.to, attributes);
-        });
-    }
-}
-
 #[test]
 fn rounded_polygon() {
     use crate::geom::point;
@@ -207,3 +298,52 @@ fn rounded_polygon() {
         previous = e.to();
     }
 }
+
+#[test]
+fn flattened_rounded_polygon() {
+    use crate::geom::point;
+    use lyon_path::PathEvent;
+
+    let points = [
+        point(0.0, 0.0),
+        point(10.0, 0.0),
+        point(10.0, 5.0),
+        point(0.0, 5.0),
+    ];
+    let polygon = Polygon {
+        points: &points,
+        closed: true,
+    };
+
+    let corners: alloc::vec::Vec<_> = rounded_polygon_corners(&polygon, 1.0).collect();
+    assert_eq!(corners.len(), 4);
+    assert_eq!(corners[0].corner, point(10.0, 0.0));
+    assert_eq!(corners[0].from, point(9.0, 0.0));
+    assert_eq!(corners[0].to, point(10.0, 1.0));
+    assert_eq!(corners[3].corner, point(0.0, 0.0));
+
+    // The radius is clamped to half of the shortest edge.
+    let corners: alloc::vec::Vec<_> = rounded_polygon_corners(&polygon, 4.0).collect();
+    assert!(corners.iter().all(|c| c.radius == 2.5));
+
+    let mut builder = lyon_path::Path::builder();
+    add_flattened_rounded_polygon(&mut builder, polygon, 1.0, 0.01, lyon_path::NO_ATTRIBUTES);
+    let flattened = builder.build();
+
+    let mut num_lines = 0;
+    for evt in flattened.iter() {
+        match evt {
+            PathEvent::Begin { at } => assert_eq!(at, point(1.0, 0.0)),
+            PathEvent::Line { to, .. } => {
+                num_lines += 1;
+                // All points are on the outline of the rounded rectangle, which is
+                // at a distance of 1.0 from the rectangle [1, 9] x [1, 4].
+                let inner = point(to.x.clamp(1.0, 9.0), to.y.clamp(1.0, 4.0));
+                assert!(((to - inner).length() - 1.0).abs() < 0.01);
+            }
+            PathEvent::End { close, .. } => assert!(close),
+            _ => panic!("unexpected curve {:?}", evt),
+        }
+    }
+    assert!(num_lines > 8);
+}
@@ -0,0 +1,540 @@
+//! Simplify polylines using the Ramer–Douglas–Peucker algorithm.
+//!
+//! Points are removed as long as the simplified polyline stays within a tolerance
+//! threshold of the original one.
+//!
+//! `merge_collinear` is a more conservative alternative that only removes the points
+//! between collinear line segments.
+//!
+//! # Example
+//!
+//! ```
+//! use lyon_algorithms::simplify::simplify_path;
+//! use lyon_algorithms::path::{Path, math::point};
+//!
+//! let mut builder = Path::builder();
+//! builder.begin(point(0.0, 0.0));
+//! builder.line_to(point(1.0, 0.01));
+//! builder.line_to(point(2.0, 0.0));
+//! builder.line_to(point(2.0, 2.0));
+//! builder.end(false);
+//! let path = builder.build();
+//!
+//! let simplified = simplify_path(&path, 0.1);
+//!
+//! assert_eq!(simplified.iter().count(), 4);
+//! ```
+
+use crate::geom::LineSegment;
+use crate::math::Point;
+use crate::path::{Path, PathEvent};
+
+use alloc::vec::Vec;
+
+/// Simplifies the line segments of a path.
+///
+/// Sub-paths are processed independently and keep their open or closed state.
+/// Curves are not simplified: they are passed through as is, along with their
+/// endpoints. To simplify curves, flatten the path first.
+///
+/// The first and last points of open sub-paths are never removed. For closed sub-paths
+/// made only of line segments, the first point is kept.
+pub fn simplify_path<Iter>(path: Iter, tolerance: f32) -> Path
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let mut builder = Path::builder();
+    let mut polyline = Vec::new();
+    let mut simplified = Vec::new();
+    let mut has_curves = false;
+
+    for evt in path {
+        match evt {
+            PathEvent::Begin { at } => {
+                builder.begin(at);
+                polyline.clear();
+                polyline.push(at);
+                has_curves = false;
+            }
+            PathEvent::Line { to, .. } => {
+                polyline.push(to);
+            }
+            PathEvent::Quadratic { ctrl, to, .. } => {
+                flush_polyline(&mut polyline, &mut simplified, tolerance, false, &mut |p| {
+                    builder.line_to(p);
+                });
+                builder.quadratic_bezier_to(ctrl, to);
+                polyline.push(to);
+                has_curves = true;
+            }
+            PathEvent::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => {
+                flush_polyline(&mut polyline, &mut simplified, tolerance, false, &mut |p| {
+                    builder.line_to(p);
+                });
+                builder.cubic_bezier_to(ctrl1, ctrl2, to);
+                polyline.push(to);
+                has_curves = true;
+            }
+            PathEvent::End { close, .. } => {
+                let closed = close && !has_curves;
+                flush_polyline(
+                    &mut polyline,
+                    &mut simplified,
+                    tolerance,
+                    closed,
+                    &mut |p| {
+                        builder.line_to(p);
+                    },
+                );
+                builder.end(close);
+            }
+        }
+    }
+
+    builder.build()
+}
+
+// Simplifies the polyline and invokes the callback for each of its remaining points
+// except the first one, which has already been added to the path. Afterwards the
+// polyline is empty.
+fn flush_polyline(
+    polyline: &mut Vec<Point>,
+    simplified: &mut Vec<Point>,
+    tolerance: f32,
+    closed: bool,
+    cb: &mut dyn FnMut(Point),
+) {
+    simplified.clear();
+    simplify_polygon_into(polyline, tolerance, closed, simplified);
+    for p in simplified.iter().skip(1) {
+        cb(*p);
+    }
+
+    polyline.clear();
+}
+
+/// Simplifies a polyline or a polygon.
+///
+/// If `closed` is true, the points are considered to form a polygon, which means that
+/// the segment between the last and first points is taken into account.
+///
+/// The first point is never removed, and neither is the last point if `closed` is false.
+pub fn simplify_polygon(points: &[Point], tolerance: f32, closed: bool) -> Vec<Point> {
+    let mut output = Vec::new();
+    simplify_polygon_into(points, tolerance, closed, &mut output);
+
+    output
+}
+
+fn simplify_polygon_into(points: &[Point], tolerance: f32, closed: bool, output: &mut Vec<Point>) {
+    if points.len() < 3 {
+        output.extend_from_slice(points);
+        return;
+    }
+
+    // A closed polygon is handled as a polyline that goes back to the first point.
+    let n = if closed {
+        points.len() + 1
+    } else {
+        points.len()
+    };
+    let point_at = |idx: usize| points[idx % points.len()];
+
+    let mut keep = alloc::vec![false; n];
+    keep[0] = true;
+    keep[n - 1] = true;
+
+    let mut stack = Vec::new();
+    stack.push((0, n - 1));
+    while let Some((start, end)) = stack.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+
+        let segment = LineSegment {
+            from: point_at(start),
+            to: point_at(end),
+        };
+
+        let mut max_dist = 0.0;
+        let mut max_idx = start;
+        for idx in (start + 1)..end {
+            let d = segment.distance_to_point(point_at(idx));
+            if d > max_dist {
+                max_dist = d;
+                max_idx = idx;
+            }
+        }
+
+        if max_dist > tolerance {
+            keep[max_idx] = true;
+            stack.push((start, max_idx));
+            stack.push((max_idx, end));
+        }
+    }
+
+    let count = if closed { n - 1 } else { n };
+    for (idx, keep) in keep.iter().take(count).enumerate() {
+        if *keep {
+            output.push(points[idx]);
+        }
+    }
+}
+
+/// Removes the points between line segments that are collinear within a tolerance.
+///
+/// Unlike `simplify_path`, this only merges consecutive line segments when the removed
+/// points are within `tolerance` of the resulting segment, which makes it suitable to
+/// clean up the redundant points produced for example by boolean operations or clipping
+/// without otherwise altering the shape. Points that double back along the line are
+/// not removed.
+///
+/// Curves are passed through as is, and their endpoints are never removed. The first and
+/// last points of open sub-paths are kept. For closed sub-paths, the first point can be
+/// removed as well, in which case the sub-path starts at another one of its points.
+pub fn merge_collinear<Iter>(path: Iter, tolerance: f32) -> Path
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let mut builder = Path::builder();
+    let mut first = Point::zero();
+    let mut segments = Vec::new();
+    let mut merged = Vec::new();
+
+    for evt in path {
+        match evt {
+            PathEvent::Begin { at } => {
+                first = at;
+                segments.clear();
+            }
+            PathEvent::Line { to, .. } => {
+                segments.push(Segment::Line(to));
+            }
+            PathEvent::Quadratic { ctrl, to, .. } => {
+                segments.push(Segment::Quadratic(ctrl, to));
+            }
+            PathEvent::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => {
+                segments.push(Segment::Cubic(ctrl1, ctrl2, to));
+            }
+            PathEvent::End { last, close, .. } => {
+                let mut start = first;
+                if close {
+                    if last != first {
+                        segments.push(Segment::Line(first));
+                    }
+                    // Start at a point that can't be removed so that the seam is
+                    // handled like the other points. Sub-paths with less than three
+                    // segments have no point to remove.
+                    let n = segments.len();
+                    let corner = (0..n).filter(|_| n >= 3).find(|&i| {
+                        let prev = segments[(i + n - 1) % n];
+                        let next = segments[i];
+                        match (prev, next) {
+                            (Segment::Line(from), Segment::Line(to)) => {
+                                let before = segments[(i + n - 2) % n].to();
+                                LineSegment { from: before, to }.distance_to_point(from) > tolerance
+                            }
+                            _ => true,
+                        }
+                    });
+                    if let Some(idx) = corner {
+                        segments.rotate_left(idx);
+                        start = segments[n - 1].to();
+                    }
+                }
+
+                merge_segments(start, &segments, tolerance, &mut merged);
+
+                builder.begin(start);
+                let count = if close && matches!(merged.last(), Some(Segment::Line(_))) {
+                    merged.len() - 1
+                } else {
+                    merged.len()
+                };
+                for segment in &merged[..count] {
+                    match *segment {
+                        Segment::Line(to) => {
+                            builder.line_to(to);
+                        }
+                        Segment::Quadratic(ctrl, to) => {
+                            builder.quadratic_bezier_to(ctrl, to);
+                        }
+                        Segment::Cubic(ctrl1, ctrl2, to) => {
+                            builder.cubic_bezier_to(ctrl1, ctrl2, to);
+                        }
+                    }
+                }
+                builder.end(close);
+            }
+        }
+    }
+
+    builder.build()
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Segment {
+    Line(Point),
+    Quadratic(Point, Point),
+    Cubic(Point, Point, Point),
+}
+
+impl Segment {
+    fn to(&self) -> Point {
+        match *self {
+            Segment::Line(to) | Segment::Quadratic(_, to) | Segment::Cubic(_, _, to) => to,
+        }
+    }
+}
+
+fn merge_segments(start: Point, segments: &[Segment], tolerance: f32, output: &mut Vec<Segment>) {
+    output.clear();
+    // The start of the last line segment and the points that were merged into it.
+    let mut line_start = start;
+    let mut removed = Vec::new();
+    let mut current = start;
+
+    for segment in segments {
+        if let (Segment::Line(to), Some(Segment::Line(prev_to))) = (*segment, output.last_mut()) {
+            let line = LineSegment {
+                from: line_start,
+                to,
+            };
+            let can_merge = line.distance_to_point(current) <= tolerance
+                && removed
+                    .iter()
+                    .all(|p| line.distance_to_point(*p) <= tolerance);
+            if can_merge {
+                removed.push(current);
+                *prev_to = to;
+                current = to;
+                continue;
+            }
+        }
+
+        if let Segment::Line(_) = segment {
+            line_start = current;
+        }
+        removed.clear();
+        output.push(*segment);
+        current = segment.to();
+    }
+}
+
+#[cfg(test)]
+use crate::math::point;
+
+#[test]
+fn simplify_open_polyline() {
+    let points = [
+        point(0.0, 0.0),
+        point(1.0, 0.05),
+        point(2.0, -0.05),
+        point(3.0, 0.0),
+        point(3.05, 1.0),
+        point(3.0, 2.0),
+    ];
+
+    assert_eq!(
+        simplify_polygon(&points, 0.1, false),
+        &[point(0.0, 0.0), point(3.0, 0.0), point(3.0, 2.0)]
+    );
+
+    // Nothing is removed with a smaller tolerance.
+    assert_eq!(simplify_polygon(&points, 0.01, false), &points);
+
+    // The first and last points are always kept.
+    assert_eq!(
+        simplify_polygon(&points, 100.0, false),
+        &[point(0.0, 0.0), point(3.0, 2.0)]
+    );
+
+    assert_eq!(simplify_polygon(&points[..2], 100.0, false), &points[..2]);
+    assert!(simplify_polygon(&[], 1.0, false).is_empty());
+}
+
+#[test]
+fn simplify_closed_polygon() {
+    let points = [
+        point(0.0, 0.0),
+        point(5.0, 0.01),
+        point(10.0, 0.0),
+        point(10.0, 10.0),
+        point(5.0, 10.01),
+        point(0.0, 10.0),
+        point(0.0, 5.0),
+    ];
+
+    assert_eq!(
+        simplify_polygon(&points, 0.1, true),
+        &[
+            point(0.0, 0.0),
+            point(10.0, 0.0),
+            point(10.0, 10.0),
+            point(0.0, 10.0)
+        ]
+    );
+
+    // When open, the last point is kept.
+    assert_eq!(
+        simplify_polygon(&points, 0.1, false),
+        &[
+            point(0.0, 0.0),
+            point(10.0, 0.0),
+            point(10.0, 10.0),
+            point(0.0, 10.0),
+            point(0.0, 5.0),
+        ]
+    );
+}
+
+#[test]
+fn simplify_path_events() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.01));
+    builder.line_to(point(2.0, 0.0));
+    builder.line_to(point(2.0, 1.0));
+    builder.line_to(point(2.0, 2.0));
+    builder.line_to(point(1.0, 1.99));
+    builder.line_to(point(0.0, 2.0));
+    builder.line_to(point(0.0, 1.0));
+    builder.close();
+
+    builder.begin(point(10.0, 0.0));
+    builder.line_to(point(11.0, 0.0));
+    builder.line_to(point(12.0, 0.0));
+    builder.quadratic_bezier_to(point(13.0, 0.0), point(13.0, 1.0));
+    builder.line_to(point(13.0, 2.0));
+    builder.line_to(point(13.0, 3.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let simplified = simplify_path(&path, 0.1);
+
+    let mut expected = Path::builder();
+    expected.begin(point(0.0, 0.0));
+    expected.line_to(point(2.0, 0.0));
+    expected.line_to(point(2.0, 2.0));
+    expected.line_to(point(0.0, 2.0));
+    expected.close();
+
+    expected.begin(point(10.0, 0.0));
+    expected.line_to(point(12.0, 0.0));
+    expected.quadratic_bezier_to(point(13.0, 0.0), point(13.0, 1.0));
+    expected.line_to(point(13.0, 3.0));
+    expected.end(false);
+    let expected = expected.build();
+
+    assert_eq!(
+        simplified.iter().collect::<Vec<_>>(),
+        expected.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn merge_collinear_segments() {
+    fn events(path: &Path) -> Vec<PathEvent> {
+        path.iter().collect()
+    }
+
+    // Open sub-path with a curve.
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.001));
+    builder.line_to(point(2.0, 0.0));
+    builder.line_to(point(3.0, 0.0));
+    builder.quadratic_bezier_to(point(4.0, 0.0), point(4.0, 1.0));
+    builder.line_to(point(4.0, 2.0));
+    builder.line_to(point(4.0, 3.0));
+    // Doubles back along the line.
+    builder.line_to(point(4.0, 2.5));
+    builder.line_to(point(5.0, 3.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let mut expected = Path::builder();
+    expected.begin(point(0.0, 0.0));
+    expected.line_to(point(3.0, 0.0));
+    expected.quadratic_bezier_to(point(4.0, 0.0), point(4.0, 1.0));
+    expected.line_to(point(4.0, 3.0));
+    expected.line_to(point(4.0, 2.5));
+    expected.line_to(point(5.0, 3.0));
+    expected.end(false);
+    let expected = expected.build();
+
+    assert_eq!(events(&merge_collinear(&path, 0.01)), events(&expected));
+
+    // With a smaller tolerance, only the exactly collinear points are removed.
+    let mut expected = Path::builder();
+    expected.begin(point(0.0, 0.0));
+    expected.line_to(point(1.0, 0.001));
+    expected.line_to(point(2.0, 0.0));
+    expected.line_to(point(3.0, 0.0));
+    expected.quadratic_bezier_to(point(4.0, 0.0), point(4.0, 1.0));
+    expected.line_to(point(4.0, 3.0));
+    expected.line_to(point(4.0, 2.5));
+    expected.line_to(point(5.0, 3.0));
+    expected.end(false);
+    let expected = expected.build();
+
+    assert_eq!(events(&merge_collinear(&path, 0.0)), events(&expected));
+
+    // Closed sub-path where the first point is on an edge.
+    let mut builder = Path::builder();
+    builder.begin(point(5.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.line_to(point(10.0, 5.0));
+    builder.line_to(point(10.0, 10.0));
+    builder.line_to(point(0.0, 10.0));
+    builder.line_to(point(0.0, 0.0));
+    builder.line_to(point(2.0, 0.0));
+    builder.close();
+    let path = builder.build();
+
+    let mut expected = Path::builder();
+    expected.begin(point(10.0, 0.0));
+    expected.line_to(point(10.0, 10.0));
+    expected.line_to(point(0.0, 10.0));
+    expected.line_to(point(0.0, 0.0));
+    expected.close();
+    let expected = expected.build();
+
+    assert_eq!(events(&merge_collinear(&path, 0.01)), events(&expected));
+
+    // Points along a gentle arc are not all removed even if each of them is close to
+    // the line through its neighbors.
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    for i in 1..=100 {
+        let x = i as f32;
+        builder.line_to(point(x, x * x * 0.0001));
+    }
+    builder.end(false);
+    let path = builder.build();
+
+    let merged = merge_collinear(&path, 0.01);
+    assert!(merged.iter().count() > 3);
+    assert!(merged.iter().count() < 50);
+}
+
+#[test]
+fn merge_collinear_degenerate_closed_sub_path() {
+    let mut builder = Path::builder();
+    builder.begin(point(1.0, 1.0));
+    builder.line_to(point(1.0, 1.0));
+    builder.close();
+    let path = builder.build();
+
+    let merged = merge_collinear(&path, 0.01);
+    assert_eq!(
+        merged.iter().next(),
+        Some(PathEvent::Begin {
+            at: point(1.0, 1.0)
+        })
+    );
+}
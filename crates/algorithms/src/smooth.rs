@@ -0,0 +1,403 @@
+//! Round the corners of arbitrary paths.
+//!
+//! Unlike `rounded_polygon`, which builds rounded polygons from scratch, this works on
+//! existing paths that can be open and contain curves.
+//!
+//! # Example
+//!
+//! ```
+//! use lyon_algorithms::smooth::round_corners;
+//! use lyon_algorithms::path::{Path, PathEvent, math::point};
+//!
+//! let mut builder = Path::builder();
+//! builder.begin(point(0.0, 0.0));
+//! builder.line_to(point(10.0, 0.0));
+//! builder.line_to(point(10.0, 10.0));
+//! builder.end(false);
+//! let path = builder.build();
+//!
+//! let rounded = round_corners(&path, 2.0, 0.01);
+//!
+//! // The corner is replaced with a quarter circle from (8, 0) to (10, 2).
+//! let events: Vec<PathEvent> = rounded.iter().collect();
+//! assert_eq!(events[1], PathEvent::Line { from: point(0.0, 0.0), to: point(8.0, 0.0) });
+//! ```
+
+use crate::geom::{Angle, Arc};
+use crate::math::{vector, Point, Vector};
+use crate::path::path::Builder;
+use crate::path::{Path, PathEvent};
+
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Replaces each corner between two line segments with a circular arc of the given radius.
+///
+/// The arcs are tangent to both line segments. When the segments are too short to fit
+/// an arc of the requested radius, the radius is reduced so that the arc doesn't use more
+/// than half of a segment that is rounded at both ends, or more than a whole segment that
+/// is rounded at one end only.
+///
+/// Curves are copied as is and the corners where they meet other segments are left
+/// sharp, as well as the endpoints of open sub-paths. Closed sub-paths are rounded at
+/// their first point as well, including when they are closed by an implicit line
+/// segment.
+///
+/// Line segments shorter than the tolerance are considered degenerate and their ends
+/// are not rounded. Corners where the rounded outline would deviate from the original
+/// one by less than the tolerance are left sharp.
+///
+/// The arcs are approximated with cubic bézier curves.
+pub fn round_corners<Iter>(path: Iter, radius: f32, tolerance: f32) -> Path
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let mut output = Path::builder();
+    let mut segments = Vec::new();
+    let mut corners = Vec::new();
+    for evt in path {
+        match evt {
+            PathEvent::Begin { .. } => {
+                segments.clear();
+            }
+            PathEvent::End { last, first, close } => {
+                if close && last != first {
+                    segments.push(PathEvent::Line {
+                        from: last,
+                        to: first,
+                    });
+                }
+
+                if segments.is_empty() {
+                    output.begin(first);
+                    output.end(close);
+                    continue;
+                }
+
+                compute_corners(&segments, close, radius, tolerance, &mut corners);
+                add_rounded_sub_path(&segments, &corners, close, &mut output);
+            }
+            segment => {
+                segments.push(segment);
+            }
+        }
+    }
+
+    output.build()
+}
+
+// The rounding of the corner at the end of a segment.
+#[derive(Clone)]
+struct Corner {
+    // The distance between the corner and the ends of the arc.
+    tangent_distance: f32,
+    arc: Option<Arc<f32>>,
+}
+
+const SHARP: Corner = Corner {
+    tangent_distance: 0.0,
+    arc: None,
+};
+
+// Returns the length and the direction of line segments with a length above the
+// tolerance.
+fn line_direction(segment: &PathEvent, tolerance: f32) -> Option<(f32, Vector)> {
+    if let PathEvent::Line { from, to } = *segment {
+        let v = to - from;
+        let length = v.length();
+        if length > tolerance {
+            return Some((length, v / length));
+        }
+    }
+
+    None
+}
+
+// Computes the corner at the end of each segment. The last corner is always sharp
+// in open sub-paths.
+fn compute_corners(
+    segments: &[PathEvent],
+    closed: bool,
+    radius: f32,
+    tolerance: f32,
+    corners: &mut Vec<Corner>,
+) {
+    let n = segments.len();
+    let directions: Vec<Option<(f32, Vector)>> = segments
+        .iter()
+        .map(|segment| line_direction(segment, tolerance))
+        .collect();
+
+    // A corner can be rounded if it joins two non-degenerate line segments.
+    let roundable: Vec<bool> = (0..n)
+        .map(|i| {
+            let has_next = n > 1 && (closed || i + 1 < n);
+            has_next && directions[i].is_some() && directions[(i + 1) % n].is_some()
+        })
+        .collect();
+
+    corners.clear();
+    for i in 0..n {
+        if !roundable[i] {
+            corners.push(SHARP);
+            continue;
+        }
+
+        let next = (i + 1) % n;
+        let (len_in, dir_in) = directions[i].unwrap();
+        let (len_out, dir_out) = directions[next].unwrap();
+
+        // Segments that are rounded at both ends share their length with the other
+        // corner.
+        let prev_roundable = (i > 0 || closed) && roundable[(i + n - 1) % n];
+        let available_in = if prev_roundable { len_in * 0.5 } else { len_in };
+        let available_out = if roundable[next] {
+            len_out * 0.5
+        } else {
+            len_out
+        };
+
+        let turn = dir_in.cross(dir_out).atan2(dir_in.dot(dir_out));
+        let tan_half = (turn.abs() * 0.5).tan();
+        if !tan_half.is_finite() || tan_half <= 0.0 {
+            corners.push(SHARP);
+            continue;
+        }
+
+        let tangent_distance = (radius * tan_half).min(available_in).min(available_out);
+        let r = tangent_distance / tan_half;
+
+        // The distance between the corner and the middle of the arc.
+        let deviation = r * (1.0 / (turn.abs() * 0.5).cos() - 1.0);
+        if deviation.is_nan() || deviation < tolerance {
+            corners.push(SHARP);
+            continue;
+        }
+
+        let corner = segments[i].to();
+        let arc_start = corner - dir_in * tangent_distance;
+        let normal = if turn > 0.0 {
+            vector(-dir_in.y, dir_in.x)
+        } else {
+            vector(dir_in.y, -dir_in.x)
+        };
+        let center = arc_start + normal * r;
+
+        corners.push(Corner {
+            tangent_distance,
+            arc: Some(Arc {
+                center,
+                radii: vector(r, r),
+                start_angle: (arc_start - center).angle_from_x_axis(),
+                sweep_angle: Angle::radians(turn),
+                x_rotation: Angle::zero(),
+            }),
+        });
+    }
+}
+
+fn add_rounded_sub_path(
+    segments: &[PathEvent],
+    corners: &[Corner],
+    closed: bool,
+    output: &mut Builder,
+) {
+    let n = segments.len();
+    let start_offset = if closed {
+        corners[n - 1].tangent_distance
+    } else {
+        0.0
+    };
+    let start = trimmed_start(&segments[0], start_offset);
+
+    output.begin(start);
+    let mut prev_offset = start_offset;
+    for (i, segment) in segments.iter().enumerate() {
+        match *segment {
+            PathEvent::Line { from, to } => {
+                let offset = corners[i].tangent_distance;
+                let end = if offset > 0.0 {
+                    to + (from - to).normalize() * offset
+                } else {
+                    to
+                };
+                // Skip lines entirely replaced by the arcs at their ends, and let the
+                // sub-path close itself if the last line goes back to the start.
+                let consumed = prev_offset + offset >= (to - from).length() && offset > 0.0;
+                let closing = closed && i == n - 1 && end == start;
+                if !consumed && !closing {
+                    output.line_to(end);
+                }
+            }
+            evt => {
+                output.path_event(evt);
+            }
+        }
+
+        prev_offset = corners[i].tangent_distance;
+        if let Some(arc) = &corners[i].arc {
+            arc.for_each_cubic_bezier(&mut |curve| {
+                output.cubic_bezier_to(curve.ctrl1, curve.ctrl2, curve.to);
+            });
+        }
+    }
+    output.end(closed);
+}
+
+fn trimmed_start(segment: &PathEvent, offset: f32) -> Point {
+    match *segment {
+        PathEvent::Line { from, to } if offset > 0.0 => from + (to - from).normalize() * offset,
+        _ => segment.from(),
+    }
+}
+
+#[test]
+fn round_open_polyline() {
+    use crate::math::point;
+
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.line_to(point(10.0, 10.0));
+    builder.line_to(point(20.0, 10.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let rounded = round_corners(&path, 2.0, 0.01);
+    let events: Vec<PathEvent> = rounded.iter().collect();
+
+    assert_eq!(
+        events[0],
+        PathEvent::Begin {
+            at: point(0.0, 0.0)
+        }
+    );
+    assert_eq!(
+        events[1],
+        PathEvent::Line {
+            from: point(0.0, 0.0),
+            to: point(8.0, 0.0)
+        }
+    );
+    assert_eq!(
+        events.last(),
+        Some(&PathEvent::End {
+            last: point(20.0, 10.0),
+            first: point(0.0, 0.0),
+            close: false
+        })
+    );
+
+    // The arcs are quarter circles of radius 2 around (8, 2) and (12, 8).
+    let centers = [point(8.0, 2.0), point(12.0, 8.0)];
+    let mut num_curves = 0;
+    for evt in &events {
+        if let PathEvent::Cubic { from, to, .. } = *evt {
+            num_curves += 1;
+            let center = centers[(from.y > 5.0) as usize];
+            assert!(((from - center).length() - 2.0).abs() < 1e-4, "{:?}", evt);
+            assert!(((to - center).length() - 2.0).abs() < 1e-4, "{:?}", evt);
+        }
+    }
+    assert_eq!(num_curves, 2);
+
+    // The middle segment is only 10 units long so larger radii are clamped.
+    let rounded = round_corners(&path, 20.0, 0.01);
+    let lines: Vec<(Point, Point)> = rounded
+        .iter()
+        .filter_map(|evt| match evt {
+            PathEvent::Line { from, to } => Some((from, to)),
+            _ => None,
+        })
+        .collect();
+    // The arcs meet in the middle of the second segment, which disappears.
+    assert_eq!(lines.len(), 2);
+    assert!((lines[0].1 - point(5.0, 0.0)).length() < 1e-4);
+    assert!((lines[1].0 - point(15.0, 10.0)).length() < 1e-4);
+}
+
+#[test]
+fn round_closed_path_with_curves() {
+    use crate::math::point;
+
+    // A square with a bulging left side and an implicit closing edge at the bottom.
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.quadratic_bezier_to(point(-5.0, 5.0), point(0.0, 10.0));
+    builder.line_to(point(10.0, 10.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.end(true);
+    let path = builder.build();
+
+    let rounded = round_corners(&path, 1.0, 0.01);
+    let events: Vec<PathEvent> = rounded.iter().collect();
+
+    // The corners next to the curve are sharp and the curve is untouched.
+    assert_eq!(
+        events[0],
+        PathEvent::Begin {
+            at: point(0.0, 0.0)
+        }
+    );
+    assert_eq!(
+        events[1],
+        PathEvent::Quadratic {
+            from: point(0.0, 0.0),
+            ctrl: point(-5.0, 5.0),
+            to: point(0.0, 10.0)
+        }
+    );
+
+    // The two corners on the right are rounded.
+    let num_curves = events
+        .iter()
+        .filter(|evt| matches!(evt, PathEvent::Cubic { .. }))
+        .count();
+    assert_eq!(num_curves, 2);
+    for evt in &events {
+        if let PathEvent::Cubic { to, .. } = *evt {
+            let center = point(9.0, if to.y > 5.0 { 9.0 } else { 1.0 });
+            assert!(((to - center).length() - 1.0).abs() < 1e-4, "{:?}", evt);
+        }
+    }
+
+    // The closing edge is shortened by the rounded corner and stays implicit.
+    if let Some(PathEvent::End { last, first, close }) = events.last() {
+        assert!(*close);
+        assert!((*last - point(9.0, 0.0)).length() < 1e-4);
+        assert_eq!(*first, point(0.0, 0.0));
+    } else {
+        panic!();
+    }
+
+    // A closed polygon is rounded at its first point too.
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.line_to(point(10.0, 10.0));
+    builder.line_to(point(0.0, 10.0));
+    builder.end(true);
+    let path = builder.build();
+
+    let rounded = round_corners(&path, 1.0, 0.01);
+    let events: Vec<PathEvent> = rounded.iter().collect();
+    assert_eq!(
+        events[0],
+        PathEvent::Begin {
+            at: point(1.0, 0.0)
+        }
+    );
+    let num_curves = events
+        .iter()
+        .filter(|evt| matches!(evt, PathEvent::Cubic { .. }))
+        .count();
+    assert_eq!(num_curves, 4);
+    if let Some(PathEvent::End { last, first, close }) = events.last() {
+        assert!(*close);
+        assert!((*last - *first).length() < 1e-4);
+    } else {
+        panic!();
+    }
+}
@@ -0,0 +1,480 @@
+//! Compute the outline of a stroke as a fillable path.
+//!
+//! Unlike the stroke tessellator which produces triangles, `stroke_to_fill` produces a
+//! path describing the area covered by the stroke. It can be filled, exported or used as
+//! input to other path algorithms.
+//!
+//! # Example
+//!
+//! ```
+//! use lyon_algorithms::stroke::stroke_to_fill;
+//! use lyon_algorithms::path::{Path, LineJoin, StrokeOptions, math::point};
+//!
+//! let mut builder = Path::builder();
+//! builder.begin(point(0.0, 0.0));
+//! builder.line_to(point(10.0, 0.0));
+//! builder.line_to(point(10.0, 10.0));
+//! builder.end(false);
+//! let path = builder.build();
+//!
+//! let options = StrokeOptions::default()
+//!     .with_line_width(2.0)
+//!     .with_line_join(LineJoin::Round);
+//!
+//! // The outline can be filled using the non-zero fill rule.
+//! let outline = stroke_to_fill(&path, &options);
+//! ```
+
+use crate::geom::{Arc, Box2D};
+use crate::math::{vector, Angle, Point, Vector};
+use crate::path::builder::NoAttributes;
+use crate::path::iterator::PathIterator;
+use crate::path::path::BuilderImpl;
+use crate::path::{LineCap, LineJoin, Path, PathEvent, StrokeOptions, Winding};
+
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Produces a path covering the area of the stroke of the input path.
+///
+/// Curves are flattened using the tolerance of the stroke options. Round joins and caps
+/// are approximated with cubic bézier curves.
+///
+/// Each open sub-path produces one closed contour going along one side of the stroke,
+/// around the end cap, back along the other side and around the start cap. Each closed
+/// sub-path produces two closed contours with opposite orientations, one for each side
+/// of the stroke. The contours may overlap themselves and each other so the result must
+/// be filled using the non-zero fill rule.
+///
+/// Variable line width, stroke alignment and dashing are not supported,
+/// `StrokeOptions::variable_line_width`, `StrokeOptions::stroke_alignment` and
+/// `StrokeOptions::dash` are ignored. Dashes can be computed beforehand with
+/// `dash::dash`.
+pub fn stroke_to_fill<Iter>(path: Iter, options: &StrokeOptions) -> Path
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let mut output = Path::builder();
+    let mut points = Vec::new();
+
+    for evt in path.into_iter().flattened(options.tolerance) {
+        match evt {
+            PathEvent::Begin { at } => {
+                points.clear();
+                points.push(at);
+            }
+            PathEvent::Line { to, .. } => {
+                if points.last() != Some(&to) {
+                    points.push(to);
+                }
+            }
+            PathEvent::End { close, .. } => {
+                if close && points.len() > 2 && points.last() == points.first() {
+                    points.pop();
+                }
+                stroke_polyline(&points, close, options, &mut output);
+            }
+            PathEvent::Quadratic { .. } | PathEvent::Cubic { .. } => {
+                debug_assert!(false, "Unexpected curve in a flattened path");
+            }
+        }
+    }
+
+    output.build()
+}
+
+fn stroke_polyline(
+    points: &[Point],
+    closed: bool,
+    options: &StrokeOptions,
+    output: &mut NoAttributes<BuilderImpl>,
+) {
+    let half_width = options.line_width * 0.5;
+    if half_width <= 0.0 || points.is_empty() {
+        return;
+    }
+
+    if points.len() == 1 {
+        // Zero-length sub-path, only the caps are visible.
+        let center = points[0];
+        match options.start_cap {
+            LineCap::Butt => {}
+            LineCap::Square => {
+                output.add_rectangle(
+                    &Box2D {
+                        min: center - vector(half_width, half_width),
+                        max: center + vector(half_width, half_width),
+                    },
+                    Winding::Positive,
+                );
+            }
+            LineCap::Round => {
+                output.add_circle(center, half_width, Winding::Positive);
+            }
+        }
+        return;
+    }
+
+    let mut side = Side {
+        output,
+        half_width,
+        options,
+    };
+
+    let mut reversed: Vec<Point> = points.to_vec();
+    reversed.reverse();
+
+    if closed {
+        side.closed_contour(points);
+        side.closed_contour(&reversed);
+    } else {
+        let n = points.len();
+        let first_direction = (points[1] - points[0]).normalize();
+        let last_direction = (points[n - 1] - points[n - 2]).normalize();
+
+        side.output
+            .begin(points[0] + normal(first_direction) * half_width);
+        side.open_side(points);
+        side.cap(points[n - 1], last_direction, options.end_cap);
+        side.open_side(&reversed);
+        side.cap(points[0], -first_direction, options.start_cap);
+        side.output.end(true);
+    }
+}
+
+// Returns the unit vector pointing to the left of the provided direction (assuming
+// a y-up coordinate system).
+fn normal(v: Vector) -> Vector {
+    vector(-v.y, v.x).normalize()
+}
+
+struct Side<'l> {
+    output: &'l mut NoAttributes<BuilderImpl>,
+    half_width: f32,
+    options: &'l StrokeOptions,
+}
+
+impl<'l> Side<'l> {
+    // Goes along the left side of an open polyline. The output's current position must
+    // be the offset of the first point.
+    fn open_side(&mut self, points: &[Point]) {
+        let w = self.half_width;
+        let mut prev_normal = normal(points[1] - points[0]);
+        self.output.line_to(points[1] + prev_normal * w);
+        for i in 1..(points.len() - 1) {
+            let n = normal(points[i + 1] - points[i]);
+            self.join(points[i], prev_normal, n);
+            self.output.line_to(points[i + 1] + n * w);
+            prev_normal = n;
+        }
+    }
+
+    // Goes along the left side of a closed polyline.
+    fn closed_contour(&mut self, points: &[Point]) {
+        let w = self.half_width;
+        let n = points.len();
+        let first_normal = normal(points[1] - points[0]);
+        self.output.begin(points[0] + first_normal * w);
+        self.output.line_to(points[1] + first_normal * w);
+        let mut prev_normal = first_normal;
+        for i in 1..n {
+            let next = points[(i + 1) % n];
+            let normal = normal(next - points[i]);
+            self.join(points[i], prev_normal, normal);
+            self.output.line_to(next + normal * w);
+            prev_normal = normal;
+        }
+        self.join(points[0], prev_normal, first_normal);
+        self.output.end(true);
+    }
+
+    // Connects the left offsets of two consecutive segments at a vertex. The current
+    // position is the end of the first segment's offset and the join ends at the
+    // beginning of the second segment's offset.
+    fn join(&mut self, vertex: Point, n0: Vector, n1: Vector) {
+        let w = self.half_width;
+        let cross = n0.cross(n1);
+        let dot = n0.dot(n1);
+
+        if cross.abs() < 1e-5 && dot > 0.0 {
+            // Collinear segments.
+            self.output.line_to(vertex + n1 * w);
+            return;
+        }
+
+        if cross > 0.0 {
+            // Inner side of the join. Going through the vertex ensures that the
+            // contour covers the area of the join with the non-zero fill rule.
+            self.output.line_to(vertex);
+            self.output.line_to(vertex + n1 * w);
+            return;
+        }
+
+        let from = vertex + n0 * w;
+        let to = vertex + n1 * w;
+
+        match self.options.line_join {
+            LineJoin::Bevel => {}
+            LineJoin::Round => {
+                let sweep = if cross.abs() < 1e-5 {
+                    -Angle::pi()
+                } else {
+                    Angle::radians(cross.atan2(dot))
+                };
+                self.arc(vertex, n0, sweep);
+            }
+            LineJoin::Miter | LineJoin::MiterClip => {
+                // The miter length relative to the line width is 1 / cos(θ / 2), θ being
+                // the angle between the normals.
+                let bisector = n0 + n1;
+                let cos_half_angle = bisector.length() * 0.5;
+                let limit = self.options.miter_limit;
+                if cos_half_angle * limit >= 1.0 {
+                    self.output
+                        .line_to(vertex + bisector.normalize() * (w / cos_half_angle));
+                } else if self.options.line_join == LineJoin::MiterClip {
+                    // Clip the miter at a distance of miter_limit * half_width from
+                    // the vertex along the bisector.
+                    let b = if cos_half_angle > 1e-5 {
+                        bisector.normalize()
+                    } else {
+                        // The segments are going back onto each other.
+                        vector(n0.y, -n0.x)
+                    };
+                    let clip = limit * w;
+                    let d0 = vector(n0.y, -n0.x);
+                    let d1 = vector(n1.y, -n1.x);
+                    let t0 = (clip - w * n0.dot(b)) / d0.dot(b);
+                    let t1 = (clip - w * n1.dot(b)) / -d1.dot(b);
+                    self.output.line_to(from + d0 * t0);
+                    self.output.line_to(to - d1 * t1);
+                }
+            }
+        }
+
+        self.output.line_to(to);
+    }
+
+    fn cap(&mut self, at: Point, direction: Vector, cap: LineCap) {
+        let w = self.half_width;
+        let n = vector(-direction.y, direction.x);
+        match cap {
+            LineCap::Butt => {}
+            LineCap::Square => {
+                let d = direction * w;
+                self.output.line_to(at + n * w + d);
+                self.output.line_to(at - n * w + d);
+            }
+            LineCap::Round => {
+                self.arc(at, n, -Angle::pi());
+            }
+        }
+        self.output.line_to(at - n * w);
+    }
+
+    fn arc(&mut self, center: Point, start_normal: Vector, sweep_angle: Angle) {
+        let arc = Arc {
+            center,
+            radii: vector(self.half_width, self.half_width),
+            start_angle: Angle::radians(start_normal.y.atan2(start_normal.x)),
+            sweep_angle,
+            x_rotation: Angle::zero(),
+        };
+        let output = &mut *self.output;
+        arc.for_each_cubic_bezier(&mut |curve| {
+            output.cubic_bezier_to(curve.ctrl1, curve.ctrl2, curve.to);
+        });
+    }
+}
+
+#[cfg(test)]
+use crate::math::point;
+
+#[cfg(test)]
+fn area(path: &Path) -> f32 {
+    crate::area::approximate_signed_area(0.01, path.iter())
+}
+
+#[cfg(test)]
+fn assert_approx_eq(a: f32, b: f32, epsilon: f32) {
+    assert!((a - b).abs() < epsilon, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn stroke_open_line() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let options = StrokeOptions::default().with_line_width(2.0);
+
+    let outline = stroke_to_fill(&path, &options);
+    assert_approx_eq(area(&outline).abs(), 20.0, 1e-3);
+    let bounds = crate::aabb::bounding_box(&outline);
+    assert_approx_eq(bounds.min.x, 0.0, 1e-5);
+    assert_approx_eq(bounds.max.x, 10.0, 1e-5);
+    assert_approx_eq(bounds.min.y, -1.0, 1e-5);
+    assert_approx_eq(bounds.max.y, 1.0, 1e-5);
+
+    let outline = stroke_to_fill(&path, &options.with_line_cap(LineCap::Square));
+    assert_approx_eq(area(&outline).abs(), 24.0, 1e-3);
+
+    let outline = stroke_to_fill(&path, &options.with_line_cap(LineCap::Round));
+    assert_approx_eq(area(&outline).abs(), 20.0 + core::f32::consts::PI, 0.05);
+}
+
+#[test]
+fn stroke_closed_square() {
+    use crate::hit_test::hit_test_path;
+    use crate::path::FillRule;
+
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.line_to(point(10.0, 10.0));
+    builder.line_to(point(0.0, 10.0));
+    builder.close();
+    let path = builder.build();
+
+    let options = StrokeOptions::default().with_line_width(2.0);
+
+    // Miter joins: the stroke covers the area between a 12x12 and a 8x8 square.
+    let outline = stroke_to_fill(&path, &options);
+    assert_eq!(
+        outline
+            .iter()
+            .filter(|evt| matches!(evt, PathEvent::Begin { .. }))
+            .count(),
+        2
+    );
+    let bounds = crate::aabb::bounding_box(&outline);
+    assert_approx_eq(bounds.min.x, -1.0, 1e-5);
+    assert_approx_eq(bounds.max.y, 11.0, 1e-5);
+    for p in [
+        point(-0.9, -0.9),
+        point(0.5, 0.5),
+        point(9.5, 9.5),
+        point(5.0, 10.5),
+    ] {
+        assert!(hit_test_path(&p, outline.iter(), FillRule::NonZero, 0.01));
+    }
+    for p in [point(5.0, 5.0), point(1.5, 1.5), point(-1.5, 5.0)] {
+        assert!(!hit_test_path(&p, outline.iter(), FillRule::NonZero, 0.01));
+    }
+
+    // Bevel joins cut the outer corners.
+    let outline = stroke_to_fill(&path, &options.with_line_join(LineJoin::Bevel));
+    let bounds = crate::aabb::bounding_box(&outline);
+    assert_approx_eq(bounds.min.x, -1.0, 1e-5);
+    assert!(!hit_test_path(
+        &point(-0.9, -0.9),
+        outline.iter(),
+        FillRule::NonZero,
+        0.01
+    ));
+    assert!(hit_test_path(
+        &point(0.5, 0.5),
+        outline.iter(),
+        FillRule::NonZero,
+        0.01
+    ));
+}
+
+#[test]
+fn stroke_joins() {
+    use crate::hit_test::hit_test_path;
+    use crate::path::FillRule;
+
+    // A sharp angle.
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.line_to(point(0.0, 2.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let options = StrokeOptions::default().with_line_width(2.0);
+
+    // The miter exceeds the limit and falls back to a bevel.
+    let outline = stroke_to_fill(&path, &options);
+    let bounds = crate::aabb::bounding_box(&outline);
+    assert!(bounds.max.x < 11.5);
+
+    let outline = stroke_to_fill(&path, &options.with_miter_limit(20.0));
+    let bounds = crate::aabb::bounding_box(&outline);
+    assert!(bounds.max.x > 15.0);
+
+    let outline = stroke_to_fill(
+        &path,
+        &options
+            .with_line_join(LineJoin::MiterClip)
+            .with_miter_limit(2.0),
+    );
+    let bounds = crate::aabb::bounding_box(&outline);
+    assert_approx_eq(bounds.max.x, 12.0, 0.1);
+
+    let outline = stroke_to_fill(&path, &options.with_line_join(LineJoin::Round));
+    let bounds = crate::aabb::bounding_box(&outline);
+    assert_approx_eq(bounds.max.x, 11.0, 0.01);
+    assert!(hit_test_path(
+        &point(10.9, 0.2),
+        outline.iter(),
+        FillRule::NonZero,
+        0.01
+    ));
+}
+
+#[test]
+fn stroke_degenerate() {
+    let mut builder = Path::builder();
+    builder.begin(point(1.0, 1.0));
+    builder.end(false);
+    builder.begin(point(5.0, 5.0));
+    builder.line_to(point(5.0, 5.0));
+    builder.end(true);
+    let path = builder.build();
+
+    let options = StrokeOptions::default().with_line_width(2.0);
+    assert!(stroke_to_fill(&path, &options).iter().next().is_none());
+
+    let outline = stroke_to_fill(&path, &options.with_line_cap(LineCap::Round));
+    assert_approx_eq(area(&outline).abs(), core::f32::consts::PI * 2.0, 0.05);
+
+    let outline = stroke_to_fill(&path, &options.with_line_cap(LineCap::Square));
+    assert_approx_eq(area(&outline).abs(), 8.0, 1e-3);
+}
+
+#[test]
+fn stroke_curve() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.quadratic_bezier_to(point(10.0, 0.0), point(10.0, 10.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let options = StrokeOptions::tolerance(0.01).with_line_width(1.0);
+    let outline = stroke_to_fill(&path, &options);
+    for evt in path.iter().flattened(0.01) {
+        if let PathEvent::Line { from, .. } = evt {
+            assert!(crate::hit_test::hit_test_path(
+                &from,
+                outline.iter(),
+                crate::path::FillRule::NonZero,
+                0.01
+            ));
+        }
+    }
+
+    for p in [point(5.0, 5.0), point(9.0, -0.6), point(10.6, 10.0)] {
+        assert!(!crate::hit_test::hit_test_path(
+            &p,
+            outline.iter(),
+            crate::path::FillRule::NonZero,
+            0.01
+        ));
+    }
+}
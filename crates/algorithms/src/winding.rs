@@ -1,8 +1,15 @@
 // Compute the winding of a path.
 
-use crate::geom::vector;
+use crate::geom::{vector, CubicBezierSegment};
+use crate::math::{Point, Vector};
 use crate::path::{PathEvent, Winding};
 
+use alloc::vec::Vec;
+use core::f32::consts::{FRAC_PI_2, PI};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
 /// Compute the winding of the next sub-path.
 ///
 /// The sub-path is expected to have a non-null area and no self-intersections, otherwise
@@ -65,6 +72,276 @@ where
     None
 }
 
+/// Compute the winding of each sub-path of a path, in order.
+///
+/// The winding is derived from the sign of the exact signed area of each sub-path,
+/// using the same convention as `compute_winding`: a positive area gives
+/// `Winding::Positive`. Unlike `compute_winding`, the area of curves is computed
+/// analytically (via Green's theorem) rather than from their control polygon, so
+/// the result is correct even when the control points of a curve fall on the other
+/// side of its endpoints.
+///
+/// Open sub-paths are treated as if they were closed with a straight line.
+/// Sub-paths with a null area are reported as `Winding::Negative`.
+pub fn contour_windings<Iter>(path: Iter) -> Vec<Winding>
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    let mut windings = Vec::new();
+    let mut area = 0.0;
+    for evt in path {
+        match evt {
+            PathEvent::Begin { .. } => {
+                area = 0.0;
+            }
+            PathEvent::Line { from, to } => {
+                area += cross(from, to);
+            }
+            PathEvent::Quadratic { from, ctrl, to } => {
+                area += (2.0 * cross(from, ctrl) + 2.0 * cross(ctrl, to) + cross(from, to)) / 3.0;
+            }
+            PathEvent::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => {
+                area += (6.0 * cross(from, ctrl1)
+                    + 3.0 * cross(from, ctrl2)
+                    + cross(from, to)
+                    + 3.0 * cross(ctrl1, ctrl2)
+                    + 3.0 * cross(ctrl1, to)
+                    + 6.0 * cross(ctrl2, to))
+                    / 10.0;
+            }
+            PathEvent::End { last, first, .. } => {
+                area += cross(last, first);
+                windings.push(if area > 0.0 {
+                    Winding::Positive
+                } else {
+                    Winding::Negative
+                });
+            }
+        }
+    }
+
+    windings
+}
+
+fn cross(a: Point, b: Point) -> f32 {
+    a.to_vector().cross(b.to_vector())
+}
+
+/// Computes the winding number of a point with respect to a path.
+///
+/// The winding number is the signed number of times the path's edges cross a ray
+/// cast from the point. It is zero outside of the path, and its value inside of the
+/// path determines whether the point is filled under a given fill rule.
+///
+/// Curves are flattened with the provided tolerance, so the result for points
+/// closer than the tolerance to a curve corresponds to its flattened approximation.
+///
+/// # Conventions
+///
+/// - The ray is cast from the point towards negative x.
+/// - Each edge covers the half-open vertical range `[min_y, max_y)`, so that a ray
+///   going through a vertex shared by two edges is counted once. Horizontal edges
+///   are ignored.
+/// - Edges crossing the ray exactly at the point are counted.
+/// - Edges going towards positive y count `+1`, edges going towards negative y
+///   count `-1`. For example a sub-path that is clockwise in a y-down coordinate
+///   system (such as SVG's), which `compute_winding` reports as `Winding::Positive`,
+///   contributes `-1` to the winding number of the points it encloses.
+///
+/// As a consequence, points exactly on the left or top (min y) boundary of a simple
+/// shape are inside of it, while points on its right or bottom (max y) boundary are
+/// outside.
+///
+/// See also `hit_test::hit_test_path`.
+pub fn winding_number<Iter>(path: Iter, point: Point, tolerance: f32) -> i32
+where
+    Iter: IntoIterator<Item = PathEvent>,
+{
+    crate::hit_test::path_winding_number_at_position(&point, path, tolerance)
+}
+
+/// Compute the total turning angle of the next sub-path, in radians.
+///
+/// This is the sum of the exterior angles at the corners of the sub-path and of the
+/// integrated curvature along its curves (for example the sweep angle of a circular
+/// arc), including the implicit closing edge and the corner at the first point.
+/// Turning in the positive direction, as a sub-path with `Winding::Positive` does,
+/// counts positively. See `turning_number`.
+///
+/// Zero-length segments are ignored. The turning at cusps is ambiguous, and is counted
+/// as a half turn in an arbitrary direction.
+///
+/// The iterator is advanced so that `total_turning_angle` can be called multiple times
+/// to process the successive sub-paths of a path.
+///
+/// Returns `None` if there is no more sub-path or if the iterator is malformed.
+pub fn total_turning_angle<Iter>(path: &mut Iter) -> Option<f32>
+where
+    Iter: Iterator<Item = PathEvent>,
+{
+    if !matches!(path.next(), Some(PathEvent::Begin { .. })) {
+        return None;
+    }
+
+    let mut turning = Turning {
+        first_tangent: None,
+        prev_tangent: None,
+        total: 0.0,
+    };
+
+    for evt in path {
+        match evt {
+            PathEvent::Begin { .. } => {
+                return None;
+            }
+            PathEvent::End { last, first, .. } => {
+                turning.segment(first - last, first - last, 0.0);
+                if let (Some(prev), Some(first)) = (turning.prev_tangent, turning.first_tangent) {
+                    turning.total += angle_between(prev, first);
+                }
+
+                return Some(turning.total);
+            }
+            PathEvent::Line { from, to } => {
+                turning.segment(to - from, to - from, 0.0);
+            }
+            PathEvent::Quadratic { from, ctrl, to } => {
+                let start = non_zero_vector(&[ctrl - from, to - from]);
+                let end = non_zero_vector(&[to - ctrl, to - from]);
+                // The tangent of a quadratic bézier curve turns by less than a half turn.
+                turning.segment(start, end, angle_between(start, end));
+            }
+            PathEvent::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => {
+                let curve = CubicBezierSegment {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                };
+                let start = non_zero_vector(&[ctrl1 - from, ctrl2 - from, to - from]);
+                let end = non_zero_vector(&[to - ctrl2, to - ctrl1, to - from]);
+                turning.segment(start, end, cubic_turning(&curve, start, end));
+            }
+        }
+    }
+
+    None
+}
+
+/// Compute the turning number of the next sub-path.
+///
+/// The turning number is the number of full turns made by the tangent of the sub-path
+/// (see `total_turning_angle`). It is `1` or `-1` (depending on the winding) for sub-paths
+/// that don't intersect themselves, so other values indicate self-intersections, while
+/// the converse is not true: a figure-eight has a turning number of zero but some
+/// self-intersecting sub-paths have a turning number of `1` or `-1`.
+///
+/// The iterator is advanced so that `turning_number` can be called multiple times
+/// to process the successive sub-paths of a path.
+///
+/// Returns `None` if there is no more sub-path or if the iterator is malformed.
+pub fn turning_number<Iter>(path: &mut Iter) -> Option<i32>
+where
+    Iter: Iterator<Item = PathEvent>,
+{
+    let angle = total_turning_angle(path)?;
+
+    Some((angle / (2.0 * PI)).round() as i32)
+}
+
+struct Turning {
+    first_tangent: Option<Vector>,
+    prev_tangent: Option<Vector>,
+    total: f32,
+}
+
+impl Turning {
+    fn segment(&mut self, start_tangent: Vector, end_tangent: Vector, turning: f32) {
+        if start_tangent == vector(0.0, 0.0) {
+            return;
+        }
+
+        match self.prev_tangent {
+            Some(prev) => {
+                self.total += angle_between(prev, start_tangent);
+            }
+            None => {
+                self.first_tangent = Some(start_tangent);
+            }
+        }
+
+        self.total += turning;
+        self.prev_tangent = Some(end_tangent);
+    }
+}
+
+// The signed angle in ]-PI, PI] from a to b.
+fn angle_between(a: Vector, b: Vector) -> f32 {
+    f32::atan2(a.cross(b), a.dot(b))
+}
+
+fn non_zero_vector(candidates: &[Vector]) -> Vector {
+    for v in candidates {
+        if *v != vector(0.0, 0.0) {
+            return *v;
+        }
+    }
+
+    vector(0.0, 0.0)
+}
+
+// The tangent of a cubic bézier curve turns in a constant direction between inflection
+// points, but may turn by more than a half turn (when the curve has a loop). Each part
+// is split into shorter pieces and a full turn is added to the pieces that turn by more
+// than a half turn.
+fn cubic_turning(curve: &CubicBezierSegment<f32>, start: Vector, end: Vector) -> f32 {
+    const SUBDIVISIONS: u32 = 4;
+
+    let mut bounds = [0.0; 4];
+    let mut num_bounds = 1;
+    for t in curve.inflection_points() {
+        bounds[num_bounds] = t;
+        num_bounds += 1;
+    }
+    bounds[num_bounds] = 1.0;
+    num_bounds += 1;
+
+    let mut total = 0.0;
+    let mut prev_tangent = start;
+    for range in bounds[..num_bounds].windows(2) {
+        let (t0, t1) = (range[0], range[1]);
+        let step = (t1 - t0) / SUBDIVISIONS as f32;
+        for i in 0..SUBDIVISIONS {
+            let ta = t0 + step * i as f32;
+            let tb = t0 + step * (i + 1) as f32;
+            let tangent = if tb >= 1.0 { end } else { curve.derivative(tb) };
+
+            let mut angle = angle_between(prev_tangent, tangent);
+
+            let t = (ta + tb) * 0.5;
+            let direction = curve.derivative(t).cross(curve.second_derivative(t));
+            if angle * direction < 0.0 && angle.abs() > FRAC_PI_2 {
+                angle += 2.0 * PI * direction.signum();
+            }
+
+            total += angle;
+            prev_tangent = tangent;
+        }
+    }
+
+    total
+}
+
 /// Iterator over the sub-path windings of a path.
 pub struct Windings<Iter = PathEvent>(pub Iter);
 
@@ -100,3 +377,154 @@ fn path_winding() {
     assert_eq!(compute_winding(&mut iter), Some(Winding::Negative));
     assert_eq!(compute_winding(&mut iter), None);
 }
+
+#[test]
+fn winding_numbers() {
+    use crate::geom::point;
+    let mut path = crate::path::Path::builder();
+
+    // Clockwise in a y-down coordinate system.
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(4.0, 0.0));
+    path.line_to(point(4.0, 4.0));
+    path.line_to(point(0.0, 4.0));
+    path.close();
+
+    // Same orientation, overlapping the first square.
+    path.begin(point(2.0, 2.0));
+    path.line_to(point(6.0, 2.0));
+    path.line_to(point(6.0, 6.0));
+    path.line_to(point(2.0, 6.0));
+    path.close();
+
+    // Opposite orientation, with a curve.
+    path.begin(point(10.0, 0.0));
+    path.line_to(point(10.0, 4.0));
+    path.quadratic_bezier_to(point(14.0, 4.0), point(14.0, 0.0));
+    path.close();
+
+    let path = path.build();
+
+    assert_eq!(compute_winding(&mut path.iter()), Some(Winding::Positive));
+
+    assert_eq!(winding_number(&path, point(1.0, 1.0), 0.1), -1);
+    assert_eq!(winding_number(&path, point(3.0, 3.0), 0.1), -2);
+    assert_eq!(winding_number(&path, point(5.0, 5.0), 0.1), -1);
+    assert_eq!(winding_number(&path, point(8.0, 1.0), 0.1), 0);
+    assert_eq!(winding_number(&path, point(12.0, 1.0), 0.1), 1);
+    assert_eq!(winding_number(&path, point(13.5, 3.5), 0.1), 0);
+    assert_eq!(winding_number(&path, point(-1.0, 1.0), 0.1), 0);
+
+    // Points on the boundary.
+    assert_eq!(winding_number(&path, point(0.0, 1.0), 0.1), -1);
+    assert_eq!(winding_number(&path, point(1.0, 0.0), 0.1), -1);
+    assert_eq!(winding_number(&path, point(4.0, 1.0), 0.1), 0);
+    assert_eq!(winding_number(&path, point(1.0, 4.0), 0.1), 0);
+}
+
+#[test]
+fn turning_numbers() {
+    use crate::geom::{point, Angle};
+    use crate::path::Path;
+
+    let mut path = Path::builder();
+
+    // A square.
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(1.0, 0.0));
+    path.line_to(point(1.0, 1.0));
+    path.line_to(point(0.0, 1.0));
+    path.close();
+
+    // The same square in the other direction, not explicitly closed and with a
+    // duplicated point.
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(0.0, 1.0));
+    path.line_to(point(0.0, 1.0));
+    path.line_to(point(1.0, 1.0));
+    path.line_to(point(1.0, 0.0));
+    path.end(false);
+
+    // A figure-eight.
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(1.0, 1.0));
+    path.line_to(point(1.0, 0.0));
+    path.line_to(point(0.0, 1.0));
+    path.close();
+
+    // A circle.
+    path.add_circle(point(5.0, 5.0), 2.0, Winding::Negative);
+
+    // A curve looping twice around a point.
+    path.begin(point(10.0, 0.0));
+    for i in 1..=4 {
+        let angle = Angle::degrees(i as f32 * 180.0);
+        let radius = 10.0 - i as f32;
+        let to = point(radius * angle.get().cos(), radius * angle.get().sin());
+        let ctrl_angle = Angle::degrees(i as f32 * 180.0 - 90.0);
+        let ctrl1 = point(
+            (radius + 1.0) * 1.3 * ctrl_angle.get().cos(),
+            (radius + 1.0) * 1.3 * ctrl_angle.get().sin(),
+        );
+        let ctrl2 = point(
+            radius * 1.3 * ctrl_angle.get().cos(),
+            radius * 1.3 * ctrl_angle.get().sin(),
+        );
+        path.cubic_bezier_to(ctrl1, ctrl2, to);
+    }
+    path.close();
+
+    // A cubic bézier curve with a loop.
+    path.begin(point(0.0, 0.0));
+    path.cubic_bezier_to(point(10.0, 10.0), point(-10.0, 10.0), point(0.0, 0.0));
+    path.close();
+
+    let path = path.build();
+    let mut iter = path.iter();
+
+    let angle = total_turning_angle(&mut path.iter()).unwrap();
+    assert!((angle - 2.0 * PI).abs() < 1e-5);
+
+    assert_eq!(turning_number(&mut iter), Some(1));
+    assert_eq!(turning_number(&mut iter), Some(-1));
+    assert_eq!(turning_number(&mut iter), Some(0));
+    assert_eq!(turning_number(&mut iter), Some(-1));
+    assert_eq!(turning_number(&mut iter), Some(2));
+    assert_eq!(turning_number(&mut iter), Some(1));
+    assert_eq!(turning_number(&mut iter), None);
+}
+
+#[test]
+fn contour_windings_of_curves() {
+    use crate::geom::point;
+    let mut path = crate::path::Path::builder();
+
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(10.0, 0.0));
+    path.line_to(point(10.0, 10.0));
+    path.line_to(point(0.0, 10.0));
+    path.close();
+
+    // A lens made of curves, in the opposite direction.
+    path.begin(point(0.0, 0.0));
+    path.quadratic_bezier_to(point(5.0, 10.0), point(10.0, 0.0));
+    path.cubic_bezier_to(point(7.0, -4.0), point(3.0, -4.0), point(0.0, 0.0));
+    path.close();
+
+    // Open sub-path, closed implicitly.
+    path.begin(point(0.0, 0.0));
+    path.cubic_bezier_to(point(0.0, 5.0), point(5.0, 10.0), point(10.0, 10.0));
+    path.end(false);
+
+    let path = path.build();
+
+    let windings = contour_windings(&path);
+    assert_eq!(
+        windings,
+        std::vec![Winding::Positive, Winding::Negative, Winding::Negative]
+    );
+    assert_eq!(
+        contour_windings(path.reversed()),
+        std::vec![Winding::Positive, Winding::Positive, Winding::Negative]
+    );
+}
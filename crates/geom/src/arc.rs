@@ -7,8 +7,10 @@ use num_traits::NumCast;
 
 use crate::scalar::{cast, Float, Scalar};
 use crate::segment::{BoundingBox, Segment};
-use crate::{point, vector, Angle, Box2D, Point, Rotation, Transform, Vector};
+use crate::traits::Transformation;
+use crate::{point, vector, Angle, Box2D, Point, Rotation, Transform, Vector, Winding};
 use crate::{CubicBezierSegment, Line, LineSegment, QuadraticBezierSegment};
+use arrayvec::ArrayVec;
 
 /// An elliptic arc curve segment.
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -54,6 +56,93 @@ impl<S: Scalar> Arc<S> {
         }
     }
 
+    /// Creates a circular arc going from `from_angle` to `to_angle` in the direction
+    /// given by `winding`.
+    ///
+    /// The sweep angle is positive with `Winding::Positive` and negative with
+    /// `Winding::Negative`. Its magnitude is in `(0, 2π]`: the arc is a full circle if
+    /// both angles are equal (modulo 2π).
+    pub fn circle_segment(
+        center: Point<S>,
+        radius: S,
+        from_angle: Angle<S>,
+        to_angle: Angle<S>,
+        winding: Winding,
+    ) -> Self {
+        let two_pi = S::TWO * S::PI();
+        let mut sweep_angle = (to_angle.radians - from_angle.radians) % two_pi;
+        match winding {
+            Winding::Positive if sweep_angle <= S::ZERO => sweep_angle += two_pi,
+            Winding::Negative if sweep_angle >= S::ZERO => sweep_angle -= two_pi,
+            _ => {}
+        }
+
+        Arc {
+            center,
+            radii: vector(radius, radius),
+            start_angle: from_angle,
+            sweep_angle: Angle::radians(sweep_angle),
+            x_rotation: Angle::zero(),
+        }
+    }
+
+    /// Creates a circular arc from its endpoints and radius.
+    ///
+    /// This is a simpler version of the SVG arc notation for circles: of the two circles
+    /// of the given radius passing through both endpoints, and the two ways of going from
+    /// one endpoint to the other on each of them, `large_arc` selects whether the sweep
+    /// angle is larger than π and `winding` selects its sign.
+    ///
+    /// Like with SVG arcs, the radius is scaled up if it is too small for the arc to
+    /// connect the endpoints, producing a half circle.
+    ///
+    /// `from` and `to` must be different.
+    pub fn from_endpoints_and_radius(
+        from: Point<S>,
+        to: Point<S>,
+        radius: S,
+        large_arc: bool,
+        winding: Winding,
+    ) -> Self {
+        debug_assert!(from != to);
+
+        let chord = to - from;
+        let half_length = chord.length() * S::HALF;
+        let radius = S::max(S::abs(radius), half_length);
+        let distance_to_center =
+            S::sqrt(S::max(radius * radius - half_length * half_length, S::ZERO));
+
+        // The center of the small positive arc is on the left of the chord, in the
+        // positive orientation.
+        let left = vector(-chord.y, chord.x) / (half_length * S::TWO);
+        let side = if large_arc == (winding == Winding::Positive) {
+            -S::ONE
+        } else {
+            S::ONE
+        };
+        let center = from.lerp(to, S::HALF) + left * (distance_to_center * side);
+
+        // Vector::angle_from_x_axis uses a fast but imprecise approximation of atan2,
+        // which would move the endpoints.
+        let angle = |v: Vector<S>| Float::atan2(v.y, v.x);
+        let start_angle = angle(from - center);
+        let two_pi = S::TWO * S::PI();
+        let mut sweep_angle = (angle(to - center) - start_angle) % two_pi;
+        match winding {
+            Winding::Positive if sweep_angle < S::ZERO => sweep_angle += two_pi,
+            Winding::Negative if sweep_angle > S::ZERO => sweep_angle -= two_pi,
+            _ => {}
+        }
+
+        Arc {
+            center,
+            radii: vector(radius, radius),
+            start_angle: Angle::radians(start_angle),
+            sweep_angle: Angle::radians(sweep_angle),
+            x_rotation: Angle::zero(),
+        }
+    }
+
     /// Convert from the SVG arc notation.
     pub fn from_svg_arc(arc: &SvgArc<S>) -> Arc<S> {
         debug_assert!(!arc.from.x.is_nan());
@@ -181,6 +270,11 @@ impl<S: Scalar> Arc<S> {
     }
 
     /// Approximate the arc with a sequence of cubic bézier curves.
+    ///
+    /// The arc is split into pieces of at most 90 degrees, so a full circle produces four
+    /// curves. Each piece is approximated with control points at `4/3 * tan(angle / 4)`
+    /// along the tangents, with a maximum distance to a circular arc of about `2.7e-4`
+    /// times its radius.
     #[inline]
     pub fn for_each_cubic_bezier<F>(&self, cb: &mut F)
     where
@@ -303,6 +397,48 @@ impl<S: Scalar> Arc<S> {
         arc
     }
 
+    /// Applies the transform to this arc and returns the result.
+    ///
+    /// Any affine transformation is supported: the image of an ellipse by an affine
+    /// transformation is another ellipse, so the resulting arc is exactly the
+    /// transformed curve. Transformations that flip the orientation (negative
+    /// determinant) reverse the sign of the sweep angle.
+    pub fn transformed<T: Transformation<S>>(&self, transform: &T) -> Self {
+        let (sin, cos) = Float::sin_cos(self.x_rotation.get());
+        // The columns of the matrix that maps the unit circle to the transformed ellipse.
+        let c0 = transform.transform_vector(vector(cos, sin) * self.radii.x);
+        let c1 = transform.transform_vector(vector(-sin, cos) * self.radii.y);
+
+        let mut start_angle = self.start_angle.get();
+        let mut sweep_angle = self.sweep_angle.get();
+        let (a, b, c, d) = if c0.cross(c1) < S::ZERO {
+            // Flip the parameter space so that the matrix preserves the orientation.
+            start_angle = -start_angle;
+            sweep_angle = -sweep_angle;
+            (c0.x, -c1.x, c0.y, -c1.y)
+        } else {
+            (c0.x, c1.x, c0.y, c1.y)
+        };
+
+        // Decompose the matrix into rotation * scale * rotation.
+        let e = (a + d) * S::HALF;
+        let f = (a - d) * S::HALF;
+        let g = (c + b) * S::HALF;
+        let h = (c - b) * S::HALF;
+        let q = S::sqrt(e * e + h * h);
+        let r = S::sqrt(f * f + g * g);
+        let a1 = Float::atan2(g, f);
+        let a2 = Float::atan2(h, e);
+
+        Arc {
+            center: transform.transform_point(self.center),
+            radii: vector(q + r, q - r),
+            start_angle: Angle::radians(start_angle + (a2 - a1) * S::HALF),
+            sweep_angle: Angle::radians(sweep_angle),
+            x_rotation: Angle::radians((a2 + a1) * S::HALF),
+        }
+    }
+
     /// Approximates the curve with sequence of line segments.
     ///
     /// The `tolerance` parameter defines the maximum distance between the curve and
@@ -367,6 +503,28 @@ impl<S: Scalar> Arc<S> {
         );
     }
 
+    /// Approximates the curve with sequence of points, providing the angle of each
+    /// point on the ellipse.
+    ///
+    /// The callback is invoked for each point of the approximation including the first
+    /// one, so it is called once more than with `for_each_flattened`. The first and last
+    /// angles are guaranteed to be equal to the start and end angles of the arc.
+    pub fn for_each_flattened_with_angle<F>(&self, tolerance: S, callback: &mut F)
+    where
+        F: FnMut(Point<S>, Angle<S>),
+    {
+        callback(self.from(), self.start_angle);
+        let end_angle = self.end_angle();
+        self.for_each_flattened_with_t(tolerance, &mut |segment, range| {
+            let angle = if range.end >= S::ONE {
+                end_angle
+            } else {
+                self.get_angle(range.end)
+            };
+            callback(segment.to, angle);
+        });
+    }
+
     /// Finds the interval of the beginning of the curve that can be approximated with a
     /// line segment.
     fn flattening_step(&self, tolerance: S) -> S {
@@ -401,7 +559,10 @@ impl<S: Scalar> Arc<S> {
         })
     }
 
-    /// Returns a conservative rectangle that contains the curve.
+    /// Returns the smallest axis-aligned rectangle that contains the curve.
+    ///
+    /// The arc is evaluated at its endpoints and at the angles where the ellipse reaches
+    /// its extrema along the x and y axes, if they are within the sweep range.
     pub fn bounding_box(&self) -> Box2D<S> {
         let from = self.from();
         let to = self.to();
@@ -445,6 +606,65 @@ impl<S: Scalar> Arc<S> {
         self.for_each_extremum_inner(a1, a2, cb);
     }
 
+    /// Invokes a callback with the parameter range of each monotonic part of the arc.
+    pub fn for_each_monotonic_range<F>(&self, cb: &mut F)
+    where
+        F: FnMut(Range<S>),
+    {
+        let mut extrema: ArrayVec<S, 4> = ArrayVec::new();
+        self.for_each_local_x_extremum_t(&mut |t| extrema.push(t));
+        self.for_each_local_y_extremum_t(&mut |t| extrema.push(t));
+        extrema.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+
+        for_each_range_between(&extrema, cb);
+    }
+
+    /// Invokes a callback for each monotonic part of the arc.
+    pub fn for_each_monotonic<F>(&self, cb: &mut F)
+    where
+        F: FnMut(&Arc<S>),
+    {
+        self.for_each_monotonic_range(&mut |range| cb(&self.split_range(range)));
+    }
+
+    /// Invokes a callback with the parameter range of each y-monotonic part of the arc.
+    pub fn for_each_y_monotonic_range<F>(&self, cb: &mut F)
+    where
+        F: FnMut(Range<S>),
+    {
+        let mut extrema: ArrayVec<S, 2> = ArrayVec::new();
+        self.for_each_local_y_extremum_t(&mut |t| extrema.push(t));
+
+        for_each_range_between(&extrema, cb);
+    }
+
+    /// Invokes a callback for each y-monotonic part of the arc.
+    pub fn for_each_y_monotonic<F>(&self, cb: &mut F)
+    where
+        F: FnMut(&Arc<S>),
+    {
+        self.for_each_y_monotonic_range(&mut |range| cb(&self.split_range(range)));
+    }
+
+    /// Invokes a callback with the parameter range of each x-monotonic part of the arc.
+    pub fn for_each_x_monotonic_range<F>(&self, cb: &mut F)
+    where
+        F: FnMut(Range<S>),
+    {
+        let mut extrema: ArrayVec<S, 2> = ArrayVec::new();
+        self.for_each_local_x_extremum_t(&mut |t| extrema.push(t));
+
+        for_each_range_between(&extrema, cb);
+    }
+
+    /// Invokes a callback for each x-monotonic part of the arc.
+    pub fn for_each_x_monotonic<F>(&self, cb: &mut F)
+    where
+        F: FnMut(&Arc<S>),
+    {
+        self.for_each_x_monotonic_range(&mut |range| cb(&self.split_range(range)));
+    }
+
     fn for_each_extremum_inner<F>(&self, a1: Angle<S>, a2: Angle<S>, cb: &mut F)
     where
         F: FnMut(S),
@@ -468,11 +688,13 @@ impl<S: Scalar> Arc<S> {
                 cb(a2 / abs_sweep);
             }
         } else {
+            // With a negative sweep, the arc goes through the angles that are at
+            // (two_pi - abs_sweep)..two_pi from the start angle, in decreasing order.
             if a1 > two_pi - abs_sweep {
-                cb(a1 / abs_sweep);
+                cb((two_pi - a1) / abs_sweep);
             }
             if a2 > two_pi - abs_sweep {
-                cb(a2 / abs_sweep);
+                cb((two_pi - a2) / abs_sweep);
             }
         }
     }
@@ -506,6 +728,114 @@ impl<S: Scalar> Arc<S> {
         len
     }
 
+    /// Finds the closest point on the arc to `pos`.
+    ///
+    /// Returns the parameter `t` of the closest point. The corresponding angle on the
+    /// ellipse is `self.get_angle(t)`.
+    pub fn closest_point(&self, pos: Point<S>) -> S {
+        self.closest_point_with_t(pos).0
+    }
+
+    /// Finds the closest point on the arc to `pos`.
+    ///
+    /// Returns the parameter `t` and the position of the closest point. If the closest
+    /// point on the full ellipse is outside of the sweep of the arc, the closest point
+    /// is either another point of the arc or one of its endpoints.
+    pub fn closest_point_with_t(&self, pos: Point<S>) -> (S, Point<S>) {
+        // The closest points are among the endpoints and the angles where the line
+        // passing through pos and the ellipse is perpendicular to the ellipse.
+        //
+        // In the frame of the ellipse, with p = (a.cos(θ), b.sin(θ)) and q the position:
+        // (p - q) . p' = (b² - a²).sin(θ).cos(θ) + a.qx.sin(θ) - b.qy.cos(θ) = 0
+        //
+        // The arc is split into pieces of at most a quarter turn and on each piece
+        // the equation is turned into a quartic polynomial with the tangent half-angle
+        // substitution θ = θ0 + 2.atan(u), which maps the piece to u in [0, 1].
+        let (a, b) = (self.radii.x, self.radii.y);
+        let q = Rotation::new(-self.x_rotation).transform_vector(pos - self.center);
+        let k = b * b - a * a;
+        let l_sin = a * q.x;
+        let l_cos = -b * q.y;
+
+        let mut t = S::ZERO;
+        let mut closest = self.from();
+        let mut sq_dist = (closest - pos).square_length();
+        let to = self.to();
+        let to_dist = (to - pos).square_length();
+        if to_dist < sq_dist {
+            sq_dist = to_dist;
+            t = S::ONE;
+            closest = to;
+        }
+
+        // Past a full turn the arc overlaps itself, so only the first turn is searched.
+        let abs_sweep = S::abs(self.sweep_angle.get());
+        let searched_sweep = abs_sweep.min(S::PI() * S::TWO);
+        let sign = if self.sweep_angle.get() < S::ZERO {
+            -S::ONE
+        } else {
+            S::ONE
+        };
+        let n_pieces = cast::<S, u32>(S::ceil(searched_sweep / S::FRAC_PI_2())).unwrap_or(0);
+        for piece in 0..n_pieces {
+            let offset = S::FRAC_PI_2() * cast(piece).unwrap();
+            let piece_sweep = (searched_sweep - offset).min(S::FRAC_PI_2());
+            let (s0, c0) = Float::sin_cos(self.start_angle.get() + sign * offset);
+
+            // sin(θ) and cos(θ) as rational functions of u, with the common
+            // denominator 1 + u², in increasing degree order.
+            let sin = [s0, S::TWO * sign * c0, -s0];
+            let cos = [c0, -S::TWO * sign * s0, -c0];
+
+            let mut coefficients = [S::ZERO; 5];
+            for i in 0..3 {
+                for j in 0..3 {
+                    coefficients[i + j] += k * sin[i] * cos[j];
+                }
+                // Multiplied by the denominator 1 + u².
+                let l = l_sin * sin[i] + l_cos * cos[i];
+                coefficients[i] += l;
+                coefficients[i + 2] += l;
+            }
+
+            // Map the piece to the unit interval and switch to decreasing degree order.
+            let m = Float::tan(piece_sweep / S::TWO);
+            let mut scale = S::ONE;
+            for c in &mut coefficients {
+                *c *= scale;
+                scale *= m;
+            }
+            coefficients.reverse();
+
+            for v in crate::utils::polynomial_roots_in_unit_interval(&coefficients) {
+                let angle = S::TWO * Float::atan(m * v);
+                let root_t = ((offset + angle) / abs_sweep).min(S::ONE);
+                let p = self.sample(root_t);
+                let d = (p - pos).square_length();
+                if d < sq_dist {
+                    sq_dist = d;
+                    t = root_t;
+                    closest = p;
+                }
+            }
+        }
+
+        (t, closest)
+    }
+
+    /// Returns the shortest distance between this arc and a point.
+    pub fn distance_to_point(&self, pos: Point<S>) -> S {
+        (self.closest_point_with_t(pos).1 - pos).length()
+    }
+
+    /// Returns the shortest squared distance between this arc and a point.
+    ///
+    /// May be useful to avoid the cost of a square root when comparing against a distance
+    /// that can be squared instead.
+    pub fn square_distance_to_point(&self, pos: Point<S>) -> S {
+        (self.closest_point_with_t(pos).1 - pos).square_length()
+    }
+
     #[inline]
     fn tangent_at_angle(&self, angle: Angle<S>) -> Vector<S> {
         let a = angle.get();
@@ -516,6 +846,22 @@ impl<S: Scalar> Arc<S> {
     }
 }
 
+// Splits the unit interval at the provided sorted parameters, skipping empty ranges.
+fn for_each_range_between<S: Scalar, F>(splits: &[S], cb: &mut F)
+where
+    F: FnMut(Range<S>),
+{
+    let mut t0 = S::ZERO;
+    for &t in splits {
+        if t > t0 && t < S::ONE {
+            cb(t0..t);
+            t0 = t;
+        }
+    }
+
+    cb(t0..S::ONE);
+}
+
 impl<S: Scalar> From<SvgArc<S>> for Arc<S> {
     fn from(svg: SvgArc<S>) -> Self {
         svg.to_arc()
@@ -537,6 +883,26 @@ impl<S: Scalar> SvgArc<S> {
             || self.from == self.to
     }
 
+    /// Applies the transform to this arc and returns the result.
+    ///
+    /// See `Arc::transformed`.
+    pub fn transformed<T: Transformation<S>>(&self, transform: &T) -> Self {
+        if self.is_straight_line() {
+            return SvgArc {
+                from: transform.transform_point(self.from),
+                to: transform.transform_point(self.to),
+                ..*self
+            };
+        }
+
+        let mut arc = self.to_arc().transformed(transform).to_svg_arc();
+        // Avoid accumulating precision errors at the endpoints.
+        arc.from = transform.transform_point(self.from);
+        arc.to = transform.transform_point(self.to);
+
+        arc
+    }
+
     /// Approximates the arc with a sequence of quadratic bézier segments.
     pub fn for_each_quadratic_bezier<F>(&self, cb: &mut F)
     where
@@ -721,13 +1087,11 @@ where
         let from = arc.center + v1;
         let to = arc.center + v2;
 
-        // From http://www.spaceroots.org/documents/ellipse/elliptical-arc.pdf
-        // Note that the parameterization used by Arc (see sample_ellipse for
-        // example) is the same as the eta-parameterization used at the link.
+        // The standard approximation, which places the curve's midpoint on the arc.
+        // The tangents are derivatives with respect to the angle, so this also works
+        // for ellipses (an ellipse is an affine transform of a circle).
         let delta_a = a2 - a1;
-        let tan_da = Float::tan(delta_a.get() * S::HALF);
-        let alpha_sqrt = S::sqrt(S::FOUR + S::THREE * tan_da * tan_da);
-        let alpha = Float::sin(delta_a.get()) * (alpha_sqrt - S::ONE) / S::THREE;
+        let alpha = S::FOUR / S::THREE * Float::tan(delta_a.get() / S::FOUR);
         let ctrl1 = from + arc.tangent_at_angle(a1) * alpha;
         let ctrl2 = to - arc.tangent_at_angle(a2) * alpha;
 
@@ -786,6 +1150,14 @@ impl<S: Scalar> Segment for Arc<S> {
         self.approximate_length(tolerance)
     }
 
+    fn for_each_flattened(
+        &self,
+        tolerance: Self::Scalar,
+        callback: &mut dyn FnMut(&LineSegment<S>),
+    ) {
+        self.for_each_flattened(tolerance, &mut |s| callback(s));
+    }
+
     fn for_each_flattened_with_t(
         &self,
         tolerance: Self::Scalar,
@@ -1188,3 +1560,359 @@ fn negative_flattening_step() {
 
     assert!(flattened.len() > 1);
 }
+
+#[test]
+fn bounding_box() {
+    fn sampled_bounding_box(arc: &Arc<f32>) -> Box2D<f32> {
+        let mut min = arc.from();
+        let mut max = arc.from();
+        for i in 1..=1000 {
+            let p = arc.sample(i as f32 / 1000.0);
+            min = min.min(p);
+            max = max.max(p);
+        }
+
+        Box2D { min, max }
+    }
+
+    fn check(arc: &Arc<f32>) {
+        let r = arc.bounding_box();
+        let sampled = sampled_bounding_box(arc);
+        assert!((r.min - sampled.min).length() < 1e-3);
+        assert!((r.max - sampled.max).length() < 1e-3);
+    }
+
+    let mut start_angle = -3.0;
+    while start_angle < 3.5 {
+        for sweep_angle in [-6.5, -5.0, -3.0, -1.0, -0.1, 0.1, 1.0, 3.0, 5.0, 6.5] {
+            for x_rotation in [0.0, 0.3, -1.2] {
+                check(&Arc {
+                    center: point(1.0, 2.0),
+                    radii: vector(3.0, 1.5),
+                    start_angle: Angle::radians(start_angle),
+                    sweep_angle: Angle::radians(sweep_angle),
+                    x_rotation: Angle::radians(x_rotation),
+                });
+            }
+        }
+        start_angle += 0.7;
+    }
+
+    // Lower half of the unit circle, drawn clockwise.
+    let r = Arc {
+        center: point(0.0, 0.0),
+        radii: vector(1.0, 1.0),
+        start_angle: Angle::radians(0.0),
+        sweep_angle: -Angle::pi(),
+        x_rotation: Angle::zero(),
+    }
+    .bounding_box();
+    assert!((r.min - point(-1.0, -1.0)).length() < 1e-5);
+    assert!((r.max - point(1.0, 0.0)).length() < 1e-5);
+}
+
+#[test]
+fn flattened_with_angle() {
+    let arc = Arc {
+        center: point(1.0f64, 2.0),
+        radii: vector(5.0, 3.0),
+        start_angle: Angle::degrees(10.0),
+        sweep_angle: Angle::degrees(-200.0),
+        x_rotation: Angle::degrees(30.0),
+    };
+
+    let mut num_points = 0;
+    let mut prev_angle = Angle::degrees(20.0);
+    arc.for_each_flattened_with_angle(0.01, &mut |p, angle| {
+        if num_points == 0 {
+            assert_eq!(p, arc.from());
+            assert_eq!(angle, arc.start_angle);
+        }
+        // Clockwise sweep.
+        assert!(angle.radians < prev_angle.radians);
+        let expected = arc.center + sample_ellipse(arc.radii, arc.x_rotation, angle).to_vector();
+        assert!((p - expected).length() < 1e-9);
+        prev_angle = angle;
+        num_points += 1;
+    });
+    assert_eq!(prev_angle, arc.end_angle());
+
+    let mut num_segments = 0;
+    arc.for_each_flattened(0.01, &mut |_| num_segments += 1);
+    assert_eq!(num_points, num_segments + 1);
+}
+
+#[test]
+fn transformed() {
+    use crate::euclid::approxeq::ApproxEq;
+
+    let arc = Arc {
+        center: point(1.0, 2.0),
+        radii: vector(3.0, 1.5),
+        start_angle: Angle::radians(0.5),
+        sweep_angle: Angle::radians(2.5),
+        x_rotation: Angle::radians(0.3),
+    };
+
+    let transforms = [
+        Transform::translation(1.0, -2.0),
+        Transform::rotation(Angle::radians(1.2)),
+        Transform::scale(2.0, 0.5),
+        Transform::scale(-1.0, 1.0),
+        Transform::new(1.0, 0.3, 0.8, 1.5, -2.0, 4.0),
+        Transform::new(0.5, 1.2, 1.0, -0.7, 0.0, 1.0),
+    ];
+
+    for transform in &transforms {
+        let transformed = arc.transformed(transform);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let expected = transform.transform_point(arc.sample(t));
+            let actual = transformed.sample(t);
+            assert!(
+                actual.approx_eq_eps(&expected, &point(1e-9, 1e-9)),
+                "{:?} != {:?} ({:?})",
+                actual,
+                expected,
+                transform
+            );
+        }
+
+        let svg_arc = arc.to_svg_arc().transformed(transform);
+        assert_eq!(svg_arc.from, transform.transform_point(arc.from()));
+        assert_eq!(svg_arc.to, transform.transform_point(arc.to()));
+        // The conversion between the two arc representations is not very precise.
+        let mid = svg_arc.to_arc().sample(0.5);
+        assert!(mid.approx_eq_eps(
+            &transform.transform_point(arc.sample(0.5)),
+            &point(1e-3, 1e-3)
+        ));
+    }
+}
+
+#[test]
+fn circle_segment() {
+    use crate::euclid::approxeq::ApproxEq;
+    use core::f64::consts::PI;
+
+    let center = point(1.0f64, 2.0);
+    let eps = point(1e-9, 1e-9);
+
+    let arc = Arc::circle_segment(
+        center,
+        2.0,
+        Angle::radians(0.0),
+        Angle::radians(PI * 0.5),
+        Winding::Positive,
+    );
+    assert!((arc.sweep_angle.radians - PI * 0.5).abs() < 1e-9);
+    assert!(arc.from().approx_eq_eps(&point(3.0, 2.0), &eps));
+    assert!(arc.to().approx_eq_eps(&point(1.0, 4.0), &eps));
+
+    let arc = Arc::circle_segment(
+        center,
+        2.0,
+        Angle::radians(0.0),
+        Angle::radians(PI * 0.5),
+        Winding::Negative,
+    );
+    assert!((arc.sweep_angle.radians + PI * 1.5).abs() < 1e-9);
+    assert!(arc.to().approx_eq_eps(&point(1.0, 4.0), &eps));
+
+    // Equal angles produce a full circle.
+    let arc = Arc::circle_segment(
+        center,
+        2.0,
+        Angle::radians(0.0),
+        Angle::radians(PI * 2.0),
+        Winding::Negative,
+    );
+    assert!((arc.sweep_angle.radians + PI * 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn from_endpoints_and_radius() {
+    use crate::euclid::approxeq::ApproxEq;
+    use core::f64::consts::PI;
+
+    let from = point(1.0f64, 0.0);
+    let to = point(0.0, 1.0);
+    let eps = point(1e-9, 1e-9);
+
+    for large_arc in [false, true] {
+        for winding in [Winding::Positive, Winding::Negative] {
+            let arc = Arc::from_endpoints_and_radius(from, to, 1.0, large_arc, winding);
+            assert!(arc.from().approx_eq_eps(&from, &eps));
+            assert!(arc.to().approx_eq_eps(&to, &eps));
+            assert!((arc.radii.x - 1.0).abs() < 1e-9);
+
+            let sweep = arc.sweep_angle.radians;
+            assert_eq!(sweep > 0.0, winding == Winding::Positive);
+            assert_eq!(sweep.abs() > PI, large_arc);
+
+            let svg_arc = Arc::from_svg_arc(&SvgArc {
+                from,
+                to,
+                radii: vector(1.0, 1.0),
+                x_rotation: Angle::zero(),
+                flags: ArcFlags {
+                    large_arc,
+                    sweep: winding == Winding::Positive,
+                },
+            });
+            assert!(arc
+                .center
+                .approx_eq_eps(&svg_arc.center, &point(1e-6, 1e-6)));
+        }
+    }
+
+    let arc = Arc::from_endpoints_and_radius(from, to, 1.0, false, Winding::Positive);
+    assert!(arc.center.approx_eq_eps(&point(0.0, 0.0), &eps));
+
+    // A radius that is too small is scaled up to produce a half circle.
+    let arc = Arc::from_endpoints_and_radius(from, to, 0.1, false, Winding::Negative);
+    assert!((arc.radii.x - 0.5f64.sqrt()).abs() < 1e-9);
+    assert!((arc.sweep_angle.radians + PI).abs() < 1e-9);
+    assert!(arc.to().approx_eq_eps(&to, &eps));
+}
+
+#[test]
+fn monotonic_ranges() {
+    fn check_monotonic(arc: &Arc<f64>, axis: fn(Point<f64>) -> f64) {
+        let mut prev = axis(arc.from());
+        let mut sign = 0.0;
+        for i in 1..=100 {
+            let v = axis(arc.sample(i as f64 / 100.0));
+            let d = v - prev;
+            if d.abs() > 1e-9 {
+                assert!(sign * d >= 0.0, "{:?}", arc);
+                sign = d.signum();
+            }
+            prev = v;
+        }
+    }
+
+    for sweep_angle in [5.0, -5.0, 2.0 * core::f64::consts::PI, 0.3] {
+        let arc = Arc {
+            center: point(1.0, 2.0),
+            radii: vector(3.0, 1.5),
+            start_angle: Angle::radians(0.3),
+            sweep_angle: Angle::radians(sweep_angle),
+            x_rotation: Angle::radians(0.4),
+        };
+
+        let mut ranges = std::vec::Vec::new();
+        arc.for_each_y_monotonic_range(&mut |range| ranges.push(range));
+        assert_eq!(ranges[0].start, 0.0);
+        assert_eq!(ranges.last().unwrap().end, 1.0);
+        for pair in ranges.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+
+        arc.for_each_y_monotonic(&mut |sub| check_monotonic(sub, |p| p.y));
+        arc.for_each_x_monotonic(&mut |sub| check_monotonic(sub, |p| p.x));
+        let mut count = 0;
+        arc.for_each_monotonic(&mut |sub| {
+            check_monotonic(sub, |p| p.x);
+            check_monotonic(sub, |p| p.y);
+            count += 1;
+        });
+
+        if sweep_angle.abs() > 4.0 {
+            assert!(count >= 4);
+        } else {
+            assert_eq!(count, 1);
+        }
+    }
+}
+
+#[test]
+fn cubic_bezier_approximation() {
+    use core::f32::consts::PI;
+
+    let radius = 100.0;
+    for (sweep_angle, expected_count) in [(0.5, 1), (-2.0, 2), (4.0, 3), (2.0 * PI, 4)] {
+        let arc = Arc {
+            center: point(10.0, 20.0),
+            radii: vector(radius, radius),
+            start_angle: Angle::radians(0.3),
+            sweep_angle: Angle::radians(sweep_angle),
+            x_rotation: Angle::zero(),
+        };
+
+        let mut count = 0;
+        arc.for_each_cubic_bezier(&mut |curve| {
+            count += 1;
+            for i in 0..=20 {
+                let d = (curve.sample(i as f32 / 20.0) - arc.center).length();
+                assert!((d - radius).abs() < 2.8e-4 * radius, "{}", d);
+            }
+        });
+        assert_eq!(count, expected_count);
+    }
+}
+
+#[test]
+fn closest_point() {
+    let arcs = [
+        Arc {
+            center: point(1.0, 2.0),
+            radii: vector(10.0, 4.0),
+            start_angle: Angle::radians(0.5),
+            sweep_angle: Angle::radians(4.0),
+            x_rotation: Angle::radians(0.3),
+        },
+        Arc {
+            center: point(0.0, 0.0),
+            radii: vector(3.0, 8.0),
+            start_angle: Angle::radians(2.0),
+            sweep_angle: Angle::radians(-5.5),
+            x_rotation: Angle::radians(-1.0),
+        },
+        Arc::circle(point(0.0, 0.0), 5.0),
+        // More than a full turn.
+        Arc {
+            center: point(-1.0, 1.0),
+            radii: vector(6.0, 4.0),
+            start_angle: Angle::radians(1.0),
+            sweep_angle: Angle::radians(9.0),
+            x_rotation: Angle::radians(0.5),
+        },
+    ];
+
+    for arc in &arcs {
+        for i in 0..10 {
+            for j in 0..10 {
+                let pos = point(i as f64 * 3.0 - 14.0, j as f64 * 3.0 - 13.0);
+                let (t, p) = arc.closest_point_with_t(pos);
+                assert!((0.0..=1.0).contains(&t));
+                assert!((arc.sample(t) - p).length() < 1e-9);
+
+                // Compare against a brute force search.
+                let mut min = f64::MAX;
+                for k in 0..=10000 {
+                    let d = (arc.sample(k as f64 / 10000.0) - pos).length();
+                    min = min.min(d);
+                }
+                let d = (p - pos).length();
+                assert!(d <= min + 1e-9, "{:?} {:?} {:?} {:?}", arc, pos, d, min);
+                assert!(d > min - 1e-2, "{:?} {:?} {:?} {:?}", arc, pos, d, min);
+            }
+        }
+    }
+
+    // The closest point on the full circle is outside of the sweep.
+    let arc = Arc {
+        center: point(0.0, 0.0),
+        radii: vector(1.0, 1.0),
+        start_angle: Angle::radians(0.0),
+        sweep_angle: Angle::radians(core::f64::consts::FRAC_PI_2),
+        x_rotation: Angle::radians(0.0),
+    };
+    assert_eq!(arc.closest_point(point(2.0, -1.0)), 0.0);
+    assert_eq!(arc.closest_point(point(-1.0, 2.0)), 1.0);
+    let (t, p) = arc.closest_point_with_t(point(2.0, 2.0));
+    assert!((t - 0.5).abs() < 1e-9);
+    assert!((p - point(0.5f64.sqrt(), 0.5f64.sqrt())).length() < 1e-9);
+    assert!((arc.distance_to_point(point(0.0, 0.0)) - 1.0).abs() < 1e-9);
+}
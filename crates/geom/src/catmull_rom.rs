@@ -0,0 +1,138 @@
+//! Catmull-Rom splines.
+//!
+//! Catmull-Rom splines are interpolating: the curve passes through all of its points,
+//! which makes them convenient to smoothly connect sampled positions. Each span between
+//! two consecutive points is exactly representable as a cubic bézier segment.
+
+use crate::scalar::Scalar;
+use crate::{CubicBezierSegment, Point};
+
+/// A span of a uniform Catmull-Rom spline going from `p1` to `p2`.
+///
+/// `p0` and `p3` are the points that precede and follow the span in the spline and
+/// only influence the tangents at `p1` and `p2`.
+///
+/// The tension scales the tangents: a tension of zero produces a regular Catmull-Rom
+/// span while a tension of one produces a straight line between `p1` and `p2`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct CatmullRom<S> {
+    pub p0: Point<S>,
+    pub p1: Point<S>,
+    pub p2: Point<S>,
+    pub p3: Point<S>,
+    pub tension: S,
+}
+
+impl<S: Scalar> CatmullRom<S> {
+    /// Returns the cubic bézier segment that is equivalent to the `p1..p2` span.
+    pub fn to_cubic(&self) -> CubicBezierSegment<S> {
+        let scale = (S::ONE - self.tension) / S::SIX;
+        CubicBezierSegment {
+            from: self.p1,
+            ctrl1: self.p1 + (self.p2 - self.p0) * scale,
+            ctrl2: self.p2 - (self.p3 - self.p1) * scale,
+            to: self.p2,
+        }
+    }
+}
+
+/// Invokes a callback with the cubic bézier segment of each span of the Catmull-Rom
+/// spline passing through the provided points.
+///
+/// The first and last points are duplicated so that the spline starts at the first
+/// point and ends at the last one. Nothing is produced with less than two points.
+pub fn for_each_cubic<S, F>(points: &[Point<S>], tension: S, cb: &mut F)
+where
+    S: Scalar,
+    F: FnMut(&CubicBezierSegment<S>),
+{
+    if points.len() < 2 {
+        return;
+    }
+
+    let last = points.len() - 1;
+    for i in 0..last {
+        let span = CatmullRom {
+            p0: points[if i == 0 { 0 } else { i - 1 }],
+            p1: points[i],
+            p2: points[i + 1],
+            p3: points[usize::min(i + 2, last)],
+            tension,
+        };
+
+        cb(&span.to_cubic());
+    }
+}
+
+#[test]
+fn catmull_rom_to_cubic() {
+    use crate::point;
+
+    let span = CatmullRom {
+        p0: point(0.0f32, 0.0),
+        p1: point(1.0, 1.0),
+        p2: point(2.0, 1.0),
+        p3: point(3.0, 0.0),
+        tension: 0.0,
+    };
+
+    let cubic = span.to_cubic();
+    assert_eq!(cubic.from, span.p1);
+    assert_eq!(cubic.to, span.p2);
+
+    // The derivative of a Catmull-Rom span at p1 is (p2 - p0) / 2, the derivative of
+    // the bézier segment is 3 * (ctrl1 - from).
+    let d0 = cubic.derivative(0.0);
+    let d1 = cubic.derivative(1.0);
+    assert!((d0 - (span.p2 - span.p0) * 0.5).length() < 1e-5);
+    assert!((d1 - (span.p3 - span.p1) * 0.5).length() < 1e-5);
+
+    let straight = CatmullRom {
+        tension: 1.0,
+        ..span
+    }
+    .to_cubic();
+    assert!(straight.is_linear(1e-5));
+}
+
+#[test]
+fn catmull_rom_for_each_cubic() {
+    use crate::point;
+    use std::vec::Vec;
+
+    let points = [
+        point(0.0f32, 0.0),
+        point(10.0, 10.0),
+        point(20.0, 0.0),
+        point(30.0, 10.0),
+    ];
+
+    let mut cubics = Vec::new();
+    for_each_cubic(&points, 0.0, &mut |c| cubics.push(*c));
+
+    assert_eq!(cubics.len(), 3);
+    for (i, cubic) in cubics.iter().enumerate() {
+        assert_eq!(cubic.from, points[i]);
+        assert_eq!(cubic.to, points[i + 1]);
+    }
+
+    // Consecutive segments share their tangents.
+    for pair in cubics.windows(2) {
+        let d0 = pair[0].derivative(1.0);
+        let d1 = pair[1].derivative(0.0);
+        assert!((d0 - d1).length() < 1e-5);
+    }
+
+    // The endpoints are duplicated.
+    assert!((cubics[0].derivative(0.0) - (points[1] - points[0]) * 0.5).length() < 1e-5);
+    assert!((cubics[2].derivative(1.0) - (points[3] - points[2]) * 0.5).length() < 1e-5);
+
+    let mut count = 0;
+    for_each_cubic(&points[..1], 0.0, &mut |_| count += 1);
+    for_each_cubic(&[], 0.0, &mut |_| count += 1);
+    assert_eq!(count, 0);
+
+    for_each_cubic(&points[..2], 0.0, &mut |_| count += 1);
+    assert_eq!(count, 1);
+}
@@ -2,7 +2,10 @@ use crate::cubic_bezier_intersections::cubic_bezier_intersections_t;
 use crate::scalar::Scalar;
 use crate::segment::{BoundingBox, Segment};
 use crate::traits::Transformation;
-use crate::utils::{cubic_polynomial_roots, min_max};
+use crate::utils::{
+    cubic_polynomial_roots, for_each_clamped_segment, min_max, normalized_tangent,
+    signed_curvature, signed_radius_of_curvature,
+};
 use crate::{point, Box2D, Point, Vector};
 use crate::{Line, LineEquation, LineSegment, QuadraticBezierSegment};
 use arrayvec::ArrayVec;
@@ -147,6 +150,74 @@ impl<S: Scalar> CubicBezierSegment<S> {
         self.from.y * c0 + self.ctrl1.y * c1 + self.ctrl2.y * c2 + self.to.y * c3
     }
 
+    /// Sample the curve's second derivative at t (expecting t between 0 and 1).
+    pub fn second_derivative(&self, t: S) -> Vector<S> {
+        let (a, b, _, _) = self.power_basis();
+        a * (S::SIX * t) + b * S::TWO
+    }
+
+    /// Returns the signed curvature of the curve at t (expecting t between 0 and 1).
+    ///
+    /// The curvature is positive where the curve turns from the x axis towards the
+    /// y axis, and negative where it turns the other way. It is zero where the curve is
+    /// locally straight and infinite where the derivative vanishes, for example at a
+    /// cusp.
+    pub fn curvature(&self, t: S) -> S {
+        signed_curvature(self.derivative(t), self.second_derivative(t))
+    }
+
+    /// Returns the signed radius of curvature of the curve at t (expecting t between 0 and 1).
+    ///
+    /// This is the inverse of the curvature and has the same sign. Infinity is returned
+    /// where the curvature is zero, and zero where the derivative vanishes.
+    pub fn radius_of_curvature(&self, t: S) -> S {
+        signed_radius_of_curvature(self.derivative(t), self.second_derivative(t))
+    }
+
+    /// Returns the parameter where the absolute value of the curvature is the highest.
+    ///
+    /// If the curve has a cusp its parameter is returned, and `curvature` is infinite
+    /// there. Otherwise the curvature is sampled at regular intervals and the tightest
+    /// sample is refined with a golden-section search, so the result is approximate.
+    pub fn max_curvature_t(&self) -> S {
+        if let Some(t) = self.cusp_point() {
+            return t;
+        }
+
+        const NUM_SAMPLES: u32 = 32;
+        let step = S::ONE / S::value(NUM_SAMPLES as f32);
+        let mut best_t = S::ZERO;
+        let mut best_curvature = S::abs(self.curvature(S::ZERO));
+        for i in 1..=NUM_SAMPLES {
+            let t = S::value(i as f32) * step;
+            let curvature = S::abs(self.curvature(t));
+            if curvature > best_curvature {
+                best_curvature = curvature;
+                best_t = t;
+            }
+        }
+
+        let inv_phi = S::value(0.618_034);
+        let mut t0 = S::max(best_t - step, S::ZERO);
+        let mut t1 = S::min(best_t + step, S::ONE);
+        for _ in 0..24 {
+            let a = t1 - (t1 - t0) * inv_phi;
+            let b = t0 + (t1 - t0) * inv_phi;
+            if S::abs(self.curvature(a)) > S::abs(self.curvature(b)) {
+                t1 = b;
+            } else {
+                t0 = a;
+            }
+        }
+
+        let t = (t0 + t1) * S::HALF;
+        if S::abs(self.curvature(t)) >= best_curvature {
+            t
+        } else {
+            best_t
+        }
+    }
+
     /// Return the sub-curve inside a given range of t.
     ///
     /// This is equivalent to splitting at the range's end points.
@@ -173,6 +244,32 @@ impl<S: Scalar> CubicBezierSegment<S> {
         }
     }
 
+    /// Split this curve at several parameters, producing `ts.len() + 1` sub-curves.
+    ///
+    /// The parameters are relative to this curve and must be sorted in increasing order
+    /// within `0..1`. Each sub-curve is computed from this curve with `split_range` rather
+    /// than by repeatedly splitting the remaining part, so errors don't accumulate and
+    /// consecutive sub-curves share their endpoints exactly.
+    pub fn split_at_many(&self, ts: &[S], out: &mut impl Extend<CubicBezierSegment<S>>) {
+        debug_assert!(ts.windows(2).all(|w| w[0] <= w[1]));
+
+        let mut t0 = S::ZERO;
+        let mut from = self.from;
+        for i in 0..=ts.len() {
+            let (t1, to) = match ts.get(i) {
+                Some(t) => (*t, self.sample(*t)),
+                None => (S::ONE, self.to),
+            };
+            let mut curve = self.split_range(t0..t1);
+            curve.from = from;
+            curve.to = to;
+            out.extend(Some(curve));
+
+            t0 = t1;
+            from = to;
+        }
+    }
+
     /// Split this curve into two sub-curves.
     pub fn split(&self, t: S) -> (CubicBezierSegment<S>, CubicBezierSegment<S>) {
         let ctrl1a = self.from + (self.ctrl1 - self.from) * t;
@@ -401,6 +498,12 @@ impl<S: Scalar> CubicBezierSegment<S> {
         Flattened::new(self, tolerance)
     }
 
+    /// Returns the curve parameters of the flattened representation of the curve as an
+    /// iterator, starting *after* the current point.
+    pub fn flattened_t(&self, tolerance: S) -> FlattenedT<S> {
+        FlattenedT::new(self, tolerance)
+    }
+
     /// Invokes a callback for each monotonic part of the segment.
     pub fn for_each_monotonic_range<F>(&self, cb: &mut F)
     where
@@ -540,10 +643,45 @@ impl<S: Scalar> CubicBezierSegment<S> {
         cb(&quad, t0..S::ONE)
     }
 
+    /// Approximates the cubic bézier curve with a fixed number of quadratic ones.
+    ///
+    /// Returns the quadratic segments and an upper bound of the maximum distance
+    /// between the cubic curve and its approximation (see `to_quadratic_error`).
+    ///
+    /// The curve is split at equal t intervals, which minimizes the error bound since
+    /// it only depends on the third derivative of the curve which is constant.
+    /// `count` is clamped between 1 and 16.
+    pub fn to_quadratics_fixed(
+        &self,
+        count: usize,
+    ) -> (ArrayVec<QuadraticBezierSegment<S>, 16>, S) {
+        let n = count.clamp(1, 16);
+        let step = S::ONE / S::value(n as f32);
+
+        let mut quadratics = ArrayVec::new();
+        let mut error = S::ZERO;
+        let mut t0 = S::ZERO;
+        for i in 0..n {
+            // Make sure the last segment ends at t = 1.0 exactly.
+            let t1 = if i == n - 1 { S::ONE } else { t0 + step };
+            let sub_curve = self.split_range(t0..t1);
+            error = S::max(error, sub_curve.to_quadratic_error());
+            quadratics.push(sub_curve.to_quadratic());
+
+            t0 = t1;
+        }
+
+        (quadratics, error)
+    }
+
     /// Approximates the curve with sequence of line segments.
     ///
     /// The `tolerance` parameter defines the maximum distance between the curve and
     /// its approximation.
+    ///
+    /// The curve is approximated with a sequence of quadratic bézier curves, each of which
+    /// is flattened using Raph Levien's method (see
+    /// `QuadraticBezierSegment::for_each_flattened`).
     pub fn for_each_flattened<F: FnMut(&LineSegment<S>)>(&self, tolerance: S, callback: &mut F) {
         debug_assert!(tolerance >= S::EPSILON * S::EPSILON);
         let quadratics_tolerance = tolerance * S::value(0.4);
@@ -562,6 +700,11 @@ impl<S: Scalar> CubicBezierSegment<S> {
     /// its approximation.
     ///
     /// The end of the t parameter range at the final segment is guaranteed to be equal to `1.0`.
+    ///
+    /// This uses the same method as `for_each_flattened` and provides the same callback
+    /// signature as `QuadraticBezierSegment::for_each_flattened_with_t`, so that both
+    /// curve types can be flattened generically through the `Segment` trait. The
+    /// parameter ranges are mapped from the quadratic approximations to the cubic curve.
     pub fn for_each_flattened_with_t<F: FnMut(&LineSegment<S>, Range<S>)>(
         &self,
         tolerance: S,
@@ -588,6 +731,50 @@ impl<S: Scalar> CubicBezierSegment<S> {
         });
     }
 
+    /// Approximates the curve with a sequence of line segments which lengths are
+    /// clamped between `min_segment_length` and `max_segment_length`.
+    ///
+    /// The flattened segments are computed with the provided `tolerance`, then long
+    /// segments are evenly subdivided and consecutive short segments are merged.
+    /// Subdividing doesn't affect the approximation error, however merging short
+    /// segments takes precedence over the tolerance, so around cusps the approximation
+    /// can deviate from the curve by up to about `min_segment_length`.
+    ///
+    /// Both bounds are guaranteed to be respected if `min_segment_length` is at most half
+    /// of `max_segment_length`, except when the whole curve is shorter than
+    /// `min_segment_length`, in which case it is approximated with a single segment.
+    /// A flattened segment is never subdivided into more than 65536 segments, so
+    /// `max_segment_length` is not respected if it is extremely small.
+    pub fn for_each_flattened_clamped<F: FnMut(&LineSegment<S>)>(
+        &self,
+        tolerance: S,
+        min_segment_length: S,
+        max_segment_length: S,
+        callback: &mut F,
+    ) {
+        for_each_clamped_segment(
+            min_segment_length,
+            max_segment_length,
+            |cb| self.for_each_flattened(tolerance, &mut |segment| cb(segment)),
+            callback,
+        );
+    }
+
+    /// Computes the length of the polyline produced by `for_each_flattened` with the same
+    /// tolerance.
+    ///
+    /// Unlike `approximate_length` which approximates the arc length of the curve, the
+    /// result matches the length of the flattened geometry produced by the tessellators,
+    /// and is slightly shorter than the arc length.
+    pub fn flattened_length(&self, tolerance: S) -> S {
+        let mut length = S::ZERO;
+        self.for_each_flattened(tolerance, &mut |segment| {
+            length += segment.length();
+        });
+
+        length
+    }
+
     /// Compute the length of the segment using a flattened approximation.
     pub fn approximate_length(&self, tolerance: S) -> S {
         let mut length = S::ZERO;
@@ -599,12 +786,37 @@ impl<S: Scalar> CubicBezierSegment<S> {
         length
     }
 
-    /// Invokes a callback at each inflection point if any.
-    pub fn for_each_inflection_t<F>(&self, cb: &mut F)
-    where
-        F: FnMut(S),
-    {
-        // Find inflection points.
+    /// Computes a lookup table mapping distances along the curve to curve parameters.
+    ///
+    /// This is useful to sample the curve at constant speed. See `ArcLengthParametrization`.
+    pub fn arc_length_parametrization(&self, tolerance: S) -> ArcLengthParametrization<S> {
+        ArcLengthParametrization::new(self, tolerance)
+    }
+
+    /// Returns the parameter `t` at a given distance along the curve.
+    ///
+    /// Distances outside of `[0, length]` are clamped to the endpoints.
+    ///
+    /// This recomputes the arc length lookup table at each call. Use
+    /// `arc_length_parametrization` to perform multiple queries on the same curve.
+    pub fn t_at_distance(&self, distance: S, tolerance: S) -> S {
+        self.arc_length_parametrization(tolerance)
+            .t_at_distance(distance)
+    }
+
+    /// Sample the curve at a given distance along the curve.
+    ///
+    /// Distances outside of `[0, length]` are clamped to the endpoints.
+    ///
+    /// This recomputes the arc length lookup table at each call. Use
+    /// `arc_length_parametrization` to perform multiple queries on the same curve.
+    pub fn sample_at_distance(&self, distance: S, tolerance: S) -> Point<S> {
+        self.sample(self.t_at_distance(distance, tolerance))
+    }
+
+    // Coefficients of the quadratic polynomial a * t² + b * t + c that is zero at the
+    // inflection points.
+    fn inflection_polynomial(&self) -> (S, S, S) {
         // See www.faculty.idc.ac.il/arik/quality/appendixa.html for an explanation
         // of this approach.
         let pa = self.ctrl1 - self.from;
@@ -613,9 +825,120 @@ impl<S: Scalar> CubicBezierSegment<S> {
             + (self.ctrl1.to_vector() * S::THREE)
             - self.from.to_vector();
 
-        let a = pb.cross(pc);
-        let b = pa.cross(pc);
-        let c = pa.cross(pb);
+        (pb.cross(pc), pa.cross(pc), pa.cross(pb))
+    }
+
+    /// Returns the parameters of the inflection points in increasing order, if any.
+    ///
+    /// The inflection points are the roots of a quadratic polynomial (see
+    /// `for_each_inflection_t`). Its coefficients are considered to be zero when their
+    /// absolute value is below `S::EPSILON`, and two roots are merged into a single
+    /// inflection point when the discriminant is below `S::EPSILON`. The latter is what
+    /// happens at a cusp, which is reported as an inflection point.
+    ///
+    /// Unlike `for_each_inflection_t`, no inflection point is returned for curves
+    /// that are straight lines.
+    pub fn inflection_points(&self) -> ArrayVec<S, 2> {
+        let mut result = ArrayVec::new();
+
+        let (a, b, c) = self.inflection_polynomial();
+        if S::abs(a) < S::EPSILON && S::abs(b) < S::EPSILON && S::abs(c) < S::EPSILON {
+            return result;
+        }
+
+        self.for_each_inflection_t(&mut |t| result.push(t));
+
+        result
+    }
+
+    /// Returns the parameter of the cusp of the curve, if any.
+    ///
+    /// A cusp is a point where the derivative of the curve vanishes. In order to account
+    /// for float precision the parameter where the derivative is the shortest is found,
+    /// and it is considered to be a cusp if the length of the derivative there is less
+    /// than `S::EPSILON` times the length of the control polygon.
+    ///
+    /// Only cusps strictly between the endpoints are reported.
+    pub fn cusp_point(&self) -> Option<S> {
+        let polygon_length = (self.ctrl1 - self.from).length()
+            + (self.ctrl2 - self.ctrl1).length()
+            + (self.to - self.ctrl2).length();
+        if polygon_length == S::ZERO {
+            return None;
+        }
+
+        // The derivative is 3a * t² + 2b * t + c and the second derivative 6a * t + 2b.
+        // The derivative is the shortest where their dot product is zero.
+        let (a, b, c, _) = self.power_basis();
+        let roots = cubic_polynomial_roots(
+            a.dot(a) * S::value(18.0),
+            a.dot(b) * S::value(18.0),
+            b.dot(b) * S::FOUR + a.dot(c) * S::SIX,
+            b.dot(c) * S::TWO,
+        );
+
+        let threshold = S::EPSILON * polygon_length;
+        let mut result = None;
+        let mut min_length = threshold;
+        for t in roots {
+            if t <= S::ZERO || t >= S::ONE {
+                continue;
+            }
+            let length = self.derivative(t).length();
+            if length <= min_length {
+                min_length = length;
+                result = Some(t);
+            }
+        }
+
+        result
+    }
+
+    /// Returns the pair of parameters, in increasing order, at which the curve crosses
+    /// itself, if it forms a loop.
+    ///
+    /// Writing the curve as `a * t³ + b * t² + c * t + d`, two parameters `s` and `t`
+    /// map to the same point if and only if `a * (σ² - π) + b * σ + c = 0`, where
+    /// `σ = s + t` and `π = s * t`. The component of this equation orthogonal to `a`
+    /// gives `σ`, the component along `a` gives `π`, and `s` and `t` are the roots of
+    /// `x² - σ * x + π`.
+    ///
+    /// `None` is returned for serpentine curves, curves with a cusp, straight lines
+    /// and loops that are not between the endpoints.
+    pub fn self_intersection(&self) -> Option<(S, S)> {
+        let (a, b, c, _) = self.power_basis();
+
+        let a_cross_b = a.cross(b);
+        if S::abs(a_cross_b) <= S::EPSILON * a.length() * b.length() {
+            return None;
+        }
+
+        let sigma = -a.cross(c) / a_cross_b;
+        let pi = sigma * sigma + (a.dot(b) * sigma + a.dot(c)) / a.square_length();
+
+        let discriminant = sigma * sigma - S::FOUR * pi;
+        if discriminant <= S::EPSILON {
+            // Zero at a cusp and negative for serpentine curves.
+            return None;
+        }
+
+        let sqrt = discriminant.sqrt();
+        let t0 = (sigma - sqrt) * S::HALF;
+        let t1 = (sigma + sqrt) * S::HALF;
+
+        if t0 < S::ZERO || t1 > S::ONE {
+            return None;
+        }
+
+        Some((t0, t1))
+    }
+
+    /// Invokes a callback at each inflection point if any.
+    pub fn for_each_inflection_t<F>(&self, cb: &mut F)
+    where
+        F: FnMut(S),
+    {
+        let (a, b, c) = self.inflection_polynomial();
 
         if S::abs(a) < S::EPSILON {
             // Not a quadratic equation.
@@ -907,6 +1230,27 @@ impl<S: Scalar> CubicBezierSegment<S> {
         }
     }
 
+    /// Returns the smallest axis-aligned rectangle that contains the curve.
+    ///
+    /// The curve is evaluated at its endpoints and at the roots of its derivative
+    /// along each axis, unlike `fast_bounding_box` which contains the control points.
+    pub fn tight_bounding_box(&self) -> Box2D<S> {
+        let mut min = Point::min(self.from, self.to);
+        let mut max = Point::max(self.from, self.to);
+        self.for_each_local_x_extremum_t(&mut |t| {
+            let x = self.x(t);
+            min.x = S::min(min.x, x);
+            max.x = S::max(max.x, x);
+        });
+        self.for_each_local_y_extremum_t(&mut |t| {
+            let y = self.y(t);
+            min.y = S::min(min.y, y);
+            max.y = S::max(max.y, y);
+        });
+
+        Box2D { min, max }
+    }
+
     /// Returns the smallest range of x that contains this curve.
     #[inline]
     pub fn bounding_range_x(&self) -> (S, S) {
@@ -1158,6 +1502,80 @@ impl<S: Scalar> CubicBezierSegment<S> {
         result
     }
 
+    /// Analytic solution to finding the closest point on the curve to `pos`.
+    ///
+    /// Returns the parameter `t` of the closest point.
+    pub fn closest_point(&self, pos: Point<S>) -> S {
+        self.closest_point_with_t(pos).0
+    }
+
+    /// Analytic solution to finding the closest point on the curve to `pos`.
+    ///
+    /// Returns the parameter `t` and the position of the closest point.
+    pub fn closest_point_with_t(&self, pos: Point<S>) -> (S, Point<S>) {
+        // We are looking for the points in the curve where the line passing through pos
+        // and these points are perpendicular to the curve, that is the roots of the
+        // quintic polynomial (B(t) - pos) . B'(t).
+        //
+        // With B(t) = a * t³ + b * t² + c * t + d:
+        let (a, b, c, d) = self.power_basis();
+        let d = d - pos.to_vector();
+
+        let roots = crate::utils::polynomial_roots_in_unit_interval(&[
+            a.dot(a) * S::THREE,
+            a.dot(b) * S::FIVE,
+            a.dot(c) * S::FOUR + b.dot(b) * S::TWO,
+            (a.dot(d) + b.dot(c)) * S::THREE,
+            c.dot(c) + b.dot(d) * S::TWO,
+            c.dot(d),
+        ]);
+
+        let mut sq_dist = d.square_length();
+        let mut t = S::ZERO;
+        let mut closest = self.from;
+        let to_dist = (self.to - pos).square_length();
+        if to_dist < sq_dist {
+            sq_dist = to_dist;
+            t = S::ONE;
+            closest = self.to;
+        }
+        for root in roots {
+            let p = self.sample(root);
+            let d = (pos - p).square_length();
+            if d < sq_dist {
+                sq_dist = d;
+                t = root;
+                closest = p;
+            }
+        }
+
+        (t, closest)
+    }
+
+    // Coefficients (a, b, c, d) of the curve in the power basis: a * t³ + b * t² + c * t + d.
+    pub(crate) fn power_basis(&self) -> (Vector<S>, Vector<S>, Vector<S>, Vector<S>) {
+        (
+            self.to - self.from + (self.ctrl1 - self.ctrl2) * S::THREE,
+            (self.from.to_vector() - self.ctrl1.to_vector() * S::TWO + self.ctrl2.to_vector())
+                * S::THREE,
+            (self.ctrl1 - self.from) * S::THREE,
+            self.from.to_vector(),
+        )
+    }
+
+    /// Returns the shortest distance between this segment and a point.
+    pub fn distance_to_point(&self, pos: Point<S>) -> S {
+        (self.closest_point_with_t(pos).1 - pos).length()
+    }
+
+    /// Returns the shortest squared distance between this segment and a point.
+    ///
+    /// May be useful to avoid the cost of a square root when comparing against a distance
+    /// that can be squared instead.
+    pub fn square_distance_to_point(&self, pos: Point<S>) -> S {
+        (self.closest_point_with_t(pos).1 - pos).square_length()
+    }
+
     fn baseline_projection(&self, t: S) -> S {
         // See https://pomax.github.io/bezierinfo/#abc
         // We are computing the interpolation factor between
@@ -1272,6 +1690,138 @@ impl<S: Scalar> CubicBezierSegment<S> {
         }
     }
 
+    /// Approximates the curve offset by `distance` with a sequence of cubic bézier
+    /// segments, invoking a callback for each of them.
+    ///
+    /// The offset is applied along the curve's normal, that is the derivative rotated
+    /// by 90 degrees (`(-dy, dx)`). Negative distances offset on the opposite side.
+    ///
+    /// The curve is first split at its inflection points and local extrema (which
+    /// include cusps), then each piece is offset and subdivided until the approximation
+    /// is within `tolerance` of the exact offset curve.
+    ///
+    /// The emitted segments are connected except at cusps, where the exact offset
+    /// curve is discontinuous.
+    pub fn offset<F>(&self, distance: S, tolerance: S, cb: &mut F)
+    where
+        F: FnMut(&CubicBezierSegment<S>),
+    {
+        if self.is_a_point(S::ZERO) {
+            return;
+        }
+
+        let mut splits: ArrayVec<S, 7> = ArrayVec::new();
+        self.for_each_inflection_t(&mut |t| splits.push(t));
+        self.for_each_local_x_extremum_t(&mut |t| splits.push(t));
+        self.for_each_local_y_extremum_t(&mut |t| splits.push(t));
+        splits.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        splits.push(S::ONE);
+
+        let mut t0 = S::ZERO;
+        for &t in &splits {
+            if t - t0 <= S::EPSILON {
+                continue;
+            }
+            self.split_range(t0..t)
+                .offset_piece(distance, tolerance, 0, cb);
+            t0 = t;
+        }
+    }
+
+    fn offset_piece<F>(&self, distance: S, tolerance: S, depth: u32, cb: &mut F)
+    where
+        F: FnMut(&CubicBezierSegment<S>),
+    {
+        const MAX_DEPTH: u32 = 10;
+
+        let approximation = self.offset_approximation(distance);
+        if depth >= MAX_DEPTH || self.offset_error(&approximation, distance) <= tolerance {
+            cb(&approximation);
+            return;
+        }
+
+        let (a, b) = self.split(S::HALF);
+        a.offset_piece(distance, tolerance, depth + 1, cb);
+        b.offset_piece(distance, tolerance, depth + 1, cb);
+    }
+
+    // Offsets the control polygon following the Tiller-Hanson method: each edge of the
+    // polygon is moved along its normal and the new control points are placed at the
+    // intersections of the offset edges.
+    fn offset_approximation(&self, distance: S) -> Self {
+        let epsilon = S::EPSILON * S::EPSILON;
+        let first_non_zero = |vectors: [Vector<S>; 3]| {
+            for v in vectors {
+                if v.square_length() > epsilon {
+                    return v;
+                }
+            }
+            vectors[2]
+        };
+
+        let v0 = self.ctrl1 - self.from;
+        let v1 = self.ctrl2 - self.ctrl1;
+        let v2 = self.to - self.ctrl2;
+        let start_tangent = first_non_zero([v0, self.ctrl2 - self.from, self.to - self.from]);
+        let end_tangent = first_non_zero([v2, self.to - self.ctrl1, self.to - self.from]);
+
+        let n0 = normalized_tangent(start_tangent) * distance;
+        let n2 = normalized_tangent(end_tangent) * distance;
+
+        let from = self.from + n0;
+        let to = self.to + n2;
+
+        let mut ctrl1 = self.ctrl1 + n0;
+        let mut ctrl2 = self.ctrl2 + n2;
+        if v1.square_length() > epsilon {
+            let middle_edge = Line {
+                point: self.ctrl1 + normalized_tangent(v1) * distance,
+                vector: v1,
+            };
+            if v0.square_length() > epsilon {
+                let first_edge = Line {
+                    point: from,
+                    vector: v0,
+                };
+                if let Some(p) = first_edge.intersection(&middle_edge) {
+                    ctrl1 = p;
+                }
+            }
+            if v2.square_length() > epsilon {
+                let last_edge = Line {
+                    point: to,
+                    vector: v2,
+                };
+                if let Some(p) = last_edge.intersection(&middle_edge) {
+                    ctrl2 = p;
+                }
+            }
+        }
+
+        CubicBezierSegment {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        }
+    }
+
+    // Measures the distance between a few points of the exact offset curve and
+    // its approximation.
+    fn offset_error(&self, approximation: &Self, distance: S) -> S {
+        let mut error = S::ZERO;
+        for t in [S::value(0.25), S::HALF, S::value(0.75)] {
+            let derivative = self.derivative(t);
+            if derivative.square_length() <= S::EPSILON * S::EPSILON {
+                continue;
+            }
+            let target = self.sample(t) + normalized_tangent(derivative) * distance;
+            error = S::max(error, approximation.distance_to_point(target));
+        }
+
+        error
+    }
+
     pub fn to_f32(&self) -> CubicBezierSegment<f32> {
         CubicBezierSegment {
             from: self.from.to_f32(),
@@ -1294,6 +1844,14 @@ impl<S: Scalar> CubicBezierSegment<S> {
 impl<S: Scalar> Segment for CubicBezierSegment<S> {
     impl_segment!(S);
 
+    fn for_each_flattened(
+        &self,
+        tolerance: Self::Scalar,
+        callback: &mut dyn FnMut(&LineSegment<S>),
+    ) {
+        self.for_each_flattened(tolerance, &mut |s| callback(s));
+    }
+
     fn for_each_flattened_with_t(
         &self,
         tolerance: Self::Scalar,
@@ -1322,6 +1880,36 @@ impl<S: Scalar> BoundingBox for CubicBezierSegment<S> {
 use crate::quadratic_bezier::FlattenedT as FlattenedQuadraticSegment;
 
 pub struct Flattened<S: Scalar> {
+    curve: CubicBezierSegment<S>,
+    t: FlattenedT<S>,
+}
+
+impl<S: Scalar> Flattened<S> {
+    pub(crate) fn new(curve: &CubicBezierSegment<S>, tolerance: S) -> Self {
+        Flattened {
+            curve: *curve,
+            t: FlattenedT::new(curve, tolerance),
+        }
+    }
+}
+
+impl<S: Scalar> Iterator for Flattened<S> {
+    type Item = Point<S>;
+
+    fn next(&mut self) -> Option<Point<S>> {
+        self.t.next().map(|t| self.curve.sample(t))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.t.size_hint()
+    }
+}
+
+/// Iterates over the curve parameters of the flattened approximation of a
+/// cubic bézier curve, starting *after* the first point.
+///
+/// See `CubicBezierSegment::flattened_t`.
+pub struct FlattenedT<S: Scalar> {
     curve: CubicBezierSegment<S>,
     current_curve: FlattenedQuadraticSegment<S>,
     remaining_sub_curves: i32,
@@ -1330,7 +1918,7 @@ pub struct Flattened<S: Scalar> {
     range_start: S,
 }
 
-impl<S: Scalar> Flattened<S> {
+impl<S: Scalar> FlattenedT<S> {
     pub(crate) fn new(curve: &CubicBezierSegment<S>, tolerance: S) -> Self {
         debug_assert!(tolerance >= S::EPSILON * S::EPSILON);
 
@@ -1344,7 +1932,7 @@ impl<S: Scalar> Flattened<S> {
         let quadratic = curve.split_range(S::ZERO..range_step).to_quadratic();
         let current_curve = FlattenedQuadraticSegment::new(&quadratic, flattening_tolerance);
 
-        Flattened {
+        FlattenedT {
             curve: *curve,
             current_curve,
             remaining_sub_curves: num_quadratics.to_i32().unwrap() - 1,
@@ -1355,13 +1943,16 @@ impl<S: Scalar> Flattened<S> {
     }
 }
 
-impl<S: Scalar> Iterator for Flattened<S> {
-    type Item = Point<S>;
+impl<S: Scalar> Iterator for FlattenedT<S> {
+    type Item = S;
 
-    fn next(&mut self) -> Option<Point<S>> {
+    fn next(&mut self) -> Option<S> {
         if let Some(t_inner) = self.current_curve.next() {
-            let t = self.range_start + t_inner * self.range_step;
-            return Some(self.curve.sample(t));
+            if t_inner == S::ONE && self.remaining_sub_curves <= 0 {
+                // Avoid accumulated rounding errors at the end of the curve.
+                return Some(S::ONE);
+            }
+            return Some(self.range_start + t_inner * self.range_step);
         }
 
         if self.remaining_sub_curves <= 0 {
@@ -1377,9 +1968,11 @@ impl<S: Scalar> Iterator for Flattened<S> {
         self.current_curve = FlattenedQuadraticSegment::new(&quadratic, self.tolerance);
 
         let t_inner = self.current_curve.next().unwrap_or(S::ONE);
-        let t = t0 + t_inner * self.range_step;
+        if t_inner == S::ONE && self.remaining_sub_curves <= 0 {
+            return Some(S::ONE);
+        }
 
-        Some(self.curve.sample(t))
+        Some(t0 + t_inner * self.range_step)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -1390,6 +1983,98 @@ impl<S: Scalar> Iterator for Flattened<S> {
     }
 }
 
+/// Maximum number of entries in an `ArcLengthParametrization` lookup table.
+const ARC_LENGTH_LUT_SIZE: usize = 64;
+
+/// A lookup table mapping distances along a cubic bézier curve to curve parameters.
+///
+/// The table is built once using the curve's flattened approximation so that
+/// repeated queries (for example to animate an object along the curve at
+/// constant speed) don't need to re-flatten the curve.
+///
+/// The number of entries depends on the tolerance threshold and is limited to 64.
+#[derive(Clone, Debug)]
+pub struct ArcLengthParametrization<S> {
+    curve: CubicBezierSegment<S>,
+    // (t, length of the curve between 0 and t) pairs, sorted by increasing t.
+    lut: ArrayVec<(S, S), ARC_LENGTH_LUT_SIZE>,
+}
+
+impl<S: Scalar> ArcLengthParametrization<S> {
+    pub fn new(curve: &CubicBezierSegment<S>, tolerance: S) -> Self {
+        let mut num_segments = 0;
+        curve.for_each_flattened_with_t(tolerance, &mut |_, _| num_segments += 1);
+        let n = num_segments.clamp(1, ARC_LENGTH_LUT_SIZE - 1);
+
+        let step = S::ONE / S::value(n as f32);
+        let mut lut = ArrayVec::new();
+        lut.push((S::ZERO, S::ZERO));
+
+        let mut t0 = S::ZERO;
+        let mut length = S::ZERO;
+        for i in 1..=n {
+            // Make sure the last entry is exactly at t = 1.
+            let t1 = if i == n {
+                S::ONE
+            } else {
+                S::value(i as f32) * step
+            };
+            let sub_curve = curve.split_range(t0..t1);
+            if !sub_curve.is_a_point(S::ZERO) {
+                length += sub_curve.approximate_length(tolerance);
+            }
+            lut.push((t1, length));
+            t0 = t1;
+        }
+
+        ArcLengthParametrization { curve: *curve, lut }
+    }
+
+    /// The curve this lookup table was built for.
+    #[inline]
+    pub fn curve(&self) -> &CubicBezierSegment<S> {
+        &self.curve
+    }
+
+    /// Approximate length of the curve.
+    #[inline]
+    pub fn length(&self) -> S {
+        self.lut.last().unwrap().1
+    }
+
+    /// Returns the parameter `t` at a given distance along the curve.
+    ///
+    /// Distances outside of `[0, length]` are clamped to the endpoints.
+    pub fn t_at_distance(&self, distance: S) -> S {
+        if distance <= S::ZERO {
+            return S::ZERO;
+        }
+        if distance >= self.length() {
+            return S::ONE;
+        }
+
+        // Index of the first entry past the requested distance. It can't be
+        // the first entry since distance > 0.
+        let idx = self.lut.partition_point(|&(_, d)| d <= distance);
+        let (t0, d0) = self.lut[idx - 1];
+        let (t1, d1) = self.lut[idx];
+
+        let range = d1 - d0;
+        if range <= S::ZERO {
+            return t0;
+        }
+
+        t0 + (t1 - t0) * (distance - d0) / range
+    }
+
+    /// Sample the curve at a given distance along the curve.
+    ///
+    /// Distances outside of `[0, length]` are clamped to the endpoints.
+    pub fn sample_at_distance(&self, distance: S) -> Point<S> {
+        self.curve.sample(self.t_at_distance(distance))
+    }
+}
+
 #[cfg(test)]
 fn print_arrays(a: &[Point<f32>], b: &[Point<f32>]) {
     std::println!("left:  {a:?}");
@@ -2066,3 +2751,636 @@ fn test_cubic_to_quadratics() {
     assert!(count < 10);
     assert!(count > 4);
 }
+
+#[test]
+fn arc_length_parametrization() {
+    let curve = CubicBezierSegment {
+        from: point(0.0f64, 0.0),
+        ctrl1: point(10.0, 0.0),
+        ctrl2: point(100.0, 50.0),
+        to: point(100.0, 100.0),
+    };
+
+    let tolerance = 0.001;
+    let lut = curve.arc_length_parametrization(tolerance);
+    let length = curve.approximate_length(tolerance);
+    assert!((lut.length() - length).abs() < 0.01);
+
+    // Clamp to the endpoints.
+    assert_eq!(lut.t_at_distance(-1.0), 0.0);
+    assert_eq!(lut.t_at_distance(length * 2.0), 1.0);
+    assert_eq!(lut.sample_at_distance(-1.0), curve.from);
+    assert_eq!(lut.sample_at_distance(length * 2.0), curve.to);
+
+    // Evenly spaced distances should produce evenly spaced points.
+    let n = 20;
+    let step = length / n as f64;
+    let mut prev = curve.from;
+    for i in 1..=n {
+        let p = curve.sample_at_distance(step * i as f64, tolerance);
+        let d = (p - prev).length();
+        assert!((d - step).abs() < step * 0.05, "{:?} != {:?}", d, step);
+        prev = p;
+    }
+
+    let t = curve.t_at_distance(length * 0.5, tolerance);
+    let first_half = curve.split_range(0.0..t).approximate_length(tolerance);
+    assert!((first_half - length * 0.5).abs() < 0.1);
+}
+
+#[test]
+fn arc_length_parametrization_point() {
+    let curve = CubicBezierSegment {
+        from: point(1.0f32, 1.0),
+        ctrl1: point(1.0, 1.0),
+        ctrl2: point(1.0, 1.0),
+        to: point(1.0, 1.0),
+    };
+
+    let lut = curve.arc_length_parametrization(0.1);
+    assert_eq!(lut.length(), 0.0);
+    assert_eq!(lut.sample_at_distance(1.0), curve.to);
+}
+
+#[test]
+fn closest_point() {
+    let curve = CubicBezierSegment {
+        from: point(0.0f64, 0.0),
+        ctrl1: point(0.0, 100.0),
+        ctrl2: point(100.0, -50.0),
+        to: point(100.0, 50.0),
+    };
+
+    // Compare against a brute-force search.
+    for pos in [
+        point(50.0, 50.0),
+        point(-10.0, 20.0),
+        point(30.0, -40.0),
+        point(110.0, 60.0),
+        point(70.0, 0.0),
+    ] {
+        let (t, p) = curve.closest_point_with_t(pos);
+        assert_eq!(p, curve.sample(t));
+
+        let mut best = f64::MAX;
+        for i in 0..=1000 {
+            let d = (curve.sample(i as f64 / 1000.0) - pos).length();
+            best = best.min(d);
+        }
+
+        let d = curve.distance_to_point(pos);
+        assert!(d <= best + 1e-6, "{:?} > {:?}", d, best);
+        assert!(d >= best - 0.1, "{:?} < {:?}", d, best);
+    }
+
+    // Points on the curve.
+    for t in [0.0, 0.25, 0.5, 0.9, 1.0] {
+        let pos = curve.sample(t);
+        let (t2, p) = curve.closest_point_with_t(pos);
+        assert!((t - t2).abs() < 1e-6);
+        assert!((p - pos).length() < 1e-6);
+    }
+
+    // The minimum is at an endpoint.
+    assert_eq!(curve.closest_point(point(-100.0, -100.0)), 0.0);
+    assert_eq!(curve.closest_point(point(200.0, 100.0)), 1.0);
+
+    // Degenerate curve.
+    let point_curve = CubicBezierSegment {
+        from: point(1.0f32, 1.0),
+        ctrl1: point(1.0, 1.0),
+        ctrl2: point(1.0, 1.0),
+        to: point(1.0, 1.0),
+    };
+    assert_eq!(
+        point_curve.closest_point_with_t(point(5.0, 4.0)),
+        (0.0, point(1.0, 1.0))
+    );
+}
+
+#[test]
+fn offset() {
+    let curves = [
+        CubicBezierSegment {
+            from: point(0.0f64, 0.0),
+            ctrl1: point(0.0, 100.0),
+            ctrl2: point(100.0, -50.0),
+            to: point(100.0, 50.0),
+        },
+        CubicBezierSegment {
+            from: point(0.0, 0.0),
+            ctrl1: point(100.0, 0.0),
+            ctrl2: point(100.0, 100.0),
+            to: point(0.0, 100.0),
+        },
+        CubicBezierSegment {
+            from: point(0.0, 0.0),
+            ctrl1: point(0.0, 0.0),
+            ctrl2: point(50.0, 70.0),
+            to: point(100.0, 100.0),
+        },
+    ];
+
+    let tolerance = 0.1;
+    for curve in &curves {
+        for distance in [10.0, -10.0, 3.0] {
+            let mut prev: Option<CubicBezierSegment<f64>> = None;
+            curve.offset(distance, tolerance, &mut |segment| {
+                for i in 0..=10 {
+                    let p = segment.sample(i as f64 / 10.0);
+                    let d = curve.distance_to_point(p);
+                    assert!(
+                        (d - f64::abs(distance)).abs() <= tolerance * 2.0,
+                        "{:?} != {:?}",
+                        d,
+                        distance
+                    );
+                }
+                if let Some(prev) = prev {
+                    assert!((prev.to - segment.from).length() < 1e-3);
+                }
+                prev = Some(*segment);
+            });
+
+            let prev = prev.unwrap();
+            let expected_end = curve.to + normalized_tangent(curve.to - curve.ctrl2) * distance;
+            assert!((prev.to - expected_end).length() < 1e-3);
+        }
+    }
+
+    // Offsetting in opposite directions by the same amount lands on opposite sides.
+    let curve = &curves[1];
+    let mut a = Vec::new();
+    let mut b = Vec::new();
+    curve.offset(5.0, tolerance, &mut |s| a.push(*s));
+    curve.offset(-5.0, tolerance, &mut |s| b.push(*s));
+    let mid_a = a[0].from;
+    let mid_b = b[0].from;
+    assert!(((mid_a + (mid_b - mid_a) * 0.5) - curve.from).length() < 1e-6);
+
+    // A straight line is simply translated.
+    let line = CubicBezierSegment {
+        from: point(0.0f32, 0.0),
+        ctrl1: point(10.0, 0.0),
+        ctrl2: point(20.0, 0.0),
+        to: point(30.0, 0.0),
+    };
+    let mut segments = Vec::new();
+    line.offset(2.0, 0.01, &mut |s| segments.push(*s));
+    assert_eq!(segments.len(), 1);
+    assert_eq!(segments[0].from, point(0.0, 2.0));
+    assert_eq!(segments[0].to, point(30.0, 2.0));
+}
+
+#[test]
+fn inflection_points() {
+    // S-shaped curve with a single inflection point in the middle.
+    let curve = CubicBezierSegment {
+        from: point(0.0f64, 0.0),
+        ctrl1: point(0.0, 100.0),
+        ctrl2: point(100.0, -100.0),
+        to: point(100.0, 0.0),
+    };
+    let inflections = curve.inflection_points();
+    assert_eq!(inflections.len(), 1);
+    assert!((inflections[0] - 0.5).abs() < 1e-6);
+    assert!(curve.cusp_point().is_none());
+
+    // Two inflection points.
+    let curve = CubicBezierSegment {
+        from: point(0.0f64, 0.0),
+        ctrl1: point(100.0, 100.0),
+        ctrl2: point(0.0, 100.0),
+        to: point(150.0, 0.0),
+    };
+    let inflections = curve.inflection_points();
+    assert_eq!(inflections.len(), 2);
+    assert!((inflections[0] - 1.0 / 3.0).abs() < 1e-6);
+    assert!((inflections[1] - 2.0 / 3.0).abs() < 1e-6);
+    assert!(curve.cusp_point().is_none());
+
+    // No inflection.
+    let curve = CubicBezierSegment {
+        from: point(0.0f32, 0.0),
+        ctrl1: point(0.0, 100.0),
+        ctrl2: point(100.0, 100.0),
+        to: point(100.0, 0.0),
+    };
+    assert!(curve.inflection_points().is_empty());
+
+    // Straight lines have no inflection point.
+    let line = CubicBezierSegment {
+        from: point(0.0f32, 0.0),
+        ctrl1: point(10.0, 10.0),
+        ctrl2: point(20.0, 20.0),
+        to: point(30.0, 30.0),
+    };
+    assert!(line.inflection_points().is_empty());
+    assert!(line.cusp_point().is_none());
+}
+
+#[test]
+fn cusp_point() {
+    let curve = CubicBezierSegment {
+        from: point(0.0f64, 0.0),
+        ctrl1: point(100.0, 100.0),
+        ctrl2: point(0.0, 100.0),
+        to: point(100.0, 0.0),
+    };
+    let t = curve.cusp_point().unwrap();
+    assert!((t - 0.5).abs() < 1e-6);
+    assert_eq!(curve.inflection_points().len(), 1);
+
+    // Moving a control point slightly turns the cusp into a small loop.
+    let curve = CubicBezierSegment {
+        ctrl2: point(0.0, 101.0),
+        ..curve
+    };
+    assert!(curve.cusp_point().is_none());
+
+    // A straight line going back and forth has a cusp where it changes direction.
+    let line = CubicBezierSegment {
+        from: point(0.0f32, 0.0),
+        ctrl1: point(100.0, 0.0),
+        ctrl2: point(100.0, 0.0),
+        to: point(0.0, 0.0),
+    };
+    assert!((line.cusp_point().unwrap() - 0.5).abs() < 1e-4);
+
+    // Degenerate tangents at the endpoints are not cusps.
+    let curve = CubicBezierSegment {
+        from: point(0.0f32, 0.0),
+        ctrl1: point(0.0, 0.0),
+        ctrl2: point(100.0, 100.0),
+        to: point(100.0, 100.0),
+    };
+    assert!(curve.cusp_point().is_none());
+}
+
+#[test]
+fn curvature() {
+    // Approximation of a quarter of the unit circle, going counter-clockwise.
+    let k = 0.552_284_8;
+    let arc: CubicBezierSegment<f32> = CubicBezierSegment {
+        from: Point::new(1.0, 0.0),
+        ctrl1: Point::new(1.0, k),
+        ctrl2: Point::new(k, 1.0),
+        to: Point::new(0.0, 1.0),
+    };
+
+    for i in 0..=10 {
+        let t = i as f32 / 10.0;
+        let curvature = arc.curvature(t);
+        assert!((curvature - 1.0).abs() < 0.05, "{:?}", curvature);
+        assert!((arc.radius_of_curvature(t) - 1.0).abs() < 0.05);
+        assert!((arc.flip().curvature(t) + 1.0).abs() < 0.05);
+    }
+
+    let line: CubicBezierSegment<f32> = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(1.0, 1.0),
+        ctrl2: Point::new(2.0, 2.0),
+        to: Point::new(3.0, 3.0),
+    };
+    assert_eq!(line.curvature(0.5), 0.0);
+    assert_eq!(line.radius_of_curvature(0.5), f32::INFINITY);
+
+    // The parabola y = x² elevated to a cubic, its tightest bend is at its apex.
+    let parabola: CubicBezierSegment<f32> = QuadraticBezierSegment {
+        from: Point::new(-1.0, 1.0),
+        ctrl: Point::new(0.0, -1.0),
+        to: Point::new(1.0, 1.0),
+    }
+    .to_cubic();
+    assert!((parabola.curvature(0.5) - 2.0).abs() < 1e-4);
+    assert!((parabola.max_curvature_t() - 0.5).abs() < 1e-3);
+
+    // An asymmetric curve, the result should be a local maximum.
+    let curve: CubicBezierSegment<f32> = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(100.0, 0.0),
+        ctrl2: Point::new(100.0, 20.0),
+        to: Point::new(0.0, 50.0),
+    };
+    let t = curve.max_curvature_t();
+    let max = curve.curvature(t).abs();
+    assert!(max >= curve.curvature(t - 0.01).abs());
+    assert!(max >= curve.curvature(t + 0.01).abs());
+    for i in 0..=100 {
+        assert!(max >= curve.curvature(i as f32 / 100.0).abs() * 0.999);
+    }
+
+    let cusp: CubicBezierSegment<f32> = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(100.0, 100.0),
+        ctrl2: Point::new(0.0, 100.0),
+        to: Point::new(100.0, 0.0),
+    };
+    assert!((cusp.max_curvature_t() - 0.5).abs() < 1e-3);
+    assert_eq!(cusp.curvature(0.5), f32::INFINITY);
+    assert_eq!(cusp.radius_of_curvature(0.5), 0.0);
+}
+
+#[test]
+fn tight_bounding_box() {
+    let curves: [CubicBezierSegment<f32>; 5] = [
+        CubicBezierSegment {
+            from: Point::new(0.0, 0.0),
+            ctrl1: Point::new(100.0, 200.0),
+            ctrl2: Point::new(-50.0, -100.0),
+            to: Point::new(20.0, 10.0),
+        },
+        CubicBezierSegment {
+            from: Point::new(10.0, 10.0),
+            ctrl1: Point::new(200.0, 10.0),
+            ctrl2: Point::new(-100.0, 50.0),
+            to: Point::new(10.0, 60.0),
+        },
+        // A loop.
+        CubicBezierSegment {
+            from: Point::new(0.0, 0.0),
+            ctrl1: Point::new(100.0, 100.0),
+            ctrl2: Point::new(-100.0, 100.0),
+            to: Point::new(0.0, 0.0),
+        },
+        // A quadratic curve elevated to a cubic.
+        QuadraticBezierSegment {
+            from: Point::new(0.0, 0.0),
+            ctrl: Point::new(50.0, 80.0),
+            to: Point::new(100.0, 0.0),
+        }
+        .to_cubic(),
+        // A straight line.
+        CubicBezierSegment {
+            from: Point::new(0.0, 0.0),
+            ctrl1: Point::new(1.0, 2.0),
+            ctrl2: Point::new(2.0, 4.0),
+            to: Point::new(3.0, 6.0),
+        },
+    ];
+
+    for curve in &curves {
+        let mut min = curve.from;
+        let mut max = curve.from;
+        for i in 1..=1000 {
+            let p = curve.sample(i as f32 / 1000.0);
+            min = min.min(p);
+            max = max.max(p);
+        }
+
+        let r = curve.tight_bounding_box();
+        assert!((r.min - min).length() < 1e-2);
+        assert!((r.max - max).length() < 1e-2);
+
+        let fast = curve.fast_bounding_box();
+        assert!(fast.contains_box(&r));
+    }
+}
+
+#[test]
+fn self_intersection() {
+    let curve = CubicBezierSegment {
+        from: Point::new(-10.0f32, -13.636363636363636),
+        ctrl1: Point::new(15.0, 11.363636363636363),
+        ctrl2: Point::new(-15.0, 11.363636363636363),
+        to: Point::new(10.0, -13.636363636363636),
+    };
+    let (t0, t1) = curve.self_intersection().unwrap();
+    assert!(t0 < t1);
+    assert!(curve.sample(t0).to_vector().length() < 1e-3);
+    assert!(curve.sample(t1).to_vector().length() < 1e-3);
+
+    let curve = CubicBezierSegment {
+        from: Point::new(0.0f32, 0.0),
+        ctrl1: Point::new(150.0, 100.0),
+        ctrl2: Point::new(-50.0, 120.0),
+        to: Point::new(100.0, 10.0),
+    };
+    let (t0, t1) = curve.self_intersection().unwrap();
+    assert!(t0 > 0.0 && t1 < 1.0 && t1 - t0 > 0.1);
+    assert!((curve.sample(t0) - curve.sample(t1)).length() < 1e-3);
+
+    // The loop closes on the endpoints.
+    let curve = CubicBezierSegment {
+        from: Point::new(0.0f32, 0.0),
+        ctrl1: Point::new(100.0, 100.0),
+        ctrl2: Point::new(-100.0, 100.0),
+        to: Point::new(0.0, 0.0),
+    };
+    let (t0, t1) = curve.self_intersection().unwrap();
+    assert!(t0.abs() < 1e-5);
+    assert!((t1 - 1.0).abs() < 1e-5);
+
+    // The loop would be outside of the curve.
+    assert!(curve.split_range(0.1..0.9).self_intersection().is_none());
+
+    // Serpentine.
+    let curve = CubicBezierSegment {
+        from: Point::new(0.0f32, 0.0),
+        ctrl1: Point::new(100.0, 100.0),
+        ctrl2: Point::new(0.0, 100.0),
+        to: Point::new(150.0, 0.0),
+    };
+    assert!(curve.self_intersection().is_none());
+
+    // Cusp.
+    let curve = CubicBezierSegment {
+        from: Point::new(0.0f32, 0.0),
+        ctrl1: Point::new(100.0, 100.0),
+        ctrl2: Point::new(0.0, 100.0),
+        to: Point::new(100.0, 0.0),
+    };
+    assert!(curve.self_intersection().is_none());
+
+    // Straight line.
+    let curve = CubicBezierSegment {
+        from: Point::new(0.0f32, 0.0),
+        ctrl1: Point::new(30.0, 0.0),
+        ctrl2: Point::new(10.0, 0.0),
+        to: Point::new(100.0, 0.0),
+    };
+    assert!(curve.self_intersection().is_none());
+}
+
+#[test]
+fn flattened_length() {
+    let curve = CubicBezierSegment {
+        from: Point::new(0.0f32, 0.0),
+        ctrl1: Point::new(100.0, 100.0),
+        ctrl2: Point::new(0.0, 100.0),
+        to: Point::new(150.0, 0.0),
+    };
+
+    for tolerance in [0.01, 0.1, 1.0, 10.0] {
+        let mut expected = 0.0;
+        curve.for_each_flattened_with_t(tolerance, &mut |segment, _| {
+            expected += segment.length();
+        });
+
+        assert_eq!(curve.flattened_length(tolerance), expected);
+        assert!(curve.flattened_length(tolerance) <= curve.approximate_length(0.001));
+    }
+
+    let coarse = curve.flattened_length(10.0);
+    let fine = curve.flattened_length(0.01);
+    assert!(coarse < fine);
+    assert!((fine - curve.approximate_length(0.001)).abs() < 0.1);
+}
+
+#[test]
+fn to_quadratics_fixed() {
+    let curve = CubicBezierSegment {
+        from: point(0.0f64, 0.0),
+        ctrl1: point(10.0, 30.0),
+        ctrl2: point(40.0, -20.0),
+        to: point(50.0, 10.0),
+    };
+
+    let (quads, error_1) = curve.to_quadratics_fixed(1);
+    assert_eq!(quads.len(), 1);
+    assert_eq!(quads[0], curve.to_quadratic());
+    assert_eq!(error_1, curve.to_quadratic_error());
+
+    let mut prev_error = error_1;
+    for count in 2..=16 {
+        let (quads, error) = curve.to_quadratics_fixed(count);
+        assert_eq!(quads.len(), count);
+        assert!(error < prev_error);
+        prev_error = error;
+
+        assert_eq!(quads[0].from, curve.from);
+        assert_eq!(quads[count - 1].to, curve.to);
+        for i in 1..count {
+            assert_eq!(quads[i - 1].to, quads[i].from);
+        }
+
+        // The sub-curves are within the error bound of their approximation.
+        let step = 1.0 / count as f64;
+        for (i, quad) in quads.iter().enumerate() {
+            for j in 0..=10 {
+                let t = (i as f64 + j as f64 / 10.0) * step;
+                let p = curve.sample(t);
+                assert!(quad.distance_to_point(p) <= error + 1e-9);
+            }
+        }
+    }
+
+    // The error bound is divided by 8 when the number of segments doubles.
+    let (_, error_2) = curve.to_quadratics_fixed(2);
+    assert!((error_2 * 8.0 - error_1).abs() < 1e-3);
+
+    assert_eq!(curve.to_quadratics_fixed(0).0.len(), 1);
+    assert_eq!(curve.to_quadratics_fixed(100).0.len(), 16);
+}
+
+#[test]
+fn flattened_t() {
+    let curve = CubicBezierSegment {
+        from: Point::new(0.0f32, 0.0),
+        ctrl1: Point::new(10.0, 0.0),
+        ctrl2: Point::new(10.0, 10.0),
+        to: Point::new(0.0, 10.0),
+    };
+
+    let points: Vec<Point<f32>> = curve.flattened(0.01).collect();
+    let ts: Vec<f32> = curve.flattened_t(0.01).collect();
+    assert_eq!(points.len(), ts.len());
+    assert_eq!(*ts.last().unwrap(), 1.0);
+    for (p, t) in points.iter().zip(ts.iter()) {
+        assert_eq!(*p, curve.sample(*t));
+    }
+    for pair in ts.windows(2) {
+        assert!(pair[0] < pair[1]);
+    }
+}
+
+#[test]
+fn flattened_clamped() {
+    // A curve with a long, nearly straight run and a tight turn.
+    let curve = CubicBezierSegment {
+        from: Point::new(0.0f32, 0.0),
+        ctrl1: Point::new(100.0, 0.0),
+        ctrl2: Point::new(100.0, 1.0),
+        to: Point::new(95.0, 0.5),
+    };
+
+    let mut segments = Vec::new();
+    curve.for_each_flattened_clamped(0.01, 0.5, 5.0, &mut |s| segments.push(*s));
+
+    assert_eq!(segments[0].from, curve.from);
+    assert_eq!(segments.last().unwrap().to, curve.to);
+    for pair in segments.windows(2) {
+        assert_eq!(pair[0].to, pair[1].from);
+    }
+    for s in &segments {
+        let length = s.length();
+        assert!((0.5..=5.0).contains(&length), "{}", length);
+    }
+
+    // Without clamping the tight turn produces many tiny segments.
+    let mut min_unclamped = f32::MAX;
+    curve.for_each_flattened(0.01, &mut |s| {
+        min_unclamped = min_unclamped.min(s.length());
+    });
+    assert!(min_unclamped < 0.5);
+
+    // A curve shorter than the minimum length is approximated with a single segment.
+    let short = CubicBezierSegment {
+        from: Point::new(0.0f32, 0.0),
+        ctrl1: Point::new(0.1, 0.0),
+        ctrl2: Point::new(0.1, 0.1),
+        to: Point::new(0.0, 0.1),
+    };
+    let mut segments = Vec::new();
+    short.for_each_flattened_clamped(0.001, 1.0, 2.0, &mut |s| segments.push(*s));
+    assert_eq!(
+        segments,
+        [LineSegment {
+            from: short.from,
+            to: short.to
+        }]
+    );
+
+    // The number of subdivisions is bounded.
+    let mut num_flattened = 0;
+    curve.for_each_flattened(0.01, &mut |_| num_flattened += 1);
+    let mut count = 0;
+    curve.for_each_flattened_clamped(0.01, 0.0, 1e-30, &mut |_| count += 1);
+    assert_eq!(
+        count,
+        num_flattened * crate::utils::MAX_CLAMPED_SUBDIVISIONS
+    );
+}
+
+#[test]
+fn split_at_many() {
+    let curve = CubicBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl1: Point::new(10.0, 20.0),
+        ctrl2: Point::new(30.0, -10.0),
+        to: Point::new(40.0, 10.0),
+    };
+
+    let ts = [0.1, 0.25, 0.5, 0.9];
+    let mut curves = Vec::new();
+    curve.split_at_many(&ts, &mut curves);
+    assert_eq!(curves.len(), 5);
+    assert_eq!(curves[0].from, curve.from);
+    assert_eq!(curves[4].to, curve.to);
+
+    let bounds = [0.0, 0.1, 0.25, 0.5, 0.9, 1.0];
+    for (i, sub_curve) in curves.iter().enumerate() {
+        if i > 0 {
+            assert_eq!(sub_curve.from, curves[i - 1].to);
+        }
+        let expected = curve.split_range(bounds[i]..bounds[i + 1]);
+        for j in 0..=10 {
+            let t = j as f32 / 10.0;
+            assert!((sub_curve.sample(t) - expected.sample(t)).length() < 1e-4);
+        }
+    }
+
+    let mut curves = Vec::new();
+    curve.split_at_many(&[], &mut curves);
+    assert_eq!(curves, [curve]);
+}
@@ -17,6 +17,7 @@
 //! - lines and line segments,
 //! - quadratic and cubic bézier curves,
 //! - elliptic arcs,
+//! - Catmull-Rom splines,
 //! - triangles.
 //!
 //! # Flattening
@@ -88,23 +89,29 @@ pub extern crate serde;
 #[macro_use]
 mod segment;
 pub mod arc;
+pub mod catmull_rom;
 pub mod cubic_bezier;
 mod cubic_bezier_intersections;
 mod line;
 pub mod quadratic_bezier;
+pub mod rational_quadratic;
 mod triangle;
 pub mod utils;
 
 #[doc(inline)]
 pub use crate::arc::{Arc, ArcFlags, SvgArc};
 #[doc(inline)]
+pub use crate::catmull_rom::CatmullRom;
+#[doc(inline)]
 pub use crate::cubic_bezier::CubicBezierSegment;
 #[doc(inline)]
 pub use crate::line::{Line, LineEquation, LineSegment};
 #[doc(inline)]
 pub use crate::quadratic_bezier::QuadraticBezierSegment;
 #[doc(inline)]
-pub use crate::segment::Segment;
+pub use crate::rational_quadratic::RationalQuadratic;
+#[doc(inline)]
+pub use crate::segment::{BezierSegment, Segment};
 #[doc(inline)]
 pub use crate::triangle::Triangle;
 
@@ -285,6 +292,16 @@ pub fn size<S>(w: S, h: S) -> Size<S> {
     Size::new(w, h)
 }
 
+/// The two possible orientations for the edges of a shape to be built in.
+///
+/// Positive winding corresponds to the positive orientation in trigonometry.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum Winding {
+    Positive,
+    Negative,
+}
+
 pub mod traits {
     pub use crate::segment::Segment;
 
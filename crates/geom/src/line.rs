@@ -471,11 +471,122 @@ impl<S: Scalar> LineSegment<S> {
     /// Computes the closest point on this segment to `p`.
     #[inline]
     pub fn closest_point(&self, p: Point<S>) -> Point<S> {
+        self.closest_point_with_t(p).1
+    }
+
+    /// Computes the closest point on this segment to `p`.
+    ///
+    /// Returns the parameter `t` and the position of the closest point.
+    #[inline]
+    pub fn closest_point_with_t(&self, p: Point<S>) -> (S, Point<S>) {
         let v1 = self.to - self.from;
         let v2 = p - self.from;
-        let t = S::min(S::max(v2.dot(v1) / v1.dot(v1), S::ZERO), S::ONE);
+        let len2 = v1.dot(v1);
+        if len2 == S::ZERO {
+            return (S::ZERO, self.from);
+        }
 
-        self.from + v1 * t
+        let t = S::min(S::max(v2.dot(v1) / len2, S::ZERO), S::ONE);
+
+        (t, self.from + v1 * t)
+    }
+
+    /// Computes the distance between this segment and another one.
+    #[inline]
+    pub fn distance_to_segment(&self, other: &Self) -> S {
+        let (a, b) = self.closest_points(other);
+        (b - a).length()
+    }
+
+    /// Computes the pair of closest points between this segment and another one.
+    ///
+    /// The first point is on this segment and the second one on `other`. If the
+    /// segments intersect, both points are at the intersection. If the segments are
+    /// parallel, one of the pairs of closest points is returned.
+    pub fn closest_points(&self, other: &Self) -> (Point<S>, Point<S>) {
+        let (t1, t2) = self.closest_points_t(other);
+
+        (self.sample(t1), other.sample(t2))
+    }
+
+    // See "Real-Time Collision Detection" by Christer Ericson, section 5.1.9.
+    fn closest_points_t(&self, other: &Self) -> (S, S) {
+        let clamp = |t: S| S::min(S::max(t, S::ZERO), S::ONE);
+
+        let d1 = self.to_vector();
+        let d2 = other.to_vector();
+        let r = self.from - other.from;
+        let a = d1.square_length();
+        let e = d2.square_length();
+        let f = d2.dot(r);
+
+        if a == S::ZERO && e == S::ZERO {
+            return (S::ZERO, S::ZERO);
+        }
+
+        if a == S::ZERO {
+            return (S::ZERO, clamp(f / e));
+        }
+
+        let c = d1.dot(r);
+        if e == S::ZERO {
+            return (clamp(-c / a), S::ZERO);
+        }
+
+        let b = d1.dot(d2);
+        let denom = a * e - b * b;
+        // If the segments are parallel, pick an arbitrary point on this segment.
+        let t1 = if denom != S::ZERO {
+            clamp((b * f - c * e) / denom)
+        } else {
+            S::ZERO
+        };
+
+        let t2 = (b * t1 + f) / e;
+        if t2 < S::ZERO {
+            (clamp(-c / a), S::ZERO)
+        } else if t2 > S::ONE {
+            (clamp((b - c) / a), S::ONE)
+        } else {
+            (t1, t2)
+        }
+    }
+
+    /// Returns the overlapping portion of this segment and another one if they are
+    /// collinear.
+    ///
+    /// The result has the same direction as this segment. Returns a segment of length
+    /// zero if the segments only touch at an endpoint, and `None` if they are not
+    /// collinear or don't overlap.
+    pub fn overlaps_collinear(&self, other: &Self) -> Option<Self> {
+        let v = self.to_vector();
+        let len2 = v.square_length();
+        if len2 == S::ZERO {
+            if other.square_distance_to_point(self.from) == S::ZERO {
+                return Some(*self);
+            }
+            return None;
+        }
+
+        if !self.overlaps_line(&other.to_line()) {
+            return None;
+        }
+
+        // Position of the other segment's endpoints along this segment.
+        let mut c = (other.from, v.dot(other.from - self.from) / len2);
+        let mut d = (other.to, v.dot(other.to - self.from) / len2);
+        if c.1 > d.1 {
+            swap(&mut c, &mut d);
+        }
+
+        if c.1 > S::ONE || d.1 < S::ZERO {
+            return None;
+        }
+
+        Some(LineSegment {
+            from: if c.1 > S::ZERO { c.0 } else { self.from },
+            to: if d.1 < S::ONE { d.0 } else { self.to },
+        })
     }
 
     #[inline]
@@ -540,6 +651,14 @@ impl<S: Scalar> Segment for LineSegment<S> {
         self.length()
     }
 
+    fn for_each_flattened(
+        &self,
+        _tolerance: Self::Scalar,
+        callback: &mut dyn FnMut(&LineSegment<S>),
+    ) {
+        callback(self);
+    }
+
     fn for_each_flattened_with_t(
         &self,
         _tolerance: Self::Scalar,
@@ -637,6 +756,15 @@ impl<S: Scalar> Line<S> {
         diagonal.intersects_line(self)
     }
 
+    /// Applies the transform to this line and returns the result.
+    #[inline]
+    pub fn transformed<T: Transformation<S>>(&self, transform: &T) -> Self {
+        Line {
+            point: transform.transform_point(self.point),
+            vector: transform.transform_vector(self.vector),
+        }
+    }
+
     #[inline]
     pub fn to_f32(&self) -> Line<f32> {
         Line {
@@ -656,6 +784,14 @@ impl<S: Scalar> Line<S> {
 
 /// A line defined by the equation
 /// `a * x + b * y + c = 0; a * a + b * b = 1`.
+///
+/// The vector `(a, b)` is the unit normal of the line. The signed distance of a point
+/// to the line is positive on the side the normal points to. For equations built from
+/// a direction (`Line::equation` or `LineEquation::from_points`), the normal is the
+/// direction rotated by 90 degrees in the positive direction, so points on the positive
+/// side of the direction (where `direction.cross(p - origin) > 0`, see `Side` in
+/// `lyon_path`) have a positive signed distance. That is the left side in a y-up
+/// coordinate system, and the right side in a y-down coordinate system such as SVG's.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
 pub struct LineEquation<S> {
@@ -665,6 +801,10 @@ pub struct LineEquation<S> {
 }
 
 impl<S: Scalar> LineEquation<S> {
+    /// Creates a line equation from its coefficients.
+    ///
+    /// The coefficients are normalized so that `a * a + b * b = 1`, which is required
+    /// for the signed distance to be a distance. `a` and `b` must not both be zero.
     pub fn new(a: S, b: S, c: S) -> Self {
         debug_assert!(a != S::ZERO || b != S::ZERO);
         let div = S::ONE / S::sqrt(a * a + b * b);
@@ -675,6 +815,28 @@ impl<S: Scalar> LineEquation<S> {
         }
     }
 
+    /// Creates the equation of the line going through `a` and `b`, in this direction.
+    ///
+    /// Points on the positive side of the direction from `a` to `b` have a positive
+    /// signed distance. The two points must be distinct.
+    #[inline]
+    pub fn from_points(a: Point<S>, b: Point<S>) -> Self {
+        Line {
+            point: a,
+            vector: b - a,
+        }
+        .equation()
+    }
+
+    /// Creates the equation of the line going through `p` and perpendicular to `normal`.
+    ///
+    /// Points on the side `normal` points to have a positive signed distance. The
+    /// normal doesn't need to be normalized but must not be zero.
+    #[inline]
+    pub fn from_point_and_normal(p: Point<S>, normal: Vector<S>) -> Self {
+        LineEquation::new(normal.x, normal.y, -(normal.x * p.x + normal.y * p.y))
+    }
+
     #[inline]
     pub fn a(&self) -> S {
         self.a
@@ -697,6 +859,8 @@ impl<S: Scalar> LineEquation<S> {
         )
     }
 
+    /// Returns the distance between the line and a point, positive on the side the
+    /// normal points to and negative on the other side.
     #[inline]
     pub fn signed_distance_to_point(&self, p: &Point<S>) -> S {
         self.a * p.x + self.b * p.y + self.c
@@ -735,16 +899,22 @@ impl<S: Scalar> LineEquation<S> {
         }
     }
 
+    /// Returns the unit direction of the line, such that the normal is on its
+    /// positive side.
     #[inline]
     pub fn tangent(&self) -> Vector<S> {
         vector(self.b, -self.a)
     }
 
+    /// Returns the unit normal `(a, b)` of the line.
     #[inline]
     pub fn normal(&self) -> Vector<S> {
         vector(self.a, self.b)
     }
 
+    /// Returns the y coordinate of the point of the line at a given x coordinate.
+    ///
+    /// Returns `None` if the line is vertical.
     #[inline]
     pub fn solve_y_for_x(&self, x: S) -> Option<S> {
         if self.b == S::ZERO {
@@ -754,6 +924,9 @@ impl<S: Scalar> LineEquation<S> {
         Some((self.a * x + self.c) / -self.b)
     }
 
+    /// Returns the x coordinate of the point of the line at a given y coordinate.
+    ///
+    /// Returns `None` if the line is horizontal.
     #[inline]
     pub fn solve_x_for_y(&self, y: S) -> Option<S> {
         if self.a == S::ZERO {
@@ -1496,3 +1669,176 @@ fn equation() {
         }
     }
 }
+
+#[test]
+fn equation_sign_convention() {
+    use euclid::approxeq::ApproxEq;
+
+    // The positive side of the x axis, in the direction of positive x, is the
+    // positive y side.
+    let eqn = LineEquation::from_points(point(0.0f64, 0.0), point(10.0, 0.0));
+    assert!(eqn
+        .signed_distance_to_point(&point(3.0, 2.0))
+        .approx_eq(&2.0));
+    assert!(eqn
+        .signed_distance_to_point(&point(-3.0, -2.0))
+        .approx_eq(&-2.0));
+    assert!(eqn.normal().approx_eq(&vector(0.0, 1.0)));
+    assert!(eqn.tangent().approx_eq(&vector(1.0, 0.0)));
+
+    let from = point(1.0f64, 2.0);
+    let to = point(-3.0, 7.0);
+    let eqn = LineEquation::from_points(from, to);
+    let direction = to - from;
+    assert_eq!(
+        eqn,
+        Line {
+            point: from,
+            vector: direction
+        }
+        .equation()
+    );
+    assert!(eqn.tangent().approx_eq(&direction.normalize()));
+    for p in [
+        point(0.0, 0.0),
+        point(10.0, -3.0),
+        point(-8.0, 4.0),
+        from,
+        to,
+    ] {
+        let expected = direction.cross(p - from) / direction.length();
+        assert!(eqn.signed_distance_to_point(&p).approx_eq(&expected));
+    }
+    // Flipping the direction flips the sign.
+    let flipped = LineEquation::from_points(to, from);
+    assert!(flipped
+        .signed_distance_to_point(&point(10.0, -3.0))
+        .approx_eq(&-eqn.signed_distance_to_point(&point(10.0, -3.0))));
+
+    let eqn = LineEquation::from_point_and_normal(point(0.0f64, 5.0), vector(0.0, -2.0));
+    assert!(eqn
+        .signed_distance_to_point(&point(4.0, 2.0))
+        .approx_eq(&3.0));
+    assert!(eqn
+        .signed_distance_to_point(&point(4.0, 7.0))
+        .approx_eq(&-2.0));
+    assert!(eqn.normal().approx_eq(&vector(0.0, -1.0)));
+    assert!(eqn.solve_y_for_x(12.0).unwrap().approx_eq(&5.0));
+    assert_eq!(eqn.solve_x_for_y(5.0), None);
+}
+
+#[test]
+fn closest_point() {
+    let segment = LineSegment {
+        from: point(0.0f32, 0.0),
+        to: point(10.0, 0.0),
+    };
+
+    assert_eq!(
+        segment.closest_point_with_t(point(5.0, 3.0)),
+        (0.5, point(5.0, 0.0))
+    );
+    assert_eq!(
+        segment.closest_point_with_t(point(-5.0, 3.0)),
+        (0.0, point(0.0, 0.0))
+    );
+    assert_eq!(
+        segment.closest_point_with_t(point(15.0, -3.0)),
+        (1.0, point(10.0, 0.0))
+    );
+
+    let degenerate = LineSegment {
+        from: point(1.0f32, 1.0),
+        to: point(1.0, 1.0),
+    };
+    assert_eq!(
+        degenerate.closest_point_with_t(point(5.0, 3.0)),
+        (0.0, point(1.0, 1.0))
+    );
+    assert_eq!(degenerate.distance_to_point(point(4.0, 5.0)), 5.0);
+}
+
+#[test]
+fn closest_points_between_segments() {
+    let seg = |x1: f32, y1: f32, x2: f32, y2: f32| LineSegment {
+        from: point(x1, y1),
+        to: point(x2, y2),
+    };
+
+    // Crossing segments.
+    let a = seg(0.0, 0.0, 2.0, 2.0);
+    let b = seg(0.0, 2.0, 2.0, 0.0);
+    assert_eq!(a.closest_points(&b), (point(1.0, 1.0), point(1.0, 1.0)));
+    assert_eq!(a.distance_to_segment(&b), 0.0);
+
+    // Endpoint to interior.
+    let a = seg(0.0, 0.0, 4.0, 0.0);
+    let b = seg(1.0, 1.0, 3.0, 5.0);
+    assert_eq!(a.closest_points(&b), (point(1.0, 0.0), point(1.0, 1.0)));
+    assert_eq!(b.closest_points(&a), (point(1.0, 1.0), point(1.0, 0.0)));
+    assert_eq!(a.distance_to_segment(&b), 1.0);
+
+    // Endpoint to endpoint.
+    let b = seg(7.0, 4.0, 9.0, 4.0);
+    assert_eq!(a.closest_points(&b), (point(4.0, 0.0), point(7.0, 4.0)));
+    assert_eq!(a.distance_to_segment(&b), 5.0);
+
+    // Parallel segments.
+    let b = seg(2.0, 3.0, 6.0, 3.0);
+    assert_eq!(a.distance_to_segment(&b), 3.0);
+    let b = seg(7.0, 4.0, 10.0, 4.0);
+    assert_eq!(a.distance_to_segment(&b), 5.0);
+
+    // Degenerate segments.
+    let p = seg(2.0, 2.0, 2.0, 2.0);
+    assert_eq!(a.closest_points(&p), (point(2.0, 0.0), point(2.0, 2.0)));
+    assert_eq!(p.closest_points(&a), (point(2.0, 2.0), point(2.0, 0.0)));
+    assert_eq!(p.distance_to_segment(&seg(5.0, 6.0, 5.0, 6.0)), 5.0);
+}
+
+#[test]
+fn overlaps_collinear() {
+    let seg = |x1: f32, y1: f32, x2: f32, y2: f32| LineSegment {
+        from: point(x1, y1),
+        to: point(x2, y2),
+    };
+
+    let a = seg(0.0, 0.0, 4.0, 0.0);
+    assert_eq!(
+        a.overlaps_collinear(&seg(2.0, 0.0, 6.0, 0.0)),
+        Some(seg(2.0, 0.0, 4.0, 0.0))
+    );
+    // Same direction as self, regardless of the direction of the other segment.
+    assert_eq!(
+        a.overlaps_collinear(&seg(3.0, 0.0, -1.0, 0.0)),
+        Some(seg(0.0, 0.0, 3.0, 0.0))
+    );
+    assert_eq!(
+        a.overlaps_collinear(&seg(1.0, 0.0, 2.0, 0.0)),
+        Some(seg(1.0, 0.0, 2.0, 0.0))
+    );
+    assert_eq!(
+        a.flip().overlaps_collinear(&seg(1.0, 0.0, 2.0, 0.0)),
+        Some(seg(2.0, 0.0, 1.0, 0.0))
+    );
+    assert_eq!(a.overlaps_collinear(&seg(-1.0, 0.0, 5.0, 0.0)), Some(a));
+
+    // Touching endpoints.
+    assert_eq!(
+        a.overlaps_collinear(&seg(4.0, 0.0, 5.0, 0.0)),
+        Some(seg(4.0, 0.0, 4.0, 0.0))
+    );
+
+    // Collinear but disjoint.
+    assert_eq!(a.overlaps_collinear(&seg(5.0, 0.0, 6.0, 0.0)), None);
+    // Parallel.
+    assert_eq!(a.overlaps_collinear(&seg(0.0, 1.0, 4.0, 1.0)), None);
+    // Crossing.
+    assert_eq!(a.overlaps_collinear(&seg(1.0, -1.0, 1.0, 1.0)), None);
+
+    // Degenerate.
+    let p = seg(1.0, 0.0, 1.0, 0.0);
+    assert_eq!(p.overlaps_collinear(&a), Some(p));
+    assert_eq!(a.overlaps_collinear(&p), Some(p));
+    assert_eq!(seg(1.0, 1.0, 1.0, 1.0).overlaps_collinear(&a), None);
+}
@@ -1,6 +1,10 @@
 use crate::scalar::Scalar;
 use crate::segment::{BoundingBox, Segment};
 use crate::traits::Transformation;
+use crate::utils::{
+    for_each_clamped_segment, polynomial_roots_in_unit_interval, signed_curvature,
+    signed_radius_of_curvature,
+};
 use crate::{point, Box2D, Point, Vector};
 use crate::{CubicBezierSegment, Line, LineEquation, LineSegment, Triangle};
 use arrayvec::ArrayVec;
@@ -81,6 +85,43 @@ impl<S: Scalar> QuadraticBezierSegment<S> {
         self.from.y * c0 + self.ctrl.y * c1 + self.to.y * c2
     }
 
+    /// Returns the curve's second derivative, which is constant for quadratic bézier curves.
+    pub fn second_derivative(&self) -> Vector<S> {
+        (self.from.to_vector() - self.ctrl.to_vector() * S::TWO + self.to.to_vector()) * S::TWO
+    }
+
+    /// Returns the signed curvature of the curve at t (expecting t between 0 and 1).
+    ///
+    /// The curvature is positive where the curve turns from the x axis towards the
+    /// y axis, and negative where it turns the other way. It is zero where the curve is
+    /// straight and infinite where the derivative vanishes.
+    pub fn curvature(&self, t: S) -> S {
+        signed_curvature(self.derivative(t), self.second_derivative())
+    }
+
+    /// Returns the signed radius of curvature of the curve at t (expecting t between 0 and 1).
+    ///
+    /// This is the inverse of the curvature and has the same sign. Infinity is returned
+    /// where the curvature is zero, and zero where the derivative vanishes.
+    pub fn radius_of_curvature(&self, t: S) -> S {
+        signed_radius_of_curvature(self.derivative(t), self.second_derivative())
+    }
+
+    /// Returns the parameter where the absolute value of the curvature is the highest.
+    ///
+    /// The second derivative being constant, this is where the derivative is the shortest.
+    /// Zero is returned if the curve is a straight line with evenly spaced control points.
+    pub fn max_curvature_t(&self) -> S {
+        let [_, b, a] = self.power_basis();
+        let sq_len = a.square_length();
+        if sq_len == S::ZERO {
+            return S::ZERO;
+        }
+
+        let t = -a.dot(b) / (S::TWO * sq_len);
+        S::max(S::ZERO, S::min(t, S::ONE))
+    }
+
     /// Swap the beginning and the end of the segment.
     pub fn flip(&self) -> Self {
         QuadraticBezierSegment {
@@ -204,6 +245,32 @@ impl<S: Scalar> QuadraticBezierSegment<S> {
         QuadraticBezierSegment { from, ctrl, to }
     }
 
+    /// Split this curve at several parameters, producing `ts.len() + 1` sub-curves.
+    ///
+    /// The parameters are relative to this curve and must be sorted in increasing order
+    /// within `0..1`. Each sub-curve is computed from this curve with `split_range` rather
+    /// than by repeatedly splitting the remaining part, so errors don't accumulate and
+    /// consecutive sub-curves share their endpoints exactly.
+    pub fn split_at_many(&self, ts: &[S], out: &mut impl Extend<QuadraticBezierSegment<S>>) {
+        debug_assert!(ts.windows(2).all(|w| w[0] <= w[1]));
+
+        let mut t0 = S::ZERO;
+        let mut from = self.from;
+        for i in 0..=ts.len() {
+            let (t1, to) = match ts.get(i) {
+                Some(t) => (*t, self.sample(*t)),
+                None => (S::ONE, self.to),
+            };
+            let mut curve = self.split_range(t0..t1);
+            curve.from = from;
+            curve.to = to;
+            out.extend(Some(curve));
+
+            t0 = t1;
+            from = to;
+        }
+    }
+
     /// Split this curve into two sub-curves.
     pub fn split(&self, t: S) -> (QuadraticBezierSegment<S>, QuadraticBezierSegment<S>) {
         let split_point = self.sample(t);
@@ -296,6 +363,70 @@ impl<S: Scalar> QuadraticBezierSegment<S> {
         }
     }
 
+    /// Computes the implicit form of the curve.
+    ///
+    /// Returns the coefficients `[a, b, c, d, e, f]` of the equation
+    /// `a * x² + b * x * y + c * y² + d * x + e * y + f = 0`, which is satisfied by the
+    /// points of the parabola that the curve belongs to.
+    ///
+    /// The value of the left hand side of the equation is positive for points that
+    /// are on the left of the curve relative to its direction in a y-up coordinate
+    /// system (on the right if the y axis points down), and negative on the other
+    /// side. The coefficients are scaled so that the value at the control point is
+    /// either 1 or -1.
+    ///
+    /// Note that the parabola extends beyond the segment, so the sign is typically
+    /// only meaningful within the triangle formed by the control points.
+    ///
+    /// If the control points are aligned, the equation of the line going through
+    /// the endpoints is returned instead, with the same sign convention.
+    pub fn implicit_coefficients(&self) -> [S; 6] {
+        // Line equation positive on the left of a -> b, as [x, y, constant].
+        let line = |a: Point<S>, b: Point<S>| {
+            let v = b - a;
+            [-v.y, v.x, v.y * a.x - v.x * a.y]
+        };
+
+        if self.is_flat() {
+            let l = if self.from != self.to {
+                line(self.from, self.to)
+            } else {
+                line(self.from, self.ctrl)
+            };
+            return [S::ZERO, S::ZERO, S::ZERO, l[0], l[1], l[2]];
+        }
+
+        // Same formulation as implicit_intersections_t: v² - 4uw = 0 where u, v and w
+        // are proportional to the barycentric coordinates relative to the control points.
+        let u = line(self.ctrl, self.to);
+        let v = line(self.to, self.from);
+        let w = line(self.from, self.ctrl);
+        let product = |p: [S; 3], q: [S; 3]| {
+            [
+                p[0] * q[0],
+                p[0] * q[1] + p[1] * q[0],
+                p[1] * q[1],
+                p[0] * q[2] + p[2] * q[0],
+                p[1] * q[2] + p[2] * q[1],
+                p[2] * q[2],
+            ]
+        };
+        let vv = product(v, v);
+        let uw = product(u, w);
+
+        // v² - 4uw is equal to area² at the control point, which is on the left of
+        // the curve if area is negative.
+        let area = (self.ctrl - self.from).cross(self.to - self.from);
+        let scale = -S::ONE / (area * area.abs());
+
+        let mut coefficients = [S::ZERO; 6];
+        for i in 0..6 {
+            coefficients[i] = (vv[i] - S::FOUR * uw[i]) * scale;
+        }
+
+        coefficients
+    }
+
     /// Applies the transform to this curve and returns the results.
     #[inline]
     pub fn transformed<T: Transformation<S>>(&self, transform: &T) -> Self {
@@ -381,6 +512,49 @@ impl<S: Scalar> QuadraticBezierSegment<S> {
         callback(&s, t_from..S::ONE);
     }
 
+    /// Approximates the curve with a sequence of line segments which lengths are
+    /// clamped between `min_segment_length` and `max_segment_length`.
+    ///
+    /// The flattened segments are computed with the provided `tolerance`, then long
+    /// segments are evenly subdivided and consecutive short segments are merged.
+    /// Subdividing doesn't affect the approximation error, however merging short
+    /// segments takes precedence over the tolerance, so around sharp turns the approximation
+    /// can deviate from the curve by up to about `min_segment_length`.
+    ///
+    /// Both bounds are guaranteed to be respected if `min_segment_length` is at most half
+    /// of `max_segment_length`, except when the whole curve is shorter than
+    /// `min_segment_length`, in which case it is approximated with a single segment.
+    /// A flattened segment is never subdivided into more than 65536 segments, so
+    /// `max_segment_length` is not respected if it is extremely small.
+    pub fn for_each_flattened_clamped<F: FnMut(&LineSegment<S>)>(
+        &self,
+        tolerance: S,
+        min_segment_length: S,
+        max_segment_length: S,
+        callback: &mut F,
+    ) {
+        for_each_clamped_segment(
+            min_segment_length,
+            max_segment_length,
+            |cb| self.for_each_flattened(tolerance, &mut |segment| cb(segment)),
+            callback,
+        );
+    }
+
+    /// Computes the length of the polyline produced by `for_each_flattened` with the same
+    /// tolerance.
+    ///
+    /// Unlike `length` which computes the arc length of the curve, the result matches the
+    /// length of the flattened geometry produced by the tessellators.
+    pub fn flattened_length(&self, tolerance: S) -> S {
+        let mut length = S::ZERO;
+        self.for_each_flattened(tolerance, &mut |segment| {
+            length += segment.length();
+        });
+
+        length
+    }
+
     /// Returns the flattened representation of the curve as an iterator, starting *after* the
     /// current point.
     pub fn flattened(&self, tolerance: S) -> Flattened<S> {
@@ -733,6 +907,113 @@ impl<S: Scalar> QuadraticBezierSegment<S> {
         result
     }
 
+    /// Computes the intersections (if any) between this segment and another quadratic
+    /// bézier segment.
+    ///
+    /// The result is provided in the form of the `t` parameters of each point along the curves. To
+    /// get the intersection points, sample the curves at the corresponding values.
+    ///
+    /// The intersections are computed using the implicit form of one of the curves, which
+    /// is more precise than approximating the curves with sub-divisions. Flat curves are
+    /// handled as line segments. Unlike `CubicBezierSegment::cubic_intersections_t`,
+    /// tangential intersections as well as endpoint/endpoint intersections are returned.
+    /// Intersections that are within epsilon of each other are merged.
+    ///
+    /// If both curves are flat and overlap, only the endpoints of `curve` that lie on this
+    /// segment are returned.
+    pub fn quadratic_intersections_t(
+        &self,
+        curve: &QuadraticBezierSegment<S>,
+    ) -> ArrayVec<(S, S), 4> {
+        let mut result = ArrayVec::new();
+
+        if !self.is_flat() {
+            implicit_intersections_t(self, &curve.power_basis(), &mut |t_self, t_other| {
+                push_intersection(&mut result, (t_self, t_other));
+            });
+        } else if !curve.is_flat() {
+            implicit_intersections_t(curve, &self.power_basis(), &mut |t_other, t_self| {
+                push_intersection(&mut result, (t_self, t_other));
+            });
+        } else {
+            flat_intersections_t(self, &curve.power_basis(), &mut |t_self, t_other| {
+                push_intersection(&mut result, (t_self, t_other));
+            });
+        }
+
+        result
+    }
+
+    /// Computes the intersection points (if any) between this segment and another quadratic
+    /// bézier segment.
+    pub fn quadratic_intersections(
+        &self,
+        curve: &QuadraticBezierSegment<S>,
+    ) -> ArrayVec<Point<S>, 4> {
+        let mut result = ArrayVec::new();
+        for (t, _) in self.quadratic_intersections_t(curve) {
+            result.push(self.sample(t));
+        }
+
+        result
+    }
+
+    /// Computes the intersections (if any) between this segment and a cubic bézier segment.
+    ///
+    /// The result is provided in the form of the `t` parameters of each point along the curves. To
+    /// get the intersection points, sample the curves at the corresponding values.
+    ///
+    /// See `quadratic_intersections_t`.
+    pub fn quadratic_cubic_intersections_t(
+        &self,
+        curve: &CubicBezierSegment<S>,
+    ) -> ArrayVec<(S, S), 6> {
+        let mut result = ArrayVec::new();
+
+        let (a, b, c, d) = curve.power_basis();
+        let mut cb = |t_self, t_other| {
+            push_intersection(&mut result, (t_self, t_other));
+        };
+        if !self.is_flat() {
+            implicit_intersections_t(self, &[d, c, b, a], &mut cb);
+        } else {
+            flat_intersections_t(self, &[d, c, b, a], &mut cb);
+        }
+
+        result
+    }
+
+    /// Computes the intersection points (if any) between this segment and a cubic bézier
+    /// segment.
+    pub fn quadratic_cubic_intersections(
+        &self,
+        curve: &CubicBezierSegment<S>,
+    ) -> ArrayVec<Point<S>, 6> {
+        let mut result = ArrayVec::new();
+        for (t, _) in self.quadratic_cubic_intersections_t(curve) {
+            result.push(self.sample(t));
+        }
+
+        result
+    }
+
+    // Coefficients of the curve in the power basis, in increasing degree order.
+    fn power_basis(&self) -> [Vector<S>; 3] {
+        [
+            self.from.to_vector(),
+            (self.ctrl - self.from) * S::TWO,
+            self.from.to_vector() - self.ctrl.to_vector() * S::TWO + self.to.to_vector(),
+        ]
+    }
+
+    // Returns true if the control points are (almost) aligned, in which case the implicit
+    // form of the curve degenerates.
+    fn is_flat(&self) -> bool {
+        let v1 = self.ctrl - self.from;
+        let v2 = self.to - self.from;
+        S::abs(v1.cross(v2)) <= S::EPSILON * (v1.square_length() + v2.square_length())
+    }
+
     /// Analytic solution to finding the closest point on the curve to `pos`.
     pub fn closest_point(&self, pos: Point<S>) -> S {
         // We are looking for the points in the curve where the line passing through pos
@@ -770,6 +1051,14 @@ impl<S: Scalar> QuadraticBezierSegment<S> {
         t
     }
 
+    /// Analytic solution to finding the closest point on the curve to `pos`.
+    ///
+    /// Returns the parameter `t` and the position of the closest point.
+    pub fn closest_point_with_t(&self, pos: Point<S>) -> (S, Point<S>) {
+        let t = self.closest_point(pos);
+        (t, self.sample(t))
+    }
+
     /// Returns the shortest distance between this segment and a point.
     pub fn distance_to_point(&self, pos: Point<S>) -> S {
         (self.sample(self.closest_point(pos)) - pos).length()
@@ -874,6 +1163,205 @@ impl<S: Scalar> QuadraticBezierSegment<S> {
     }
 }
 
+// Computes the intersections between a curve expressed in implicit form and another
+// curve given by its power basis coefficients (in increasing degree order).
+//
+// The implicit form of the quadratic bézier curve is derived from the barycentric
+// coordinates (u, v, w) of a point relative to the control points. Points on the curve
+// satisfy u = (1 - t)², v = 2t(1 - t) and w = t², therefore v² - 4uw = 0.
+fn implicit_intersections_t<S: Scalar>(
+    curve: &QuadraticBezierSegment<S>,
+    other: &[Vector<S>],
+    cb: &mut dyn FnMut(S, S),
+) {
+    // Each barycentric coordinate is proportional to a linear function of the position,
+    // and each of them is a polynomial in the other curve's parameter.
+    let line_poly = |a: Point<S>, b: Point<S>| {
+        let v = b - a;
+        let mut coefficients: ArrayVec<S, 4> = ArrayVec::new();
+        for c in other {
+            coefficients.push(v.cross(*c));
+        }
+        coefficients[0] -= v.cross(a.to_vector());
+        coefficients
+    };
+    let u = line_poly(curve.ctrl, curve.to);
+    let v = line_poly(curve.to, curve.from);
+    let w = line_poly(curve.from, curve.ctrl);
+    let area = (curve.ctrl - curve.from).cross(curve.to - curve.from);
+
+    // f = v² - 4uw
+    let degree = other.len() - 1;
+    let mut f: ArrayVec<S, 7> = ArrayVec::new();
+    for _ in 0..(degree * 2 + 1) {
+        f.push(S::ZERO);
+    }
+    for i in 0..=degree {
+        for j in 0..=degree {
+            f[i + j] += v[i] * v[j] - S::FOUR * u[i] * w[j];
+        }
+    }
+    f.reverse();
+
+    let sample_other = |t: S| {
+        let mut p = Vector::zero();
+        for c in other.iter().rev() {
+            p = p * t + *c;
+        }
+        p.to_point()
+    };
+
+    let max_coord = [
+        curve.from,
+        curve.ctrl,
+        curve.to,
+        sample_other(S::ZERO),
+        sample_other(S::ONE),
+    ]
+    .iter()
+    .fold(S::ZERO, |m, p| S::max(m, S::max(p.x.abs(), p.y.abs())));
+    let threshold = S::EPSILON * (S::ONE + max_coord);
+
+    let mut check_candidate = |t_other: S| {
+        let p = sample_other(t_other);
+        // On the curve, t = w + v / 2.
+        let t_curve = (eval_line_poly(&w, t_other) + eval_line_poly(&v, t_other) * S::HALF) / area;
+        if t_curve < -S::EPSILON || t_curve > S::ONE + S::EPSILON {
+            return;
+        }
+        let t_curve = S::min(S::max(t_curve, S::ZERO), S::ONE);
+        if (curve.sample(t_curve) - p).length() <= threshold {
+            cb(t_curve, t_other);
+        }
+    };
+
+    for t in polynomial_roots_in_unit_interval(&f) {
+        check_candidate(t);
+    }
+
+    // Tangential intersections are double roots which can't be isolated by looking at
+    // sign changes. They are local extrema of f.
+    let mut derivative: ArrayVec<S, 6> = ArrayVec::new();
+    for (i, c) in f[..f.len() - 1].iter().enumerate() {
+        derivative.push(*c * S::value((f.len() - 1 - i) as f32));
+    }
+    for t in polynomial_roots_in_unit_interval(&derivative) {
+        check_candidate(t);
+    }
+}
+
+// Computes the intersections between a flat curve, handled as a line segment, and another
+// curve given by its power basis coefficients (in increasing degree order).
+fn flat_intersections_t<S: Scalar>(
+    curve: &QuadraticBezierSegment<S>,
+    other: &[Vector<S>],
+    cb: &mut dyn FnMut(S, S),
+) {
+    // The control point of a flat curve can be outside of the chord.
+    let chord = curve.to - curve.from;
+    let arm = curve.ctrl - curve.from;
+    let dir = if chord.square_length() >= arm.square_length() {
+        chord
+    } else {
+        arm
+    };
+    if dir.square_length() == S::ZERO {
+        return;
+    }
+
+    let sample_other = |t: S| {
+        let mut p = Vector::zero();
+        for c in other.iter().rev() {
+            p = p * t + *c;
+        }
+        p.to_point()
+    };
+
+    let max_coord = [
+        curve.from,
+        curve.ctrl,
+        curve.to,
+        sample_other(S::ZERO),
+        sample_other(S::ONE),
+    ]
+    .iter()
+    .fold(S::ZERO, |m, p| S::max(m, S::max(p.x.abs(), p.y.abs())));
+    let threshold = S::EPSILON * (S::ONE + max_coord);
+
+    // Distance of the other curve to the line, scaled by the length of dir.
+    let mut f: ArrayVec<S, 4> = ArrayVec::new();
+    for c in other {
+        f.push(dir.cross(*c));
+    }
+    f[0] -= dir.cross(curve.from.to_vector());
+
+    // Position along the line of the flat curve, which may go back and forth.
+    let [_, b, a] = curve.power_basis();
+    let (a, b) = (dir.dot(a), dir.dot(b));
+
+    let mut check_candidate = |t_other: S| {
+        let p = sample_other(t_other);
+        let mut candidates = polynomial_roots_in_unit_interval(&[a, b, -dir.dot(p - curve.from)]);
+        candidates.push(S::ZERO);
+        candidates.push(S::ONE);
+        for t_curve in candidates {
+            if (curve.sample(t_curve) - p).length() <= threshold {
+                cb(t_curve, t_other);
+            }
+        }
+    };
+
+    // The endpoints are checked explicitly since they are the only intersections of
+    // overlapping curves.
+    check_candidate(S::ZERO);
+    check_candidate(S::ONE);
+
+    let length = dir.length();
+    if f.iter().all(|c| S::abs(*c) <= threshold * length) {
+        return;
+    }
+
+    f.reverse();
+    for t in polynomial_roots_in_unit_interval(&f) {
+        check_candidate(t);
+    }
+
+    // Tangential intersections.
+    let mut derivative: ArrayVec<S, 3> = ArrayVec::new();
+    for (i, c) in f[..f.len() - 1].iter().enumerate() {
+        derivative.push(*c * S::value((f.len() - 1 - i) as f32));
+    }
+    for t in polynomial_roots_in_unit_interval(&derivative) {
+        check_candidate(t);
+    }
+}
+
+fn eval_line_poly<S: Scalar>(coefficients: &[S], t: S) -> S {
+    let mut result = S::ZERO;
+    for c in coefficients.iter().rev() {
+        result = result * t + *c;
+    }
+
+    result
+}
+
+// Adds an intersection to the list unless it is within epsilon of an existing one.
+fn push_intersection<S: Scalar, const N: usize>(
+    result: &mut ArrayVec<(S, S), N>,
+    intersection: (S, S),
+) {
+    let (t0, t1) = intersection;
+    for &(other0, other1) in result.iter() {
+        if S::abs(other0 - t0) <= S::EPSILON && S::abs(other1 - t1) <= S::EPSILON {
+            return;
+        }
+    }
+
+    if !result.is_full() {
+        result.push(intersection);
+    }
+}
+
 pub struct FlatteningParameters<S> {
     count: S,
     integral_from: S,
@@ -1064,6 +1552,14 @@ impl<S: Scalar> Iterator for FlattenedT<S> {
 impl<S: Scalar> Segment for QuadraticBezierSegment<S> {
     impl_segment!(S);
 
+    fn for_each_flattened(
+        &self,
+        tolerance: Self::Scalar,
+        callback: &mut dyn FnMut(&LineSegment<S>),
+    ) {
+        self.for_each_flattened(tolerance, &mut |s| callback(s));
+    }
+
     fn for_each_flattened_with_t(
         &self,
         tolerance: Self::Scalar,
@@ -1549,3 +2045,327 @@ fn arc_length() {
         );
     }
 }
+
+#[test]
+fn closest_point() {
+    let curve = QuadraticBezierSegment {
+        from: point(0.0f64, 0.0),
+        ctrl: point(100.0, 0.0),
+        to: point(100.0, 100.0),
+    };
+
+    let (t, p) = curve.closest_point_with_t(curve.sample(0.3));
+    assert!((t - 0.3).abs() < 1e-6);
+    assert!((p - curve.sample(0.3)).length() < 1e-6);
+
+    assert_eq!(
+        curve.closest_point_with_t(point(-10.0, -10.0)),
+        (0.0, curve.from)
+    );
+    assert_eq!(
+        curve.closest_point_with_t(point(110.0, 110.0)),
+        (1.0, curve.to)
+    );
+
+    let point_curve = QuadraticBezierSegment {
+        from: point(1.0f64, 1.0),
+        ctrl: point(1.0, 1.0),
+        to: point(1.0, 1.0),
+    };
+    assert_eq!(
+        point_curve.closest_point_with_t(point(5.0, 4.0)),
+        (0.0, point(1.0, 1.0))
+    );
+}
+
+#[test]
+fn quadratic_intersections() {
+    fn check(a: &QuadraticBezierSegment<f64>, b: &QuadraticBezierSegment<f64>, expected: usize) {
+        let intersections = a.quadratic_intersections_t(b);
+        assert_eq!(intersections.len(), expected, "{:?}", intersections);
+        for (t1, t2) in intersections {
+            assert!((a.sample(t1) - b.sample(t2)).length() < 1e-5);
+        }
+    }
+
+    let a = QuadraticBezierSegment {
+        from: point(0.0f64, 0.0),
+        ctrl: point(50.0, 100.0),
+        to: point(100.0, 0.0),
+    };
+
+    // Two crossings.
+    let b = QuadraticBezierSegment {
+        from: point(0.0, 40.0),
+        ctrl: point(50.0, 0.0),
+        to: point(100.0, 40.0),
+    };
+    check(&a, &b, 2);
+    check(&b, &a, 2);
+
+    // Four crossings.
+    let c = QuadraticBezierSegment {
+        from: point(-10.0, 10.0),
+        ctrl: point(210.0, 25.0),
+        to: point(-10.0, 40.0),
+    };
+    check(&a, &c, 4);
+    check(&c, &a, 4);
+
+    // Tangential touch at the top of the curve.
+    let touch = QuadraticBezierSegment {
+        from: point(0.0, 100.0),
+        ctrl: point(50.0, 0.0),
+        to: point(100.0, 100.0),
+    };
+    let intersections = a.quadratic_intersections_t(&touch);
+    assert_eq!(intersections.len(), 1);
+    assert!((intersections[0].0 - 0.5).abs() < 1e-5);
+    assert!((intersections[0].1 - 0.5).abs() < 1e-5);
+
+    // Endpoint/endpoint intersection.
+    let e = QuadraticBezierSegment {
+        from: point(100.0, 0.0),
+        ctrl: point(150.0, 50.0),
+        to: point(200.0, 0.0),
+    };
+    check(&a, &e, 1);
+
+    // No intersection.
+    let f = QuadraticBezierSegment {
+        from: point(0.0, 100.0),
+        ctrl: point(50.0, 150.0),
+        to: point(100.0, 100.0),
+    };
+    check(&a, &f, 0);
+
+    // Flat curve.
+    let line = QuadraticBezierSegment {
+        from: point(0.0, 25.0),
+        ctrl: point(50.0, 25.0),
+        to: point(100.0, 25.0),
+    };
+    check(&a, &line, 2);
+    check(&line, &a, 2);
+
+    // Two flat curves crossing, and sharing an endpoint.
+    let cross = QuadraticBezierSegment {
+        from: point(50.0, 0.0),
+        ctrl: point(50.0, 30.0),
+        to: point(50.0, 50.0),
+    };
+    check(&line, &cross, 1);
+    check(&cross, &line, 1);
+    let corner = QuadraticBezierSegment {
+        from: point(100.0, 25.0),
+        ctrl: point(100.0, 50.0),
+        to: point(100.0, 75.0),
+    };
+    let intersections = line.quadratic_intersections_t(&corner);
+    assert_eq!(intersections.len(), 1);
+    assert_eq!(intersections[0], (1.0, 0.0));
+
+    // Overlapping flat curves.
+    let overlap = QuadraticBezierSegment {
+        from: point(50.0, 25.0),
+        ctrl: point(100.0, 25.0),
+        to: point(150.0, 25.0),
+    };
+    check(&line, &overlap, 1);
+}
+
+#[test]
+fn quadratic_cubic_intersections() {
+    let quad = QuadraticBezierSegment {
+        from: point(0.0f64, 0.0),
+        ctrl: point(50.0, 100.0),
+        to: point(100.0, 0.0),
+    };
+
+    let cubic = CubicBezierSegment {
+        from: point(0.0, 10.0),
+        ctrl1: point(30.0, 100.0),
+        ctrl2: point(70.0, -60.0),
+        to: point(100.0, 30.0),
+    };
+
+    let intersections = quad.quadratic_cubic_intersections_t(&cubic);
+    let reference = cubic.quadratic_intersections_t(&quad);
+    assert_eq!(intersections.len(), reference.len());
+    assert!(!intersections.is_empty());
+    for (t1, t2) in intersections {
+        assert!((quad.sample(t1) - cubic.sample(t2)).length() < 1e-5);
+    }
+
+    // Matches the quadratic/quadratic intersections after elevating to a cubic.
+    let other = QuadraticBezierSegment {
+        from: point(0.0, 40.0),
+        ctrl: point(50.0, 0.0),
+        to: point(100.0, 40.0),
+    };
+    let a = quad.quadratic_intersections(&other);
+    let b = quad.quadratic_cubic_intersections(&other.to_cubic());
+    assert_eq!(a.len(), b.len());
+    for (p1, p2) in a.iter().zip(b.iter()) {
+        assert!((*p1 - *p2).length() < 1e-5);
+    }
+
+    // Flat quadratic curve sharing both endpoints with the cubic curve.
+    let line = QuadraticBezierSegment {
+        from: point(0.0, 10.0),
+        ctrl: point(50.0, 20.0),
+        to: point(100.0, 30.0),
+    };
+    let intersections = line.quadratic_cubic_intersections_t(&cubic);
+    assert!(intersections.contains(&(0.0, 0.0)), "{:?}", intersections);
+    assert!(intersections.contains(&(1.0, 1.0)), "{:?}", intersections);
+    for (t1, t2) in intersections {
+        assert!((line.sample(t1) - cubic.sample(t2)).length() < 1e-5);
+    }
+}
+
+#[test]
+fn curvature() {
+    // The parabola y = x² which has a curvature of 2 at its apex.
+    let curve: QuadraticBezierSegment<f32> = QuadraticBezierSegment {
+        from: Point::new(-1.0, 1.0),
+        ctrl: Point::new(0.0, -1.0),
+        to: Point::new(1.0, 1.0),
+    };
+
+    assert!((curve.curvature(0.5) - 2.0).abs() < 1e-5);
+    assert!((curve.radius_of_curvature(0.5) - 0.5).abs() < 1e-5);
+    assert!(curve.curvature(0.0) > 0.0);
+    assert!(curve.curvature(0.0) < curve.curvature(0.5));
+    assert!((curve.max_curvature_t() - 0.5).abs() < 1e-5);
+
+    // Turning the other way flips the sign.
+    let flipped = curve.flip();
+    assert!((flipped.curvature(0.5) + 2.0).abs() < 1e-5);
+    assert!((flipped.radius_of_curvature(0.5) + 0.5).abs() < 1e-5);
+
+    let line: QuadraticBezierSegment<f32> = QuadraticBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl: Point::new(1.0, 1.0),
+        to: Point::new(2.0, 2.0),
+    };
+    assert_eq!(line.curvature(0.3), 0.0);
+    assert_eq!(line.radius_of_curvature(0.3), f32::INFINITY);
+    assert_eq!(line.max_curvature_t(), 0.0);
+}
+
+#[test]
+fn implicit_coefficients() {
+    let eval = |c: [f64; 6], p: Point<f64>| {
+        c[0] * p.x * p.x + c[1] * p.x * p.y + c[2] * p.y * p.y + c[3] * p.x + c[4] * p.y + c[5]
+    };
+
+    // The parabola y = x².
+    let curve: QuadraticBezierSegment<f64> = QuadraticBezierSegment {
+        from: Point::new(-1.0, 1.0),
+        ctrl: Point::new(0.0, -1.0),
+        to: Point::new(1.0, 1.0),
+    };
+    let c = curve.implicit_coefficients();
+    let expected = [-1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+    for i in 0..6 {
+        assert!((c[i] - expected[i]).abs() < 1e-12);
+    }
+
+    for i in 0..=10 {
+        assert!(eval(c, curve.sample(i as f64 / 10.0)).abs() < 1e-12);
+    }
+    // The curve turns left so the inside of the parabola is on its left.
+    assert!(eval(c, Point::new(0.0, 0.5)) > 0.0);
+    assert!(eval(c, Point::new(0.0, -0.5)) < 0.0);
+    assert_eq!(eval(c, curve.ctrl), -1.0);
+
+    // Flipping the curve flips the sign.
+    let c = curve.flip().implicit_coefficients();
+    assert!(eval(c, Point::new(0.0, 0.5)) < 0.0);
+    assert_eq!(eval(c, curve.ctrl), 1.0);
+
+    // Aligned control points give the line equation.
+    let line: QuadraticBezierSegment<f64> = QuadraticBezierSegment {
+        from: Point::new(0.0, 0.0),
+        ctrl: Point::new(1.0, 0.0),
+        to: Point::new(2.0, 0.0),
+    };
+    let c = line.implicit_coefficients();
+    assert_eq!(&c[..3], &[0.0, 0.0, 0.0]);
+    assert_eq!(eval(c, Point::new(5.0, 0.0)), 0.0);
+    assert!(eval(c, Point::new(1.0, 1.0)) > 0.0);
+    assert!(eval(c, Point::new(1.0, -1.0)) < 0.0);
+}
+
+#[test]
+fn split_at_many() {
+    let curve = QuadraticBezierSegment {
+        from: point(0.0, 0.0),
+        ctrl: point(10.0, 20.0),
+        to: point(40.0, 10.0),
+    };
+
+    let ts = [0.2, 0.3, 0.75];
+    let mut curves = std::vec::Vec::new();
+    curve.split_at_many(&ts, &mut curves);
+    assert_eq!(curves.len(), 4);
+    assert_eq!(curves[0].from, curve.from);
+    assert_eq!(curves[3].to, curve.to);
+
+    let bounds = [0.0, 0.2, 0.3, 0.75, 1.0];
+    for (i, sub_curve) in curves.iter().enumerate() {
+        if i > 0 {
+            assert_eq!(sub_curve.from, curves[i - 1].to);
+        }
+        // The sub-curve's midpoint is on the curve.
+        let t = (bounds[i] + bounds[i + 1]) * 0.5;
+        assert!((sub_curve.sample(0.5) - curve.sample(t)).length() < 1e-4);
+    }
+}
+
+#[test]
+fn flattening_through_segment_trait() {
+    use crate::Segment;
+
+    // The same routine works for both curve types.
+    fn check<Seg: Segment<Scalar = f32>>(curve: &Seg, tolerance: f32) -> usize {
+        let mut count = 0;
+        let mut prev_t = 0.0;
+        let mut prev_point = curve.from();
+        curve.for_each_flattened_with_t(tolerance, &mut |segment, t| {
+            assert_eq!(t.start, prev_t);
+            assert!(t.end > t.start);
+            assert_eq!(segment.from, prev_point);
+            prev_t = t.end;
+            prev_point = segment.to;
+            count += 1;
+        });
+        assert_eq!(prev_t, 1.0);
+        assert_eq!(prev_point, curve.to());
+
+        count
+    }
+
+    let quad = QuadraticBezierSegment {
+        from: point(0.0, 0.0),
+        ctrl: point(50.0, 100.0),
+        to: point(100.0, 0.0),
+    };
+    let cubic = CubicBezierSegment {
+        from: point(0.0, 0.0),
+        ctrl1: point(33.0, 66.0),
+        ctrl2: point(66.0, 66.0),
+        to: point(100.0, 0.0),
+    };
+
+    let n_quad = check(&quad, 0.1);
+    let n_cubic = check(&cubic, 0.1);
+    assert!(n_quad > 5);
+    assert!(n_cubic > 5);
+
+    // An exactly elevated quadratic curve produces a similar number of segments.
+    let elevated = quad.to_cubic();
+    let n_elevated = check(&elevated, 0.1);
+    assert!((n_elevated as i32 - n_quad as i32).abs() <= n_quad as i32 / 2);
+}
@@ -0,0 +1,247 @@
+//! Rational quadratic bézier curves.
+
+use crate::scalar::{Float, Scalar};
+use crate::{point, vector, Arc, Point, Rotation};
+use crate::{CubicBezierSegment, QuadraticBezierSegment};
+use num_traits::NumCast;
+
+/// A rational (weighted) quadratic bézier curve.
+///
+/// This is the projective generalization of `QuadraticBezierSegment`: the weight
+/// of the control point pulls the curve toward it (weight > 1) or away from it
+/// (weight < 1). A weight of one gives a regular quadratic bézier curve.
+///
+/// Contrary to polynomial bézier curves, rational quadratic curves can represent
+/// conic sections such as circular and elliptic arcs exactly.
+///
+/// The curve is defined by equation:
+/// ```∀ t ∈ [0..1],  P(t) = ((1 - t)² * from + 2 * (1 - t) * t * weight * ctrl + t² * to) / ((1 - t)² + 2 * (1 - t) * t * weight + t²)```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct RationalQuadratic<S> {
+    pub from: Point<S>,
+    pub ctrl: Point<S>,
+    pub to: Point<S>,
+    pub weight: S,
+}
+
+impl<S: Scalar> RationalQuadratic<S> {
+    pub fn cast<NewS: NumCast>(self) -> RationalQuadratic<NewS> {
+        RationalQuadratic {
+            from: self.from.cast(),
+            ctrl: self.ctrl.cast(),
+            to: self.to.cast(),
+            weight: NumCast::from(self.weight).unwrap(),
+        }
+    }
+
+    /// Creates a rational quadratic curve with a weight of one from a regular
+    /// quadratic bézier curve.
+    pub fn from_quadratic(curve: &QuadraticBezierSegment<S>) -> Self {
+        RationalQuadratic {
+            from: curve.from,
+            ctrl: curve.ctrl,
+            to: curve.to,
+            weight: S::ONE,
+        }
+    }
+
+    /// Exactly represents an elliptic arc as a rational quadratic curve.
+    ///
+    /// Returns `None` if the absolute value of the arc's sweep angle is greater
+    /// than or equal to π. Larger arcs must be split beforehand, for example with
+    /// `Arc::split`.
+    pub fn from_arc(arc: &Arc<S>) -> Option<Self> {
+        let half_sweep = arc.sweep_angle.get() * S::HALF;
+        let weight = Float::cos(half_sweep);
+        if weight <= S::EPSILON {
+            return None;
+        }
+
+        // Build the arc on the unit circle and map it onto the ellipse. Affine
+        // transformations preserve the weight.
+        let mid_angle = arc.start_angle.get() + half_sweep;
+        let rotation = Rotation::new(arc.x_rotation);
+        let map = |angle: S, scale: S| {
+            arc.center
+                + rotation.transform_vector(vector(
+                    arc.radii.x * Float::cos(angle) * scale,
+                    arc.radii.y * Float::sin(angle) * scale,
+                ))
+        };
+
+        Some(RationalQuadratic {
+            from: map(arc.start_angle.get(), S::ONE),
+            ctrl: map(mid_angle, S::ONE / weight),
+            to: map(arc.end_angle().get(), S::ONE),
+            weight,
+        })
+    }
+
+    /// Sample the curve at t (expecting t between 0 and 1).
+    pub fn sample(&self, t: S) -> Point<S> {
+        let one_t = S::ONE - t;
+        let b0 = one_t * one_t;
+        let b1 = S::TWO * one_t * t * self.weight;
+        let b2 = t * t;
+        let inv_w = S::ONE / (b0 + b1 + b2);
+
+        point(
+            (self.from.x * b0 + self.ctrl.x * b1 + self.to.x * b2) * inv_w,
+            (self.from.y * b0 + self.ctrl.y * b1 + self.to.y * b2) * inv_w,
+        )
+    }
+
+    /// Split this curve into two sub-curves.
+    ///
+    /// Both sub-curves are normalized so that their endpoints have a weight of one.
+    pub fn split(&self, t: S) -> (Self, Self) {
+        // De Casteljau's algorithm in homogeneous coordinates.
+        let w = self.weight;
+        let ctrl = self.ctrl.to_vector() * w;
+        let one_t = S::ONE - t;
+
+        let ctrl1 = self.from.to_vector() * one_t + ctrl * t;
+        let w1 = one_t + w * t;
+        let ctrl2 = ctrl * one_t + self.to.to_vector() * t;
+        let w2 = w * one_t + t;
+        let split = ctrl1 * one_t + ctrl2 * t;
+        let w12 = w1 * one_t + w2 * t;
+
+        let split_point = (split / w12).to_point();
+        let inv_sqrt_w12 = S::ONE / Float::sqrt(w12);
+
+        (
+            RationalQuadratic {
+                from: self.from,
+                ctrl: (ctrl1 / w1).to_point(),
+                to: split_point,
+                weight: w1 * inv_sqrt_w12,
+            },
+            RationalQuadratic {
+                from: split_point,
+                ctrl: (ctrl2 / w2).to_point(),
+                to: self.to,
+                weight: w2 * inv_sqrt_w12,
+            },
+        )
+    }
+
+    /// Approximates the curve with a single cubic bézier curve.
+    ///
+    /// The approximation shares the endpoints, the tangents at the endpoints and
+    /// the point at t = 0.5 with the rational curve. It is exact if the weight is
+    /// equal to one. For a quarter of a circle, the error is about 0.03% of the radius.
+    pub fn to_cubic_approx(&self) -> CubicBezierSegment<S> {
+        let k = S::FOUR / S::THREE * self.weight / (S::ONE + self.weight);
+        CubicBezierSegment {
+            from: self.from,
+            ctrl1: self.from + (self.ctrl - self.from) * k,
+            ctrl2: self.to + (self.ctrl - self.to) * k,
+            to: self.to,
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::Angle;
+
+#[test]
+fn rational_quadratic_from_arc() {
+    let arc = Arc {
+        center: point(1.0f64, 2.0),
+        radii: vector(3.0, 3.0),
+        start_angle: Angle::degrees(30.0),
+        sweep_angle: Angle::degrees(120.0),
+        x_rotation: Angle::zero(),
+    };
+
+    let curve = RationalQuadratic::from_arc(&arc).unwrap();
+    assert!((curve.from - arc.from()).length() < 1e-9);
+    assert!((curve.to - arc.to()).length() < 1e-9);
+    assert!((curve.sample(0.5) - arc.sample(0.5)).length() < 1e-9);
+
+    // All points lie exactly on the circle.
+    for i in 0..=10 {
+        let p = curve.sample(i as f64 / 10.0);
+        assert!(((p - arc.center).length() - 3.0).abs() < 1e-9);
+    }
+
+    // Rotated ellipse, clockwise.
+    let arc = Arc {
+        center: point(0.0f64, 0.0),
+        radii: vector(4.0, 2.0),
+        start_angle: Angle::degrees(10.0),
+        sweep_angle: Angle::degrees(-150.0),
+        x_rotation: Angle::degrees(20.0),
+    };
+    let curve = RationalQuadratic::from_arc(&arc).unwrap();
+    let inv_rotation = Rotation::new(-arc.x_rotation);
+    for i in 0..=10 {
+        let p = inv_rotation.transform_point(curve.sample(i as f64 / 10.0));
+        let v = p.x * p.x / 16.0 + p.y * p.y / 4.0;
+        assert!((v - 1.0).abs() < 1e-9);
+    }
+    assert!((curve.sample(0.5) - arc.sample(0.5)).length() < 1e-9);
+
+    let mut half_circle = arc;
+    half_circle.sweep_angle = Angle::degrees(180.0);
+    assert!(RationalQuadratic::from_arc(&half_circle).is_none());
+}
+
+#[test]
+fn rational_quadratic_split() {
+    let arc = Arc {
+        center: point(1.0f64, 1.0),
+        radii: vector(2.0, 2.0),
+        start_angle: Angle::degrees(-40.0),
+        sweep_angle: Angle::degrees(160.0),
+        x_rotation: Angle::zero(),
+    };
+    let curve = RationalQuadratic::from_arc(&arc).unwrap();
+
+    let (a, b) = curve.split(0.3);
+    assert_eq!(a.from, curve.from);
+    assert_eq!(b.to, curve.to);
+    assert_eq!(a.to, b.from);
+    assert!((a.to - curve.sample(0.3)).length() < 1e-9);
+
+    // The sub-curves are still exact arcs of the same circle.
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        assert!(((a.sample(t) - arc.center).length() - 2.0).abs() < 1e-9);
+        assert!(((b.sample(t) - arc.center).length() - 2.0).abs() < 1e-9);
+    }
+
+    // Splitting a polynomial curve gives the same result as QuadraticBezierSegment::split.
+    let quadratic = QuadraticBezierSegment {
+        from: point(0.0f64, 0.0),
+        ctrl: point(1.0, 2.0),
+        to: point(3.0, 0.0),
+    };
+    let (qa, qb) = quadratic.split(0.3);
+    let (ra, rb) = RationalQuadratic::from_quadratic(&quadratic).split(0.3);
+    assert!((qa.ctrl - ra.ctrl).length() < 1e-9);
+    assert!((qb.ctrl - rb.ctrl).length() < 1e-9);
+    assert!((ra.weight - 1.0).abs() < 1e-9);
+    assert!((rb.weight - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn rational_quadratic_to_cubic() {
+    let arc = Arc {
+        center: point(0.0f64, 0.0),
+        radii: vector(1.0, 1.0),
+        start_angle: Angle::zero(),
+        sweep_angle: Angle::degrees(90.0),
+        x_rotation: Angle::zero(),
+    };
+    let cubic = RationalQuadratic::from_arc(&arc).unwrap().to_cubic_approx();
+
+    assert!((cubic.ctrl1 - point(1.0, 0.5523)).length() < 1e-3);
+    assert!((cubic.ctrl2 - point(0.5523, 1.0)).length() < 1e-3);
+    for i in 0..=10 {
+        let p = cubic.sample(i as f64 / 10.0);
+        assert!((p.to_vector().length() - 1.0).abs() < 1e-3);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::scalar::Scalar;
-use crate::{point, Box2D, LineSegment, Point, Vector};
+use crate::{point, Box2D, CubicBezierSegment, LineSegment, Point, QuadraticBezierSegment, Vector};
 
 use core::ops::Range;
 
@@ -50,7 +50,8 @@ pub trait Segment: Copy + Sized {
 
     /// Return the curve inside a given range of t.
     ///
-    /// This is equivalent splitting at the range's end points.
+    /// This is equivalent splitting at the range's end points. For arcs, the start and
+    /// sweep angles are restricted to the corresponding range of angles.
     fn split_range(&self, t_range: Range<Self::Scalar>) -> Self;
 
     /// Swap the direction of the segment.
@@ -59,6 +60,20 @@ pub trait Segment: Copy + Sized {
     /// Compute the length of the segment using a flattened approximation.
     fn approximate_length(&self, tolerance: Self::Scalar) -> Self::Scalar;
 
+    /// Approximates the curve with sequence of line segments.
+    ///
+    /// The `tolerance` parameter defines the maximum distance between the curve and
+    /// its approximation.
+    ///
+    /// Line segments produce themselves.
+    fn for_each_flattened(
+        &self,
+        tolerance: Self::Scalar,
+        callback: &mut dyn FnMut(&LineSegment<Self::Scalar>),
+    ) {
+        self.for_each_flattened_with_t(tolerance, &mut |segment, _| callback(segment));
+    }
+
     /// Approximates the curve with sequence of line segments.
     ///
     /// The `tolerance` parameter defines the maximum distance between the curve and
@@ -113,6 +128,105 @@ pub trait BoundingBox {
     fn fast_bounding_range_y(&self) -> (Self::Scalar, Self::Scalar);
 }
 
+/// Either a quadratic or a cubic bézier curve.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum BezierSegment<S> {
+    Quadratic(QuadraticBezierSegment<S>),
+    Cubic(CubicBezierSegment<S>),
+}
+
+impl<S: Scalar> BezierSegment<S> {
+    /// Start of the curve.
+    #[inline]
+    pub fn from(&self) -> Point<S> {
+        match self {
+            BezierSegment::Quadratic(curve) => curve.from,
+            BezierSegment::Cubic(curve) => curve.from,
+        }
+    }
+
+    /// End of the curve.
+    #[inline]
+    pub fn to(&self) -> Point<S> {
+        match self {
+            BezierSegment::Quadratic(curve) => curve.to,
+            BezierSegment::Cubic(curve) => curve.to,
+        }
+    }
+
+    /// Sample the curve at t (expecting t between 0 and 1).
+    pub fn sample(&self, t: S) -> Point<S> {
+        match self {
+            BezierSegment::Quadratic(curve) => curve.sample(t),
+            BezierSegment::Cubic(curve) => curve.sample(t),
+        }
+    }
+
+    /// Sample the derivative at t (expecting t between 0 and 1).
+    pub fn derivative(&self, t: S) -> Vector<S> {
+        match self {
+            BezierSegment::Quadratic(curve) => curve.derivative(t),
+            BezierSegment::Cubic(curve) => curve.derivative(t),
+        }
+    }
+
+    /// Swap the direction of the segment.
+    pub fn flip(&self) -> Self {
+        match self {
+            BezierSegment::Quadratic(curve) => BezierSegment::Quadratic(curve.flip()),
+            BezierSegment::Cubic(curve) => BezierSegment::Cubic(curve.flip()),
+        }
+    }
+
+    /// Returns the curve as a cubic bézier curve, elevating quadratic curves.
+    pub fn to_cubic(&self) -> CubicBezierSegment<S> {
+        match self {
+            BezierSegment::Quadratic(curve) => curve.to_cubic(),
+            BezierSegment::Cubic(curve) => *curve,
+        }
+    }
+
+    /// Returns the smallest rectangle that contains the curve.
+    pub fn bounding_box(&self) -> Box2D<S> {
+        match self {
+            BezierSegment::Quadratic(curve) => curve.bounding_box(),
+            BezierSegment::Cubic(curve) => curve.bounding_box(),
+        }
+    }
+
+    /// Returns a conservative rectangle that contains the curve.
+    pub fn fast_bounding_box(&self) -> Box2D<S> {
+        match self {
+            BezierSegment::Quadratic(curve) => curve.fast_bounding_box(),
+            BezierSegment::Cubic(curve) => curve.fast_bounding_box(),
+        }
+    }
+
+    /// Approximates the curve with sequence of line segments.
+    ///
+    /// The `tolerance` parameter defines the maximum distance between the curve and
+    /// its approximation.
+    pub fn for_each_flattened<F: FnMut(&LineSegment<S>)>(&self, tolerance: S, callback: &mut F) {
+        match self {
+            BezierSegment::Quadratic(curve) => curve.for_each_flattened(tolerance, callback),
+            BezierSegment::Cubic(curve) => curve.for_each_flattened(tolerance, callback),
+        }
+    }
+}
+
+impl<S> From<QuadraticBezierSegment<S>> for BezierSegment<S> {
+    fn from(curve: QuadraticBezierSegment<S>) -> Self {
+        BezierSegment::Quadratic(curve)
+    }
+}
+
+impl<S> From<CubicBezierSegment<S>> for BezierSegment<S> {
+    fn from(curve: CubicBezierSegment<S>) -> Self {
+        BezierSegment::Cubic(curve)
+    }
+}
+
 macro_rules! impl_segment {
     ($S:ty) => {
         type Scalar = $S;
@@ -160,3 +274,99 @@ macro_rules! impl_segment {
         }
     };
 }
+
+#[test]
+fn flatten_generic_segments() {
+    use crate::{vector, Angle, Arc, CubicBezierSegment, QuadraticBezierSegment};
+    use std::vec::Vec;
+
+    fn flatten<Seg: Segment<Scalar = f32>>(segment: &Seg) -> Vec<LineSegment<f32>> {
+        let mut output = Vec::new();
+        segment.for_each_flattened(0.01, &mut |line| output.push(*line));
+
+        let mut with_t = Vec::new();
+        segment.for_each_flattened_with_t(0.01, &mut |line, _| with_t.push(*line));
+        assert_eq!(output, with_t);
+
+        assert_eq!(output[0].from, segment.from());
+        assert_eq!(output.last().unwrap().to, segment.to());
+        for pair in output.windows(2) {
+            assert_eq!(pair[0].to, pair[1].from);
+        }
+
+        output
+    }
+
+    let line = LineSegment {
+        from: point(0.0, 0.0),
+        to: point(10.0, 5.0),
+    };
+    assert_eq!(flatten(&line), &[line]);
+
+    let quad = QuadraticBezierSegment {
+        from: point(0.0, 0.0),
+        ctrl: point(5.0, 10.0),
+        to: point(10.0, 0.0),
+    };
+    assert!(flatten(&quad).len() > 1);
+
+    let cubic = CubicBezierSegment {
+        from: point(0.0, 0.0),
+        ctrl1: point(0.0, 10.0),
+        ctrl2: point(10.0, 10.0),
+        to: point(10.0, 0.0),
+    };
+    assert!(flatten(&cubic).len() > 1);
+
+    let arc = Arc {
+        center: point(0.0, 0.0),
+        radii: vector(10.0, 5.0),
+        start_angle: Angle::radians(0.0),
+        sweep_angle: Angle::radians(2.0),
+        x_rotation: Angle::radians(0.5),
+    };
+    assert!(flatten(&arc).len() > 1);
+}
+
+#[test]
+fn split_range_generic_segments() {
+    use crate::euclid::approxeq::ApproxEq;
+    use crate::{vector, Angle, Arc, CubicBezierSegment, QuadraticBezierSegment};
+
+    fn check<Seg: Segment<Scalar = f32>>(segment: &Seg) {
+        for range in [0.0..1.0, 0.0..0.5, 0.25..0.75, 0.5..1.0, 0.3..0.3] {
+            let sub = segment.split_range(range.clone());
+            let from = segment.sample(range.start);
+            let to = segment.sample(range.end);
+            assert!(sub.from().approx_eq_eps(&from, &point(0.001, 0.001)));
+            assert!(sub.to().approx_eq_eps(&to, &point(0.001, 0.001)));
+
+            // The middle of the sub-segment is on the original segment.
+            let mid = segment.sample((range.start + range.end) * 0.5);
+            assert!(sub.sample(0.5).approx_eq_eps(&mid, &point(0.001, 0.001)));
+        }
+    }
+
+    check(&LineSegment {
+        from: point(0.0, 0.0),
+        to: point(10.0, 5.0),
+    });
+    check(&QuadraticBezierSegment {
+        from: point(0.0, 0.0),
+        ctrl: point(5.0, 10.0),
+        to: point(10.0, 0.0),
+    });
+    check(&CubicBezierSegment {
+        from: point(0.0, 0.0),
+        ctrl1: point(0.0, 10.0),
+        ctrl2: point(10.0, 10.0),
+        to: point(10.0, 0.0),
+    });
+    check(&Arc {
+        center: point(0.0, 0.0),
+        radii: vector(10.0, 5.0),
+        start_angle: Angle::radians(0.5),
+        sweep_angle: Angle::radians(-2.0),
+        x_rotation: Angle::radians(0.5),
+    });
+}
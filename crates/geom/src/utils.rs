@@ -1,5 +1,5 @@
 use crate::scalar::{Float, Scalar};
-use crate::{vector, Point, Vector};
+use crate::{vector, LineSegment, Point, Vector};
 use arrayvec::ArrayVec;
 
 #[inline]
@@ -126,6 +126,222 @@ pub fn cubic_polynomial_roots<S: Scalar>(a: S, b: S, c: S, d: S) -> ArrayVec<S,
     result
 }
 
+/// Finds the roots of a polynomial of degree up to 6 in the `[0, 1]` interval.
+///
+/// The coefficients are provided in decreasing degree order. Roots are isolated
+/// by recursively computing the roots of the derivative, which splits the interval
+/// into monotonic pieces, and refined with a bisection. Double roots where the
+/// polynomial touches zero without changing sign are only found if the polynomial
+/// evaluates exactly to zero.
+pub(crate) fn polynomial_roots_in_unit_interval<S: Scalar>(coefficients: &[S]) -> ArrayVec<S, 6> {
+    debug_assert!(!coefficients.is_empty() && coefficients.len() <= 7);
+    let mut result = ArrayVec::new();
+
+    let degree = coefficients.len() - 1;
+    if degree == 0 || coefficients.iter().all(|c| *c == S::ZERO) {
+        return result;
+    }
+
+    if degree == 1 {
+        let (a, b) = (coefficients[0], coefficients[1]);
+        if a != S::ZERO {
+            let t = -b / a;
+            if t >= S::ZERO && t <= S::ONE {
+                result.push(t);
+            }
+        }
+        return result;
+    }
+
+    let mut derivative: ArrayVec<S, 6> = ArrayVec::new();
+    for (i, c) in coefficients[..degree].iter().enumerate() {
+        derivative.push(*c * S::value((degree - i) as f32));
+    }
+
+    // The polynomial is monotonic between two consecutive roots of its derivative.
+    let mut bounds: ArrayVec<S, 7> = ArrayVec::new();
+    bounds.push(S::ZERO);
+    for t in polynomial_roots_in_unit_interval(&derivative) {
+        if t > *bounds.last().unwrap() && t < S::ONE {
+            bounds.push(t);
+        }
+    }
+    bounds.push(S::ONE);
+
+    for i in 1..bounds.len() {
+        let mut t0 = bounds[i - 1];
+        let mut t1 = bounds[i];
+        let mut v0 = evaluate_polynomial(coefficients, t0);
+        let v1 = evaluate_polynomial(coefficients, t1);
+
+        if v0 == S::ZERO {
+            if result.last() != Some(&t0) {
+                result.push(t0);
+            }
+            continue;
+        }
+        if v1 == S::ZERO {
+            result.push(t1);
+            continue;
+        }
+        if (v0 < S::ZERO) == (v1 < S::ZERO) {
+            continue;
+        }
+
+        for _ in 0..64 {
+            let t = (t0 + t1) * S::HALF;
+            if t <= t0 || t >= t1 {
+                break;
+            }
+            let v = evaluate_polynomial(coefficients, t);
+            if v == S::ZERO {
+                t0 = t;
+                t1 = t;
+                break;
+            }
+            if (v < S::ZERO) == (v0 < S::ZERO) {
+                t0 = t;
+                v0 = v;
+            } else {
+                t1 = t;
+            }
+        }
+
+        result.push((t0 + t1) * S::HALF);
+    }
+
+    result
+}
+
+/// Evaluates a polynomial with coefficients provided in decreasing degree order.
+#[inline]
+pub(crate) fn evaluate_polynomial<S: Scalar>(coefficients: &[S], t: S) -> S {
+    let mut result = S::ZERO;
+    for c in coefficients {
+        result = result * t + *c;
+    }
+
+    result
+}
+
+/// Signed curvature of a curve given its first and second derivatives at a point.
+///
+/// Returns infinity if the first derivative vanishes, which is the case at cusps.
+#[inline]
+pub(crate) fn signed_curvature<S: Scalar>(d1: Vector<S>, d2: Vector<S>) -> S {
+    let length = d1.length();
+    if length == S::ZERO {
+        return S::infinity();
+    }
+
+    d1.cross(d2) / (length * length * length)
+}
+
+/// Signed radius of curvature of a curve given its first and second derivatives at a point.
+///
+/// Returns zero if the first derivative vanishes and infinity if the curvature is zero.
+#[inline]
+pub(crate) fn signed_radius_of_curvature<S: Scalar>(d1: Vector<S>, d2: Vector<S>) -> S {
+    if d1 == Vector::zero() {
+        return S::ZERO;
+    }
+
+    let cross = d1.cross(d2);
+    if cross == S::ZERO {
+        return S::infinity();
+    }
+
+    let length = d1.length();
+    length * length * length / cross
+}
+
+/// Maximum number of segments a flattened segment is subdivided into by
+/// `for_each_clamped_segment`.
+pub(crate) const MAX_CLAMPED_SUBDIVISIONS: u32 = 1 << 16;
+
+/// Merges and subdivides the line segments produced by a flattening function so that
+/// their lengths stay within `min_length..=max_length`.
+///
+/// Consecutive segments are merged until the distance from the start of the run is at
+/// least `min_length`, and the resulting segments are evenly subdivided if they are
+/// longer than `max_length`. A trailing run shorter than `min_length` is merged into the
+/// previous segment. Both bounds can be satisfied as long as `min_length` is at most
+/// half of `max_length`.
+///
+/// Each segment is subdivided into at most `MAX_CLAMPED_SUBDIVISIONS` segments.
+pub(crate) fn for_each_clamped_segment<S, Flatten, F>(
+    min_length: S,
+    max_length: S,
+    flatten: Flatten,
+    callback: &mut F,
+) where
+    S: Scalar,
+    Flatten: FnOnce(&mut dyn FnMut(&LineSegment<S>)),
+    F: FnMut(&LineSegment<S>),
+{
+    debug_assert!(min_length <= max_length);
+    let mut subdivide = |segment: &LineSegment<S>| {
+        let n = S::ceil(segment.length() / max_length);
+        if n <= S::ONE || !n.is_finite() {
+            callback(segment);
+            return;
+        }
+
+        let n = n
+            .to_u32()
+            .unwrap_or(MAX_CLAMPED_SUBDIVISIONS)
+            .min(MAX_CLAMPED_SUBDIVISIONS);
+        let step = S::ONE / S::value(n as f32);
+        let mut from = segment.from;
+        for i in 1..n {
+            let to = segment.sample(step * S::value(i as f32));
+            callback(&LineSegment { from, to });
+            from = to;
+        }
+        callback(&LineSegment {
+            from,
+            to: segment.to,
+        });
+    };
+
+    let mut start = None;
+    let mut end = None;
+    let mut pending: Option<LineSegment<S>> = None;
+    flatten(&mut |segment| {
+        let from = *start.get_or_insert(segment.from);
+        end = Some(segment.to);
+        if (segment.to - from).square_length() < min_length * min_length {
+            return;
+        }
+
+        if let Some(pending) = &pending {
+            subdivide(pending);
+        }
+        pending = Some(LineSegment {
+            from,
+            to: segment.to,
+        });
+        start = Some(segment.to);
+    });
+
+    let (start, end) = match (start, end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return,
+    };
+
+    match &mut pending {
+        Some(pending) => pending.to = end,
+        None => {
+            pending = Some(LineSegment {
+                from: start,
+                to: end,
+            })
+        }
+    }
+
+    subdivide(&pending.unwrap());
+}
+
 #[test]
 fn cubic_polynomial() {
     fn assert_approx_eq(a: ArrayVec<f32, 3>, b: &[f32], epsilon: f32) {
@@ -175,3 +391,30 @@ fn cubic_polynomial() {
     // Constant.
     assert_approx_eq(cubic_polynomial_roots(0.0, 0.0, 0.0, 0.0), &[], 0.00005);
 }
+
+#[test]
+fn polynomial_roots() {
+    fn assert_approx_eq(a: ArrayVec<f64, 6>, b: &[f64]) {
+        assert_eq!(a.len(), b.len(), "{:?} != {:?}", a, b);
+        for i in 0..a.len() {
+            assert!((a[i] - b[i]).abs() < 1e-6, "{:?} != {:?}", a, b);
+        }
+    }
+
+    // (t - 0.1)(t - 0.3)(t - 0.5)(t - 0.7)(t - 0.9)
+    assert_approx_eq(
+        polynomial_roots_in_unit_interval(&[1.0, -2.5, 2.3, -0.95, 0.1689, -0.00945]),
+        &[0.1, 0.3, 0.5, 0.7, 0.9],
+    );
+    // (t - 0.5)(t - 2.0)
+    assert_approx_eq(polynomial_roots_in_unit_interval(&[1.0, -2.5, 1.0]), &[0.5]);
+    // Root at an endpoint.
+    assert_approx_eq(
+        polynomial_roots_in_unit_interval(&[0.0, 0.0, 1.0, 0.0]),
+        &[0.0],
+    );
+    assert_approx_eq(
+        polynomial_roots_in_unit_interval(&[0.0, 0.0, 0.0, 0.0]),
+        &[],
+    );
+}
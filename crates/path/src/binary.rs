@@ -0,0 +1,193 @@
+//! A compact binary representation of paths.
+//!
+//! See [`Path::to_bytes`](../struct.Path.html#method.to_bytes) and
+//! [`Path::from_bytes`](../struct.Path.html#method.from_bytes).
+//!
+//! # Format
+//!
+//! All values are little-endian. The layout (version 1) is:
+//!
+//! | Offset         | Size               | Content                                  |
+//! |----------------|--------------------|------------------------------------------|
+//! | 0              | 4                  | The magic bytes `b"lyon"`.               |
+//! | 4              | 4                  | Format version (`u32`), currently `1`.   |
+//! | 8              | 4                  | Number of custom attributes (`u32`).     |
+//! | 12             | 4                  | Number of points `P` (`u32`).            |
+//! | 16             | 4                  | Number of verbs `V` (`u32`).             |
+//! | 20             | `P * 8`            | The points, as pairs of `f32` (x, y).    |
+//! | `20 + P * 8`   | `V`                | The verbs, one byte each.                |
+//!
+//! The verbs are encoded as follows: `0` begin, `1` line, `2` quadratic bézier curve,
+//! `3` cubic bézier curve, `4` close and `5` end.
+//!
+//! The points are stored in the same order as in the [`Path`](../struct.Path.html)
+//! data structure: each verb is followed by its control points, then its endpoint
+//! (if any), then the custom attributes of the endpoint packed in pairs.
+//!
+//! The format is stable: future changes will use a different version number.
+
+use crate::math::{point, Point};
+use crate::path::{PathSlice, Verb};
+
+use alloc::vec::Vec;
+
+const MAGIC: &[u8; 4] = b"lyon";
+const VERSION: u32 = 1;
+const HEADER_SIZE: usize = 20;
+
+/// An error that can happen when decoding a path from its binary representation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DecodeError {
+    /// The data does not start with the expected magic bytes.
+    InvalidHeader,
+    /// The data was encoded with an unsupported version of the format.
+    UnsupportedVersion(u32),
+    /// The data is shorter than announced in the header.
+    UnexpectedEnd,
+    /// A byte that does not correspond to a verb was found at the given offset.
+    InvalidVerb { offset: usize },
+    /// The sequence of verbs does not form a valid path or does not match the
+    /// number of points.
+    InvalidPath,
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::InvalidHeader => std::write!(f, "Invalid header"),
+            DecodeError::UnsupportedVersion(v) => std::write!(f, "Unsupported version {v}"),
+            DecodeError::UnexpectedEnd => std::write!(f, "Unexpected end of data"),
+            DecodeError::InvalidVerb { offset } => {
+                std::write!(f, "Invalid verb at offset {offset}")
+            }
+            DecodeError::InvalidPath => std::write!(f, "Invalid path"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+pub(crate) fn encode(path: PathSlice) -> Vec<u8> {
+    let num_points = path.points.len();
+    let num_verbs = path.verbs.len();
+    let mut bytes = Vec::with_capacity(HEADER_SIZE + num_points * 8 + num_verbs);
+
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes.extend_from_slice(&(path.num_attributes as u32).to_le_bytes());
+    bytes.extend_from_slice(&(num_points as u32).to_le_bytes());
+    bytes.extend_from_slice(&(num_verbs as u32).to_le_bytes());
+
+    for p in path.points {
+        bytes.extend_from_slice(&p.x.to_le_bytes());
+        bytes.extend_from_slice(&p.y.to_le_bytes());
+    }
+
+    for verb in path.verbs {
+        bytes.push(match verb {
+            Verb::Begin => 0,
+            Verb::LineTo => 1,
+            Verb::QuadraticTo => 2,
+            Verb::CubicTo => 3,
+            Verb::Close => 4,
+            Verb::End => 5,
+        });
+    }
+
+    bytes
+}
+
+/// Returns the points, verbs and number of attributes of the decoded path.
+pub(crate) fn decode(bytes: &[u8]) -> Result<(Vec<Point>, Vec<Verb>, usize), DecodeError> {
+    if bytes.len() < 4 || &bytes[0..4] != MAGIC {
+        return Err(DecodeError::InvalidHeader);
+    }
+    if bytes.len() < HEADER_SIZE {
+        return Err(DecodeError::UnexpectedEnd);
+    }
+
+    let read_u32 = |offset: usize| {
+        u32::from_le_bytes([
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ])
+    };
+
+    let version = read_u32(4);
+    if version != VERSION {
+        return Err(DecodeError::UnsupportedVersion(version));
+    }
+
+    let num_attributes = read_u32(8) as usize;
+    let num_points = read_u32(12) as usize;
+    let num_verbs = read_u32(16) as usize;
+
+    let verbs_offset = num_points
+        .checked_mul(8)
+        .and_then(|n| n.checked_add(HEADER_SIZE))
+        .ok_or(DecodeError::UnexpectedEnd)?;
+    let end = verbs_offset
+        .checked_add(num_verbs)
+        .ok_or(DecodeError::UnexpectedEnd)?;
+    if bytes.len() < end {
+        return Err(DecodeError::UnexpectedEnd);
+    }
+    if bytes.len() > end {
+        return Err(DecodeError::InvalidPath);
+    }
+
+    let mut points = Vec::with_capacity(num_points);
+    for i in 0..num_points {
+        let offset = HEADER_SIZE + i * 8;
+        let x = f32::from_bits(read_u32(offset));
+        let y = f32::from_bits(read_u32(offset + 4));
+        points.push(point(x, y));
+    }
+
+    // Check that the verbs form a valid sequence of sub-paths and that they
+    // consume exactly the encoded points, so that iterating over the path can't
+    // read out of bounds.
+    let attribute_slots = num_attributes / 2 + num_attributes % 2;
+    let mut verbs = Vec::with_capacity(num_verbs);
+    let mut expected_points: usize = 0;
+    let mut in_sub_path = false;
+    for (i, byte) in bytes[verbs_offset..end].iter().enumerate() {
+        let (verb, n) = match *byte {
+            0 => (Verb::Begin, 1 + attribute_slots),
+            1 => (Verb::LineTo, 1 + attribute_slots),
+            2 => (Verb::QuadraticTo, 2 + attribute_slots),
+            3 => (Verb::CubicTo, 3 + attribute_slots),
+            4 => (Verb::Close, 1 + attribute_slots),
+            5 => (Verb::End, 0),
+            _ => {
+                return Err(DecodeError::InvalidVerb {
+                    offset: verbs_offset + i,
+                })
+            }
+        };
+
+        let valid = match verb {
+            Verb::Begin => !in_sub_path,
+            _ => in_sub_path,
+        };
+        if !valid {
+            return Err(DecodeError::InvalidPath);
+        }
+        in_sub_path = !matches!(verb, Verb::Close | Verb::End);
+
+        expected_points = expected_points
+            .checked_add(n)
+            .ok_or(DecodeError::InvalidPath)?;
+        verbs.push(verb);
+    }
+
+    if in_sub_path || expected_points != num_points {
+        return Err(DecodeError::InvalidPath);
+    }
+
+    Ok((points, verbs, num_attributes))
+}
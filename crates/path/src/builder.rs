@@ -213,6 +213,30 @@ impl<B: PathBuilder> NoAttributes<B> {
         self.inner.cubic_bezier_to(ctrl1, ctrl2, to, NO_ATTRIBUTES)
     }
 
+    /// Adds an elliptic arc to the current sub-path.
+    ///
+    /// See [`PathBuilder::arc`](trait.PathBuilder.html#method.arc).
+    #[inline]
+    pub fn arc(
+        &mut self,
+        center: Point,
+        radii: Vector,
+        start_angle: Angle,
+        sweep_angle: Angle,
+        x_rotation: Angle,
+        winding: Winding,
+    ) {
+        self.inner.arc(
+            center,
+            radii,
+            start_angle,
+            sweep_angle,
+            x_rotation,
+            winding,
+            NO_ATTRIBUTES,
+        );
+    }
+
     /// Hints at the builder that a certain number of endpoints and control
     /// points will be added.
     ///
@@ -223,6 +247,15 @@ impl<B: PathBuilder> NoAttributes<B> {
         self.inner.reserve(endpoints, ctrl_points);
     }
 
+    /// Returns the last control point of the current sub-path's last segment
+    /// if it is a quadratic or cubic bézier curve.
+    ///
+    /// See [`PathBuilder::last_ctrl`].
+    #[inline]
+    pub fn last_ctrl(&self) -> Option<Point> {
+        self.inner.last_ctrl()
+    }
+
     /// Applies the provided path event.
     ///
     /// By default this calls one of `begin`, `end`, `line`, `quadratic_bezier_segment`,
@@ -290,6 +323,71 @@ impl<B: PathBuilder> NoAttributes<B> {
             .add_circle(center, radius, winding, NO_ATTRIBUTES);
     }
 
+    /// Adds two sub-paths containing the outer and inner circles of an annulus (a ring).
+    ///
+    /// See [`PathBuilder::add_annulus`](trait.PathBuilder.html#method.add_annulus).
+    #[inline]
+    pub fn add_annulus(
+        &mut self,
+        center: Point,
+        inner_radius: f32,
+        outer_radius: f32,
+        winding: Winding,
+    ) where
+        B: Sized,
+    {
+        self.inner
+            .add_annulus(center, inner_radius, outer_radius, winding, NO_ATTRIBUTES);
+    }
+
+    /// Adds a sub-path containing a circle sector (a pie slice).
+    ///
+    /// There must be no sub-path in progress when this method is called.
+    /// No sub-path is in progress after the method is called.
+    #[inline]
+    pub fn add_circle_sector(
+        &mut self,
+        center: Point,
+        radius: f32,
+        start_angle: Angle,
+        sweep_angle: Angle,
+        winding: Winding,
+    ) {
+        self.inner.add_circle_sector(
+            center,
+            radius,
+            start_angle,
+            sweep_angle,
+            winding,
+            NO_ATTRIBUTES,
+        );
+    }
+
+    /// Adds a sub-path containing a sector of an annulus (a ring segment).
+    ///
+    /// There must be no sub-path in progress when this method is called.
+    /// No sub-path is in progress after the method is called.
+    #[inline]
+    pub fn add_annulus_sector(
+        &mut self,
+        center: Point,
+        inner_radius: f32,
+        outer_radius: f32,
+        start_angle: Angle,
+        sweep_angle: Angle,
+        winding: Winding,
+    ) {
+        self.inner.add_annulus_sector(
+            center,
+            inner_radius,
+            outer_radius,
+            start_angle,
+            sweep_angle,
+            winding,
+            NO_ATTRIBUTES,
+        );
+    }
+
     /// Adds a sub-path containing a rectangle.
     ///
     /// There must be no sub-path in progress when this method is called.
@@ -338,6 +436,29 @@ impl<B: PathBuilder> NoAttributes<B> {
         }
     }
 
+    /// Returns a builder that drops segments that don't move away from the current
+    /// position by more than `epsilon`.
+    #[inline]
+    pub fn deduplicated(self, epsilon: f32) -> NoAttributes<DedupBuilder<B>>
+    where
+        B: Sized,
+    {
+        NoAttributes {
+            inner: DedupBuilder::new(self.inner, epsilon),
+        }
+    }
+
+    /// Returns a builder that checks the sequence of commands and reports malformed ones.
+    #[inline]
+    pub fn validated(self) -> NoAttributes<ValidatingPathBuilder<B>>
+    where
+        B: Sized,
+    {
+        NoAttributes {
+            inner: ValidatingPathBuilder::new(self.inner),
+        }
+    }
+
     /// Returns a builder that support SVG commands.
     ///
     /// This must be called before starting to add any sub-path.
@@ -420,6 +541,11 @@ impl<B: PathBuilder> PathBuilder for NoAttributes<B> {
     fn reserve(&mut self, endpoints: usize, ctrl_points: usize) {
         self.inner.reserve(endpoints, ctrl_points)
     }
+
+    #[inline]
+    fn last_ctrl(&self) -> Option<Point> {
+        self.inner.last_ctrl()
+    }
 }
 
 impl<B: PathBuilder + Build> Build for NoAttributes<B> {
@@ -496,6 +622,37 @@ pub trait PathBuilder {
         custom_attributes: Attributes,
     ) -> EndpointId;
 
+    /// Adds an elliptic arc to the current sub-path.
+    ///
+    /// The arc is defined by its center, radii and rotation, and covers the angles from
+    /// `start_angle` to `start_angle + sweep_angle`, measured before the rotation is applied.
+    /// The sweep angle is clamped to a full turn. The winding defines the direction in
+    /// which the arc is traversed, regardless of the sign of the sweep angle.
+    ///
+    /// The arc is approximated with cubic bézier curves. Use a flattening builder to add it
+    /// as line segments instead.
+    ///
+    /// A sub-path must be in progress when this method is called and the current position
+    /// must be the start of the arc, which can be obtained with `Arc::from`. Unlike
+    /// `SvgPathBuilder::arc_to`, the builder doesn't know the current position so it doesn't
+    /// connect it to the arc.
+    #[allow(clippy::too_many_arguments)]
+    fn arc(
+        &mut self,
+        center: Point,
+        radii: Vector,
+        start_angle: Angle,
+        sweep_angle: Angle,
+        x_rotation: Angle,
+        winding: Winding,
+        attributes: Attributes,
+    ) {
+        let arc = oriented_arc(center, radii, start_angle, sweep_angle, x_rotation, winding);
+        arc.for_each_cubic_bezier(&mut |curve| {
+            self.cubic_bezier_to(curve.ctrl1, curve.ctrl2, curve.to, attributes);
+        });
+    }
+
     /// Hints at the builder that a certain number of endpoints and control
     /// points will be added.
     ///
@@ -503,6 +660,18 @@ pub trait PathBuilder {
     /// memory as an optimization.
     fn reserve(&mut self, _endpoints: usize, _ctrl_points: usize) {}
 
+    /// Returns the last control point of the current sub-path's last segment
+    /// if it is a quadratic or cubic bézier curve.
+    ///
+    /// The control point of a smooth continuation of the curve is the reflection
+    /// of this point around the current position, as with SVG's `S` and `T`
+    /// commands.
+    ///
+    /// Builders that don't keep track of this information return `None`.
+    fn last_ctrl(&self) -> Option<Point> {
+        None
+    }
+
     /// Applies the provided path event.
     ///
     /// By default this calls one of `begin`, `end`, `line`, `quadratic_bezier_segment`,
@@ -601,6 +770,8 @@ pub trait PathBuilder {
 
     /// Adds a sub-path containing an ellipse.
     ///
+    /// See `add_circle` about the winding of sub-paths used as holes.
+    ///
     /// There must be no sub-path in progress when this method is called.
     /// No sub-path is in progress after the method is called.
     fn add_ellipse(
@@ -633,6 +804,10 @@ pub trait PathBuilder {
 
     /// Adds a sub-path containing a circle.
     ///
+    /// To cut a hole in a filled shape with the `NonZero` fill rule, the sub-path of the
+    /// hole must have the opposite winding of the sub-path that contains it. With the
+    /// `EvenOdd` fill rule the winding doesn't matter. See also `add_annulus`.
+    ///
     /// There must be no sub-path in progress when this method is called.
     /// No sub-path is in progress after the method is called.
     fn add_circle(&mut self, center: Point, radius: f32, winding: Winding, attributes: Attributes)
@@ -642,6 +817,100 @@ pub trait PathBuilder {
         add_circle(self, center, radius, winding, attributes);
     }
 
+    /// Adds two sub-paths containing the outer and inner circles of an annulus (a ring).
+    ///
+    /// The outer circle has the provided winding and the inner circle has the opposite
+    /// winding, so that the inner circle is a hole with both the `EvenOdd` and `NonZero`
+    /// fill rules. Adding two circles with the same winding instead would fill the inner
+    /// circle with the `NonZero` fill rule.
+    ///
+    /// There must be no sub-path in progress when this method is called.
+    /// No sub-path is in progress after the method is called.
+    fn add_annulus(
+        &mut self,
+        center: Point,
+        inner_radius: f32,
+        outer_radius: f32,
+        winding: Winding,
+        attributes: Attributes,
+    ) where
+        Self: Sized,
+    {
+        let inner_winding = match winding {
+            Winding::Positive => Winding::Negative,
+            Winding::Negative => Winding::Positive,
+        };
+
+        self.add_circle(center, outer_radius, winding, attributes);
+        self.add_circle(center, inner_radius, inner_winding, attributes);
+    }
+
+    /// Adds a sub-path containing a circle sector (a pie slice).
+    ///
+    /// The sector is delimited by the arc starting at `start_angle` and sweeping
+    /// `sweep_angle` and by the two radial edges connecting the arc to the center.
+    /// The sweep angle is clamped to a full circle. The winding defines the orientation
+    /// of the sub-path, regardless of the sign of the sweep angle.
+    ///
+    /// There must be no sub-path in progress when this method is called.
+    /// No sub-path is in progress after the method is called.
+    fn add_circle_sector(
+        &mut self,
+        center: Point,
+        radius: f32,
+        start_angle: Angle,
+        sweep_angle: Angle,
+        winding: Winding,
+        attributes: Attributes,
+    ) {
+        let arc = sector_arc(center, radius, start_angle, sweep_angle, winding);
+
+        self.begin(center, attributes);
+        self.line_to(arc.from(), attributes);
+        arc.for_each_cubic_bezier(&mut |curve| {
+            self.cubic_bezier_to(curve.ctrl1, curve.ctrl2, curve.to, attributes);
+        });
+        self.end(true);
+    }
+
+    /// Adds a sub-path containing a sector of an annulus (a ring segment).
+    ///
+    /// The sector is delimited by the arcs of the inner and outer circles starting at
+    /// `start_angle` and sweeping `sweep_angle`, and by the two radial edges connecting
+    /// them. The sweep angle is clamped to a full circle. The winding defines the orientation
+    /// of the sub-path, regardless of the sign of the sweep angle.
+    ///
+    /// There must be no sub-path in progress when this method is called.
+    /// No sub-path is in progress after the method is called.
+    #[allow(clippy::too_many_arguments)]
+    fn add_annulus_sector(
+        &mut self,
+        center: Point,
+        inner_radius: f32,
+        outer_radius: f32,
+        start_angle: Angle,
+        sweep_angle: Angle,
+        winding: Winding,
+        attributes: Attributes,
+    ) {
+        let outer = sector_arc(center, outer_radius, start_angle, sweep_angle, winding);
+        let inner = Arc {
+            radii: vector(inner_radius.abs(), inner_radius.abs()),
+            ..outer
+        }
+        .flip();
+
+        self.begin(outer.from(), attributes);
+        outer.for_each_cubic_bezier(&mut |curve| {
+            self.cubic_bezier_to(curve.ctrl1, curve.ctrl2, curve.to, attributes);
+        });
+        self.line_to(inner.from(), attributes);
+        inner.for_each_cubic_bezier(&mut |curve| {
+            self.cubic_bezier_to(curve.ctrl1, curve.ctrl2, curve.to, attributes);
+        });
+        self.end(true);
+    }
+
     /// Adds a sub-path containing a rectangle.
     ///
     /// There must be no sub-path in progress when this method is called.
@@ -708,6 +977,27 @@ pub trait PathBuilder {
         Transformed::new(self, transform)
     }
 
+    /// Returns a builder that drops segments that don't move away from the current
+    /// position by more than `epsilon`.
+    ///
+    /// See [`DedupBuilder`](struct.DedupBuilder.html).
+    fn deduplicated(self, epsilon: f32) -> DedupBuilder<Self>
+    where
+        Self: Sized,
+    {
+        DedupBuilder::new(self, epsilon)
+    }
+
+    /// Returns a builder that checks the sequence of commands and reports malformed ones.
+    ///
+    /// See [`ValidatingPathBuilder`](struct.ValidatingPathBuilder.html).
+    fn validated(self) -> ValidatingPathBuilder<Self>
+    where
+        Self: Sized,
+    {
+        ValidatingPathBuilder::new(self)
+    }
+
     /// Returns a builder that support SVG commands.
     ///
     /// This must be called before starting to add any sub-path.
@@ -1139,107 +1429,612 @@ where
     }
 }
 
-/// Implements an SVG-like building interface on top of a PathBuilder.
-pub struct WithSvg<Builder: PathBuilder> {
-    builder: Builder,
-
-    first_position: Point,
-    current_position: Point,
-    last_ctrl: Point,
-    last_cmd: Verb,
-    need_moveto: bool,
+/// A path builder that writes SVG path data into a `core::fmt::Write` implementation
+/// instead of storing a path.
+///
+/// `begin` writes a `M` command, `line_to`, `quadratic_bezier_to` and `cubic_bezier_to`
+/// respectively write `L`, `Q` and `C` commands and closing a sub-path writes `Z`.
+/// Custom attributes are ignored.
+///
+/// Formatting errors can't be reported by the `PathBuilder` methods. The first error
+/// is kept and returned by `build`, after which nothing else is written.
+///
+/// # Example
+///
+/// ```
+/// use lyon_path::{geom::point, builder::{SvgPathWriter, PathBuilder, Build}, NO_ATTRIBUTES};
+///
+/// let mut writer = SvgPathWriter::new(String::new());
+/// writer.begin(point(0.0, 0.0), NO_ATTRIBUTES);
+/// writer.line_to(point(10.0, 0.0), NO_ATTRIBUTES);
+/// writer.cubic_bezier_to(point(10.0, 5.0), point(5.0, 10.0), point(0.0, 10.0), NO_ATTRIBUTES);
+/// writer.close();
+///
+/// assert_eq!(writer.build().unwrap(), "M 0 0 L 10 0 C 10 5 5 10 0 10 Z");
+/// ```
+pub struct SvgPathWriter<W> {
+    writer: W,
+    precision: Option<usize>,
     is_empty: bool,
-    attribute_buffer: Vec<f32>,
+    result: core::fmt::Result,
 }
 
-impl<Builder: PathBuilder> WithSvg<Builder> {
-    pub fn new(builder: Builder) -> Self {
-        let attribute_buffer = vec![0.0; builder.num_attributes()];
-        WithSvg {
-            builder,
-            first_position: point(0.0, 0.0),
-            current_position: point(0.0, 0.0),
-            last_ctrl: point(0.0, 0.0),
-            need_moveto: true,
+impl<W: core::fmt::Write> SvgPathWriter<W> {
+    /// Creates a path writer that formats coordinates with as many digits as necessary
+    /// to represent them exactly.
+    pub fn new(writer: W) -> Self {
+        SvgPathWriter {
+            writer,
+            precision: None,
             is_empty: true,
-            last_cmd: Verb::End,
-            attribute_buffer,
+            result: Ok(()),
         }
     }
 
-    pub fn build(mut self) -> Builder::PathType
-    where
-        Builder: Build,
-    {
-        self.end_if_needed();
-        self.builder.build()
-    }
-
-    pub fn flattened(self, tolerance: f32) -> WithSvg<Flattened<Builder>> {
-        WithSvg::new(Flattened::new(self.builder, tolerance))
+    /// Creates a path writer that formats coordinates with a fixed number of decimal
+    /// digits.
+    pub fn with_precision(writer: W, precision: usize) -> Self {
+        SvgPathWriter {
+            precision: Some(precision),
+            ..SvgPathWriter::new(writer)
+        }
     }
 
-    pub fn transformed<Transform>(
-        self,
-        transform: Transform,
-    ) -> WithSvg<Transformed<Builder, Transform>>
-    where
-        Transform: Transformation<f32>,
-    {
-        WithSvg::new(Transformed::new(self.builder, transform))
+    /// Sets the number of decimal digits of the coordinates, or `None` to use as many
+    /// digits as necessary.
+    pub fn set_precision(&mut self, precision: Option<usize>) {
+        self.precision = precision;
     }
 
-    pub fn move_to(&mut self, to: Point) -> EndpointId {
-        self.end_if_needed();
-
-        let id = self.builder.begin(to, &self.attribute_buffer);
-
-        self.is_empty = false;
-        self.need_moveto = false;
-        self.first_position = to;
-        self.current_position = to;
-        self.last_cmd = Verb::Begin;
+    fn write_command(&mut self, command: char, points: &[Point]) {
+        if self.result.is_err() {
+            return;
+        }
 
-        id
+        self.result = self.write_command_impl(command, points);
     }
 
-    pub fn line_to(&mut self, to: Point) -> EndpointId {
-        if let Some(id) = self.begin_if_needed(&to) {
-            return id;
+    fn write_command_impl(&mut self, command: char, points: &[Point]) -> core::fmt::Result {
+        if !self.is_empty {
+            self.writer.write_char(' ')?;
         }
+        self.is_empty = false;
 
-        self.current_position = to;
-        self.last_cmd = Verb::LineTo;
+        self.writer.write_char(command)?;
+        for p in points {
+            for val in [p.x, p.y] {
+                match self.precision {
+                    Some(precision) => write!(self.writer, " {:.*}", precision, val)?,
+                    None => write!(self.writer, " {}", val)?,
+                }
+            }
+        }
 
-        self.builder.line_to(to, &self.attribute_buffer)
+        Ok(())
     }
+}
 
-    pub fn close(&mut self) {
-        if self.need_moveto {
-            return;
-        }
+impl<W: core::fmt::Write> PathBuilder for SvgPathWriter<W> {
+    fn num_attributes(&self) -> usize {
+        0
+    }
 
-        // Relative path ops tend to accumulate small floating point error,
-        // which results in the last segment ending almost but not quite at the
-        // start of the sub-path, causing a new edge to be inserted which often
-        // intersects with the first or last edge. This can affect algorithms that
-        // Don't handle self-intersecting paths.
-        // Deal with this by snapping the last point if it is very close to the
-        // start of the sub path.
-        //
-        // TODO
-        // if let Some(p) = self.builder.points.last_mut() {
-        //     let d = (*p - self.first_position).abs();
-        //     if d.x + d.y < 0.0001 {
-        //         *p = self.first_position;
-        //     }
-        // }
+    fn begin(&mut self, at: Point, _attributes: Attributes) -> EndpointId {
+        self.write_command('M', &[at]);
+        EndpointId::INVALID
+    }
 
-        self.current_position = self.first_position;
-        self.need_moveto = true;
-        self.last_cmd = Verb::Close;
+    fn end(&mut self, close: bool) {
+        if close {
+            self.write_command('Z', &[]);
+        }
+    }
 
-        self.builder.close();
+    fn line_to(&mut self, to: Point, _attributes: Attributes) -> EndpointId {
+        self.write_command('L', &[to]);
+        EndpointId::INVALID
+    }
+
+    fn quadratic_bezier_to(
+        &mut self,
+        ctrl: Point,
+        to: Point,
+        _attributes: Attributes,
+    ) -> EndpointId {
+        self.write_command('Q', &[ctrl, to]);
+        EndpointId::INVALID
+    }
+
+    fn cubic_bezier_to(
+        &mut self,
+        ctrl1: Point,
+        ctrl2: Point,
+        to: Point,
+        _attributes: Attributes,
+    ) -> EndpointId {
+        self.write_command('C', &[ctrl1, ctrl2, to]);
+        EndpointId::INVALID
+    }
+}
+
+impl<W: core::fmt::Write> Build for SvgPathWriter<W> {
+    type PathType = Result<W, core::fmt::Error>;
+
+    fn build(self) -> Result<W, core::fmt::Error> {
+        self.result?;
+
+        Ok(self.writer)
+    }
+}
+
+/// A problem detected by [`ValidatingPathBuilder`](struct.ValidatingPathBuilder.html).
+///
+/// The `command` members are the indices of the offending calls, counting all calls to
+/// `begin`, `end`, `line_to`, `quadratic_bezier_to` and `cubic_bezier_to` from zero.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ValidationError {
+    /// A segment was added or a sub-path was ended while no sub-path was in progress.
+    ///
+    /// The command is not forwarded to the wrapped builder.
+    MissingBegin { command: usize },
+    /// A sub-path was started while another one was in progress, or the path was
+    /// built before the last sub-path was ended.
+    ///
+    /// The sub-path in progress is ended (without closing it) before forwarding the
+    /// command to the wrapped builder.
+    MissingEnd { command: usize },
+    /// A segment was added that doesn't move away from the current position.
+    DuplicatePoint { command: usize, position: Point },
+    /// A sub-path was started at the position where the previous unclosed sub-path
+    /// ended.
+    ///
+    /// This typically happens when building a polyline one segment at a time, with
+    /// a sub-path for each segment. The segments are stroked independently, without
+    /// joins between them.
+    SplitSubPath { command: usize, position: Point },
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ValidationError::MissingBegin { command } => {
+                std::write!(f, "Command {command}: no sub-path in progress")
+            }
+            ValidationError::MissingEnd { command } => {
+                std::write!(f, "Command {command}: the previous sub-path was not ended")
+            }
+            ValidationError::DuplicatePoint { command, position } => {
+                std::write!(f, "Command {command}: duplicate point {position:?}")
+            }
+            ValidationError::SplitSubPath { command, position } => std::write!(
+                f,
+                "Command {command}: sub-path begins at {position:?} where the previous one ended"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// A path builder that checks the sequence of commands it receives before forwarding
+/// them to another builder.
+///
+/// `PathBuilder` implementations assume that the commands they receive are valid, and
+/// questionable sequences tend to silently produce surprising results. This adapter
+/// records the problems it detects (see [`ValidationError`](enum.ValidationError.html))
+/// and keeps the sequence of commands forwarded to the wrapped builder valid.
+///
+/// # Example
+///
+/// ```
+/// use lyon_path::{Path, geom::point, builder::{Build, ValidationError}};
+///
+/// let mut builder = Path::builder().validated();
+/// builder.line_to(point(1.0, 0.0));
+/// builder.begin(point(0.0, 0.0));
+/// builder.line_to(point(1.0, 0.0));
+///
+/// assert_eq!(
+///     builder.build().unwrap_err(),
+///     vec![
+///         ValidationError::MissingBegin { command: 0 },
+///         ValidationError::MissingEnd { command: 3 },
+///     ]
+/// );
+/// ```
+pub struct ValidatingPathBuilder<Builder> {
+    builder: Builder,
+    errors: Vec<ValidationError>,
+    num_commands: usize,
+    in_sub_path: bool,
+    current_position: Point,
+    num_segments: usize,
+    // The position where the previous sub-path ended, if it wasn't closed.
+    previous_open_end: Option<Point>,
+}
+
+impl<Builder: PathBuilder> ValidatingPathBuilder<Builder> {
+    pub fn new(builder: Builder) -> Self {
+        ValidatingPathBuilder {
+            builder,
+            errors: Vec::new(),
+            num_commands: 0,
+            in_sub_path: false,
+            current_position: point(0.0, 0.0),
+            num_segments: 0,
+            previous_open_end: None,
+        }
+    }
+
+    /// Returns the problems detected so far.
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    // Returns the index of the command, or None if no sub-path is in progress.
+    fn segment_command(&mut self, points: &[Point]) -> Option<usize> {
+        let command = self.next_command();
+        if !self.in_sub_path {
+            self.errors.push(ValidationError::MissingBegin { command });
+            return None;
+        }
+
+        if points.iter().all(|p| *p == self.current_position) {
+            self.errors.push(ValidationError::DuplicatePoint {
+                command,
+                position: self.current_position,
+            });
+        }
+
+        self.current_position = *points.last().unwrap();
+        self.num_segments += 1;
+
+        Some(command)
+    }
+
+    fn next_command(&mut self) -> usize {
+        let command = self.num_commands;
+        self.num_commands += 1;
+
+        command
+    }
+}
+
+impl<Builder: PathBuilder> PathBuilder for ValidatingPathBuilder<Builder> {
+    fn num_attributes(&self) -> usize {
+        self.builder.num_attributes()
+    }
+
+    fn begin(&mut self, at: Point, attributes: Attributes) -> EndpointId {
+        let command = self.next_command();
+        if self.in_sub_path {
+            self.errors.push(ValidationError::MissingEnd { command });
+            self.builder.end(false);
+        } else if self.previous_open_end == Some(at) {
+            self.errors.push(ValidationError::SplitSubPath {
+                command,
+                position: at,
+            });
+        }
+
+        self.in_sub_path = true;
+        self.current_position = at;
+        self.num_segments = 0;
+        self.previous_open_end = None;
+
+        self.builder.begin(at, attributes)
+    }
+
+    fn end(&mut self, close: bool) {
+        let command = self.next_command();
+        if !self.in_sub_path {
+            self.errors.push(ValidationError::MissingBegin { command });
+            return;
+        }
+
+        self.in_sub_path = false;
+        if !close && self.num_segments > 0 {
+            self.previous_open_end = Some(self.current_position);
+        }
+
+        self.builder.end(close)
+    }
+
+    fn line_to(&mut self, to: Point, attributes: Attributes) -> EndpointId {
+        if self.segment_command(&[to]).is_none() {
+            return EndpointId::INVALID;
+        }
+
+        self.builder.line_to(to, attributes)
+    }
+
+    fn quadratic_bezier_to(
+        &mut self,
+        ctrl: Point,
+        to: Point,
+        attributes: Attributes,
+    ) -> EndpointId {
+        if self.segment_command(&[ctrl, to]).is_none() {
+            return EndpointId::INVALID;
+        }
+
+        self.builder.quadratic_bezier_to(ctrl, to, attributes)
+    }
+
+    fn cubic_bezier_to(
+        &mut self,
+        ctrl1: Point,
+        ctrl2: Point,
+        to: Point,
+        attributes: Attributes,
+    ) -> EndpointId {
+        if self.segment_command(&[ctrl1, ctrl2, to]).is_none() {
+            return EndpointId::INVALID;
+        }
+
+        self.builder.cubic_bezier_to(ctrl1, ctrl2, to, attributes)
+    }
+
+    fn reserve(&mut self, endpoints: usize, ctrl_points: usize) {
+        self.builder.reserve(endpoints, ctrl_points);
+    }
+
+    fn last_ctrl(&self) -> Option<Point> {
+        self.builder.last_ctrl()
+    }
+}
+
+impl<Builder: PathBuilder + Build> Build for ValidatingPathBuilder<Builder> {
+    type PathType = Result<Builder::PathType, Vec<ValidationError>>;
+
+    /// Builds the path if no problem was detected, otherwise returns the list of
+    /// problems.
+    fn build(mut self) -> Self::PathType {
+        if self.in_sub_path {
+            let command = self.num_commands;
+            self.errors.push(ValidationError::MissingEnd { command });
+            self.builder.end(false);
+        }
+
+        let path = self.builder.build();
+        if !self.errors.is_empty() {
+            return Err(self.errors);
+        }
+
+        Ok(path)
+    }
+}
+
+/// A path builder that drops segments that don't move away from the current position.
+///
+/// A `line_to` is dropped if its endpoint is within `epsilon` of the current position,
+/// and a curve is dropped if all of its points are. Sub-paths are preserved, including
+/// those that only contain a single point, as well as the custom attributes of the
+/// endpoints that are kept.
+///
+/// When a segment is dropped, its endpoint id is the one of the current position.
+///
+/// # Example
+///
+/// ```
+/// use lyon_path::{Path, geom::point};
+///
+/// let mut builder = Path::builder().deduplicated(0.001);
+/// builder.begin(point(0.0, 0.0));
+/// builder.line_to(point(0.0, 0.0));
+/// builder.line_to(point(1.0, 0.0));
+/// builder.line_to(point(1.0, 0.0005));
+/// builder.end(false);
+///
+/// // Begin, line, end.
+/// assert_eq!(builder.build().iter().count(), 3);
+/// ```
+pub struct DedupBuilder<Builder> {
+    builder: Builder,
+    square_epsilon: f32,
+    current_position: Point,
+    current_id: EndpointId,
+}
+
+impl<Builder: PathBuilder> DedupBuilder<Builder> {
+    pub fn new(builder: Builder, epsilon: f32) -> Self {
+        DedupBuilder {
+            builder,
+            square_epsilon: epsilon * epsilon,
+            current_position: point(0.0, 0.0),
+            current_id: EndpointId::INVALID,
+        }
+    }
+
+    pub fn set_epsilon(&mut self, epsilon: f32) {
+        self.square_epsilon = epsilon * epsilon;
+    }
+
+    fn is_duplicate(&self, p: Point) -> bool {
+        (p - self.current_position).square_length() <= self.square_epsilon
+    }
+}
+
+impl<Builder: Build> Build for DedupBuilder<Builder> {
+    type PathType = Builder::PathType;
+
+    fn build(self) -> Builder::PathType {
+        self.builder.build()
+    }
+}
+
+impl<Builder: PathBuilder> PathBuilder for DedupBuilder<Builder> {
+    fn num_attributes(&self) -> usize {
+        self.builder.num_attributes()
+    }
+
+    fn begin(&mut self, at: Point, attributes: Attributes) -> EndpointId {
+        self.current_position = at;
+        self.current_id = self.builder.begin(at, attributes);
+
+        self.current_id
+    }
+
+    fn end(&mut self, close: bool) {
+        self.builder.end(close)
+    }
+
+    fn line_to(&mut self, to: Point, attributes: Attributes) -> EndpointId {
+        if self.is_duplicate(to) {
+            return self.current_id;
+        }
+
+        self.current_position = to;
+        self.current_id = self.builder.line_to(to, attributes);
+
+        self.current_id
+    }
+
+    fn quadratic_bezier_to(
+        &mut self,
+        ctrl: Point,
+        to: Point,
+        attributes: Attributes,
+    ) -> EndpointId {
+        if self.is_duplicate(ctrl) && self.is_duplicate(to) {
+            return self.current_id;
+        }
+
+        self.current_position = to;
+        self.current_id = self.builder.quadratic_bezier_to(ctrl, to, attributes);
+
+        self.current_id
+    }
+
+    fn cubic_bezier_to(
+        &mut self,
+        ctrl1: Point,
+        ctrl2: Point,
+        to: Point,
+        attributes: Attributes,
+    ) -> EndpointId {
+        if self.is_duplicate(ctrl1) && self.is_duplicate(ctrl2) && self.is_duplicate(to) {
+            return self.current_id;
+        }
+
+        self.current_position = to;
+        self.current_id = self.builder.cubic_bezier_to(ctrl1, ctrl2, to, attributes);
+
+        self.current_id
+    }
+
+    fn reserve(&mut self, endpoints: usize, ctrl_points: usize) {
+        self.builder.reserve(endpoints, ctrl_points);
+    }
+
+    fn last_ctrl(&self) -> Option<Point> {
+        self.builder.last_ctrl()
+    }
+}
+
+/// Implements an SVG-like building interface on top of a PathBuilder.
+pub struct WithSvg<Builder: PathBuilder> {
+    builder: Builder,
+
+    first_position: Point,
+    current_position: Point,
+    last_ctrl: Point,
+    last_cmd: Verb,
+    need_moveto: bool,
+    is_empty: bool,
+    attribute_buffer: Vec<f32>,
+}
+
+impl<Builder: PathBuilder> WithSvg<Builder> {
+    pub fn new(builder: Builder) -> Self {
+        let attribute_buffer = vec![0.0; builder.num_attributes()];
+        WithSvg {
+            builder,
+            first_position: point(0.0, 0.0),
+            current_position: point(0.0, 0.0),
+            last_ctrl: point(0.0, 0.0),
+            need_moveto: true,
+            is_empty: true,
+            last_cmd: Verb::End,
+            attribute_buffer,
+        }
+    }
+
+    pub fn build(mut self) -> Builder::PathType
+    where
+        Builder: Build,
+    {
+        self.end_if_needed();
+        self.builder.build()
+    }
+
+    pub fn flattened(self, tolerance: f32) -> WithSvg<Flattened<Builder>> {
+        WithSvg::new(Flattened::new(self.builder, tolerance))
+    }
+
+    pub fn transformed<Transform>(
+        self,
+        transform: Transform,
+    ) -> WithSvg<Transformed<Builder, Transform>>
+    where
+        Transform: Transformation<f32>,
+    {
+        WithSvg::new(Transformed::new(self.builder, transform))
+    }
+
+    pub fn move_to(&mut self, to: Point) -> EndpointId {
+        self.end_if_needed();
+
+        let id = self.builder.begin(to, &self.attribute_buffer);
+
+        self.is_empty = false;
+        self.need_moveto = false;
+        self.first_position = to;
+        self.current_position = to;
+        self.last_cmd = Verb::Begin;
+
+        id
+    }
+
+    pub fn line_to(&mut self, to: Point) -> EndpointId {
+        if let Some(id) = self.begin_if_needed(&to) {
+            return id;
+        }
+
+        self.current_position = to;
+        self.last_cmd = Verb::LineTo;
+
+        self.builder.line_to(to, &self.attribute_buffer)
+    }
+
+    pub fn close(&mut self) {
+        if self.need_moveto {
+            return;
+        }
+
+        // Relative path ops tend to accumulate small floating point error,
+        // which results in the last segment ending almost but not quite at the
+        // start of the sub-path, causing a new edge to be inserted which often
+        // intersects with the first or last edge. This can affect algorithms that
+        // Don't handle self-intersecting paths.
+        // Deal with this by snapping the last point if it is very close to the
+        // start of the sub path.
+        //
+        // TODO
+        // if let Some(p) = self.builder.points.last_mut() {
+        //     let d = (*p - self.first_position).abs();
+        //     if d.x + d.y < 0.0001 {
+        //         *p = self.first_position;
+        //     }
+        // }
+
+        self.current_position = self.first_position;
+        self.need_moveto = true;
+        self.last_cmd = Verb::Close;
+
+        self.builder.close();
     }
 
     pub fn quadratic_bezier_to(&mut self, ctrl: Point, to: Point) -> EndpointId {
@@ -1344,6 +2139,15 @@ impl<Builder: PathBuilder> WithSvg<Builder> {
         self.current_position
     }
 
+    /// Returns the last control point if the previous command was a quadratic
+    /// or cubic bézier curve.
+    pub fn last_ctrl(&self) -> Option<Point> {
+        match self.last_cmd {
+            Verb::QuadraticTo | Verb::CubicTo => Some(self.last_ctrl),
+            _ => None,
+        }
+    }
+
     pub fn reserve(&mut self, endpoints: usize, ctrl_points: usize) {
         self.builder.reserve(endpoints, ctrl_points);
     }
@@ -1504,6 +2308,55 @@ impl<Builder: PathBuilder> SvgPathBuilder for WithSvg<Builder> {
     }
 }
 
+// Returns the arc of a circle sector, oriented according to the winding.
+fn sector_arc(
+    center: Point,
+    radius: f32,
+    start_angle: Angle,
+    sweep_angle: Angle,
+    winding: Winding,
+) -> Arc<f32> {
+    let radius = radius.abs();
+    oriented_arc(
+        center,
+        vector(radius, radius),
+        start_angle,
+        sweep_angle,
+        Angle::zero(),
+        winding,
+    )
+}
+
+// Returns an elliptic arc covering the same angles as `start_angle` and `sweep_angle`,
+// oriented according to the winding.
+fn oriented_arc(
+    center: Point,
+    radii: Vector,
+    start_angle: Angle,
+    sweep_angle: Angle,
+    x_rotation: Angle,
+    winding: Winding,
+) -> Arc<f32> {
+    let mut start = start_angle.radians;
+    let mut sweep = sweep_angle.radians.clamp(-2.0 * PI, 2.0 * PI);
+    if sweep < 0.0 {
+        start += sweep;
+        sweep = -sweep;
+    }
+    if winding == Winding::Negative {
+        start += sweep;
+        sweep = -sweep;
+    }
+
+    Arc {
+        center,
+        radii,
+        start_angle: Angle::radians(start),
+        sweep_angle: Angle::radians(sweep),
+        x_rotation,
+    }
+}
+
 /// Tessellate the stroke for an axis-aligned rounded rectangle.
 fn add_circle<Builder: PathBuilder>(
     builder: &mut Builder,
@@ -1803,3 +2656,383 @@ fn straight_line_arc() {
         point(100.0, 0.0),
     );
 }
+
+#[test]
+fn svg_path_writer() {
+    use alloc::string::String;
+
+    let mut writer = SvgPathWriter::new(String::new());
+    writer.begin(point(0.0, 0.0), NO_ATTRIBUTES);
+    writer.line_to(point(1.5, -2.0), NO_ATTRIBUTES);
+    writer.quadratic_bezier_to(point(2.0, 0.0), point(2.0, 1.0), NO_ATTRIBUTES);
+    writer.end(false);
+    writer.begin(point(10.0, 0.0), NO_ATTRIBUTES);
+    writer.cubic_bezier_to(
+        point(12.0, 2.0),
+        point(11.0, 2.0),
+        point(5.0, 0.0),
+        NO_ATTRIBUTES,
+    );
+    writer.close();
+
+    assert_eq!(
+        writer.build().unwrap(),
+        "M 0 0 L 1.5 -2 Q 2 0 2 1 M 10 0 C 12 2 11 2 5 0 Z"
+    );
+
+    let mut writer = SvgPathWriter::with_precision(String::new(), 2).with_svg();
+    writer.move_to(point(0.0, 0.0));
+    writer.relative_line_to(vector(1.0 / 3.0, 0.5));
+    writer.close();
+
+    assert_eq!(writer.build().unwrap(), "M 0.00 0.00 L 0.33 0.50 Z");
+
+    // Writing through a trait object.
+    let mut string = String::new();
+    {
+        let output: &mut dyn core::fmt::Write = &mut string;
+        let mut writer = SvgPathWriter::new(output);
+        writer.add_rectangle(
+            &Box2D {
+                min: point(0.0, 0.0),
+                max: point(1.0, 1.0),
+            },
+            Winding::Positive,
+            NO_ATTRIBUTES,
+        );
+        writer.build().unwrap();
+    }
+    assert_eq!(string, "M 0 0 L 1 0 L 1 1 L 0 1 Z");
+}
+
+#[test]
+fn svg_path_writer_error() {
+    struct Failing(u32);
+    impl core::fmt::Write for Failing {
+        fn write_str(&mut self, _: &str) -> core::fmt::Result {
+            self.0 += 1;
+            Err(core::fmt::Error)
+        }
+    }
+
+    let mut writer = SvgPathWriter::new(Failing(0));
+    writer.begin(point(0.0, 0.0), NO_ATTRIBUTES);
+    writer.line_to(point(1.0, 0.0), NO_ATTRIBUTES);
+    writer.end(true);
+
+    assert_eq!(writer.writer.0, 1);
+    assert!(writer.build().is_err());
+}
+
+#[test]
+fn circle_sector() {
+    use crate::Path;
+
+    let center = point(1.0, 2.0);
+    let mut builder = Path::builder();
+    builder.add_circle_sector(
+        center,
+        2.0,
+        Angle::radians(0.0),
+        Angle::radians(PI * 0.5),
+        Winding::Positive,
+    );
+    // A negative sweep describes the same sector.
+    builder.add_circle_sector(
+        center,
+        2.0,
+        Angle::radians(PI * 0.5),
+        Angle::radians(-PI * 0.5),
+        Winding::Positive,
+    );
+    let path = builder.build();
+
+    let mut iter = path.iter();
+    for _ in 0..2 {
+        assert_eq!(iter.next(), Some(PathEvent::Begin { at: center }));
+        assert_eq!(
+            iter.next(),
+            Some(PathEvent::Line {
+                from: center,
+                to: point(3.0, 2.0)
+            })
+        );
+        let mut last = point(3.0, 2.0);
+        loop {
+            match iter.next() {
+                Some(PathEvent::Cubic { to, .. }) => {
+                    assert!(((to - center).length() - 2.0).abs() < 1e-5);
+                    last = to;
+                }
+                Some(PathEvent::End { close, .. }) => {
+                    assert!(close);
+                    break;
+                }
+                evt => panic!("unexpected event {:?}", evt),
+            }
+        }
+        assert!((last - point(1.0, 4.0)).length() < 1e-5);
+    }
+    assert_eq!(iter.next(), None);
+
+    // Negative winding goes around the other way.
+    let mut builder = Path::builder();
+    builder.add_circle_sector(
+        center,
+        2.0,
+        Angle::radians(0.0),
+        Angle::radians(PI * 0.5),
+        Winding::Negative,
+    );
+    let path = builder.build();
+    let mut iter = path.iter();
+    iter.next();
+    match iter.next() {
+        Some(PathEvent::Line { to, .. }) => assert!((to - point(1.0, 4.0)).length() < 1e-5),
+        evt => panic!("unexpected event {:?}", evt),
+    }
+}
+
+#[test]
+fn annulus_sector() {
+    use crate::Path;
+
+    let center = point(0.0, 0.0);
+    let mut builder = Path::builder();
+    builder.add_annulus_sector(
+        center,
+        1.0,
+        2.0,
+        Angle::radians(0.0),
+        Angle::radians(PI),
+        Winding::Positive,
+    );
+    let path = builder.build();
+
+    let mut iter = path.iter();
+    assert_eq!(
+        iter.next(),
+        Some(PathEvent::Begin {
+            at: point(2.0, 0.0)
+        })
+    );
+    let mut radial_edges = 0;
+    for evt in iter {
+        match evt {
+            PathEvent::Line { from, to } => {
+                assert!((from - point(-2.0, 0.0)).length() < 1e-5);
+                assert!((to - point(-1.0, 0.0)).length() < 1e-5);
+                radial_edges += 1;
+            }
+            PathEvent::Cubic { from, to, .. } => {
+                let r = from.to_vector().length();
+                assert!((to.to_vector().length() - r).abs() < 1e-5);
+            }
+            PathEvent::End { last, first, close } => {
+                assert!(close);
+                assert!((last - point(1.0, 0.0)).length() < 1e-5);
+                assert_eq!(first, point(2.0, 0.0));
+            }
+            evt => panic!("unexpected event {:?}", evt),
+        }
+    }
+    assert_eq!(radial_edges, 1);
+}
+
+#[test]
+fn base_builder_arc() {
+    use crate::Path;
+
+    let center = point(1.0, 2.0);
+    let radii = vector(4.0, 2.0);
+    let x_rotation = Angle::radians(PI * 0.5);
+    // With the rotation, the angles 0 and PI/2 correspond to the points above and left
+    // of the center.
+    let top = point(1.0, 6.0);
+    let left = point(-1.0, 2.0);
+
+    for &(winding, from, to) in &[
+        (Winding::Positive, top, left),
+        (Winding::Negative, left, top),
+    ] {
+        let mut builder = Path::builder();
+        builder.begin(from);
+        builder.arc(
+            center,
+            radii,
+            Angle::radians(0.0),
+            Angle::radians(PI * 0.5),
+            x_rotation,
+            winding,
+        );
+        builder.end(false);
+        let path = builder.build();
+
+        let mut last = from;
+        for evt in path.iter() {
+            match evt {
+                PathEvent::Begin { at } => {
+                    assert!((at - from).length() < 1e-5);
+                }
+                PathEvent::Cubic { from, to, .. } => {
+                    assert_eq!(from, last);
+                    // The endpoints are on the ellipse.
+                    let local = Rotation::new(-x_rotation).transform_vector(to - center);
+                    let d = (local.x / radii.x).powi(2) + (local.y / radii.y).powi(2);
+                    assert!((d - 1.0).abs() < 1e-5, "{:?}", to);
+                    last = to;
+                }
+                PathEvent::End { .. } => {}
+                evt => panic!("unexpected event {:?}", evt),
+            }
+        }
+        assert!((last - to).length() < 1e-5, "{:?}", last);
+    }
+
+    // The arc can be added as line segments with a flattening builder.
+    let mut builder = Path::builder().flattened(0.01);
+    builder.begin(top);
+    builder.arc(
+        center,
+        radii,
+        Angle::radians(0.0),
+        Angle::radians(PI * 0.5),
+        x_rotation,
+        Winding::Positive,
+    );
+    builder.end(false);
+    let path = builder.build();
+    assert!(path.iter().count() > 3);
+    for evt in path.iter() {
+        assert!(matches!(
+            evt,
+            PathEvent::Begin { .. } | PathEvent::Line { .. } | PathEvent::End { .. }
+        ));
+    }
+}
+
+#[test]
+fn validating_builder() {
+    use crate::Path;
+
+    // A well formed path.
+    let mut builder = Path::builder().validated();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.0));
+    builder.quadratic_bezier_to(point(2.0, 0.0), point(2.0, 1.0));
+    builder.close();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.0));
+    builder.end(false);
+    builder.add_point(point(5.0, 0.0));
+    assert!(builder.inner().errors().is_empty());
+    assert_eq!(builder.build().unwrap().iter().count(), 9);
+
+    // Missing begin and end commands.
+    let mut builder = Path::builder().validated();
+    builder.line_to(point(1.0, 0.0));
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.0));
+    builder.begin(point(5.0, 0.0));
+    builder.cubic_bezier_to(point(6.0, 0.0), point(6.0, 1.0), point(5.0, 1.0));
+    builder.end(true);
+    builder.end(true);
+    builder.begin(point(0.0, 0.0));
+    assert_eq!(
+        builder.build().unwrap_err(),
+        vec![
+            ValidationError::MissingBegin { command: 0 },
+            ValidationError::MissingEnd { command: 3 },
+            ValidationError::MissingBegin { command: 6 },
+            ValidationError::MissingEnd { command: 8 },
+        ]
+    );
+
+    // Duplicate points.
+    let mut builder = Path::builder().validated();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(0.0, 0.0));
+    builder.quadratic_bezier_to(point(1.0, 0.0), point(0.0, 0.0));
+    builder.cubic_bezier_to(point(0.0, 0.0), point(0.0, 0.0), point(0.0, 0.0));
+    builder.end(false);
+    assert_eq!(
+        builder.build().unwrap_err(),
+        vec![
+            ValidationError::DuplicatePoint {
+                command: 1,
+                position: point(0.0, 0.0)
+            },
+            ValidationError::DuplicatePoint {
+                command: 3,
+                position: point(0.0, 0.0)
+            },
+        ]
+    );
+
+    // A polyline built with a sub-path per segment.
+    let mut builder = Path::builder().validated();
+    builder.add_line_segment(&LineSegment {
+        from: point(0.0, 0.0),
+        to: point(1.0, 0.0),
+    });
+    builder.add_line_segment(&LineSegment {
+        from: point(1.0, 0.0),
+        to: point(1.0, 1.0),
+    });
+    builder.add_line_segment(&LineSegment {
+        from: point(2.0, 0.0),
+        to: point(2.0, 1.0),
+    });
+    assert_eq!(
+        builder.build().unwrap_err(),
+        vec![ValidationError::SplitSubPath {
+            command: 3,
+            position: point(1.0, 0.0)
+        }]
+    );
+}
+
+#[test]
+fn dedup_builder() {
+    use crate::{Path, PathEvent};
+
+    let mut builder = Path::builder_with_attributes(1).deduplicated(0.01);
+    let a = builder.begin(point(0.0, 0.0), &[1.0]);
+    assert_eq!(builder.line_to(point(0.005, 0.0), &[2.0]), a);
+    let b = builder.line_to(point(1.0, 0.0), &[3.0]);
+    assert_eq!(builder.line_to(point(1.0, 0.0), &[4.0]), b);
+    assert_eq!(
+        builder.quadratic_bezier_to(point(1.0, 0.001), point(1.0, 0.0), &[5.0]),
+        b
+    );
+    assert_eq!(
+        builder.cubic_bezier_to(point(1.0, 0.0), point(1.0, 0.0), point(1.0, 0.0), &[6.0]),
+        b
+    );
+    // A loop that ends where it started is kept.
+    builder.cubic_bezier_to(point(2.0, 0.0), point(2.0, 1.0), point(1.0, 0.0), &[7.0]);
+    builder.quadratic_bezier_to(point(2.0, 2.0), point(2.0, 2.0), &[8.0]);
+    builder.close();
+    // Single point sub-path.
+    builder.begin(point(5.0, 5.0), &[9.0]);
+    builder.line_to(point(5.0, 5.0), &[10.0]);
+    builder.end(false);
+    let path = builder.build();
+
+    let attributes: Vec<f32> = path
+        .iter_with_attributes()
+        .filter_map(|event| match event {
+            Event::Begin { at } => Some(at.1[0]),
+            Event::Line { to, .. } | Event::Quadratic { to, .. } | Event::Cubic { to, .. } => {
+                Some(to.1[0])
+            }
+            Event::End { .. } => None,
+        })
+        .collect();
+    assert_eq!(attributes, vec![1.0, 3.0, 7.0, 8.0, 9.0]);
+
+    let events: Vec<PathEvent> = path.iter().collect();
+    assert_eq!(events.len(), 7);
+    assert!(matches!(events[4], PathEvent::End { close: true, .. }));
+    assert!(matches!(events[6], PathEvent::End { close: false, .. }));
+}
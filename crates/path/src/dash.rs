@@ -0,0 +1,381 @@
+// Dashing shared by `lyon_algorithms::dash` and the stroke tessellator.
+//
+// See `lyon_algorithms::dash::dash` for a description of the dash patterns.
+
+use crate::builder::PathBuilder;
+use crate::geom::{CubicBezierSegment, LineSegment, QuadraticBezierSegment};
+use crate::math::Point;
+use crate::{PathEvent, NO_ATTRIBUTES};
+
+use core::ops::Range;
+
+use alloc::vec::Vec;
+
+/// Splits a path into the dashes described by a pattern and writes them into `output`.
+///
+/// Dashes are written as sub-paths without custom attributes. Sub-paths longer than
+/// 2^20 repetitions of the pattern are written without dashes.
+pub fn dash_into<Iter, B>(path: Iter, pattern: &[f32], offset: f32, tolerance: f32, output: &mut B)
+where
+    Iter: IntoIterator<Item = PathEvent>,
+    B: PathBuilder,
+{
+    let pattern_length: f32 = pattern.iter().sum();
+    let invalid_pattern = pattern.iter().any(|v| *v < 0.0 || !v.is_finite());
+    if pattern_length <= 0.0 || invalid_pattern {
+        for evt in path {
+            output.path_event(evt, NO_ATTRIBUTES);
+        }
+        return;
+    }
+
+    let mut dasher = Dasher {
+        pattern,
+        phase: Phase::new(pattern, offset),
+        tolerance: tolerance.max(1e-4),
+        segments: Vec::new(),
+        edges: Vec::new(),
+        dashes: Vec::new(),
+        output,
+    };
+
+    for evt in path {
+        match evt {
+            PathEvent::Begin { .. } => {
+                dasher.segments.clear();
+            }
+            PathEvent::Line { from, to } => {
+                dasher
+                    .segments
+                    .push(Segment::Line(LineSegment { from, to }));
+            }
+            PathEvent::Quadratic { from, ctrl, to } => {
+                dasher
+                    .segments
+                    .push(Segment::Quadratic(QuadraticBezierSegment {
+                        from,
+                        ctrl,
+                        to,
+                    }));
+            }
+            PathEvent::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => {
+                dasher.segments.push(Segment::Cubic(CubicBezierSegment {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                }));
+            }
+            PathEvent::End { last, first, close } => {
+                if close && last != first {
+                    dasher.segments.push(Segment::Line(LineSegment {
+                        from: last,
+                        to: first,
+                    }));
+                }
+                dasher.sub_path(close);
+            }
+        }
+    }
+}
+
+// Maximum number of repetitions of the pattern along a sub-path. Beyond that the
+// dashes are far below the precision of the coordinates.
+const MAX_PERIODS_PER_SUB_PATH: u32 = 1 << 20;
+
+// Position in the dash pattern.
+#[derive(Copy, Clone, Debug)]
+struct Phase {
+    // Index in the pattern. The pattern is considered to have an even number of values.
+    index: usize,
+    // Remaining distance until the next index.
+    remaining: f32,
+}
+
+impl Phase {
+    fn new(pattern: &[f32], offset: f32) -> Self {
+        let n = pattern_len(pattern);
+        let total: f32 = (0..n).map(|i| pattern[i % pattern.len()]).sum();
+
+        let mut offset = offset % total;
+        if offset < 0.0 {
+            offset += total;
+        }
+
+        let mut index = 0;
+        loop {
+            let value = pattern[index % pattern.len()];
+            if offset < value || index == n - 1 {
+                return Phase {
+                    index,
+                    remaining: value - offset,
+                };
+            }
+            offset -= value;
+            index += 1;
+        }
+    }
+
+    fn is_dash(&self) -> bool {
+        self.index & 1 == 0
+    }
+}
+
+fn pattern_len(pattern: &[f32]) -> usize {
+    if pattern.len() % 2 == 1 {
+        pattern.len() * 2
+    } else {
+        pattern.len()
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+enum Segment {
+    Line(LineSegment<f32>),
+    Quadratic(QuadraticBezierSegment<f32>),
+    Cubic(CubicBezierSegment<f32>),
+}
+
+impl Segment {
+    fn from(&self) -> Point {
+        match self {
+            Segment::Line(segment) => segment.from,
+            Segment::Quadratic(segment) => segment.from,
+            Segment::Cubic(segment) => segment.from,
+        }
+    }
+
+    fn split_range(&self, range: Range<f32>) -> Self {
+        match self {
+            Segment::Line(segment) => Segment::Line(segment.split_range(range)),
+            Segment::Quadratic(segment) => Segment::Quadratic(segment.split_range(range)),
+            Segment::Cubic(segment) => Segment::Cubic(segment.split_range(range)),
+        }
+    }
+}
+
+struct Edge {
+    // distance from the beginning of the sub-path
+    distance: f32,
+    // which segment this edge is on
+    index: usize,
+    // t-value of the endpoint on the segment
+    t: f32,
+}
+
+struct Dasher<'l, B: PathBuilder> {
+    pattern: &'l [f32],
+    phase: Phase,
+    tolerance: f32,
+    segments: Vec<Segment>,
+    edges: Vec<Edge>,
+    dashes: Vec<Range<f32>>,
+    output: &'l mut B,
+}
+
+impl<'l, B: PathBuilder> Dasher<'l, B> {
+    fn sub_path(&mut self, closed: bool) {
+        let length = self.measure();
+        if length <= 0.0 {
+            return;
+        }
+
+        // Compute the distance ranges of the dashes.
+        self.dashes.clear();
+        let n = pattern_len(self.pattern);
+        let pattern = self.pattern;
+        let value = |index: usize| pattern[index % pattern.len()] as f64;
+        let period_length: f64 = (0..n).map(value).sum();
+        let length64 = length as f64;
+        if length64 / period_length > MAX_PERIODS_PER_SUB_PATH as f64 {
+            // The dashes are too small to be told apart, draw the sub-path solid.
+            self.dashes.push(0.0..length);
+        } else {
+            // The dash boundaries are computed from the index of the period rather than
+            // accumulated, so that the loop always makes progress.
+            let mut phase = self.phase;
+            let mut distance = 0.0;
+            let mut end = phase.remaining as f64;
+            let origin = end - (0..=phase.index).map(value).sum::<f64>();
+            let mut period = 0u32;
+            loop {
+                if phase.is_dash() {
+                    self.dashes.push(distance as f32..end.min(length64) as f32);
+                }
+                if end >= length64 {
+                    break;
+                }
+                phase.index += 1;
+                if phase.index == n {
+                    phase.index = 0;
+                    period += 1;
+                    distance = origin + period as f64 * period_length;
+                } else {
+                    distance = end;
+                }
+                end = distance + value(phase.index);
+            }
+        }
+
+        let num_dashes = self.dashes.len();
+        if num_dashes == 0 {
+            return;
+        }
+
+        if closed && self.dashes[0].start == 0.0 && self.dashes[num_dashes - 1].end == length {
+            if num_dashes == 1 {
+                // The whole sub-path is visible.
+                self.output.begin(self.segments[0].from(), NO_ATTRIBUTES);
+                for i in 0..self.segments.len() {
+                    let segment = self.segments[i];
+                    self.add_segment(&segment);
+                }
+                self.output.end(true);
+                return;
+            }
+
+            // Connect the last and first dashes across the start of the sub-path.
+            self.add_dash(self.dashes[num_dashes - 1].clone(), true);
+            self.add_dash(self.dashes[0].clone(), false);
+            self.output.end(false);
+            for i in 1..(num_dashes - 1) {
+                self.add_dash(self.dashes[i].clone(), true);
+                self.output.end(false);
+            }
+            return;
+        }
+
+        for i in 0..num_dashes {
+            self.add_dash(self.dashes[i].clone(), true);
+            self.output.end(false);
+        }
+    }
+
+    // Computes the edges of the current sub-path and returns its length.
+    fn measure(&mut self) -> f32 {
+        self.edges.clear();
+        self.edges.push(Edge {
+            distance: 0.0,
+            index: 0,
+            t: 0.0,
+        });
+
+        let mut distance = 0.0;
+        let edges = &mut self.edges;
+        for (index, segment) in self.segments.iter().enumerate() {
+            match segment {
+                Segment::Line(segment) => {
+                    distance += segment.length();
+                    edges.push(Edge {
+                        distance,
+                        index,
+                        t: 1.0,
+                    });
+                }
+                Segment::Quadratic(segment) => {
+                    segment.for_each_flattened_with_t(self.tolerance, &mut |line, t| {
+                        distance += line.length();
+                        edges.push(Edge {
+                            distance,
+                            index,
+                            t: t.end,
+                        });
+                    });
+                }
+                Segment::Cubic(segment) => {
+                    segment.for_each_flattened_with_t(self.tolerance, &mut |line, t| {
+                        distance += line.length();
+                        edges.push(Edge {
+                            distance,
+                            index,
+                            t: t.end,
+                        });
+                    });
+                }
+            }
+        }
+
+        distance
+    }
+
+    // Returns the segment index and t-value at a given distance along the sub-path.
+    fn locate(&self, distance: f32) -> (usize, f32) {
+        let idx = self
+            .edges
+            .partition_point(|edge| edge.distance < distance)
+            .max(1)
+            .min(self.edges.len() - 1);
+        let prev = &self.edges[idx - 1];
+        let edge = &self.edges[idx];
+
+        let prev_t = if prev.index == edge.index {
+            prev.t
+        } else {
+            0.0
+        };
+        let d = edge.distance - prev.distance;
+        let ratio = if d > 0.0 {
+            ((distance - prev.distance) / d).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        (edge.index, prev_t + (edge.t - prev_t) * ratio)
+    }
+
+    fn add_dash(&mut self, range: Range<f32>, begin: bool) {
+        let (start_index, start_t) = self.locate(range.start);
+        let (end_index, end_t) = self.locate(range.end);
+
+        if begin {
+            let segment = self.segments[start_index].split_range(start_t..1.0);
+            self.output.begin(segment.from(), NO_ATTRIBUTES);
+        }
+
+        if start_index == end_index {
+            if start_t < end_t {
+                let segment = self.segments[start_index].split_range(start_t..end_t);
+                self.add_segment(&segment);
+            }
+            return;
+        }
+
+        if start_t < 1.0 {
+            let segment = self.segments[start_index].split_range(start_t..1.0);
+            self.add_segment(&segment);
+        }
+        for i in (start_index + 1)..end_index {
+            let segment = self.segments[i];
+            self.add_segment(&segment);
+        }
+        if end_t > 0.0 {
+            let segment = self.segments[end_index].split_range(0.0..end_t);
+            self.add_segment(&segment);
+        }
+    }
+
+    fn add_segment(&mut self, segment: &Segment) {
+        match segment {
+            Segment::Line(segment) => {
+                self.output.line_to(segment.to, NO_ATTRIBUTES);
+            }
+            Segment::Quadratic(segment) => {
+                self.output
+                    .quadratic_bezier_to(segment.ctrl, segment.to, NO_ATTRIBUTES);
+            }
+            Segment::Cubic(segment) => {
+                self.output.cubic_bezier_to(
+                    segment.ctrl1,
+                    segment.ctrl2,
+                    segment.to,
+                    NO_ATTRIBUTES,
+                );
+            }
+        }
+    }
+}
@@ -79,7 +79,9 @@
 //! ```
 
 use crate::geom::traits::Transformation;
-use crate::geom::{cubic_bezier, quadratic_bezier, CubicBezierSegment, QuadraticBezierSegment};
+use crate::geom::{
+    cubic_bezier, quadratic_bezier, BezierSegment, CubicBezierSegment, QuadraticBezierSegment,
+};
 use crate::math::*;
 use crate::{Attributes, Event, PathEvent};
 
@@ -96,6 +98,24 @@ pub trait PathIterator: Iterator<Item = PathEvent> + Sized {
     fn transformed<T: Transformation<f32>>(self, mat: &T) -> Transformed<Self, T> {
         Transformed::new(mat, self)
     }
+
+    /// Returns an iterator that keeps track of the tangent of the path at the end of
+    /// each event.
+    fn with_tangents(self) -> WithTangents<Self> {
+        WithTangents::new(self)
+    }
+
+    /// Returns an iterator that only yields `Begin`, `Line` and `End` events, with
+    /// curves flattened or dropped depending on the mode.
+    fn lines(self, mode: CurveMode) -> Lines<Self> {
+        Lines::new(mode, self)
+    }
+
+    /// Returns an iterator over the quadratic and cubic bézier curves of the path,
+    /// skipping all other events.
+    fn bezier_segments(self) -> BezierSegments<Self> {
+        BezierSegments::new(self)
+    }
 }
 
 impl<Iter> PathIterator for Iter where Iter: Iterator<Item = PathEvent> {}
@@ -224,6 +244,139 @@ where
     }
 }
 
+/// What `Lines` does with the curves of the path.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CurveMode {
+    /// Approximate curves with line segments.
+    Flatten { tolerance: f32 },
+    /// Skip curves.
+    ///
+    /// This leaves gaps in the sub-paths where the curves were, which is useful when
+    /// the curves are handled separately, for example with `bezier_segments`.
+    Drop,
+}
+
+/// An iterator that yields the `Begin`, `Line` and `End` events of a path, flattening
+/// or dropping its curves.
+///
+/// # Example
+///
+/// ```
+/// # use lyon_path::iterator::{CurveMode, PathIterator};
+/// # use lyon_path::math::point;
+/// # use lyon_path::{Path, PathEvent};
+/// let mut builder = Path::builder();
+/// builder.begin(point(0.0, 0.0));
+/// builder.line_to(point(10.0, 0.0));
+/// builder.quadratic_bezier_to(point(10.0, 10.0), point(0.0, 10.0));
+/// builder.end(true);
+/// let path = builder.build();
+///
+/// let lines: Vec<PathEvent> = path.iter().lines(CurveMode::Drop).collect();
+/// assert_eq!(lines.len(), 3);
+///
+/// let curves: Vec<_> = path.iter().bezier_segments().collect();
+/// assert_eq!(curves.len(), 1);
+/// ```
+pub struct Lines<Iter> {
+    it: Flattened<Iter>,
+    drop_curves: bool,
+}
+
+impl<Iter: Iterator<Item = PathEvent>> Lines<Iter> {
+    /// Creates the iterator.
+    pub fn new(mode: CurveMode, it: Iter) -> Self {
+        let (tolerance, drop_curves) = match mode {
+            CurveMode::Flatten { tolerance } => (tolerance, false),
+            CurveMode::Drop => (0.0, true),
+        };
+
+        Lines {
+            it: Flattened::new(tolerance, it),
+            drop_curves,
+        }
+    }
+}
+
+impl<Iter> Iterator for Lines<Iter>
+where
+    Iter: Iterator<Item = PathEvent>,
+{
+    type Item = PathEvent;
+    fn next(&mut self) -> Option<PathEvent> {
+        if !self.drop_curves {
+            return self.it.next();
+        }
+
+        loop {
+            match self.it.it.next()? {
+                PathEvent::Quadratic { .. } | PathEvent::Cubic { .. } => {}
+                evt => {
+                    return Some(evt);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.drop_curves {
+            (0, self.it.it.size_hint().1)
+        } else {
+            self.it.size_hint()
+        }
+    }
+}
+
+/// An iterator that yields the quadratic and cubic bézier curves of a path.
+pub struct BezierSegments<Iter> {
+    it: Iter,
+}
+
+impl<Iter: Iterator<Item = PathEvent>> BezierSegments<Iter> {
+    /// Creates the iterator.
+    pub fn new(it: Iter) -> Self {
+        BezierSegments { it }
+    }
+}
+
+impl<Iter> Iterator for BezierSegments<Iter>
+where
+    Iter: Iterator<Item = PathEvent>,
+{
+    type Item = BezierSegment<f32>;
+    fn next(&mut self) -> Option<BezierSegment<f32>> {
+        loop {
+            match self.it.next()? {
+                PathEvent::Quadratic { from, ctrl, to } => {
+                    return Some(BezierSegment::Quadratic(QuadraticBezierSegment {
+                        from,
+                        ctrl,
+                        to,
+                    }));
+                }
+                PathEvent::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                } => {
+                    return Some(BezierSegment::Cubic(CubicBezierSegment {
+                        from,
+                        ctrl1,
+                        ctrl2,
+                        to,
+                    }));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.it.size_hint().1)
+    }
+}
+
 /// Applies a 2D transform to a path iterator and yields the resulting path iterator.
 pub struct Transformed<'l, I, T> {
     it: I,
@@ -255,6 +408,109 @@ where
     }
 }
 
+/// An iterator that keeps track of the tangent of the path at the end of each event.
+///
+/// # Example
+///
+/// ```
+/// # use lyon_path::iterator::PathIterator;
+/// # use lyon_path::math::{point, vector};
+/// # use lyon_path::{Path, PathEvent};
+/// let mut builder = Path::builder();
+/// builder.begin(point(0.0, 0.0));
+/// builder.line_to(point(10.0, 0.0));
+/// builder.quadratic_bezier_to(point(10.0, 10.0), point(0.0, 10.0));
+/// builder.end(false);
+/// let path = builder.build();
+///
+/// let mut iter = path.iter().with_tangents();
+/// while let Some(evt) = iter.next() {
+///     if let PathEvent::Quadratic { .. } = evt {
+///         assert_eq!(iter.tangent(), vector(-1.0, 0.0));
+///     }
+/// }
+/// ```
+pub struct WithTangents<Iter> {
+    it: Iter,
+    tangent: Vector,
+}
+
+impl<Iter: Iterator<Item = PathEvent>> WithTangents<Iter> {
+    /// Creates the iterator.
+    pub fn new(it: Iter) -> Self {
+        WithTangents {
+            it,
+            tangent: vector(0.0, 0.0),
+        }
+    }
+
+    /// Returns the normalized tangent at the end of the last event returned by the iterator.
+    ///
+    /// - For lines, it is the direction of the line.
+    /// - For curves, it is the direction of the derivative at the end of the curve.
+    /// - At a `Begin` event, there is no tangent yet and a zero vector is returned.
+    /// - At a closing `End` event, it is the direction of the closing segment, toward the
+    ///   start of the sub-path.
+    ///
+    /// At corners, the tangent of the incoming edge is reported. When an edge has no
+    /// length, the tangent of the previous edge is kept.
+    pub fn tangent(&self) -> Vector {
+        self.tangent
+    }
+
+    fn set_tangent(&mut self, tangent: Vector) -> bool {
+        let len = tangent.length();
+        if len > 0.0 && len.is_finite() {
+            self.tangent = tangent / len;
+            return true;
+        }
+
+        false
+    }
+}
+
+impl<Iter> Iterator for WithTangents<Iter>
+where
+    Iter: Iterator<Item = PathEvent>,
+{
+    type Item = PathEvent;
+    fn next(&mut self) -> Option<PathEvent> {
+        let evt = self.it.next()?;
+        match evt {
+            PathEvent::Begin { .. } => {
+                self.tangent = vector(0.0, 0.0);
+            }
+            PathEvent::Line { from, to } => {
+                self.set_tangent(to - from);
+            }
+            PathEvent::Quadratic { from, ctrl, to } => {
+                let _ = self.set_tangent(to - ctrl) || self.set_tangent(to - from);
+            }
+            PathEvent::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => {
+                let _ = self.set_tangent(to - ctrl2)
+                    || self.set_tangent(to - ctrl1)
+                    || self.set_tangent(to - from);
+            }
+            PathEvent::End { last, first, close } => {
+                if close {
+                    self.set_tangent(first - last);
+                }
+            }
+        }
+
+        Some(evt)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+}
+
 /// An iterator that consumes an iterator of `Point`s and produces `Event`s.
 ///
 /// # Example
@@ -434,3 +690,103 @@ fn test_from_polyline_closed() {
     );
     assert_eq!(evts.next(), None);
 }
+
+#[test]
+fn test_with_tangents() {
+    use crate::Path;
+    use alloc::vec::Vec;
+
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    // Zero-length edge.
+    builder.line_to(point(10.0, 0.0));
+    builder.quadratic_bezier_to(point(10.0, 10.0), point(0.0, 10.0));
+    // Degenerate control point.
+    builder.cubic_bezier_to(point(0.0, 20.0), point(0.0, 5.0), point(0.0, 5.0));
+    builder.end(true);
+    builder.begin(point(100.0, 0.0));
+    builder.line_to(point(100.0, 10.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let mut iter = path.iter().with_tangents();
+    let mut tangents = Vec::new();
+    while iter.next().is_some() {
+        tangents.push(iter.tangent());
+    }
+
+    assert_eq!(
+        tangents,
+        &[
+            vector(0.0, 0.0),
+            vector(1.0, 0.0),
+            vector(1.0, 0.0),
+            vector(-1.0, 0.0),
+            vector(0.0, -1.0),
+            // Closing segment, toward the first point.
+            vector(0.0, -1.0),
+            vector(0.0, 0.0),
+            vector(0.0, 1.0),
+            vector(0.0, 1.0),
+        ]
+    );
+}
+
+#[test]
+fn test_lines_and_bezier_segments() {
+    use crate::Path;
+    use alloc::vec::Vec;
+
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.quadratic_bezier_to(point(10.0, 10.0), point(0.0, 10.0));
+    builder.cubic_bezier_to(point(-5.0, 10.0), point(-5.0, 5.0), point(0.0, 5.0));
+    builder.end(true);
+    let path = builder.build();
+
+    let dropped: Vec<PathEvent> = path.iter().lines(CurveMode::Drop).collect();
+    assert_eq!(
+        dropped,
+        &[
+            PathEvent::Begin {
+                at: point(0.0, 0.0)
+            },
+            PathEvent::Line {
+                from: point(0.0, 0.0),
+                to: point(10.0, 0.0)
+            },
+            PathEvent::End {
+                last: point(0.0, 5.0),
+                first: point(0.0, 0.0),
+                close: true
+            },
+        ]
+    );
+
+    let flattened: Vec<PathEvent> = path
+        .iter()
+        .lines(CurveMode::Flatten { tolerance: 0.1 })
+        .collect();
+    let expected: Vec<PathEvent> = path.iter().flattened(0.1).collect();
+    assert_eq!(flattened, expected);
+
+    let curves: Vec<BezierSegment<f32>> = path.iter().bezier_segments().collect();
+    assert_eq!(
+        curves,
+        &[
+            BezierSegment::Quadratic(QuadraticBezierSegment {
+                from: point(10.0, 0.0),
+                ctrl: point(10.0, 10.0),
+                to: point(0.0, 10.0),
+            }),
+            BezierSegment::Cubic(CubicBezierSegment {
+                from: point(0.0, 10.0),
+                ctrl1: point(-5.0, 10.0),
+                ctrl2: point(-5.0, 5.0),
+                to: point(0.0, 5.0),
+            }),
+        ]
+    );
+}
@@ -52,8 +52,10 @@ pub use lyon_geom as geom;
 #[macro_use]
 pub extern crate serde;
 
+pub mod binary;
 pub mod builder;
 pub mod commands;
+mod dash;
 mod events;
 pub mod iterator;
 // TODO: remove "pub" on mod path to avoid redundant  "use lyon::path::path::Path" in user code
@@ -62,6 +64,7 @@ pub mod path;
 pub use path::*;
 pub mod path_buffer;
 pub mod polygon;
+pub mod svg;
 
 #[doc(hidden)]
 pub mod private;
@@ -69,7 +72,7 @@ pub mod private;
 #[doc(inline)]
 pub use crate::commands::{PathCommands, PathCommandsSlice};
 pub use crate::events::*;
-pub use crate::geom::ArcFlags;
+pub use crate::geom::{ArcFlags, Winding};
 #[doc(inline)]
 pub use crate::path::{Path, PathSlice};
 #[doc(inline)]
@@ -78,7 +81,10 @@ pub use crate::path_buffer::{PathBuffer, PathBufferSlice};
 pub use crate::polygon::{IdPolygon, Polygon};
 
 use core::fmt;
-use math::Point;
+use math::{Point, Transform};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 pub mod traits {
     //! `lyon_path` traits reexported here for convenience.
@@ -204,6 +210,338 @@ pub enum LineJoin {
     Bevel,
 }
 
+/// Where the stroke is placed relative to the path.
+///
+/// The inner side is the positive side of the path (see `Side`), which is the inside of
+/// shapes built with `Winding::Positive`, for example with
+/// `PathBuilder::add_rectangle(rect, Winding::Positive)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum StrokeAlignment {
+    /// The stroke is centered on the path.
+    Center,
+    /// The stroke lies entirely on the positive side of the path.
+    Inner,
+    /// The stroke lies entirely on the negative side of the path.
+    Outer,
+}
+
+/// A flattening tolerance.
+///
+/// The tolerance can be expressed either directly in the coordinate space of the path,
+/// or in the coordinate space the path is transformed into (typically screen pixels).
+/// In the latter case, the tolerance is converted using the maximum scale factor of the
+/// transformation, so that the flattening error stays bounded in every direction, even
+/// with anisotropic transformations.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum Tolerance {
+    /// Maximum distance to the path in the coordinate space of the path.
+    Absolute(f32),
+    /// Maximum distance to the path after transformation, along with the maximum scale
+    /// factor of the transformation.
+    Screen { pixels: f32, max_scale: f32 },
+}
+
+impl Tolerance {
+    /// A tolerance expressed in the coordinate space the path is transformed into by
+    /// `transform`.
+    pub fn screen(pixels: f32, transform: &Transform) -> Self {
+        // The maximum scale factor is the largest singular value of the linear part
+        // of the transformation.
+        let (a, b, c, d) = (transform.m11, transform.m12, transform.m21, transform.m22);
+        let p = a * a + b * b + c * c + d * d;
+        let det = a * d - b * c;
+        let q = (p * p - 4.0 * det * det).max(0.0).sqrt();
+        let max_scale = ((p + q) * 0.5).sqrt();
+
+        Tolerance::Screen { pixels, max_scale }
+    }
+
+    /// Returns the tolerance in the coordinate space of the path.
+    ///
+    /// If the transformation collapses everything into a single point, the tolerance
+    /// is returned unscaled.
+    pub fn to_absolute(self) -> f32 {
+        match self {
+            Tolerance::Absolute(tolerance) => tolerance,
+            Tolerance::Screen { pixels, max_scale } => {
+                if max_scale > 0.0 && max_scale.is_finite() {
+                    pixels / max_scale
+                } else {
+                    pixels
+                }
+            }
+        }
+    }
+}
+
+impl From<f32> for Tolerance {
+    fn from(tolerance: f32) -> Self {
+        Tolerance::Absolute(tolerance)
+    }
+}
+
+/// A dash pattern for strokes.
+///
+/// The pattern alternates the lengths of the dashes and of the gaps between them,
+/// starting with a dash, and is repeated along each sub-path. Like in SVG, a pattern
+/// with an odd number of values is repeated twice to obtain an even number of values.
+///
+/// The offset shifts the phase at which the pattern starts on each sub-path. On closed
+/// sub-paths the pattern wraps around: a dash overlapping the start of the sub-path is
+/// not split in two. Sub-paths along which the pattern would repeat more than 2^20
+/// times are not dashed.
+///
+/// The pattern is stored inline so that `StrokeOptions` stays `Copy`, which limits it
+/// to `DashPattern::MAX_LEN` values.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialization", serde(try_from = "DashPatternData"))]
+pub struct DashPattern {
+    values: [f32; DashPattern::MAX_LEN],
+    len: usize,
+    /// Distance along the pattern at which the dashing starts.
+    pub offset: f32,
+}
+
+// The serialized representation of `DashPattern`, validated when deserializing.
+#[cfg(feature = "serialization")]
+#[derive(Deserialize)]
+struct DashPatternData {
+    values: [f32; DashPattern::MAX_LEN],
+    len: usize,
+    offset: f32,
+}
+
+#[cfg(feature = "serialization")]
+impl core::convert::TryFrom<DashPatternData> for DashPattern {
+    type Error = &'static str;
+
+    fn try_from(data: DashPatternData) -> Result<Self, Self::Error> {
+        if data.len > DashPattern::MAX_LEN {
+            return Err("Too many values in the dash pattern");
+        }
+
+        Ok(DashPattern {
+            values: data.values,
+            len: data.len,
+            offset: data.offset,
+        })
+    }
+}
+
+impl DashPattern {
+    /// Maximum number of values in a dash pattern.
+    pub const MAX_LEN: usize = 16;
+
+    /// Creates a dash pattern from the lengths of the dashes and gaps.
+    ///
+    /// Panics if `values` contains more than `DashPattern::MAX_LEN` values.
+    pub fn new(values: &[f32], offset: f32) -> Self {
+        assert!(values.len() <= Self::MAX_LEN);
+        let mut pattern = DashPattern {
+            values: [0.0; Self::MAX_LEN],
+            len: values.len(),
+            offset,
+        };
+        pattern.values[..values.len()].copy_from_slice(values);
+
+        pattern
+    }
+
+    /// The lengths of the dashes and gaps.
+    #[inline]
+    pub fn values(&self) -> &[f32] {
+        &self.values[..self.len]
+    }
+
+    #[inline]
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Parameters for stroking paths.
+///
+/// These are used by the stroke tessellator as well as the `stroke_to_fill` algorithm.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+#[non_exhaustive]
+pub struct StrokeOptions {
+    /// What cap to use at the start of each sub-path.
+    ///
+    /// Default value: `LineCap::Butt`.
+    pub start_cap: LineCap,
+
+    /// What cap to use at the end of each sub-path.
+    ///
+    /// Default value: `LineCap::Butt`.
+    pub end_cap: LineCap,
+
+    /// See the SVG specification.
+    ///
+    /// Default value: `LineJoin::Miter`.
+    pub line_join: LineJoin,
+
+    /// Line width
+    ///
+    /// Default value: `StrokeOptions::DEFAULT_LINE_WIDTH`.
+    pub line_width: f32,
+
+    /// Index of a custom attribute defining a per-vertex
+    /// factor to modulate the line width.
+    ///
+    /// Default value: `None`.
+    pub variable_line_width: Option<AttributeIndex>,
+
+    /// See the SVG specification.
+    ///
+    /// Must be greater than or equal to 1.0.
+    /// Default value: `StrokeOptions::DEFAULT_MITER_LIMIT`.
+    pub miter_limit: f32,
+
+    /// Where the stroke is placed relative to the path.
+    ///
+    /// With `Inner` and `Outer` alignments, the stroke keeps its width but is extruded
+    /// on a single side of the path. Joins and caps are extruded the same way, which
+    /// means that square and round caps are skewed towards that side.
+    ///
+    /// Default value: `StrokeAlignment::Center`.
+    pub stroke_alignment: StrokeAlignment,
+
+    /// Maximum allowed distance to the path when building an approximation.
+    ///
+    /// See [Flattening and tolerance](https://docs.rs/lyon_tessellation/latest/lyon_tessellation/#flattening-and-tolerance).
+    /// Default value: `StrokeOptions::DEFAULT_TOLERANCE`.
+    pub tolerance: f32,
+
+    /// Dash pattern applied to the stroke.
+    ///
+    /// Only the dashes are tessellated, each with its own caps. If the pattern is
+    /// empty, contains negative values or only zeros, the stroke is not dashed.
+    ///
+    /// The dashes don't carry custom attributes, so `variable_line_width` is ignored
+    /// when the stroke is dashed. The vertex sources reported by the tessellator refer
+    /// to the endpoints of the dashes, numbered in order from zero, rather than to the
+    /// endpoints of the input path.
+    ///
+    /// Default value: `None`.
+    pub dash: Option<DashPattern>,
+}
+
+impl StrokeOptions {
+    /// Minimum miter limit as defined by the SVG specification.
+    ///
+    /// See [StrokeMiterLimitProperty](https://svgwg.org/specs/strokes/#StrokeMiterlimitProperty)
+    pub const MINIMUM_MITER_LIMIT: f32 = 1.0;
+    /// Default miter limit as defined by the SVG specification.
+    ///
+    /// See [StrokeMiterLimitProperty](https://svgwg.org/specs/strokes/#StrokeMiterlimitProperty)
+    pub const DEFAULT_MITER_LIMIT: f32 = 4.0;
+    pub const DEFAULT_LINE_CAP: LineCap = LineCap::Butt;
+    pub const DEFAULT_LINE_JOIN: LineJoin = LineJoin::Miter;
+    pub const DEFAULT_LINE_WIDTH: f32 = 1.0;
+    pub const DEFAULT_TOLERANCE: f32 = 0.1;
+    pub const DEFAULT_STROKE_ALIGNMENT: StrokeAlignment = StrokeAlignment::Center;
+
+    pub const DEFAULT: Self = StrokeOptions {
+        start_cap: Self::DEFAULT_LINE_CAP,
+        end_cap: Self::DEFAULT_LINE_CAP,
+        line_join: Self::DEFAULT_LINE_JOIN,
+        line_width: Self::DEFAULT_LINE_WIDTH,
+        variable_line_width: None,
+        miter_limit: Self::DEFAULT_MITER_LIMIT,
+        stroke_alignment: Self::DEFAULT_STROKE_ALIGNMENT,
+        tolerance: Self::DEFAULT_TOLERANCE,
+        dash: None,
+    };
+
+    #[inline]
+    pub fn tolerance(tolerance: f32) -> Self {
+        Self::DEFAULT.with_tolerance(tolerance)
+    }
+
+    #[inline]
+    pub const fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets the tolerance from an absolute or transformed [`Tolerance`].
+    ///
+    /// For example `options.with_tolerance_from(Tolerance::screen(0.25, &transform))`
+    /// bounds the flattening error to a quarter of a pixel after transformation.
+    #[inline]
+    pub fn with_tolerance_from(mut self, tolerance: Tolerance) -> Self {
+        self.tolerance = tolerance.to_absolute();
+        self
+    }
+
+    #[inline]
+    pub const fn with_line_cap(mut self, cap: LineCap) -> Self {
+        self.start_cap = cap;
+        self.end_cap = cap;
+        self
+    }
+
+    #[inline]
+    pub const fn with_start_cap(mut self, cap: LineCap) -> Self {
+        self.start_cap = cap;
+        self
+    }
+
+    #[inline]
+    pub const fn with_end_cap(mut self, cap: LineCap) -> Self {
+        self.end_cap = cap;
+        self
+    }
+
+    #[inline]
+    pub const fn with_line_join(mut self, join: LineJoin) -> Self {
+        self.line_join = join;
+        self
+    }
+
+    #[inline]
+    pub const fn with_line_width(mut self, width: f32) -> Self {
+        self.line_width = width;
+        self
+    }
+
+    #[inline]
+    pub fn with_miter_limit(mut self, limit: f32) -> Self {
+        assert!(limit >= Self::MINIMUM_MITER_LIMIT);
+        self.miter_limit = limit;
+        self
+    }
+
+    #[inline]
+    pub const fn with_variable_line_width(mut self, idx: AttributeIndex) -> Self {
+        self.variable_line_width = Some(idx);
+        self
+    }
+
+    #[inline]
+    pub const fn with_stroke_alignment(mut self, alignment: StrokeAlignment) -> Self {
+        self.stroke_alignment = alignment;
+        self
+    }
+
+    #[inline]
+    pub const fn with_dash(mut self, dash: DashPattern) -> Self {
+        self.dash = Some(dash);
+        self
+    }
+}
+
+impl Default for StrokeOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// The positive or negative side of a vector or segment.
 ///
 /// Given a reference vector `v0`, a vector `v1` is on the positive side
@@ -278,16 +616,6 @@ impl FillRule {
     }
 }
 
-/// The two possible orientations for the edges of a shape to be built in.
-///
-/// Positive winding corresponds to the positive orientation in trigonometry.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
-pub enum Winding {
-    Positive,
-    Negative,
-}
-
 /// ID of a control point in a path.
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
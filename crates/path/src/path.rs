@@ -1,12 +1,16 @@
 //! The default path data structure.
 //!
 
+use crate::binary::DecodeError;
 use crate::builder::*;
 use crate::geom::traits::Transformation;
-use crate::geom::{CubicBezierSegment, QuadraticBezierSegment};
+use crate::geom::{cubic_bezier, quadratic_bezier};
+use crate::geom::{CubicBezierSegment, LineSegment, QuadraticBezierSegment};
 use crate::iterator::NoAttributes as IterNoAttributes;
+use crate::iterator::PathIterator;
 use crate::math::*;
 use crate::private::DebugValidator;
+use crate::svg::ParseError;
 use crate::{
     AttributeStore, Attributes, ControlPointId, EndpointId, Event, IdEvent, PathEvent,
     PositionStore, NO_ATTRIBUTES,
@@ -14,6 +18,7 @@ use crate::{
 
 use core::fmt;
 use core::iter::{FromIterator, IntoIterator};
+use core::ops::Range;
 use core::u32;
 
 use alloc::boxed::Box;
@@ -126,6 +131,113 @@ impl Path {
         }
     }
 
+    /// Parses SVG path data and builds a `Path`.
+    ///
+    /// See the [svg](../svg/index.html) module.
+    pub fn from_svg(data: &str) -> Result<Path, ParseError> {
+        let mut builder = Path::svg_builder();
+        crate::svg::parse_path_data(data, &mut builder)?;
+
+        Ok(builder.build())
+    }
+
+    /// Serializes the path into a compact binary representation.
+    ///
+    /// See the [binary](../binary/index.html) module for a description of the format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        crate::binary::encode(self.as_slice())
+    }
+
+    /// Deserializes a path from the binary representation produced by `Path::to_bytes`.
+    ///
+    /// See the [binary](../binary/index.html) module for a description of the format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Path, DecodeError> {
+        let (points, verbs, num_attributes) = crate::binary::decode(bytes)?;
+
+        Ok(Path {
+            points: points.into_boxed_slice(),
+            verbs: verbs.into_boxed_slice(),
+            num_attributes,
+        })
+    }
+
+    /// Builds a path from a sequence of events.
+    ///
+    /// This is faster than feeding the events to a builder one by one: the size of
+    /// the path is computed ahead of time, and the points and verbs are written
+    /// directly into the path's buffers.
+    ///
+    /// The events must form a valid sequence of sub-paths, which is checked in debug
+    /// builds. The `from` and `first` positions of the events are ignored.
+    pub fn from_events(events: &[PathEvent]) -> Path {
+        let mut num_points = 0;
+        for evt in events {
+            num_points += match evt {
+                PathEvent::Begin { .. } | PathEvent::Line { .. } => 1,
+                PathEvent::Quadratic { .. } => 2,
+                PathEvent::Cubic { .. } => 3,
+                PathEvent::End { close: true, .. } => 1,
+                PathEvent::End { close: false, .. } => 0,
+            };
+        }
+
+        let mut points = Vec::with_capacity(num_points);
+        let mut verbs = Vec::with_capacity(events.len());
+        let mut validator = DebugValidator::new();
+        let mut first = point(0.0, 0.0);
+        for evt in events {
+            match *evt {
+                PathEvent::Begin { at } => {
+                    validator.begin();
+                    nan_check(at);
+                    first = at;
+                    points.push(at);
+                    verbs.push(Verb::Begin);
+                }
+                PathEvent::Line { to, .. } => {
+                    validator.edge();
+                    nan_check(to);
+                    points.push(to);
+                    verbs.push(Verb::LineTo);
+                }
+                PathEvent::Quadratic { ctrl, to, .. } => {
+                    validator.edge();
+                    nan_check(ctrl);
+                    nan_check(to);
+                    points.push(ctrl);
+                    points.push(to);
+                    verbs.push(Verb::QuadraticTo);
+                }
+                PathEvent::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => {
+                    validator.edge();
+                    nan_check(ctrl1);
+                    nan_check(ctrl2);
+                    nan_check(to);
+                    points.push(ctrl1);
+                    points.push(ctrl2);
+                    points.push(to);
+                    verbs.push(Verb::CubicTo);
+                }
+                PathEvent::End { close, .. } => {
+                    validator.end();
+                    if close {
+                        points.push(first);
+                    }
+                    verbs.push(if close { Verb::Close } else { Verb::End });
+                }
+            }
+        }
+        validator.build();
+
+        Path {
+            points: points.into_boxed_slice(),
+            verbs: verbs.into_boxed_slice(),
+            num_attributes: 0,
+        }
+    }
+
     /// Returns a view on this `Path`.
     #[inline]
     pub fn as_slice(&self) -> PathSlice {
@@ -157,8 +269,58 @@ impl Path {
         IterWithAttributes::new(self.num_attributes(), &self.points[..], &self.verbs[..])
     }
 
+    /// Iterates over the line segments of a flattened approximation of the path.
+    ///
+    /// See [`FlattenedSegments`].
+    pub fn flattened_segments(&self, tolerance: f32) -> FlattenedSegments<'_> {
+        FlattenedSegments::new(self.as_slice(), tolerance)
+    }
+
+    /// Returns a flattened approximation of each sub-path as a polygon.
+    ///
+    /// See [`PathSlice::to_polygons`].
+    pub fn to_polygons(&self, tolerance: f32) -> Vec<(Vec<Point>, bool)> {
+        self.as_slice().to_polygons(tolerance)
+    }
+
+    /// Returns the number of elements that the point and verb buffers can hold.
+    ///
+    /// Builders trim their buffers when producing a `Path`, so the path's memory
+    /// footprint is exactly the size of its content.
+    #[inline]
+    pub fn capacity(&self) -> (usize, usize) {
+        (self.points.len(), self.verbs.len())
+    }
+
+    /// Consumes this path and returns an empty builder that reuses its allocations.
+    ///
+    /// This is useful to avoid allocations when a path is rebuilt frequently.
+    /// Custom attributes are discarded.
+    pub fn into_builder(self) -> Builder {
+        let mut builder = BuilderImpl::new();
+        builder.points = self.points.into_vec();
+        builder.verbs = self.verbs.into_vec();
+        builder.points.clear();
+        builder.verbs.clear();
+
+        NoAttributes::wrap(builder)
+    }
+
+    /// Consumes this path and returns an empty builder with the same number of
+    /// custom attributes that reuses its allocations.
+    pub fn into_builder_with_attributes(self) -> BuilderWithAttributes {
+        let num_attributes = self.num_attributes;
+        let mut builder = BuilderWithAttributes::new(num_attributes);
+        builder.builder = self.into_builder().into_inner();
+
+        builder
+    }
+
     /// Applies a transform to all endpoints and control points of this path and
     /// Returns the result.
+    ///
+    /// The path's buffers are reused. See `PathSlice::transformed` to produce a
+    /// transformed copy of a borrowed path.
     pub fn transformed<T: Transformation<f32>>(mut self, transform: &T) -> Self {
         self.apply_transform(transform);
 
@@ -170,6 +332,13 @@ impl Path {
         IterNoAttributes(Reversed::new(self.as_slice()))
     }
 
+    /// Iterates over the sub-paths of this `Path`.
+    ///
+    /// See [`SubPathIter`](struct.SubPathIter.html).
+    pub fn sub_paths(&self) -> SubPathIter<'_> {
+        SubPathIter::new(self.as_slice())
+    }
+
     /// Returns the first endpoint and its custom attributes if any.
     #[inline]
     pub fn first_endpoint(&self) -> Option<(Point, Attributes)> {
@@ -183,37 +352,12 @@ impl Path {
     }
 
     fn apply_transform<T: Transformation<f32>>(&mut self, transform: &T) {
-        let iter = IdIter::new(self.num_attributes, &self.verbs[..]);
-
-        for evt in iter {
-            match evt {
-                IdEvent::Begin { at } => {
-                    self.points[at.to_usize()] =
-                        transform.transform_point(self.points[at.to_usize()]);
-                }
-                IdEvent::Line { to, .. } => {
-                    self.points[to.to_usize()] =
-                        transform.transform_point(self.points[to.to_usize()]);
-                }
-                IdEvent::Quadratic { ctrl, to, .. } => {
-                    self.points[ctrl.to_usize()] =
-                        transform.transform_point(self.points[ctrl.to_usize()]);
-                    self.points[to.to_usize()] =
-                        transform.transform_point(self.points[to.to_usize()]);
-                }
-                IdEvent::Cubic {
-                    ctrl1, ctrl2, to, ..
-                } => {
-                    self.points[ctrl1.to_usize()] =
-                        transform.transform_point(self.points[ctrl1.to_usize()]);
-                    self.points[ctrl2.to_usize()] =
-                        transform.transform_point(self.points[ctrl2.to_usize()]);
-                    self.points[to.to_usize()] =
-                        transform.transform_point(self.points[to.to_usize()]);
-                }
-                IdEvent::End { .. } => {}
-            }
-        }
+        transform_points(
+            &mut self.points,
+            &self.verbs,
+            self.num_attributes,
+            transform,
+        );
     }
 }
 
@@ -326,10 +470,43 @@ impl<'l> PathSlice<'l> {
         IterWithAttributes::new(self.num_attributes(), self.points, self.verbs)
     }
 
+    /// Iterates over the line segments of a flattened approximation of the path.
+    ///
+    /// See [`FlattenedSegments`].
+    pub fn flattened_segments(&self, tolerance: f32) -> FlattenedSegments<'l> {
+        FlattenedSegments::new(*self, tolerance)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.verbs.is_empty()
     }
 
+    /// Returns a flattened approximation of each sub-path as a polygon.
+    ///
+    /// Each sub-path produces the sequence of its flattened endpoints, along with
+    /// whether it is closed. The first point of closed sub-paths is not repeated at the
+    /// end: the closing segment is implicit.
+    pub fn to_polygons(&self, tolerance: f32) -> Vec<(Vec<Point>, bool)> {
+        let mut polygons = Vec::new();
+        let mut points = Vec::new();
+        for event in self.iter().flattened(tolerance) {
+            match event {
+                PathEvent::Begin { at } => {
+                    points.push(at);
+                }
+                PathEvent::Line { to, .. } => {
+                    points.push(to);
+                }
+                PathEvent::End { close, .. } => {
+                    polygons.push((core::mem::take(&mut points), close));
+                }
+                _ => {}
+            }
+        }
+
+        polygons
+    }
+
     /// Returns a slice over an endpoint's custom attributes.
     #[inline]
     pub fn attributes(&self, endpoint: EndpointId) -> Attributes<'l> {
@@ -340,6 +517,25 @@ impl<'l> PathSlice<'l> {
     pub fn reversed(&self) -> IterNoAttributes<Reversed> {
         IterNoAttributes(Reversed::new(*self))
     }
+
+    /// Iterates over the sub-paths of this `PathSlice`.
+    ///
+    /// See [`SubPathIter`](struct.SubPathIter.html).
+    pub fn sub_paths(&self) -> SubPathIter<'l> {
+        SubPathIter::new(*self)
+    }
+
+    /// Returns a new path with a transform applied to all endpoints and control points.
+    ///
+    /// Custom attributes are copied unchanged.
+    pub fn transformed<T: Transformation<f32>>(&self, transform: &T) -> Path {
+        Path {
+            points: self.points.into(),
+            verbs: self.verbs.into(),
+            num_attributes: self.num_attributes,
+        }
+        .transformed(transform)
+    }
 }
 
 impl<'l> fmt::Debug for PathSlice<'l> {
@@ -505,6 +701,16 @@ impl BuilderImpl {
     pub fn extend_from_paths(&mut self, paths: &[PathSlice]) {
         concatenate_paths(&mut self.points, &mut self.verbs, paths, 0);
     }
+
+    // The last endpoint is followed by `attr_slots` points worth of custom attributes.
+    fn last_ctrl_impl(&self, attr_slots: usize) -> Option<Point> {
+        match self.verbs.last() {
+            Some(Verb::QuadraticTo) | Some(Verb::CubicTo) => {
+                Some(self.points[self.points.len() - 2 - attr_slots])
+            }
+            _ => None,
+        }
+    }
 }
 
 impl NoAttributes<BuilderImpl> {
@@ -596,6 +802,10 @@ impl PathBuilder for BuilderImpl {
         self.points.reserve(endpoints + ctrl_points);
         self.verbs.reserve(endpoints);
     }
+
+    fn last_ctrl(&self) -> Option<Point> {
+        self.last_ctrl_impl(0)
+    }
 }
 
 impl Build for BuilderImpl {
@@ -740,6 +950,14 @@ impl BuilderWithAttributes {
         self.builder.verbs.reserve(endpoints);
     }
 
+    /// Returns the last control point of the current sub-path's last segment
+    /// if it is a quadratic or cubic bézier curve.
+    #[inline]
+    pub fn last_ctrl(&self) -> Option<Point> {
+        let attr = self.num_attributes / 2 + self.num_attributes % 2;
+        self.builder.last_ctrl_impl(attr)
+    }
+
     #[inline]
     pub fn build(self) -> Path {
         self.builder.validator.build();
@@ -797,6 +1015,11 @@ impl PathBuilder for BuilderWithAttributes {
     fn reserve(&mut self, endpoints: usize, ctrl_points: usize) {
         self.reserve(endpoints, ctrl_points)
     }
+
+    #[inline]
+    fn last_ctrl(&self) -> Option<Point> {
+        self.last_ctrl()
+    }
 }
 
 impl Build for BuilderWithAttributes {
@@ -1263,6 +1486,244 @@ impl<'l> Iterator for IdIter<'l> {
     }
 }
 
+/// A line segment of the flattened approximation of a path.
+///
+/// See [`FlattenedSegments`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlattenedSegment {
+    /// The line segment.
+    pub segment: LineSegment<f32>,
+    /// The endpoint at the start of the edge this segment approximates.
+    pub from: EndpointId,
+    /// The endpoint at the end of the edge this segment approximates.
+    pub to: EndpointId,
+    /// The range of the curve parameter on the original edge covered by the segment.
+    ///
+    /// Line segments between two endpoints cover the full `0.0..1.0` range.
+    pub t: Range<f32>,
+}
+
+enum FlatteningState {
+    Quadratic(
+        QuadraticBezierSegment<f32>,
+        quadratic_bezier::FlattenedT<f32>,
+    ),
+    Cubic(CubicBezierSegment<f32>, cubic_bezier::FlattenedT<f32>),
+    None,
+}
+
+/// An iterator over the line segments of a flattened approximation of a `Path` or
+/// `PathSlice`, along with the edge and the curve parameter range each of them
+/// comes from.
+///
+/// This makes it possible to interpolate per-endpoint attributes along the flattened
+/// approximation: the attributes at `segment.from` are those of the `from` endpoint
+/// interpolated towards the `to` endpoint by `t.start`.
+///
+/// The closing edge of a closed sub-path is produced as a line segment from the last
+/// endpoint to the first one, unless they are at the same position.
+pub struct FlattenedSegments<'l> {
+    points: &'l [Point],
+    ids: IdIter<'l>,
+    tolerance: f32,
+    curve: FlatteningState,
+    from: EndpointId,
+    to: EndpointId,
+    prev: (Point, f32),
+}
+
+impl<'l> FlattenedSegments<'l> {
+    fn new(path: PathSlice<'l>, tolerance: f32) -> Self {
+        FlattenedSegments {
+            points: path.points,
+            ids: IdIter::new(path.num_attributes, path.verbs),
+            tolerance,
+            curve: FlatteningState::None,
+            from: EndpointId::INVALID,
+            to: EndpointId::INVALID,
+            prev: (point(0.0, 0.0), 0.0),
+        }
+    }
+
+    fn next_on_curve(&mut self, t: f32, position: Point) -> FlattenedSegment {
+        let (from, t0) = self.prev;
+        self.prev = (position, t);
+
+        FlattenedSegment {
+            segment: LineSegment { from, to: position },
+            from: self.from,
+            to: self.to,
+            t: t0..t,
+        }
+    }
+}
+
+impl<'l> Iterator for FlattenedSegments<'l> {
+    type Item = FlattenedSegment;
+
+    fn next(&mut self) -> Option<FlattenedSegment> {
+        match &mut self.curve {
+            FlatteningState::Quadratic(curve, iter) => {
+                if let Some(t) = iter.next() {
+                    let position = if t == 1.0 { curve.to } else { curve.sample(t) };
+                    return Some(self.next_on_curve(t, position));
+                }
+            }
+            FlatteningState::Cubic(curve, iter) => {
+                if let Some(t) = iter.next() {
+                    let position = if t == 1.0 { curve.to } else { curve.sample(t) };
+                    return Some(self.next_on_curve(t, position));
+                }
+            }
+            FlatteningState::None => {}
+        }
+        self.curve = FlatteningState::None;
+
+        let points = self.points;
+        let position = |id: EndpointId| points[id.to_usize()];
+        let ctrl = |id: ControlPointId| points[id.to_usize()];
+        loop {
+            match self.ids.next()? {
+                IdEvent::Begin { .. } => {}
+                IdEvent::Line { from, to } => {
+                    return Some(FlattenedSegment {
+                        segment: LineSegment {
+                            from: position(from),
+                            to: position(to),
+                        },
+                        from,
+                        to,
+                        t: 0.0..1.0,
+                    });
+                }
+                IdEvent::End {
+                    last,
+                    first,
+                    close: true,
+                } => {
+                    let segment = LineSegment {
+                        from: position(last),
+                        to: position(first),
+                    };
+                    if segment.from != segment.to {
+                        return Some(FlattenedSegment {
+                            segment,
+                            from: last,
+                            to: first,
+                            t: 0.0..1.0,
+                        });
+                    }
+                }
+                IdEvent::End { .. } => {}
+                IdEvent::Quadratic { from, ctrl: c, to } => {
+                    let curve = QuadraticBezierSegment {
+                        from: position(from),
+                        ctrl: ctrl(c),
+                        to: position(to),
+                    };
+                    self.from = from;
+                    self.to = to;
+                    self.prev = (curve.from, 0.0);
+                    self.curve =
+                        FlatteningState::Quadratic(curve, curve.flattened_t(self.tolerance));
+                    return self.next();
+                }
+                IdEvent::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                } => {
+                    let curve = CubicBezierSegment {
+                        from: position(from),
+                        ctrl1: ctrl(ctrl1),
+                        ctrl2: ctrl(ctrl2),
+                        to: position(to),
+                    };
+                    self.from = from;
+                    self.to = to;
+                    self.prev = (curve.from, 0.0);
+                    self.curve = FlatteningState::Cubic(curve, curve.flattened_t(self.tolerance));
+                    return self.next();
+                }
+            }
+        }
+    }
+}
+
+/// An iterator over the sub-paths of a `Path` or `PathSlice`.
+///
+/// Each item is a `PathSlice` containing the events of a single sub-path, from its
+/// `Begin` event up to and including its `End` event.
+///
+/// The endpoint and control point ids of the produced slices are relative to the
+/// beginning of the slice rather than to the original path.
+///
+/// If a sub-path does not start with a `Begin` event, or is interrupted by the next `Begin`
+/// event or the end of the path before its `End` event, the produced slice contains the
+/// events up to that point.
+#[derive(Clone)]
+pub struct SubPathIter<'l> {
+    points: &'l [Point],
+    verbs: &'l [Verb],
+    num_attributes: usize,
+    attrib_stride: usize,
+}
+
+impl<'l> SubPathIter<'l> {
+    fn new(path: PathSlice<'l>) -> Self {
+        SubPathIter {
+            points: path.points,
+            verbs: path.verbs,
+            num_attributes: path.num_attributes,
+            attrib_stride: path.num_attributes.div_ceil(2),
+        }
+    }
+}
+
+impl<'l> Iterator for SubPathIter<'l> {
+    type Item = PathSlice<'l>;
+    fn next(&mut self) -> Option<PathSlice<'l>> {
+        if self.verbs.is_empty() {
+            return None;
+        }
+
+        let mut num_verbs = 0;
+        let mut num_points = 0;
+        for (i, verb) in self.verbs.iter().enumerate() {
+            if *verb == Verb::Begin && i > 0 {
+                break;
+            }
+
+            num_verbs += 1;
+            num_points += match *verb {
+                Verb::Begin | Verb::LineTo | Verb::Close => 1 + self.attrib_stride,
+                Verb::QuadraticTo => 2 + self.attrib_stride,
+                Verb::CubicTo => 3 + self.attrib_stride,
+                Verb::End => 0,
+            };
+
+            if *verb == Verb::Close || *verb == Verb::End {
+                break;
+            }
+        }
+
+        // Don't panic if the path is malformed.
+        let num_points = num_points.min(self.points.len());
+
+        let (verbs, remaining_verbs) = self.verbs.split_at(num_verbs);
+        let (points, remaining_points) = self.points.split_at(num_points);
+        self.verbs = remaining_verbs;
+        self.points = remaining_points;
+
+        Some(PathSlice {
+            points,
+            verbs,
+            num_attributes: self.num_attributes,
+        })
+    }
+}
+
 #[inline]
 fn interpolated_attributes(
     num_attributes: usize,
@@ -1282,6 +1743,40 @@ fn interpolated_attributes(
     }
 }
 
+/// Applies a transform to the endpoints and control points of a path's point buffer,
+/// leaving the custom attributes untouched.
+pub(crate) fn transform_points<T: Transformation<f32>>(
+    points: &mut [Point],
+    verbs: &[Verb],
+    num_attributes: usize,
+    transform: &T,
+) {
+    let iter = IdIter::new(num_attributes, verbs);
+
+    for evt in iter {
+        match evt {
+            IdEvent::Begin { at } => {
+                points[at.to_usize()] = transform.transform_point(points[at.to_usize()]);
+            }
+            IdEvent::Line { to, .. } => {
+                points[to.to_usize()] = transform.transform_point(points[to.to_usize()]);
+            }
+            IdEvent::Quadratic { ctrl, to, .. } => {
+                points[ctrl.to_usize()] = transform.transform_point(points[ctrl.to_usize()]);
+                points[to.to_usize()] = transform.transform_point(points[to.to_usize()]);
+            }
+            IdEvent::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => {
+                points[ctrl1.to_usize()] = transform.transform_point(points[ctrl1.to_usize()]);
+                points[ctrl2.to_usize()] = transform.transform_point(points[ctrl2.to_usize()]);
+                points[to.to_usize()] = transform.transform_point(points[to.to_usize()]);
+            }
+            IdEvent::End { .. } => {}
+        }
+    }
+}
+
 fn concatenate_paths(
     points: &mut Vec<Point>,
     verbs: &mut Vec<Verb>,
@@ -2229,3 +2724,391 @@ fn id_events() {
 
     assert_eq!(iter.next(), None);
 }
+
+#[test]
+fn transformed_path() {
+    use crate::geom::euclid::default::Transform2D;
+
+    let mut path = Path::builder_with_attributes(1);
+    path.begin(point(0.0, 0.0), &[1.0]);
+    path.line_to(point(1.0, 0.0), &[2.0]);
+    path.quadratic_bezier_to(point(2.0, 0.0), point(2.0, 1.0), &[3.0]);
+    path.cubic_bezier_to(point(2.0, 2.0), point(1.0, 2.0), point(0.0, 1.0), &[4.0]);
+    path.end(true);
+    let path = path.build();
+
+    let transform = Transform2D::scale(2.0, 3.0).then_translate(vector(10.0, 20.0));
+
+    let transformed = path.as_slice().transformed(&transform);
+
+    let expected: Vec<_> = path.iter().map(|evt| evt.transformed(&transform)).collect();
+    let actual: Vec<_> = transformed.iter().collect();
+    assert_eq!(actual, expected);
+
+    for evt in path.id_iter() {
+        let id = evt.to();
+        assert_eq!(transformed.attributes(id), path.attributes(id));
+    }
+
+    // Same as transforming an owned path.
+    let owned = path.clone().transformed(&transform);
+    assert_eq!(
+        owned.iter().collect::<Vec<_>>(),
+        transformed.iter().collect::<Vec<_>>()
+    );
+
+    // The original path is left untouched.
+    assert_eq!(
+        path.first_endpoint(),
+        Some((point(0.0, 0.0), slice(&[1.0])))
+    );
+}
+
+#[test]
+fn sub_paths() {
+    let mut builder = Path::builder_with_attributes(1);
+    builder.begin(point(0.0, 0.0), &[0.0]);
+    builder.line_to(point(1.0, 0.0), &[1.0]);
+    builder.quadratic_bezier_to(point(1.0, 1.0), point(0.0, 1.0), &[2.0]);
+    builder.end(true);
+    builder.begin(point(10.0, 0.0), &[3.0]);
+    builder.cubic_bezier_to(point(11.0, 0.0), point(11.0, 1.0), point(10.0, 1.0), &[4.0]);
+    builder.end(false);
+    builder.begin(point(20.0, 0.0), &[5.0]);
+    builder.end(false);
+    let path = builder.build();
+
+    let sub_paths: Vec<PathSlice> = path.sub_paths().collect();
+    assert_eq!(sub_paths.len(), 3);
+
+    let mut expected = Vec::new();
+    for sub_path in &sub_paths {
+        assert_eq!(sub_path.num_attributes(), 1);
+        expected.extend(sub_path.iter_with_attributes());
+    }
+    assert_eq!(path.iter_with_attributes().collect::<Vec<_>>(), expected);
+
+    assert_eq!(
+        sub_paths[1].first_endpoint(),
+        Some((point(10.0, 0.0), &[3.0][..]))
+    );
+    assert_eq!(
+        sub_paths[1].last_endpoint(),
+        Some((point(10.0, 1.0), &[4.0][..]))
+    );
+    assert_eq!(sub_paths[2].iter().count(), 2);
+
+    assert!(Path::new().sub_paths().next().is_none());
+}
+
+#[test]
+fn sub_paths_malformed() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.0));
+    builder.line_to(point(1.0, 1.0));
+    builder.end(false);
+    builder.begin(point(10.0, 0.0));
+    builder.line_to(point(11.0, 0.0));
+    builder.end(false);
+    let path = builder.build();
+
+    // Missing begin at the start and missing end at the end of the path.
+    let n = path.verbs.len();
+    let path = PathSlice {
+        points: &path.points[1..],
+        verbs: &path.verbs[1..(n - 1)],
+        num_attributes: 0,
+    };
+
+    let sub_paths: Vec<PathSlice> = path.sub_paths().collect();
+    assert_eq!(sub_paths.len(), 2);
+    assert_eq!(sub_paths[0].verbs, &[Verb::LineTo, Verb::LineTo, Verb::End]);
+    assert_eq!(sub_paths[0].points, &[point(1.0, 0.0), point(1.0, 1.0)]);
+    assert_eq!(sub_paths[1].verbs, &[Verb::Begin, Verb::LineTo]);
+    assert_eq!(sub_paths[1].points, &[point(10.0, 0.0), point(11.0, 0.0)]);
+    assert_eq!(
+        sub_paths[1].iter().collect::<Vec<_>>(),
+        &[
+            PathEvent::Begin {
+                at: point(10.0, 0.0)
+            },
+            PathEvent::Line {
+                from: point(10.0, 0.0),
+                to: point(11.0, 0.0)
+            },
+        ]
+    );
+}
+
+#[test]
+fn reuse_path_allocations() {
+    let mut builder = Path::builder_with_attributes(1);
+    builder.begin(point(0.0, 0.0), &[1.0]);
+    builder.line_to(point(1.0, 0.0), &[2.0]);
+    builder.quadratic_bezier_to(point(2.0, 0.0), point(2.0, 1.0), &[3.0]);
+    builder.end(true);
+    let path = builder.build();
+
+    assert_eq!(path.capacity(), (path.points.len(), path.verbs.len()));
+    let (points_capacity, verbs_capacity) = path.capacity();
+
+    let builder = path.into_builder_with_attributes();
+    assert_eq!(builder.num_attributes, 1);
+    assert!(builder.builder.points.is_empty());
+    assert!(builder.builder.verbs.is_empty());
+    assert_eq!(builder.builder.points.capacity(), points_capacity);
+    assert_eq!(builder.builder.verbs.capacity(), verbs_capacity);
+
+    let mut builder = builder.build().into_builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.0));
+    builder.end(false);
+    let path = builder.build();
+    assert_eq!(path.num_attributes(), 0);
+    assert_eq!(path.capacity(), (2, 3));
+}
+
+#[test]
+fn last_ctrl() {
+    let mut builder = Path::builder();
+    assert_eq!(builder.last_ctrl(), None);
+    builder.begin(point(0.0, 0.0));
+    assert_eq!(builder.last_ctrl(), None);
+    builder.quadratic_bezier_to(point(1.0, 0.0), point(1.0, 1.0));
+    assert_eq!(builder.last_ctrl(), Some(point(1.0, 0.0)));
+    builder.cubic_bezier_to(point(1.0, 2.0), point(2.0, 3.0), point(3.0, 3.0));
+    assert_eq!(builder.last_ctrl(), Some(point(2.0, 3.0)));
+    builder.line_to(point(4.0, 3.0));
+    assert_eq!(builder.last_ctrl(), None);
+    builder.quadratic_bezier_to(point(5.0, 3.0), point(5.0, 4.0));
+    builder.end(true);
+    assert_eq!(builder.last_ctrl(), None);
+
+    let mut builder = Path::builder_with_attributes(3);
+    builder.begin(point(0.0, 0.0), &[1.0, 2.0, 3.0]);
+    builder.cubic_bezier_to(
+        point(1.0, 0.0),
+        point(2.0, 1.0),
+        point(2.0, 2.0),
+        &[4.0, 5.0, 6.0],
+    );
+    assert_eq!(builder.last_ctrl(), Some(point(2.0, 1.0)));
+
+    let mut builder = Path::svg_builder();
+    builder.move_to(point(0.0, 0.0));
+    builder.quadratic_bezier_to(point(1.0, 0.0), point(1.0, 1.0));
+    assert_eq!(builder.last_ctrl(), Some(point(1.0, 0.0)));
+    builder.line_to(point(2.0, 2.0));
+    assert_eq!(builder.last_ctrl(), None);
+}
+
+#[test]
+fn binary_round_trip() {
+    let mut builder = Path::builder_with_attributes(3);
+    builder.begin(point(0.0, 1.0), &[1.0, 2.0, 3.0]);
+    builder.line_to(point(2.0, 1.0), &[4.0, 5.0, 6.0]);
+    builder.quadratic_bezier_to(point(3.0, 2.0), point(4.0, 1.0), &[7.0, 8.0, 9.0]);
+    builder.end(true);
+    builder.begin(point(10.0, 10.0), &[0.0, 0.0, 0.0]);
+    builder.cubic_bezier_to(
+        point(11.0, 10.0),
+        point(12.0, 12.0),
+        point(13.0, 10.0),
+        &[-1.0, -2.0, -3.0],
+    );
+    builder.end(false);
+    let path = builder.build();
+
+    let bytes = path.to_bytes();
+    assert_eq!(&bytes[0..4], b"lyon");
+    assert_eq!(bytes.len(), 20 + path.points.len() * 8 + path.verbs.len());
+
+    let decoded = Path::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.num_attributes(), 3);
+    assert_eq!(decoded.points, path.points);
+    assert_eq!(decoded.verbs, path.verbs);
+
+    let empty = Path::from_bytes(&Path::new().to_bytes()).unwrap();
+    assert!(empty.iter().next().is_none());
+
+    assert_eq!(
+        Path::from_bytes(b"svg!").err(),
+        Some(DecodeError::InvalidHeader)
+    );
+    assert_eq!(
+        Path::from_bytes(&bytes[..bytes.len() - 1]).err(),
+        Some(DecodeError::UnexpectedEnd)
+    );
+
+    let mut wrong_version = bytes.clone();
+    wrong_version[4] = 2;
+    assert_eq!(
+        Path::from_bytes(&wrong_version).err(),
+        Some(DecodeError::UnsupportedVersion(2))
+    );
+
+    let mut invalid_verb = bytes.clone();
+    let offset = bytes.len() - 1;
+    invalid_verb[offset] = 42;
+    assert_eq!(
+        Path::from_bytes(&invalid_verb).err(),
+        Some(DecodeError::InvalidVerb { offset })
+    );
+
+    // An edge outside of a sub-path.
+    let mut invalid_path = bytes.clone();
+    invalid_path[20 + path.points.len() * 8] = 1;
+    assert_eq!(
+        Path::from_bytes(&invalid_path).err(),
+        Some(DecodeError::InvalidPath)
+    );
+
+    // A different number of attributes doesn't match the number of points.
+    let mut invalid_path = bytes;
+    invalid_path[8] = 1;
+    assert_eq!(
+        Path::from_bytes(&invalid_path).err(),
+        Some(DecodeError::InvalidPath)
+    );
+}
+
+#[test]
+fn flattened_segments() {
+    let mut builder = Path::builder_with_attributes(1);
+    let a = builder.begin(point(0.0, 0.0), &[0.0]);
+    let b = builder.line_to(point(10.0, 0.0), &[1.0]);
+    let c = builder.quadratic_bezier_to(point(20.0, 0.0), point(20.0, 10.0), &[2.0]);
+    let d = builder.cubic_bezier_to(
+        point(20.0, 20.0),
+        point(10.0, 20.0),
+        point(0.0, 10.0),
+        &[3.0],
+    );
+    builder.end(true);
+    let path = builder.build();
+
+    let segments: Vec<FlattenedSegment> = path.flattened_segments(0.01).collect();
+
+    assert_eq!(
+        segments[0],
+        FlattenedSegment {
+            segment: LineSegment {
+                from: point(0.0, 0.0),
+                to: point(10.0, 0.0),
+            },
+            from: a,
+            to: b,
+            t: 0.0..1.0,
+        }
+    );
+    assert_eq!(
+        *segments.last().unwrap(),
+        FlattenedSegment {
+            segment: LineSegment {
+                from: point(0.0, 10.0),
+                to: point(0.0, 0.0),
+            },
+            from: d,
+            to: a,
+            t: 0.0..1.0,
+        }
+    );
+
+    // The segments are connected and the curve parameters cover the edges.
+    for pair in segments.windows(2) {
+        assert_eq!(pair[0].segment.to, pair[1].segment.from);
+        if (pair[0].from, pair[0].to) == (pair[1].from, pair[1].to) {
+            assert_eq!(pair[0].t.end, pair[1].t.start);
+        } else {
+            assert_eq!(pair[0].t.end, 1.0);
+            assert_eq!(pair[1].t.start, 0.0);
+        }
+    }
+
+    let quadratic: Vec<&FlattenedSegment> = segments
+        .iter()
+        .filter(|s| (s.from, s.to) == (b, c))
+        .collect();
+    let cubic: Vec<&FlattenedSegment> = segments
+        .iter()
+        .filter(|s| (s.from, s.to) == (c, d))
+        .collect();
+    assert!(quadratic.len() > 1);
+    assert!(cubic.len() > 1);
+    assert_eq!(quadratic.len() + cubic.len() + 2, segments.len());
+    assert_eq!(cubic.last().unwrap().segment.to, point(0.0, 10.0));
+
+    // Sub-paths that are explicitly closed don't produce an empty closing segment.
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.0));
+    builder.line_to(point(0.0, 0.0));
+    builder.end(true);
+    builder.begin(point(5.0, 0.0));
+    builder.line_to(point(6.0, 0.0));
+    builder.end(false);
+    let path = builder.build();
+    assert_eq!(path.flattened_segments(0.1).count(), 3);
+}
+
+#[test]
+fn to_polygons() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.0));
+    builder.line_to(point(1.0, 1.0));
+    builder.close();
+    builder.begin(point(10.0, 0.0));
+    builder.quadratic_bezier_to(point(11.0, 0.0), point(11.0, 1.0));
+    builder.end(false);
+    builder.add_point(point(5.0, 5.0));
+    let path = builder.build();
+
+    let polygons = path.to_polygons(0.01);
+    assert_eq!(polygons.len(), 3);
+
+    assert_eq!(
+        polygons[0],
+        (
+            vec![point(0.0, 0.0), point(1.0, 0.0), point(1.0, 1.0)],
+            true
+        )
+    );
+
+    let (curve, closed) = &polygons[1];
+    assert!(!closed);
+    assert!(curve.len() > 2);
+    assert_eq!(curve[0], point(10.0, 0.0));
+    assert_eq!(*curve.last().unwrap(), point(11.0, 1.0));
+
+    assert_eq!(polygons[2], (vec![point(5.0, 5.0)], false));
+
+    assert!(Path::new().to_polygons(0.01).is_empty());
+}
+
+#[test]
+fn from_events() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.0));
+    builder.quadratic_bezier_to(point(2.0, 0.0), point(2.0, 1.0));
+    builder.cubic_bezier_to(point(2.0, 2.0), point(1.0, 2.0), point(0.0, 2.0));
+    builder.close();
+    builder.begin(point(10.0, 0.0));
+    builder.cubic_bezier_to(point(11.0, 0.0), point(12.0, 1.0), point(12.0, 2.0));
+    builder.end(false);
+    builder.begin(point(20.0, 0.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let events: Vec<PathEvent> = path.iter().collect();
+    let rebuilt = Path::from_events(&events);
+
+    assert_eq!(rebuilt.points, path.points);
+    assert_eq!(rebuilt.verbs, path.verbs);
+    assert_eq!(rebuilt.capacity(), (path.points.len(), path.verbs.len()));
+    assert_eq!(rebuilt.iter().collect::<Vec<_>>(), events);
+
+    assert!(Path::from_events(&[]).as_slice().is_empty());
+}
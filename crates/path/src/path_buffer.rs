@@ -1,6 +1,7 @@
 //! A container to store multiple paths contiguously.
 
 use crate::builder::*;
+use crate::geom::traits::Transformation;
 use crate::math::*;
 use crate::path;
 use crate::{Attributes, EndpointId, PathSlice, NO_ATTRIBUTES};
@@ -90,6 +91,61 @@ impl PathBuffer {
         Builder::new(self)
     }
 
+    /// Appends a copy of an existing path to the buffer and returns its index.
+    ///
+    /// All paths in the buffer must have the same number of custom attributes. An
+    /// error is returned if it is not the case.
+    pub fn push_path(&mut self, path: &PathSlice) -> Result<usize, AttributeCountMismatch> {
+        self.push_path_impl(path, |_| {})
+    }
+
+    /// Appends a transformed copy of an existing path to the buffer and returns its index.
+    ///
+    /// The transform is applied to endpoints and control points. Custom attributes are
+    /// copied unchanged.
+    ///
+    /// All paths in the buffer must have the same number of custom attributes. An
+    /// error is returned if it is not the case.
+    pub fn push_path_transformed<T: Transformation<f32>>(
+        &mut self,
+        path: &PathSlice,
+        transform: &T,
+    ) -> Result<usize, AttributeCountMismatch> {
+        self.push_path_impl(path, |points| {
+            path::transform_points(points, path.verbs, path.num_attributes, transform);
+        })
+    }
+
+    fn push_path_impl(
+        &mut self,
+        path: &PathSlice,
+        transform: impl FnOnce(&mut [Point]),
+    ) -> Result<usize, AttributeCountMismatch> {
+        if let Some(desc) = self.paths.first() {
+            if desc.num_attributes as usize != path.num_attributes {
+                return Err(AttributeCountMismatch {
+                    expected: desc.num_attributes as usize,
+                    actual: path.num_attributes,
+                });
+            }
+        }
+
+        let points_start = self.points.len();
+        let verbs_start = self.verbs.len();
+        self.points.extend_from_slice(path.points);
+        self.verbs.extend_from_slice(path.verbs);
+        transform(&mut self.points[points_start..]);
+
+        let index = self.paths.len();
+        self.paths.push(PathDescriptor {
+            points: (points_start as u32, self.points.len() as u32),
+            verbs: (verbs_start as u32, self.verbs.len() as u32),
+            num_attributes: path.num_attributes as u32,
+        });
+
+        Ok(index)
+    }
+
     #[inline]
     pub fn clear(&mut self) {
         self.points.clear();
@@ -127,6 +183,31 @@ impl<'l> FromIterator<PathSlice<'l>> for PathBuffer {
     }
 }
 
+/// The error returned when appending a path to a `PathBuffer` that contains paths
+/// with a different number of custom attributes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AttributeCountMismatch {
+    /// The number of custom attributes of the paths in the buffer.
+    pub expected: usize,
+    /// The number of custom attributes of the appended path.
+    pub actual: usize,
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for AttributeCountMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        std::write!(
+            f,
+            "Expected {} custom attributes, got {}",
+            self.expected,
+            self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AttributeCountMismatch {}
+
 /// A view on a `PathBuffer`.
 #[derive(Clone)]
 pub struct PathBufferSlice<'l> {
@@ -337,6 +418,11 @@ impl<'l> PathBuilder for Builder<'l> {
     fn reserve(&mut self, endpoints: usize, ctrl_points: usize) {
         self.reserve(endpoints, ctrl_points);
     }
+
+    #[inline]
+    fn last_ctrl(&self) -> Option<Point> {
+        self.builder.last_ctrl()
+    }
 }
 
 impl<'l> Build for Builder<'l> {
@@ -385,7 +471,7 @@ impl<'l> BuilderWithAttributes<'l> {
         self.buffer.paths.push(PathDescriptor {
             points: (self.points_start, points_end),
             verbs: (self.verbs_start, verbs_end),
-            num_attributes: 0,
+            num_attributes: self.builder.num_attributes as u32,
         });
 
         index
@@ -490,6 +576,11 @@ impl<'l> PathBuilder for BuilderWithAttributes<'l> {
     fn reserve(&mut self, endpoints: usize, ctrl_points: usize) {
         self.reserve(endpoints, ctrl_points);
     }
+
+    #[inline]
+    fn last_ctrl(&self) -> Option<Point> {
+        self.builder.last_ctrl()
+    }
 }
 
 impl<'l> Build for BuilderWithAttributes<'l> {
@@ -652,3 +743,61 @@ fn simple() {
     assert_eq!(buffer.get(p1)[a], point(0.0, 10.0));
     assert_eq!(buffer.get(p2)[b], point(0.0, 20.0));
 }
+
+#[test]
+fn push_path() {
+    use crate::geom::euclid::default::Transform2D;
+    use crate::Path;
+
+    let mut builder = Path::builder_with_attributes(1);
+    builder.begin(point(0.0, 0.0), &[1.0]);
+    builder.line_to(point(10.0, 0.0), &[2.0]);
+    builder.quadratic_bezier_to(point(10.0, 10.0), point(0.0, 10.0), &[3.0]);
+    builder.end(true);
+    let path = builder.build();
+
+    let mut buffer = PathBuffer::new();
+    let p1 = buffer.push_path(&path.as_slice()).unwrap();
+    let transform = Transform2D::translation(5.0, 5.0);
+    let p2 = buffer
+        .push_path_transformed(&path.as_slice(), &transform)
+        .unwrap();
+
+    assert_eq!(p1, 0);
+    assert_eq!(p2, 1);
+    assert_eq!(
+        buffer.get(p1).iter_with_attributes().collect::<Vec<_>>(),
+        path.iter_with_attributes().collect::<Vec<_>>()
+    );
+
+    let transformed = path.as_slice().transformed(&transform);
+    assert_eq!(
+        buffer.get(p2).iter_with_attributes().collect::<Vec<_>>(),
+        transformed.iter_with_attributes().collect::<Vec<_>>()
+    );
+    assert_eq!(buffer.get(p2).first_endpoint().unwrap().1, &[1.0]);
+
+    // Paths with a different number of attributes are rejected.
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 1.0));
+    builder.end(false);
+    let other = builder.build();
+
+    assert_eq!(
+        buffer.push_path(&other.as_slice()),
+        Err(AttributeCountMismatch {
+            expected: 1,
+            actual: 0
+        })
+    );
+    assert_eq!(buffer.len(), 2);
+
+    // Mixing with paths created by the buffer's builders.
+    let mut builder = buffer.builder().with_attributes(1);
+    builder.begin(point(0.0, 0.0), &[4.0]);
+    builder.end(false);
+    let p3 = builder.build();
+    assert_eq!(buffer.get(p3).num_attributes, 1);
+    assert_eq!(buffer.push_path(&path.as_slice()), Ok(3));
+}
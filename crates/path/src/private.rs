@@ -9,6 +9,8 @@ pub use crate::math::Point;
 pub use crate::traits::PathBuilder;
 pub use crate::{Attributes, EndpointId};
 
+pub use crate::dash::dash_into;
+
 #[derive(Default, Copy, Clone, Debug, PartialEq)]
 pub struct DebugValidator {
     #[cfg(debug_assertions)]
@@ -0,0 +1,411 @@
+//! Parsing of SVG path data.
+//!
+//! The full SVG path grammar is supported, including relative commands, implicit
+//! commands and elliptical arcs. The parser only reads the path data and forwards the
+//! commands to an `SvgPathBuilder`, which takes care of the special cases.
+//!
+//! # Example
+//!
+//! ```
+//! use lyon_path::Path;
+//!
+//! let path = Path::from_svg("M 0 0 L 10 0 a 5 5 0 0 1 0 10 z").unwrap();
+//! ```
+//!
+//! See <https://svgwg.org/svg2-draft/paths.html#PathDataBNF>.
+
+use crate::builder::SvgPathBuilder;
+use crate::geom::ArcFlags;
+use crate::math::{point, vector, Angle, Point, Vector};
+
+/// An error that can happen while parsing SVG path data.
+///
+/// Each variant holds the byte offset of the offending token in the source string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// A character that is not a valid command was found where a command was expected.
+    InvalidCommand { offset: usize },
+    /// The path data does not start with a move-to command.
+    MissingMoveTo { offset: usize },
+    /// A number was expected.
+    ExpectedNumber { offset: usize },
+    /// An arc flag (`0` or `1`) was expected.
+    ExpectedFlag { offset: usize },
+}
+
+impl ParseError {
+    /// Returns the byte offset of the offending token in the source string.
+    pub fn offset(&self) -> usize {
+        match *self {
+            ParseError::InvalidCommand { offset }
+            | ParseError::MissingMoveTo { offset }
+            | ParseError::ExpectedNumber { offset }
+            | ParseError::ExpectedFlag { offset } => offset,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseError::InvalidCommand { offset } => {
+                std::write!(f, "Invalid command at offset {offset}")
+            }
+            ParseError::MissingMoveTo { offset } => {
+                std::write!(f, "Expected move-to command at offset {offset}")
+            }
+            ParseError::ExpectedNumber { offset } => {
+                std::write!(f, "Expected number at offset {offset}")
+            }
+            ParseError::ExpectedFlag { offset } => {
+                std::write!(f, "Expected flag (0/1) at offset {offset}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Parses SVG path data and forwards the commands to an `SvgPathBuilder`.
+///
+/// Commands that were read before an error happened are forwarded to the builder.
+pub fn parse_path_data<Builder: SvgPathBuilder>(
+    data: &str,
+    builder: &mut Builder,
+) -> Result<(), ParseError> {
+    let mut src = Source {
+        bytes: data.as_bytes(),
+        offset: 0,
+    };
+
+    src.skip_whitespace();
+
+    let mut prev_cmd = None;
+    while let Some(c) = src.peek() {
+        let cmd_offset = src.offset;
+        let cmd = if c.is_ascii_alphabetic() {
+            src.offset += 1;
+            c
+        } else {
+            // Repeated commands can be omitted, except after a close-path command.
+            // Coordinates following a move-to are implicit line-to commands.
+            match prev_cmd {
+                Some(b'M') => b'L',
+                Some(b'm') => b'l',
+                Some(b'Z') | Some(b'z') | None => {
+                    return Err(ParseError::InvalidCommand { offset: cmd_offset })
+                }
+                Some(cmd) => cmd,
+            }
+        };
+
+        if prev_cmd.is_none() && cmd != b'M' && cmd != b'm' {
+            return Err(ParseError::MissingMoveTo { offset: cmd_offset });
+        }
+
+        match cmd {
+            b'M' => builder.move_to(src.point()?),
+            b'm' => builder.relative_move_to(src.vector()?),
+            b'L' => builder.line_to(src.point()?),
+            b'l' => builder.relative_line_to(src.vector()?),
+            b'H' => builder.horizontal_line_to(src.number()?),
+            b'h' => builder.relative_horizontal_line_to(src.number()?),
+            b'V' => builder.vertical_line_to(src.number()?),
+            b'v' => builder.relative_vertical_line_to(src.number()?),
+            b'Q' => {
+                let ctrl = src.point()?;
+                let to = src.point()?;
+                builder.quadratic_bezier_to(ctrl, to);
+            }
+            b'q' => {
+                let ctrl = src.vector()?;
+                let to = src.vector()?;
+                builder.relative_quadratic_bezier_to(ctrl, to);
+            }
+            b'T' => builder.smooth_quadratic_bezier_to(src.point()?),
+            b't' => builder.smooth_relative_quadratic_bezier_to(src.vector()?),
+            b'C' => {
+                let ctrl1 = src.point()?;
+                let ctrl2 = src.point()?;
+                let to = src.point()?;
+                builder.cubic_bezier_to(ctrl1, ctrl2, to);
+            }
+            b'c' => {
+                let ctrl1 = src.vector()?;
+                let ctrl2 = src.vector()?;
+                let to = src.vector()?;
+                builder.relative_cubic_bezier_to(ctrl1, ctrl2, to);
+            }
+            b'S' => {
+                let ctrl2 = src.point()?;
+                let to = src.point()?;
+                builder.smooth_cubic_bezier_to(ctrl2, to);
+            }
+            b's' => {
+                let ctrl2 = src.vector()?;
+                let to = src.vector()?;
+                builder.smooth_relative_cubic_bezier_to(ctrl2, to);
+            }
+            b'A' | b'a' => {
+                let radii = src.vector()?;
+                let x_rotation = Angle::degrees(src.number()?);
+                let large_arc = src.flag()?;
+                let sweep = src.flag()?;
+                let flags = ArcFlags { large_arc, sweep };
+                if cmd == b'A' {
+                    builder.arc_to(radii, x_rotation, flags, src.point()?);
+                } else {
+                    builder.relative_arc_to(radii, x_rotation, flags, src.vector()?);
+                }
+            }
+            b'Z' | b'z' => builder.close(),
+            _ => {
+                return Err(ParseError::InvalidCommand { offset: cmd_offset });
+            }
+        }
+
+        prev_cmd = Some(cmd);
+
+        src.skip_whitespace();
+    }
+
+    Ok(())
+}
+
+struct Source<'l> {
+    bytes: &'l [u8],
+    offset: usize,
+}
+
+impl<'l> Source<'l> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.offset).cloned()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C') = self.peek() {
+            self.offset += 1;
+        }
+    }
+
+    // Skips whitespace and at most one comma.
+    fn skip_separator(&mut self) {
+        self.skip_whitespace();
+        if self.peek() == Some(b',') {
+            self.offset += 1;
+            self.skip_whitespace();
+        }
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.offset;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.offset += 1;
+        }
+
+        self.offset - start
+    }
+
+    fn number(&mut self) -> Result<f32, ParseError> {
+        self.skip_separator();
+
+        let start = self.offset;
+        if let Some(b'+' | b'-') = self.peek() {
+            self.offset += 1;
+        }
+
+        let mut num_digits = self.skip_digits();
+        if self.peek() == Some(b'.') {
+            self.offset += 1;
+            num_digits += self.skip_digits();
+        }
+
+        if num_digits == 0 {
+            return Err(ParseError::ExpectedNumber { offset: start });
+        }
+
+        // Only consume the exponent if it is well-formed, so that something like "1e"
+        // is read as the number 1 followed by an invalid command.
+        if let Some(b'e' | b'E') = self.peek() {
+            let mantissa_end = self.offset;
+            self.offset += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.offset += 1;
+            }
+            if self.skip_digits() == 0 {
+                self.offset = mantissa_end;
+            }
+        }
+
+        // The slice only contains ASCII characters.
+        let text = core::str::from_utf8(&self.bytes[start..self.offset]).unwrap();
+        text.parse::<f32>()
+            .map_err(|_| ParseError::ExpectedNumber { offset: start })
+    }
+
+    fn flag(&mut self) -> Result<bool, ParseError> {
+        self.skip_separator();
+
+        let result = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => {
+                return Err(ParseError::ExpectedFlag {
+                    offset: self.offset,
+                })
+            }
+        };
+        self.offset += 1;
+
+        Ok(result)
+    }
+
+    fn point(&mut self) -> Result<Point, ParseError> {
+        let x = self.number()?;
+        let y = self.number()?;
+
+        Ok(point(x, y))
+    }
+
+    fn vector(&mut self) -> Result<Vector, ParseError> {
+        let x = self.number()?;
+        let y = self.number()?;
+
+        Ok(vector(x, y))
+    }
+}
+
+#[cfg(test)]
+use crate::Path;
+
+#[cfg(test)]
+use alloc::vec::Vec;
+
+#[cfg(test)]
+fn assert_same_path(data: &str, expected: &Path) {
+    let path = Path::from_svg(data).unwrap();
+    let a: Vec<_> = path.iter().collect();
+    let b: Vec<_> = expected.iter().collect();
+    assert_eq!(a, b, "{:?}", data);
+}
+
+#[test]
+fn parse_simple() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.line_to(point(10.0, 10.0));
+    builder.line_to(point(0.0, 10.0));
+    builder.close();
+    let expected = builder.build();
+
+    assert_same_path("M 0 0 L 10 0 L 10 10 L 0 10 Z", &expected);
+    assert_same_path("M0,0L10,0L10,10L0,10Z", &expected);
+    assert_same_path("M 0 0 10 0 10 10 0 10 z", &expected);
+    assert_same_path("m 0 0 10 0 0 10 -10 0 z", &expected);
+    assert_same_path("M0 0H10V10H0Z", &expected);
+    assert_same_path("M0 0h10v10h-10z", &expected);
+    assert_same_path("\n\tM 0 , 0 L 1e1 0 L 10 10 L 0.0 +1E+1 Z ", &expected);
+
+    assert!(Path::from_svg("").unwrap().iter().next().is_none());
+    assert!(Path::from_svg("  ").unwrap().iter().next().is_none());
+}
+
+#[test]
+fn parse_curves() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.quadratic_bezier_to(point(10.0, 0.0), point(10.0, 10.0));
+    builder.quadratic_bezier_to(point(10.0, 20.0), point(0.0, 20.0));
+    builder.cubic_bezier_to(point(-5.0, 20.0), point(-10.0, 15.0), point(-10.0, 10.0));
+    builder.cubic_bezier_to(point(-10.0, 5.0), point(-5.0, 0.0), point(0.0, 0.0));
+    builder.end(false);
+    let expected = builder.build();
+
+    assert_same_path(
+        "M 0 0 Q 10 0 10 10 T 0 20 C -5 20 -10 15 -10 10 S -5 0 0 0",
+        &expected,
+    );
+    assert_same_path(
+        "M0 0q10 0 10 10t-10 10c-5 0-10-5-10-10s5-10 10-10",
+        &expected,
+    );
+    assert_same_path(
+        "M0 0Q10 0 10 10 10 20 0 20C-5 20-10 15-10 10-10 5-5 0 0 0",
+        &expected,
+    );
+}
+
+#[test]
+fn parse_arcs() {
+    let mut builder = Path::svg_builder();
+    builder.move_to(point(0.0, 0.0));
+    builder.arc_to(
+        vector(5.0, 5.0),
+        Angle::degrees(30.0),
+        ArcFlags {
+            large_arc: false,
+            sweep: true,
+        },
+        point(10.0, 0.0),
+    );
+    builder.relative_arc_to(
+        vector(5.0, 10.0),
+        Angle::degrees(0.0),
+        ArcFlags {
+            large_arc: true,
+            sweep: false,
+        },
+        vector(0.0, 10.0),
+    );
+    let expected = builder.build();
+
+    assert_same_path("M 0 0 A 5 5 30 0 1 10 0 a 5 10 0 1 0 0 10", &expected);
+    // Flags don't need separators.
+    assert_same_path("M0 0A5 5 30 0110 0a5 10 0 1,0 0 10", &expected);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        Path::from_svg("M 0 0 L 1 0 X 2 2").err(),
+        Some(ParseError::InvalidCommand { offset: 12 })
+    );
+    assert_eq!(
+        Path::from_svg("L 0 0").err(),
+        Some(ParseError::MissingMoveTo { offset: 0 })
+    );
+    assert_eq!(
+        Path::from_svg("M 0 0 L 1").err(),
+        Some(ParseError::ExpectedNumber { offset: 9 })
+    );
+    assert_eq!(
+        Path::from_svg("M 0 0 L 1 -").err(),
+        Some(ParseError::ExpectedNumber { offset: 10 })
+    );
+    assert_eq!(
+        Path::from_svg("M 0 0 A 5 5 0 2 1 10 0").err(),
+        Some(ParseError::ExpectedFlag { offset: 14 })
+    );
+    // Implicit commands aren't allowed after a close-path command.
+    assert_eq!(
+        Path::from_svg("M 0 0 L 1 1 Z 2 2").err(),
+        Some(ParseError::InvalidCommand { offset: 14 })
+    );
+    assert_eq!(Path::from_svg("M 0 0 é").unwrap_err().offset(), 6);
+}
+
+#[test]
+fn round_trip() {
+    use crate::builder::{PathBuilder, SvgPathWriter};
+    use alloc::string::String;
+
+    let data = "M 0 0 L 10 0 Q 20 0 20 10 C 20 15 15 20 10 20 Z M -1.5 2 L 3 4";
+
+    let mut writer = SvgPathWriter::new(String::new()).with_svg();
+    parse_path_data(data, &mut writer).unwrap();
+
+    assert_eq!(writer.build().unwrap(), data);
+}
@@ -1,40 +1,259 @@
 use crate::event_queue::{EventQueue, INVALID_EVENT_ID};
+use crate::geom::LineSegment;
 use crate::math::*;
+use crate::path::Winding;
 use crate::{
     FillGeometryBuilder, FillOptions, FillVertex, TessellationError, TessellationResult, VertexId,
 };
 
+use alloc::vec::Vec;
+
 use core::f32::consts::PI;
 
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
 
 pub fn fill_rectangle(rect: &Box2D, output: &mut dyn FillGeometryBuilder) -> TessellationResult {
-    output.begin_geometry();
+    generate(output, |output| {
+        let dummy_queue = EventQueue::new();
+
+        let vertex = &mut |position| {
+            output.add_fill_vertex(FillVertex {
+                position,
+                events: &dummy_queue,
+                current_event: INVALID_EVENT_ID,
+                attrib_store: None,
+                attrib_buffer: &mut [],
+                coverage: 1.0,
+                bounds: *rect,
+            })
+        };
+
+        let a = vertex(rect.min)?;
+        let b = vertex(bottom_left(rect))?;
+        let c = vertex(bottom_right(rect))?;
+        let d = vertex(top_right(rect))?;
+
+        output.add_triangle(a, b, c);
+        output.add_triangle(a, c, d);
+
+        Ok(())
+    })
+}
 
-    let dummy_queue = EventQueue::new();
+pub fn fill_convex_polygon(
+    points: &[Point],
+    output: &mut dyn FillGeometryBuilder,
+) -> TessellationResult {
+    debug_assert!(is_convex(points), "The polygon is not convex");
 
-    let vertex = &mut |position| {
-        output.add_fill_vertex(FillVertex {
-            position,
+    if points.len() < 3 {
+        return Ok(());
+    }
+
+    generate(output, |output| {
+        let dummy_queue = EventQueue::new();
+        let bounds = Box2D::from_points(points);
+
+        let mut area = 0.0;
+        for i in 2..points.len() {
+            area += (points[i - 1] - points[0]).cross(points[i] - points[0]);
+        }
+
+        let first = output.add_fill_vertex(FillVertex {
+            position: points[0],
             events: &dummy_queue,
             current_event: INVALID_EVENT_ID,
             attrib_store: None,
             attrib_buffer: &mut [],
-        })
-    };
+            coverage: 1.0,
+            bounds,
+        })?;
+
+        let mut prev = None;
+        for position in &points[1..] {
+            let vertex = output.add_fill_vertex(FillVertex {
+                position: *position,
+                events: &dummy_queue,
+                current_event: INVALID_EVENT_ID,
+                attrib_store: None,
+                attrib_buffer: &mut [],
+                coverage: 1.0,
+                bounds,
+            })?;
+
+            // Emit the triangles with the same orientation as the fill tessellator.
+            if let Some(prev) = prev {
+                if area > 0.0 {
+                    output.add_triangle(first, vertex, prev);
+                } else {
+                    output.add_triangle(first, prev, vertex);
+                }
+            }
+            prev = Some(vertex);
+        }
+
+        Ok(())
+    })
+}
 
-    let a = vertex(rect.min)?;
-    let b = vertex(bottom_left(rect))?;
-    let c = vertex(bottom_right(rect))?;
-    let d = vertex(top_right(rect))?;
+pub fn fill_simple_polygon(
+    points: &[Point],
+    winding: Winding,
+    output: &mut dyn FillGeometryBuilder,
+) -> TessellationResult {
+    debug_assert!(is_simple(points), "The polygon is not simple");
 
-    output.add_triangle(a, b, c);
-    output.add_triangle(a, c, d);
+    let n = points.len();
+    if n < 3 {
+        return Ok(());
+    }
 
-    output.end_geometry();
+    generate(output, |output| {
+        let dummy_queue = EventQueue::new();
+        let bounds = Box2D::from_points(points);
+
+        let mut ids = Vec::with_capacity(n);
+        for position in points {
+            ids.push(output.add_fill_vertex(FillVertex {
+                position: *position,
+                events: &dummy_queue,
+                current_event: INVALID_EVENT_ID,
+                attrib_store: None,
+                attrib_buffer: &mut [],
+                coverage: 1.0,
+                bounds,
+            })?);
+        }
 
-    Ok(())
+        // Positive if a turn is convex.
+        let sign = match winding {
+            Winding::Positive => 1.0,
+            Winding::Negative => -1.0,
+        };
+        let turn = |a: usize, b: usize, c: usize| {
+            (points[b] - points[a]).cross(points[c] - points[b]) * sign
+        };
+
+        // The remaining vertices form a doubly linked list.
+        let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+        let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+
+        let mut remaining = n;
+        let mut current = 0;
+        // Number of vertices visited since the last ear was clipped.
+        let mut visited = 0;
+        while remaining > 2 {
+            let a = prev[current];
+            let b = current;
+            let c = next[current];
+
+            let is_convex = turn(a, b, c) > 0.0;
+            let is_ear =
+                is_convex && !triangle_contains_reflex_vertex(points, &next, a, b, c, &turn);
+
+            // If no ear can be found (which can only happen with degenerate or
+            // non-simple input), clip the vertex anyway to guarantee progress.
+            if is_ear || visited > remaining {
+                if is_convex {
+                    // Emit the triangles with the same orientation as the fill tessellator.
+                    match winding {
+                        Winding::Positive => output.add_triangle(ids[a], ids[c], ids[b]),
+                        Winding::Negative => output.add_triangle(ids[a], ids[b], ids[c]),
+                    }
+                }
+
+                next[a] = c;
+                prev[c] = a;
+                remaining -= 1;
+                visited = 0;
+                current = a;
+            } else {
+                visited += 1;
+                current = c;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+// Whether a non-convex vertex of the polygon other than the triangle's is in the
+// triangle abc (including its boundary).
+fn triangle_contains_reflex_vertex(
+    points: &[Point],
+    next: &[usize],
+    a: usize,
+    b: usize,
+    c: usize,
+    turn: &dyn Fn(usize, usize, usize) -> f32,
+) -> bool {
+    let (pa, pb, pc) = (points[a], points[b], points[c]);
+    let mut prev = c;
+    let mut current = next[c];
+    while current != a {
+        let following = next[current];
+        let p = points[current];
+        if turn(prev, current, following) <= 0.0 && p != pa && p != pb && p != pc {
+            let inside = turn(a, b, current) >= 0.0
+                && turn(b, c, current) >= 0.0
+                && turn(c, a, current) >= 0.0;
+            if inside {
+                return true;
+            }
+        }
+        prev = current;
+        current = following;
+    }
+
+    false
+}
+
+// Returns true if no two non-adjacent edges of the polygon intersect.
+fn is_simple(points: &[Point]) -> bool {
+    let n = points.len();
+    for i in 0..n {
+        let e1 = LineSegment {
+            from: points[i],
+            to: points[(i + 1) % n],
+        };
+        for j in (i + 2)..n {
+            if i == 0 && j == n - 1 {
+                continue;
+            }
+            let e2 = LineSegment {
+                from: points[j],
+                to: points[(j + 1) % n],
+            };
+            if e1.intersects(&e2) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+// Returns true if all turns of the polygon are in the same direction. Aligned points
+// are allowed.
+fn is_convex(points: &[Point]) -> bool {
+    let n = points.len();
+    let mut sign = 0.0;
+    for i in 0..n {
+        let a = points[i];
+        let b = points[(i + 1) % n];
+        let c = points[(i + 2) % n];
+        let cross = (b - a).cross(c - b);
+        if cross == 0.0 {
+            continue;
+        }
+        if cross * sign < 0.0 {
+            return false;
+        }
+        sign = cross.signum();
+    }
+
+    true
 }
 
 pub fn fill_circle(
@@ -48,74 +267,99 @@ pub fn fill_circle(
         return Ok(());
     }
 
+    generate(output, |output| {
+        let up = vector(0.0, -1.0);
+        let down = vector(0.0, 1.0);
+        let left = vector(-1.0, 0.0);
+        let right = vector(1.0, 0.0);
+
+        let events = &EventQueue::new();
+        let attrib_store = None;
+        let bounds = circle_bounds(center, radius);
+        let current_event = INVALID_EVENT_ID;
+
+        let v = [
+            output.add_fill_vertex(FillVertex {
+                position: center + (left * radius),
+                events,
+                current_event,
+                attrib_store,
+                attrib_buffer: &mut [],
+                coverage: 1.0,
+                bounds,
+            })?,
+            output.add_fill_vertex(FillVertex {
+                position: center + (up * radius),
+                events,
+                current_event,
+                attrib_store,
+                attrib_buffer: &mut [],
+                coverage: 1.0,
+                bounds,
+            })?,
+            output.add_fill_vertex(FillVertex {
+                position: center + (right * radius),
+                events,
+                current_event,
+                attrib_store,
+                attrib_buffer: &mut [],
+                coverage: 1.0,
+                bounds,
+            })?,
+            output.add_fill_vertex(FillVertex {
+                position: center + (down * radius),
+                events,
+                current_event,
+                attrib_store,
+                attrib_buffer: &mut [],
+                coverage: 1.0,
+                bounds,
+            })?,
+        ];
+
+        output.add_triangle(v[0], v[3], v[1]);
+        output.add_triangle(v[1], v[3], v[2]);
+
+        let angles = [
+            (PI, 1.5 * PI),
+            (1.5 * PI, 2.0 * PI),
+            (0.0, PI * 0.5),
+            (PI * 0.5, PI),
+        ];
+
+        let arc_len = 0.5 * PI * radius;
+        let step = circle_flattening_step(radius, options.tolerance);
+        let num_segments = (arc_len / step).ceil();
+        let num_recursions = num_segments.log2() as u32;
+
+        for i in 0..4 {
+            fill_border_radius(
+                center,
+                angles[i],
+                radius,
+                v[i],
+                v[(i + 1) % 4],
+                num_recursions,
+                events,
+                output,
+            )?;
+        }
+
+        Ok(())
+    })
+}
+
+// Generates the geometry between calls to `begin_geometry` and `end_geometry`, or
+// `abort_geometry` if an error occurs so that the partial geometry is discarded.
+fn generate(
+    output: &mut dyn FillGeometryBuilder,
+    callback: impl FnOnce(&mut dyn FillGeometryBuilder) -> TessellationResult,
+) -> TessellationResult {
     output.begin_geometry();
 
-    let up = vector(0.0, -1.0);
-    let down = vector(0.0, 1.0);
-    let left = vector(-1.0, 0.0);
-    let right = vector(1.0, 0.0);
-
-    let events = &EventQueue::new();
-    let attrib_store = None;
-    let current_event = INVALID_EVENT_ID;
-
-    let v = [
-        output.add_fill_vertex(FillVertex {
-            position: center + (left * radius),
-            events,
-            current_event,
-            attrib_store,
-            attrib_buffer: &mut [],
-        })?,
-        output.add_fill_vertex(FillVertex {
-            position: center + (up * radius),
-            events,
-            current_event,
-            attrib_store,
-            attrib_buffer: &mut [],
-        })?,
-        output.add_fill_vertex(FillVertex {
-            position: center + (right * radius),
-            events,
-            current_event,
-            attrib_store,
-            attrib_buffer: &mut [],
-        })?,
-        output.add_fill_vertex(FillVertex {
-            position: center + (down * radius),
-            events,
-            current_event,
-            attrib_store,
-            attrib_buffer: &mut [],
-        })?,
-    ];
-
-    output.add_triangle(v[0], v[3], v[1]);
-    output.add_triangle(v[1], v[3], v[2]);
-
-    let angles = [
-        (PI, 1.5 * PI),
-        (1.5 * PI, 2.0 * PI),
-        (0.0, PI * 0.5),
-        (PI * 0.5, PI),
-    ];
-
-    let arc_len = 0.5 * PI * radius;
-    let step = circle_flattening_step(radius, options.tolerance);
-    let num_segments = (arc_len / step).ceil();
-    let num_recursions = num_segments.log2() as u32;
-
-    for i in 0..4 {
-        fill_border_radius(
-            center,
-            angles[i],
-            radius,
-            v[i],
-            v[(i + 1) % 4],
-            num_recursions,
-            events,
-            output,
-        )?;
+    if let Err(e) = callback(output) {
+        output.abort_geometry();
+        return Err(e);
     }
 
     output.end_geometry();
@@ -154,6 +398,13 @@ pub(crate) fn circle_flattening_step(radius: f32, mut tolerance: f32) -> f32 {
     2.0 * f32::sqrt(2.0 * tolerance * radius - tolerance * tolerance)
 }
 
+fn circle_bounds(center: Point, radius: f32) -> Box2D {
+    Box2D {
+        min: center - vector(radius, radius),
+        max: center + vector(radius, radius),
+    }
+}
+
 // recursively tessellate the rounded corners.
 fn fill_border_radius(
     center: Point,
@@ -180,6 +431,8 @@ fn fill_border_radius(
         current_event: INVALID_EVENT_ID,
         attrib_store: None,
         attrib_buffer: &mut [],
+        coverage: 1.0,
+        bounds: circle_bounds(center, radius),
     })?;
 
     output.add_triangle(vb, vertex, va);
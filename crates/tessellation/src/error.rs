@@ -113,6 +113,11 @@ impl core::convert::From<InternalError> for TessellationError {
 pub enum UnsupportedParamater {
     PositionIsNaN,
     ToleranceIsNaN,
+    /// A sub-path was not closed while `FillOptions::allow_open_sub_paths` is `false`.
+    OpenSubPath,
+    /// An intersection required a new vertex while `FillOptions::allow_steiner_points`
+    /// is `false`.
+    Intersection,
 }
 
 #[cfg(feature = "std")]
@@ -125,6 +130,12 @@ impl core::fmt::Display for UnsupportedParamater {
             UnsupportedParamater::ToleranceIsNaN => {
                 std::write!(f, "Tolerance threshold is not a number")
             },
+            UnsupportedParamater::OpenSubPath => {
+                std::write!(f, "Sub-path is not closed")
+            },
+            UnsupportedParamater::Intersection => {
+                std::write!(f, "Intersection requires a new vertex")
+            },
         }
     }
 }
@@ -1,12 +1,12 @@
 use crate::fill::{compare_positions, is_after};
 use crate::geom::{CubicBezierSegment, LineSegment, QuadraticBezierSegment};
-use crate::math::{point, Point};
+use crate::math::{point, Box2D, Point};
 use crate::path::private::DebugValidator;
 use crate::path::{EndpointId, IdEvent, PathEvent, PositionStore};
 use crate::Orientation;
 
 use core::cmp::Ordering;
-use core::mem::swap;
+use core::mem::{self, swap};
 use core::ops::Range;
 use alloc::vec::Vec;
 
@@ -42,6 +42,8 @@ pub struct EventQueue {
     pub(crate) edge_data: Vec<EdgeData>,
     first: TessEventId,
     sorted: bool,
+    // Whether a sub-path ended away from its first point without being closed.
+    pub(crate) has_open_sub_paths: bool,
 }
 
 impl Default for EventQueue {
@@ -57,6 +59,7 @@ impl EventQueue {
             edge_data: Vec::new(),
             first: INVALID_EVENT_ID,
             sorted: false,
+            has_open_sub_paths: false,
         }
     }
 
@@ -66,6 +69,7 @@ impl EventQueue {
             edge_data: Vec::with_capacity(cap),
             first: 0,
             sorted: false,
+            has_open_sub_paths: false,
         }
     }
 
@@ -74,6 +78,7 @@ impl EventQueue {
         self.edge_data.clear();
         self.first = INVALID_EVENT_ID;
         self.sorted = false;
+        self.has_open_sub_paths = false;
     }
 
     /// Creates an `EventQueue` from an iterator of path event and a tolerance threshold.
@@ -124,6 +129,9 @@ impl EventQueue {
             tolerance,
             prev_endpoint_id: EndpointId(u32::MAX),
             validator: DebugValidator::new(),
+            clip_rect: None,
+            unclipped_current: point(f32::NAN, f32::NAN),
+            flattened: Vec::new(),
         }
     }
 
@@ -463,6 +471,12 @@ pub struct EventQueueBuilder {
     tolerance: f32,
     prev_endpoint_id: EndpointId,
     validator: DebugValidator,
+    // Edges are clipped to this rectangle (in the sweep orientation), if any.
+    clip_rect: Option<Box2D>,
+    // The current position before clipping.
+    unclipped_current: Point,
+    // Scratch buffer for the flattened curves when clipping.
+    flattened: Vec<(LineSegment<f32>, Range<f32>)>,
 }
 
 impl EventQueueBuilder {
@@ -478,6 +492,30 @@ impl EventQueueBuilder {
         self.tolerance = tolerance;
     }
 
+    /// Clips the edges to a rectangle.
+    ///
+    /// The parts of the edges outside of the rectangle are projected onto its sides,
+    /// which doesn't change the winding number of the points inside of the rectangle.
+    pub(crate) fn set_clip_rect(&mut self, rect: Option<Box2D>, sweep_orientation: Orientation) {
+        self.clip_rect = rect.map(|rect| match sweep_orientation {
+            Orientation::Vertical => rect,
+            Orientation::Horizontal => Box2D {
+                min: reorient(point(rect.min.x, rect.max.y)),
+                max: reorient(point(rect.max.x, rect.min.y)),
+            },
+        });
+    }
+
+    fn clip(&self, p: Point) -> Point {
+        match self.clip_rect {
+            Some(rect) => point(
+                p.x.max(rect.min.x).min(rect.max.x),
+                p.y.max(rect.min.y).min(rect.max.y),
+            ),
+            None => p,
+        }
+    }
+
     pub fn build(mut self) -> EventQueue {
         self.validator.build();
 
@@ -491,31 +529,62 @@ impl EventQueueBuilder {
         tolerance: f32,
         sweep_orientation: Orientation,
         path: impl IntoIterator<Item = PathEvent>,
+    ) {
+        self.set_path_impl(tolerance, sweep_orientation, path, false);
+    }
+
+    /// Same as `set_path`, but numbers the endpoints in the order they appear in the
+    /// events: the position of each `Begin` event and the destination of each segment.
+    pub(crate) fn set_path_with_endpoint_indices(
+        &mut self,
+        tolerance: f32,
+        sweep_orientation: Orientation,
+        path: impl IntoIterator<Item = PathEvent>,
+    ) {
+        self.set_path_impl(tolerance, sweep_orientation, path, true);
+    }
+
+    fn set_path_impl(
+        &mut self,
+        tolerance: f32,
+        sweep_orientation: Orientation,
+        path: impl IntoIterator<Item = PathEvent>,
+        numbered: bool,
     ) {
         self.reset();
 
         self.tolerance = tolerance;
-        let endpoint_id = EndpointId(u32::MAX);
+        let mut next_endpoint = 0;
+        let mut endpoint_id = || {
+            if !numbered {
+                return EndpointId::INVALID;
+            }
+            let id = EndpointId(next_endpoint);
+            next_endpoint += 1;
+            id
+        };
+        let mut first_id = EndpointId::INVALID;
         match sweep_orientation {
             Orientation::Vertical => {
                 for evt in path {
                     match evt {
                         PathEvent::Begin { at } => {
-                            self.begin(at, endpoint_id);
+                            first_id = endpoint_id();
+                            self.begin(at, first_id);
                         }
                         PathEvent::Line { to, .. } => {
-                            self.line_segment(to, endpoint_id, 0.0, 1.0);
+                            self.line_segment(to, endpoint_id(), 0.0, 1.0);
                         }
                         PathEvent::Quadratic { ctrl, to, .. } => {
-                            self.quadratic_bezier_segment(ctrl, to, endpoint_id);
+                            self.quadratic_bezier_segment(ctrl, to, endpoint_id());
                         }
                         PathEvent::Cubic {
                             ctrl1, ctrl2, to, ..
                         } => {
-                            self.cubic_bezier_segment(ctrl1, ctrl2, to, endpoint_id);
+                            self.cubic_bezier_segment(ctrl1, ctrl2, to, endpoint_id());
                         }
-                        PathEvent::End { first, .. } => {
-                            self.end(first, endpoint_id);
+                        PathEvent::End { first, close, .. } => {
+                            self.end_sub_path(first, first_id, close);
                         }
                     }
                 }
@@ -525,16 +594,17 @@ impl EventQueueBuilder {
                 for evt in path {
                     match evt {
                         PathEvent::Begin { at } => {
-                            self.begin(reorient(at), endpoint_id);
+                            first_id = endpoint_id();
+                            self.begin(reorient(at), first_id);
                         }
                         PathEvent::Line { to, .. } => {
-                            self.line_segment(reorient(to), endpoint_id, 0.0, 1.0);
+                            self.line_segment(reorient(to), endpoint_id(), 0.0, 1.0);
                         }
                         PathEvent::Quadratic { ctrl, to, .. } => {
                             self.quadratic_bezier_segment(
                                 reorient(ctrl),
                                 reorient(to),
-                                endpoint_id,
+                                endpoint_id(),
                             );
                         }
                         PathEvent::Cubic {
@@ -544,11 +614,11 @@ impl EventQueueBuilder {
                                 reorient(ctrl1),
                                 reorient(ctrl2),
                                 reorient(to),
-                                endpoint_id,
+                                endpoint_id(),
                             );
                         }
-                        PathEvent::End { first, .. } => {
-                            self.end(reorient(first), endpoint_id);
+                        PathEvent::End { first, close, .. } => {
+                            self.end_sub_path(reorient(first), first_id, close);
                         }
                     }
                 }
@@ -593,8 +663,8 @@ impl EventQueueBuilder {
                                 to,
                             );
                         }
-                        IdEvent::End { first, .. } => {
-                            self.end(points.get_endpoint(first), first);
+                        IdEvent::End { first, close, .. } => {
+                            self.end_sub_path(points.get_endpoint(first), first, close);
                         }
                     }
                 }
@@ -626,8 +696,8 @@ impl EventQueueBuilder {
                                 to,
                             );
                         }
-                        IdEvent::End { first, .. } => {
-                            self.end(reorient(points.get_endpoint(first)), first);
+                        IdEvent::End { first, close, .. } => {
+                            self.end_sub_path(reorient(points.get_endpoint(first)), first, close);
                         }
                     }
                 }
@@ -664,6 +734,22 @@ impl EventQueueBuilder {
         });
     }
 
+    /// Same as `end`, also recording whether the sub-path is open.
+    ///
+    /// Open sub-paths are closed anyway, but the tessellator can reject them.
+    pub(crate) fn end_sub_path(
+        &mut self,
+        first: Point,
+        first_endpoint_id: EndpointId,
+        close: bool,
+    ) {
+        if !close && self.nth > 0 && self.unclipped_current != first {
+            self.queue.has_open_sub_paths = true;
+        }
+
+        self.end(first, first_endpoint_id);
+    }
+
     pub fn end(&mut self, first: Point, first_endpoint_id: EndpointId) {
         if self.nth == 0 {
             self.validator.end();
@@ -677,6 +763,7 @@ impl EventQueueBuilder {
         // Since we can only check for the need of a vertex event when
         // we have a previous edge, we skipped it for the first edge
         // and have to do it now.
+        let first = self.clip(first);
         if is_after(first, self.prev) && is_after(first, self.second) {
             self.vertex_event(first, first_endpoint_id);
         }
@@ -691,7 +778,8 @@ impl EventQueueBuilder {
         self.validator.begin();
 
         self.nth = 0;
-        self.current = to;
+        self.unclipped_current = to;
+        self.current = self.clip(to);
         self.prev_endpoint_id = to_id;
     }
 
@@ -734,33 +822,140 @@ impl EventQueueBuilder {
     pub fn line_segment(&mut self, to: Point, to_id: EndpointId, t0: f32, t1: f32) {
         self.validator.edge();
 
+        let from = self.unclipped_current;
+        let from_id = self.prev_endpoint_id;
+        self.unclipped_current = to;
+
+        match self.clip_rect {
+            Some(rect) => {
+                self.clipped_edge(
+                    &rect,
+                    &LineSegment { from, to },
+                    from_id,
+                    to_id,
+                    t0..t1,
+                    true,
+                );
+            }
+            None => {
+                self.edge_to(to, from_id, to_id, t0, t1, true);
+            }
+        }
+    }
+
+    // Adds an edge from the current position.
+    //
+    // If `from_is_endpoint` is false, the current position is on the edge between
+    // `from_id` and `to_id` at `t0`.
+    fn edge_to(
+        &mut self,
+        to: Point,
+        from_id: EndpointId,
+        to_id: EndpointId,
+        t0: f32,
+        t1: f32,
+        from_is_endpoint: bool,
+    ) {
         let from = self.current;
         if from == to {
             return;
         }
 
         if is_after(from, to) && self.nth > 0 && is_after(from, self.prev) {
-            self.vertex_event(from, self.prev_endpoint_id);
+            if from_is_endpoint {
+                self.vertex_event(from, from_id);
+            } else {
+                self.vertex_event_on_curve(from, t0, from_id, to_id);
+            }
         }
 
         if self.nth == 0 {
             self.second = to;
         }
 
-        self.add_edge(
-            &LineSegment { from, to },
-            1,
-            self.prev_endpoint_id,
-            to_id,
-            t0,
-            t1,
-        );
+        self.add_edge(&LineSegment { from, to }, 1, from_id, to_id, t0, t1);
 
         self.prev = self.current;
         self.prev_endpoint_id = to_id;
         self.current = to;
     }
 
+    // Splits an edge where it crosses the sides of the clip rectangle, and adds the
+    // pieces clamped to the rectangle.
+    fn clipped_edge(
+        &mut self,
+        rect: &Box2D,
+        edge: &LineSegment<f32>,
+        from_id: EndpointId,
+        to_id: EndpointId,
+        t_range: Range<f32>,
+        from_is_endpoint: bool,
+    ) {
+        let mut splits = [0.0; 6];
+        let mut num_splits = 1;
+        let v = edge.to - edge.from;
+        for (from, delta, sides) in [
+            (edge.from.x, v.x, [rect.min.x, rect.max.x]),
+            (edge.from.y, v.y, [rect.min.y, rect.max.y]),
+        ] {
+            if delta == 0.0 {
+                continue;
+            }
+            for side in sides {
+                let t = (side - from) / delta;
+                if t > 0.0 && t < 1.0 {
+                    splits[num_splits] = t;
+                    num_splits += 1;
+                }
+            }
+        }
+        splits[1..num_splits].sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        splits[num_splits] = 1.0;
+        num_splits += 1;
+
+        let dt = t_range.end - t_range.start;
+        for i in 1..num_splits {
+            let (to, t1) = if i == num_splits - 1 {
+                (edge.to, t_range.end)
+            } else {
+                (edge.sample(splits[i]), t_range.start + dt * splits[i])
+            };
+            let t0 = t_range.start + dt * splits[i - 1];
+
+            self.edge_to(
+                self.clip(to),
+                from_id,
+                to_id,
+                t0,
+                t1,
+                from_is_endpoint && i == 1,
+            );
+        }
+    }
+
+    // Adds the clipped edges of a curve flattened in `self.flattened`.
+    //
+    // If `reversed` is true, the curve was flattened from its end to its start.
+    fn clipped_curve(&mut self, rect: &Box2D, reversed: bool, to: Point, to_id: EndpointId) {
+        let mut flattened = mem::take(&mut self.flattened);
+        if reversed {
+            flattened.reverse();
+            for (line, t) in &mut flattened {
+                *line = line.flip();
+                *t = (1.0 - t.end)..(1.0 - t.start);
+            }
+        }
+
+        let from_id = self.prev_endpoint_id;
+        for (i, (line, t)) in flattened.iter().enumerate() {
+            self.clipped_edge(rect, line, from_id, to_id, t.clone(), i == 0);
+        }
+
+        flattened.clear();
+        self.flattened = flattened;
+        self.unclipped_current = to;
+    }
+
     pub fn quadratic_bezier_segment(&mut self, ctrl: Point, to: Point, to_id: EndpointId) {
         self.validator.edge();
         // Swap the curve so that it always goes downwards. This way if two
@@ -771,7 +966,7 @@ impl EventQueueBuilder {
         // points as if we hadn't swapped.
 
         let original = QuadraticBezierSegment {
-            from: self.current,
+            from: self.unclipped_current,
             ctrl,
             to,
         };
@@ -785,6 +980,16 @@ impl EventQueueBuilder {
             winding = -1;
         }
 
+        if let Some(rect) = self.clip_rect {
+            let mut flattened = mem::take(&mut self.flattened);
+            segment.for_each_flattened_with_t(self.tolerance, &mut |line, t| {
+                flattened.push((*line, t));
+            });
+            self.flattened = flattened;
+            self.clipped_curve(&rect, needs_swap, to, to_id);
+            return;
+        }
+
         let mut prev = segment.from;
         let mut first = None;
         let is_first_edge = self.nth == 0;
@@ -827,6 +1032,8 @@ impl EventQueueBuilder {
             self.current = original.to;
             self.prev_endpoint_id = to_id;
         }
+
+        self.unclipped_current = original.to;
     }
 
     pub fn cubic_bezier_segment(
@@ -845,7 +1052,7 @@ impl EventQueueBuilder {
         // points as if we hadn't swapped.
 
         let original = CubicBezierSegment {
-            from: self.current,
+            from: self.unclipped_current,
             ctrl1,
             ctrl2,
             to,
@@ -861,6 +1068,16 @@ impl EventQueueBuilder {
             winding = -1;
         }
 
+        if let Some(rect) = self.clip_rect {
+            let mut flattened = mem::take(&mut self.flattened);
+            segment.for_each_flattened_with_t(self.tolerance, &mut |line, t| {
+                flattened.push((*line, t));
+            });
+            self.flattened = flattened;
+            self.clipped_curve(&rect, needs_swap, to, to_id);
+            return;
+        }
+
         let mut prev = segment.from;
         let mut first = None;
         let is_first_edge = self.nth == 0;
@@ -903,6 +1120,8 @@ impl EventQueueBuilder {
             self.current = original.to;
             self.prev_endpoint_id = to_id;
         }
+
+        self.unclipped_current = original.to;
     }
 
     pub fn reserve(&mut self, n: usize) {
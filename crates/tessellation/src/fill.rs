@@ -1,14 +1,16 @@
 use crate::event_queue::*;
-use crate::geom::LineSegment;
+use crate::geom::{CubicBezierSegment, LineSegment, QuadraticBezierSegment};
+use crate::geometry_builder::{BuffersBuilder, Count, MaxIndex};
 use crate::math::*;
 use crate::monotone::*;
+use crate::path::iterator::PathIterator;
 use crate::path::polygon::Polygon;
 use crate::path::traits::{Build, PathBuilder};
 use crate::path::{
     builder::NoAttributes, AttributeStore, Attributes, EndpointId, FillRule, IdEvent, PathEvent,
     PathSlice, PositionStore, Winding, NO_ATTRIBUTES,
 };
-use crate::{FillGeometryBuilder, Orientation, VertexId};
+use crate::{FillGeometryBuilder, FillVertexConstructor, Orientation, VertexId};
 use crate::{
     FillOptions, InternalError, SimpleAttributeStore, TessellationError, TessellationResult,
     UnsupportedParamater, VertexSource,
@@ -17,7 +19,7 @@ use float_next_after::NextAfter;
 use core::cmp::Ordering;
 use core::f32::consts::FRAC_1_SQRT_2;
 use core::mem;
-use core::ops::Range;
+use core::ops::{Add, Range};
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 
@@ -112,6 +114,20 @@ impl WindingState {
     }
 }
 
+// An edge on the boundary of the filled area, connected to the current vertex.
+//
+// Only used to generate the anti-aliasing band and the boundary edges.
+#[derive(Copy, Clone, Debug)]
+struct BoundaryEdge {
+    // The other endpoint of the edge.
+    position: Point,
+    // The vertex of the other endpoint, for edges above the current vertex.
+    id: VertexId,
+    src_edge: TessEventId,
+    // Whether the filled area is on the left side of the edge.
+    interior_on_left: bool,
+}
+
 struct ActiveEdgeScan {
     vertex_events: Vec<(SpanIdx, Side)>,
     edges_to_split: Vec<ActiveEdgeIdx>,
@@ -119,8 +135,11 @@ struct ActiveEdgeScan {
     merge_event: bool,
     split_event: bool,
     merge_split_event: bool,
+    num_started_spans: u32,
     above: Range<ActiveEdgeIdx>,
     winding_before_point: WindingState,
+    boundary_above: Vec<BoundaryEdge>,
+    boundary_below: Vec<BoundaryEdge>,
 }
 
 impl ActiveEdgeScan {
@@ -132,8 +151,11 @@ impl ActiveEdgeScan {
             merge_event: false,
             split_event: false,
             merge_split_event: false,
+            num_started_spans: 0,
             above: 0..0,
             winding_before_point: WindingState::new(),
+            boundary_above: Vec::new(),
+            boundary_below: Vec::new(),
         }
     }
 
@@ -144,8 +166,11 @@ impl ActiveEdgeScan {
         self.merge_event = false;
         self.split_event = false;
         self.merge_split_event = false;
+        self.num_started_spans = 0;
         self.above = 0..0;
         self.winding_before_point = WindingState::new();
+        self.boundary_above.clear();
+        self.boundary_below.clear();
     }
 }
 
@@ -411,6 +436,14 @@ struct PendingEdge {
 /// In other words they don't add overhead when not used, however it is best to avoid calling
 /// interpolated_attributes several times per vertex.
 ///
+/// ## Reusing allocations
+///
+/// The tessellator keeps its internal buffers (event queue, active edges, monotone
+/// tessellators, etc.) between calls. Tessellating several paths with the same
+/// `FillTessellator` only allocates when a path needs more memory than the previous
+/// ones did, so it is best to keep a tessellator around rather than creating a new
+/// one for each path. See also `FillTessellator::reset`.
+///
 /// # Examples
 ///
 /// ```
@@ -532,7 +565,17 @@ pub struct FillTessellator {
     fill: Spans,
     log: bool,
     assume_no_intersection: bool,
+    allow_steiner_points: bool,
+    // Set when an intersection requires a new vertex while Steiner points aren't allowed.
+    found_steiner_point: bool,
+    intersection_epsilon: f32,
     attrib_buffer: Vec<f32>,
+    anti_alias: Option<f32>,
+    emit_boundary: bool,
+    bounds: Box2D,
+    // Outer vertex of the anti-aliasing band at the start of each edge, indexed by
+    // source edge.
+    band_vertices: Vec<VertexId>,
 
     scan: ActiveEdgeScan,
     events: EventQueue,
@@ -567,13 +610,33 @@ impl FillTessellator {
             },
             log,
             assume_no_intersection: false,
+            allow_steiner_points: true,
+            found_steiner_point: false,
+            intersection_epsilon: FillOptions::DEFAULT_INTERSECTION_EPSILON,
             attrib_buffer: Vec::new(),
+            anti_alias: None,
+            emit_boundary: false,
+            bounds: Box2D::zero(),
+            band_vertices: Vec::new(),
 
             scan: ActiveEdgeScan::new(),
             events: EventQueue::new(),
         }
     }
 
+    /// Clears the internal state of the tessellator while retaining the capacity of
+    /// its buffers.
+    ///
+    /// The tessellation methods already do this, so calling `reset` is not necessary
+    /// between two tessellations. It is useful to drop the content of the buffers (for
+    /// example the flattened edges of a large path) without releasing their memory.
+    pub fn reset(&mut self) {
+        self.reset_sweep_state();
+        self.scan.reset();
+        self.events.reset();
+        self.attrib_buffer.clear();
+    }
+
     /// Compute the tessellation from a path iterator.
     pub fn tessellate(
         &mut self,
@@ -581,18 +644,60 @@ impl FillTessellator {
         options: &FillOptions,
         output: &mut dyn FillGeometryBuilder,
     ) -> TessellationResult {
-        let event_queue = core::mem::replace(&mut self.events, EventQueue::new());
+        self.build_events(path, options);
+
+        self.tessellate_impl(options, None, None, output)
+    }
+
+    /// Compute the tessellation from a path iterator and, optionally, storage for
+    /// custom endpoint attributes.
+    ///
+    /// This makes it possible to tessellate procedurally generated events without
+    /// building a `Path`. Curves are flattened with the tolerance of the options.
+    ///
+    /// Endpoints are identified by their index in the sequence of endpoints of the
+    /// events: the `at` position of each `Begin` event followed by the `to` position of
+    /// each segment, `End` events don't add endpoints. These are the ids used to look
+    /// up custom attributes in `custom_attributes` and reported by `FillVertex::sources`.
+    pub fn tessellate_events(
+        &mut self,
+        events: impl IntoIterator<Item = PathEvent>,
+        custom_attributes: Option<&dyn AttributeStore>,
+        options: &FillOptions,
+        output: &mut dyn FillGeometryBuilder,
+    ) -> TessellationResult {
+        let event_queue = mem::take(&mut self.events);
         let mut queue_builder = event_queue.into_builder(options.tolerance);
+        queue_builder.set_clip_rect(options.clip_rect, options.sweep_orientation);
 
-        queue_builder.set_path(
+        queue_builder.set_path_with_endpoint_indices(
             options.tolerance,
             options.sweep_orientation,
-            path.into_iter(),
+            events,
         );
 
         self.events = queue_builder.build();
 
-        self.tessellate_impl(options, None, output)
+        self.tessellate_impl(options, custom_attributes, None, output)
+    }
+
+    /// Compute the tessellation from a path iterator, reporting the progress of
+    /// the sweep line to an inspector.
+    ///
+    /// This is meant for debugging and visualization: `inspector` is called once for
+    /// each vertex processed by the sweep line, with the classification of the vertex
+    /// and a copy of the active edges. It produces the same geometry as `tessellate`
+    /// but is slower.
+    pub fn tessellate_with_inspector(
+        &mut self,
+        path: impl IntoIterator<Item = PathEvent>,
+        options: &FillOptions,
+        inspector: &mut dyn FillInspector,
+        output: &mut dyn FillGeometryBuilder,
+    ) -> TessellationResult {
+        self.build_events(path, options);
+
+        self.tessellate_impl(options, None, Some(inspector), output)
     }
 
     /// Compute the tessellation using an iterator over endpoint and control
@@ -608,6 +713,7 @@ impl FillTessellator {
     ) -> TessellationResult {
         let event_queue = core::mem::replace(&mut self.events, EventQueue::new());
         let mut queue_builder = event_queue.into_builder(options.tolerance);
+        queue_builder.set_clip_rect(options.clip_rect, options.sweep_orientation);
 
         queue_builder.set_path_with_ids(
             options.tolerance,
@@ -618,13 +724,14 @@ impl FillTessellator {
 
         self.events = queue_builder.build();
 
-        self.tessellate_impl(options, custom_attributes, output)
+        self.tessellate_impl(options, custom_attributes, None, output)
     }
 
     /// Compute the tessellation from a path slice.
     ///
-    /// The tessellator will internally only track vertex sources and interpolated
-    /// attributes if the path has interpolated attributes.
+    /// The endpoint ids of the path are tracked, so that `FillVertex::sources`
+    /// refers to the endpoints of the path. Interpolated attributes are only
+    /// available if the path has custom attributes.
     pub fn tessellate_path<'l>(
         &'l mut self,
         path: impl Into<PathSlice<'l>>,
@@ -633,14 +740,53 @@ impl FillTessellator {
     ) -> TessellationResult {
         let path = path.into();
 
-        if path.num_attributes() > 0 {
-            self.tessellate_with_ids(path.id_iter(), &path, Some(&path), options, builder)
+        let attributes: Option<&dyn AttributeStore> = if path.num_attributes() > 0 {
+            Some(&path)
         } else {
-            self.tessellate(path.iter(), options, builder)
+            None
+        };
+
+        self.tessellate_with_ids(path.id_iter(), &path, attributes, options, builder)
+    }
+
+    /// Tessellate a batch of transformed paths (for example the glyphs of a string of text)
+    /// into the same vertex buffers.
+    ///
+    /// Each path is tessellated separately and the returned vector contains, for each path,
+    /// the range of the indices it produced in the index buffer.
+    ///
+    /// If a path fails to tessellate, its geometry is removed from the buffers and the
+    /// error is returned. The geometry of the paths that were tessellated before it
+    /// remains in the buffers.
+    pub fn tessellate_batch<OutputVertex, OutputIndex, Ctor>(
+        &mut self,
+        items: &[(PathSlice, Transform)],
+        options: &FillOptions,
+        output: &mut BuffersBuilder<OutputVertex, OutputIndex, Ctor>,
+    ) -> Result<Vec<Range<u32>>, TessellationError>
+    where
+        OutputIndex: Add + From<VertexId> + MaxIndex,
+        Ctor: FillVertexConstructor<OutputVertex>,
+    {
+        let mut ranges = Vec::with_capacity(items.len());
+        for (path, transform) in items {
+            let start = output.buffers().indices.len() as u32;
+            if path.num_attributes() > 0 {
+                self.tessellate_path(&path.transformed(transform), options, output)?;
+            } else {
+                self.tessellate(path.iter().transformed(transform), options, output)?;
+            }
+            let end = output.buffers().indices.len() as u32;
+            ranges.push(start..end);
         }
+
+        Ok(ranges)
     }
 
     /// Tessellate a `Polygon`.
+    ///
+    /// The polygon is tessellated directly, without building a `Path`. Polygons are always
+    /// filled as if they were closed, regardless of their `closed` flag.
     pub fn tessellate_polygon(
         &mut self,
         polygon: Polygon<Point>,
@@ -654,12 +800,75 @@ impl FillTessellator {
     pub fn tessellate_rectangle(
         &mut self,
         rect: &Box2D,
-        _options: &FillOptions,
+        options: &FillOptions,
         output: &mut dyn FillGeometryBuilder,
     ) -> TessellationResult {
+        if needs_sweep_line(options) {
+            let mut builder = self.builder(options, output);
+            builder.add_rectangle(rect, Winding::Positive);
+
+            return builder.build();
+        }
+
         crate::basic_shapes::fill_rectangle(rect, output)
     }
 
+    /// Tessellate a convex polygon.
+    ///
+    /// This produces a simple triangle fan without going through the sweep line
+    /// algorithm, which is much faster for simple shapes. The polygon must be convex
+    /// (this is checked in debug builds), otherwise the output is incorrect.
+    ///
+    /// Like with `tessellate_rectangle`, the vertices passed to the vertex constructor
+    /// are not associated with endpoints or custom attributes.
+    pub fn tessellate_convex_polygon(
+        &mut self,
+        points: &[Point],
+        options: &FillOptions,
+        output: &mut dyn FillGeometryBuilder,
+    ) -> TessellationResult {
+        if needs_sweep_line(options) {
+            let polygon = Polygon {
+                points,
+                closed: true,
+            };
+            return self.tessellate(polygon.path_events(), options, output);
+        }
+
+        crate::basic_shapes::fill_convex_polygon(points, output)
+    }
+
+    /// Tessellate a simple polygon using ear clipping.
+    ///
+    /// A lighter alternative to the sweep line algorithm for small polygons without holes.
+    /// The polygon must be simple (its edges don't intersect each other, which is checked
+    /// in debug builds), otherwise the output is incorrect. `winding` is the orientation
+    /// of the polygon: `Winding::Positive` if its signed area is positive. The fill rule
+    /// doesn't matter since the whole interior of a simple polygon is filled.
+    ///
+    /// Ear clipping is quadratic in the number of points, so the sweep line algorithm is
+    /// faster for large polygons.
+    ///
+    /// Like with `tessellate_rectangle`, the vertices passed to the vertex constructor
+    /// are not associated with endpoints or custom attributes.
+    pub fn tessellate_simple_polygon(
+        &mut self,
+        points: &[Point],
+        winding: Winding,
+        options: &FillOptions,
+        output: &mut dyn FillGeometryBuilder,
+    ) -> TessellationResult {
+        if needs_sweep_line(options) {
+            let polygon = Polygon {
+                points,
+                closed: true,
+            };
+            return self.tessellate(polygon.path_events(), options, output);
+        }
+
+        crate::basic_shapes::fill_simple_polygon(points, winding, output)
+    }
+
     /// Tessellate a circle.
     pub fn tessellate_circle(
         &mut self,
@@ -668,6 +877,13 @@ impl FillTessellator {
         options: &FillOptions,
         output: &mut dyn FillGeometryBuilder,
     ) -> TessellationResult {
+        if needs_sweep_line(options) {
+            let mut builder = self.builder(options, output);
+            builder.add_circle(center, radius, Winding::Positive);
+
+            return builder.build();
+        }
+
         crate::basic_shapes::fill_circle(center, radius, options, output)
     }
 
@@ -746,10 +962,25 @@ impl FillTessellator {
         FillBuilder::new(num_attributes, self, options, output)
     }
 
+    fn build_events(&mut self, path: impl IntoIterator<Item = PathEvent>, options: &FillOptions) {
+        let event_queue = core::mem::replace(&mut self.events, EventQueue::new());
+        let mut queue_builder = event_queue.into_builder(options.tolerance);
+        queue_builder.set_clip_rect(options.clip_rect, options.sweep_orientation);
+
+        queue_builder.set_path(
+            options.tolerance,
+            options.sweep_orientation,
+            path.into_iter(),
+        );
+
+        self.events = queue_builder.build();
+    }
+
     fn tessellate_impl(
         &mut self,
         options: &FillOptions,
         attrib_store: Option<&dyn AttributeStore>,
+        inspector: Option<&mut dyn FillInspector>,
         builder: &mut dyn FillGeometryBuilder,
     ) -> TessellationResult {
         if options.tolerance.is_nan() || options.tolerance <= 0.0 {
@@ -758,7 +989,13 @@ impl FillTessellator {
             ));
         }
 
-        self.reset();
+        if !options.allow_open_sub_paths && self.events.has_open_sub_paths {
+            return Err(TessellationError::UnsupportedParamater(
+                UnsupportedParamater::OpenSubPath,
+            ));
+        }
+
+        self.reset_sweep_state();
 
         if let Some(store) = attrib_store {
             self.attrib_buffer.resize(store.num_attributes(), 0.0);
@@ -769,13 +1006,24 @@ impl FillTessellator {
         self.fill_rule = options.fill_rule;
         self.orientation = options.sweep_orientation;
         self.tolerance = options.tolerance * 0.5;
-        self.assume_no_intersection = !options.handle_intersections;
+        // Intersections must be detected to report them when Steiner points aren't allowed.
+        self.assume_no_intersection = !options.handle_intersections && options.allow_steiner_points;
+        self.allow_steiner_points = options.allow_steiner_points;
+        self.intersection_epsilon = options.intersection_epsilon;
+        self.anti_alias = options.anti_alias.filter(|width| *width > 0.0);
+        self.emit_boundary = options.emit_boundary;
+
+        let orientation = self.orientation;
+        self.bounds = Box2D::from_points(self.events.events.iter().map(|evt| match orientation {
+            Orientation::Vertical => evt.position,
+            Orientation::Horizontal => reorient(evt.position),
+        }));
 
         builder.begin_geometry();
 
         let mut scan = mem::replace(&mut self.scan, ActiveEdgeScan::new());
 
-        let result = self.tessellator_loop(attrib_store, &mut scan, builder);
+        let result = self.tessellator_loop(attrib_store, inspector, &mut scan, builder);
 
         mem::swap(&mut self.scan, &mut scan);
 
@@ -823,11 +1071,14 @@ impl FillTessellator {
     fn tessellator_loop(
         &mut self,
         attrib_store: Option<&dyn AttributeStore>,
+        mut inspector: Option<&mut dyn FillInspector>,
         scan: &mut ActiveEdgeScan,
         output: &mut dyn FillGeometryBuilder,
     ) -> Result<(), TessellationError> {
         log_svg_preamble(self);
 
+        let mut inspected_edges = Vec::new();
+
         let mut _prev_position = point(f32::MIN, f32::MIN);
         self.current_event_id = self.events.first_id();
         while self.events.valid_id(self.current_event_id) {
@@ -844,6 +1095,24 @@ impl FillTessellator {
                 self.process_events(scan, output)?
             }
 
+            if self.found_steiner_point {
+                return Err(TessellationError::UnsupportedParamater(
+                    UnsupportedParamater::Intersection,
+                ));
+            }
+
+            if let Some(width) = self.anti_alias {
+                self.add_anti_aliasing_band(width, attrib_store, scan, output)?;
+            }
+
+            if self.emit_boundary {
+                self.add_boundary_edges(scan, output);
+            }
+
+            if let Some(inspector) = inspector.as_mut() {
+                self.inspect_sweep_event(scan, &mut inspected_edges, *inspector);
+            }
+
             #[cfg(debug_assertions)]
             self.check_active_edges();
 
@@ -885,6 +1154,8 @@ impl FillTessellator {
             current_event,
             attrib_store,
             attrib_buffer: &mut self.attrib_buffer,
+            coverage: 1.0,
+            bounds: self.bounds,
         })?;
 
         let mut current_sibling = current_event;
@@ -951,6 +1222,50 @@ impl FillTessellator {
         Ok(())
     }
 
+    fn inspect_sweep_event(
+        &self,
+        scan: &ActiveEdgeScan,
+        edges: &mut Vec<SweepEdge>,
+        inspector: &mut dyn FillInspector,
+    ) {
+        let orientation = self.orientation;
+        let position = |p: Point| match orientation {
+            Orientation::Vertical => p,
+            Orientation::Horizontal => reorient(p),
+        };
+
+        let started = scan.num_started_spans > 0;
+        let ended = !scan.spans_to_end.is_empty();
+        let kind = if scan.split_event || scan.merge_split_event {
+            SweepEventKind::Split
+        } else if scan.merge_event {
+            SweepEventKind::Merge
+        } else if started && !ended {
+            SweepEventKind::Start
+        } else if ended && !started {
+            SweepEventKind::End
+        } else if !started && !scan.vertex_events.is_empty() {
+            SweepEventKind::Regular
+        } else {
+            SweepEventKind::Other
+        };
+
+        edges.clear();
+        edges.extend(self.active.edges.iter().map(|edge| SweepEdge {
+            from: position(edge.from),
+            to: position(edge.to),
+            winding: edge.winding,
+            is_merge: edge.is_merge,
+        }));
+
+        inspector.sweep_event(&SweepEvent {
+            position: position(self.current_position),
+            vertex: self.current_vertex,
+            kind,
+            active_edges: edges,
+        });
+    }
+
     #[cfg(debug_assertions)]
     fn log_active_edges(&self) {
         tess_log!(self, r#"<g class="active-edges">"#);
@@ -1213,8 +1528,18 @@ impl FillTessellator {
                     scan.spans_to_end.push(winding.span_index);
                 }
 
+                let was_in = winding.is_in;
                 winding.update(self.fill_rule, active_edge.winding);
 
+                if (self.anti_alias.is_some() || self.emit_boundary) && was_in != winding.is_in {
+                    scan.boundary_above.push(BoundaryEdge {
+                        position: active_edge.from,
+                        id: active_edge.from_id,
+                        src_edge: active_edge.src_edge,
+                        interior_on_left: was_in,
+                    });
+                }
+
                 tess_log!(
                     self,
                     " x span: {} in: {}",
@@ -1489,9 +1814,20 @@ impl FillTessellator {
                     &self.current_position,
                     self.current_vertex,
                 );
+                scan.num_started_spans += 1;
             }
+            let was_in = winding.is_in;
             winding.update(self.fill_rule, pending_edge.winding);
 
+            if self.anti_alias.is_some() && was_in != winding.is_in {
+                scan.boundary_below.push(BoundaryEdge {
+                    position: pending_edge.to,
+                    id: VertexId::INVALID,
+                    src_edge: pending_edge.src_edge,
+                    interior_on_left: was_in,
+                });
+            }
+
             tess_log!(
                 self,
                 "edge below: span: {}, in: {}",
@@ -1714,14 +2050,25 @@ impl FillTessellator {
             self.current_position
         );
 
-        if is_near(intersection_position, edge_below.to) {
+        let epsilon = self.intersection_epsilon;
+        if is_near(intersection_position, edge_below.to, epsilon) {
             tess_log!(self, "intersection near below.to");
             intersection_position = edge_below.to;
-        } else if is_near(intersection_position, active_edge.to) {
+        } else if is_near(intersection_position, active_edge.to, epsilon) {
             tess_log!(self, "intersection near active_edge.to");
             intersection_position = active_edge.to;
         }
 
+        if !self.allow_steiner_points
+            && intersection_position != edge_below.to
+            && intersection_position != active_edge.to
+            && intersection_position != active_edge.from
+        {
+            tess_log!(self, "intersection requires a Steiner point");
+            self.found_steiner_point = true;
+            return;
+        }
+
         let a_src_edge_data = self.events.edge_data[active_edge.src_edge as usize].clone();
         let b_src_edge_data = self.events.edge_data[edge_below.src_edge as usize].clone();
 
@@ -2083,13 +2430,250 @@ impl FillTessellator {
             .insert_sorted(split_point, edge_data, self.current_event_id);
     }
 
-    fn reset(&mut self) {
+    fn reset_sweep_state(&mut self) {
         self.current_position = point(f32::MIN, f32::MIN);
         self.current_vertex = VertexId::INVALID;
         self.current_event_id = INVALID_EVENT_ID;
         self.active.edges.clear();
         self.edges_below.clear();
-        self.fill.spans.clear();
+        // Spans can be left over if the previous tessellation failed, recycle their
+        // monotone tessellators.
+        for span in self.fill.spans.drain(..) {
+            if let Some(tess) = span.tess {
+                self.fill.pool.push(tess);
+            }
+        }
+        self.band_vertices.clear();
+        self.found_steiner_point = false;
+    }
+
+    /// Adds the part of the anti-aliasing band that touches the current vertex.
+    ///
+    /// Each boundary edge produces a quad between the edge and its outward offset. The
+    /// outer vertex at the start of an edge is created when the edge is inserted and the
+    /// quad is added when the edge ends.
+    fn add_anti_aliasing_band(
+        &mut self,
+        width: f32,
+        attrib_store: Option<&dyn AttributeStore>,
+        scan: &ActiveEdgeScan,
+        output: &mut dyn FillGeometryBuilder,
+    ) -> Result<(), TessellationError> {
+        let current = self.current_position;
+        let mut joins = [(VertexId::INVALID, current); 2];
+        let mut num_joins = 0;
+
+        for edge in &scan.boundary_above {
+            let outer_from = self
+                .band_vertices
+                .get(edge.src_edge as usize)
+                .cloned()
+                .unwrap_or(VertexId::INVALID);
+            let normal = match outward_normal(edge.position, current, edge.interior_on_left) {
+                Some(normal) if outer_from != VertexId::INVALID => normal,
+                _ => continue,
+            };
+
+            let outer_position = current + normal * width;
+            let outer_to = self.add_band_vertex(outer_position, attrib_store, output)?;
+            add_triangle(
+                output,
+                (edge.id, edge.position),
+                (self.current_vertex, current),
+                (outer_to, outer_position),
+            );
+            add_triangle(
+                output,
+                (edge.id, edge.position),
+                (outer_to, outer_position),
+                (outer_from, edge.position + normal * width),
+            );
+
+            if num_joins < 2 {
+                joins[num_joins] = (outer_to, outer_position);
+            }
+            num_joins += 1;
+        }
+
+        for edge in &scan.boundary_below {
+            let normal = match outward_normal(current, edge.position, edge.interior_on_left) {
+                Some(normal) => normal,
+                None => continue,
+            };
+
+            let outer_position = current + normal * width;
+            let outer = self.add_band_vertex(outer_position, attrib_store, output)?;
+
+            let idx = edge.src_edge as usize;
+            if self.band_vertices.len() <= idx {
+                self.band_vertices.resize(idx + 1, VertexId::INVALID);
+            }
+            self.band_vertices[idx] = outer;
+
+            if num_joins < 2 {
+                joins[num_joins] = (outer, outer_position);
+            }
+            num_joins += 1;
+        }
+
+        if num_joins == 2 {
+            // Bevel join.
+            add_triangle(output, (self.current_vertex, current), joins[0], joins[1]);
+        }
+
+        Ok(())
+    }
+
+    /// Reports the boundary edges that end at the current vertex.
+    ///
+    /// See `FillOptions::emit_boundary`.
+    fn add_boundary_edges(&self, scan: &ActiveEdgeScan, output: &mut dyn FillGeometryBuilder) {
+        for edge in &scan.boundary_above {
+            if edge.id == self.current_vertex {
+                continue;
+            }
+
+            // Orient the edge like the triangles: the interior is on the side where
+            // `(to - from).cross(p - from)` is negative.
+            if edge.interior_on_left {
+                output.add_boundary_edge(self.current_vertex, edge.id);
+            } else {
+                output.add_boundary_edge(edge.id, self.current_vertex);
+            }
+        }
+    }
+
+    fn add_band_vertex(
+        &mut self,
+        position: Point,
+        attrib_store: Option<&dyn AttributeStore>,
+        output: &mut dyn FillGeometryBuilder,
+    ) -> Result<VertexId, TessellationError> {
+        let position = match self.orientation {
+            Orientation::Vertical => position,
+            Orientation::Horizontal => reorient(position),
+        };
+
+        let id = output.add_fill_vertex(FillVertex {
+            position,
+            events: &self.events,
+            current_event: self.current_event_id,
+            attrib_store,
+            attrib_buffer: &mut self.attrib_buffer,
+            coverage: 0.0,
+            bounds: self.bounds,
+        })?;
+
+        Ok(id)
+    }
+}
+
+/// Returns an upper bound of the number of vertices and indices that the fill tessellator
+/// produces for a path.
+///
+/// This is useful to reserve the capacity of the output buffers before tessellating. The
+/// curves are flattened the same way as during the tessellation, which is much cheaper
+/// than the tessellation itself.
+///
+/// The bound assumes that the path does not intersect itself and that
+/// `FillOptions::clip_rect` is not set. Under these assumptions, all vertices are endpoints
+/// of the flattened path, and a triangulation of `n` vertices has at most `2 * n` triangles.
+/// Each intersection or clipped edge adds vertices that are not accounted for. The
+/// anti-aliasing band is taken into account. The boundary edges reported with
+/// `FillOptions::emit_boundary` are not counted in the indices.
+pub fn estimate_tessellation_counts(
+    path: impl IntoIterator<Item = PathEvent>,
+    options: &FillOptions,
+) -> Count {
+    let mut points: u32 = 0;
+    for evt in path {
+        points += match evt {
+            PathEvent::Begin { .. } | PathEvent::Line { .. } => 1,
+            PathEvent::Quadratic { from, ctrl, to } => {
+                // The event queue may flatten the curve in the opposite direction.
+                let curve = QuadraticBezierSegment { from, ctrl, to };
+                let mut forward = 0;
+                let mut backward = 0;
+                curve.for_each_flattened(options.tolerance, &mut |_| forward += 1);
+                curve
+                    .flip()
+                    .for_each_flattened(options.tolerance, &mut |_| backward += 1);
+                u32::max(forward, backward)
+            }
+            PathEvent::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => {
+                let curve = CubicBezierSegment {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                };
+                let mut forward = 0;
+                let mut backward = 0;
+                curve.for_each_flattened(options.tolerance, &mut |_| forward += 1);
+                curve
+                    .flip()
+                    .for_each_flattened(options.tolerance, &mut |_| backward += 1);
+                u32::max(forward, backward)
+            }
+            PathEvent::End { .. } => 0,
+        };
+    }
+
+    let mut vertices = points;
+    let mut triangles = 2 * points;
+    if options.anti_alias.is_some() {
+        // Each edge adds up to two vertices and two triangles to the band, and each
+        // vertex adds up to one triangle for the join.
+        vertices += 2 * points;
+        triangles += 3 * points;
+    }
+
+    Count {
+        vertices,
+        indices: triangles * 3,
+    }
+}
+
+// Returns the unit normal of the edge pointing away from the filled area.
+fn outward_normal(from: Point, to: Point, interior_on_left: bool) -> Option<Vector> {
+    let v = to - from;
+    let length = v.length();
+    if length < 1e-6 {
+        return None;
+    }
+
+    let normal = if interior_on_left {
+        vector(v.y, -v.x)
+    } else {
+        vector(-v.y, v.x)
+    };
+
+    Some(normal / length)
+}
+
+// The fast paths for simple shapes don't support the options that rely on the
+// sweep line.
+fn needs_sweep_line(options: &FillOptions) -> bool {
+    options.anti_alias.is_some() || options.emit_boundary || options.clip_rect.is_some()
+}
+
+// Adds a triangle with the same winding as the ones produced by the monotone
+// tessellator.
+fn add_triangle(
+    output: &mut dyn FillGeometryBuilder,
+    a: (VertexId, Point),
+    b: (VertexId, Point),
+    c: (VertexId, Point),
+) {
+    if (b.1 - a.1).cross(c.1 - b.1) > 0.0 {
+        output.add_triangle(a.0, c.0, b.0);
+    } else {
+        output.add_triangle(a.0, b.0, c.0);
     }
 }
 
@@ -2124,8 +2708,8 @@ pub(crate) fn is_after(a: Point, b: Point) -> bool {
 }
 
 #[inline]
-pub(crate) fn is_near(a: Point, b: Point) -> bool {
-    (a - b).square_length() < 0.000000001
+pub(crate) fn is_near(a: Point, b: Point, epsilon: f32) -> bool {
+    (a - b).square_length() < epsilon * epsilon
 }
 
 #[inline]
@@ -2133,6 +2717,77 @@ fn reorient(p: Point) -> Point {
     point(p.y, -p.x)
 }
 
+/// Receives the events of the sweep line of the fill tessellator.
+///
+/// See `FillTessellator::tessellate_with_inspector`.
+pub trait FillInspector {
+    /// Called after the sweep line has processed a vertex.
+    fn sweep_event(&mut self, event: &SweepEvent);
+}
+
+/// The classification of a vertex in the monotone decomposition performed by the
+/// fill tessellator.
+///
+/// The sweep line moves downward (towards positive y) in the default vertical sweep
+/// orientation. The "spans" are the monotone polygons being tessellated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SweepEventKind {
+    /// One or more spans start at this vertex: the interior of the shape is
+    /// between edges below the vertex, and the vertex is outside of the shape.
+    Start,
+    /// One or more spans end at this vertex: the interior of the shape is
+    /// between edges above the vertex, and there is no interior below it.
+    End,
+    /// The vertex is inside of a span and has edges below it, which splits the span
+    /// in two.
+    ///
+    /// This includes vertices that resolve a pending merge at the same time.
+    Split,
+    /// The vertex joins two spans that are above it, and has no edge below it.
+    ///
+    /// The merge is resolved later, when the sweep line reaches another vertex of
+    /// the merged area. Until then it appears in the active edges (see
+    /// `SweepEdge::is_merge`).
+    Merge,
+    /// The vertex is on the left or right side of a span, with edges above and
+    /// below it.
+    Regular,
+    /// The vertex does not fit in the other categories, for example it touches the
+    /// shape without changing the spans, or it ends and starts spans at the same time.
+    Other,
+}
+
+/// An edge intersecting the sweep line, passed to `FillInspector::sweep_event`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct SweepEdge {
+    /// The upper endpoint of the edge.
+    pub from: Point,
+    /// The lower endpoint of the edge.
+    pub to: Point,
+    /// The winding of the edge: `1` for edges going down, `-1` for edges going up.
+    pub winding: i16,
+    /// Whether this is a pending merge vertex rather than an edge. Merge vertices
+    /// have the same `from` and `to` positions.
+    pub is_merge: bool,
+}
+
+/// The state of the sweep line after processing a vertex, passed to
+/// `FillInspector::sweep_event`.
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub struct SweepEvent<'l> {
+    /// The position of the vertex.
+    pub position: Point,
+    /// The id of the vertex in the output geometry.
+    pub vertex: VertexId,
+    /// The classification of the vertex.
+    pub kind: SweepEventKind,
+    /// The edges intersecting the sweep line after the vertex was processed, ordered
+    /// along the sweep line.
+    pub active_edges: &'l [SweepEdge],
+}
+
 /// Extra vertex information from the `FillTessellator`, accessible when building vertices.
 pub struct FillVertex<'l> {
     pub(crate) position: Point,
@@ -2140,6 +2795,8 @@ pub struct FillVertex<'l> {
     pub(crate) current_event: TessEventId,
     pub(crate) attrib_buffer: &'l mut [f32],
     pub(crate) attrib_store: Option<&'l dyn AttributeStore>,
+    pub(crate) coverage: f32,
+    pub(crate) bounds: Box2D,
 }
 
 impl<'l> FillVertex<'l> {
@@ -2147,7 +2804,70 @@ impl<'l> FillVertex<'l> {
         self.position
     }
 
+    /// The bounding box of the shape being tessellated.
+    ///
+    /// The bounding box contains all of the vertices of the shape, except for the outer
+    /// vertices of the anti-aliasing band.
+    pub fn bounds(&self) -> Box2D {
+        self.bounds
+    }
+
+    /// The position of the vertex relative to the bounding box of the shape, mapped to
+    /// `[0, 1]` along each axis.
+    ///
+    /// This is convenient to generate texture coordinates. An axis along which the shape
+    /// is degenerate is mapped to zero.
+    pub fn uv(&self) -> Point {
+        let size = self.bounds.size();
+        let v = self.position - self.bounds.min;
+        let u = if size.width > 0.0 {
+            v.x / size.width
+        } else {
+            0.0
+        };
+        let v = if size.height > 0.0 {
+            v.y / size.height
+        } else {
+            0.0
+        };
+
+        point(u, v)
+    }
+
+    /// Returns 1.0 for vertices of the shape and 0.0 for the outer vertices of the
+    /// anti-aliasing band.
+    ///
+    /// See `FillOptions::anti_alias`.
+    pub fn coverage(&self) -> f32 {
+        self.coverage
+    }
+
     /// Return an iterator over the sources of the vertex.
+    ///
+    /// The sources describe where the vertex comes from in the input path:
+    ///
+    /// - A vertex placed on an endpoint of the path has a `VertexSource::Endpoint`
+    ///   source with the id of that endpoint. This is the common case for paths made
+    ///   of line segments without self-intersections.
+    /// - A vertex produced by flattening a curve has a `VertexSource::Edge` source
+    ///   where `from` and `to` are the endpoints of the curve and `t` is the curve
+    ///   parameter at the vertex.
+    /// - A vertex created at an intersection has one `VertexSource::Edge` source for
+    ///   each of the intersecting edges.
+    /// - When several endpoints or edges of the path meet at the same position, the
+    ///   vertex has a source for each of them.
+    ///
+    /// Vertices of the anti-aliasing band have the same sources as the vertex of the
+    /// shape they are attached to. The fast paths that don't go through the sweep line
+    /// algorithm (`tessellate_rectangle`, `tessellate_circle`, etc.) produce vertices
+    /// without sources.
+    ///
+    /// `FillTessellator::tessellate` takes `PathEvent`s which don't carry endpoint ids:
+    /// the sources then refer to `EndpointId::INVALID`. Use `tessellate_path`,
+    /// `tessellate_with_ids` or `tessellate_events` to get meaningful endpoint ids.
+    ///
+    /// The iterator reads directly from the tessellator's internal data and does not
+    /// allocate.
     pub fn sources(&self) -> VertexSourceIterator {
         VertexSourceIterator {
             events: self.events,
@@ -2269,6 +2989,8 @@ impl<'l> FillVertex<'l> {
 }
 
 /// An iterator over the sources of a given vertex.
+///
+/// See `FillVertex::sources`.
 #[derive(Clone)]
 pub struct VertexSourceIterator<'l> {
     events: &'l EventQueue,
@@ -2343,8 +3065,9 @@ impl<'l> FillBuilder<'l> {
         options: &'l FillOptions,
         output: &'l mut dyn FillGeometryBuilder,
     ) -> Self {
-        let events = core::mem::replace(&mut tessellator.events, EventQueue::new())
+        let mut events = core::mem::replace(&mut tessellator.events, EventQueue::new())
             .into_builder(options.tolerance);
+        events.set_clip_rect(options.clip_rect, options.sweep_orientation);
 
         FillBuilder {
             events,
@@ -2382,8 +3105,9 @@ impl<'l> FillBuilder<'l> {
         id
     }
 
-    pub fn end(&mut self, _close: bool) {
-        self.events.end(self.first_position, self.first_id);
+    pub fn end(&mut self, close: bool) {
+        self.events
+            .end_sub_path(self.first_position, self.first_id, close);
     }
 
     pub fn line_to(&mut self, to: Point, attributes: Attributes) -> EndpointId {
@@ -2520,7 +3244,7 @@ impl<'l> FillBuilder<'l> {
         };
 
         self.tessellator
-            .tessellate_impl(self.options, attrib_store, self.output)
+            .tessellate_impl(self.options, attrib_store, None, self.output)
     }
 }
 
@@ -2,7 +2,10 @@ use crate::extra::rust_logo::build_logo_path;
 use crate::geometry_builder::*;
 use crate::math::*;
 use crate::path::{Path, PathSlice};
-use crate::{FillOptions, FillRule, FillTessellator, FillVertex, TessellationError, VertexId};
+use crate::{
+    FillOptions, FillRule, FillTessellator, FillVertex, TessellationError, UnsupportedParamater,
+    VertexId,
+};
 
 use core::f32::consts::PI;
 use alloc::vec::Vec;
@@ -77,6 +80,106 @@ fn test_too_many_vertices() {
     );
 }
 
+#[test]
+fn test_too_many_vertices_for_index_type() {
+    // The buffers builder reports an error instead of truncating indices that
+    // don't fit in the index type.
+    let mut path = Path::builder().with_svg();
+    build_logo_path(&mut path);
+    let path = path.build();
+
+    let mut tess = FillTessellator::new();
+    let options = FillOptions::tolerance(0.05);
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    assert_eq!(
+        tess.tessellate(
+            &path,
+            &options,
+            &mut simple_builder(&mut buffers).with_vertex_offset(65000),
+        ),
+        Err(TessellationError::GeometryBuilder(
+            GeometryBuilderError::TooManyVertices
+        )),
+    );
+    // The partial geometry is removed.
+    assert!(buffers.vertices.is_empty());
+    assert!(buffers.indices.is_empty());
+
+    buffers.vertices.resize(65000, point(0.0, 0.0));
+    assert_eq!(
+        tess.tessellate(&path, &options, &mut simple_builder(&mut buffers)),
+        Err(TessellationError::GeometryBuilder(
+            GeometryBuilderError::TooManyVertices
+        )),
+    );
+    assert_eq!(buffers.vertices.len(), 65000);
+
+    // The same geometry fits with 32 bits indices.
+    let mut buffers: VertexBuffers<Point, u32> = VertexBuffers::new();
+    buffers.vertices.resize(65000, point(0.0, 0.0));
+    tess.tessellate(
+        &path,
+        &options,
+        &mut BuffersBuilder::new(&mut buffers, Positions),
+    )
+    .unwrap();
+    assert!(buffers.indices.iter().all(|idx| (*idx as usize) < buffers.vertices.len()));
+    assert!(buffers.indices.iter().any(|idx| *idx > u16::MAX as u32));
+}
+
+#[test]
+fn test_reuse_tessellator() {
+    use crate::{StrokeOptions, StrokeTessellator};
+
+    let mut path = Path::builder().with_svg();
+    build_logo_path(&mut path);
+    let path = path.build();
+
+    let options = FillOptions::tolerance(0.05);
+    let mut expected: VertexBuffers<Point, u16> = VertexBuffers::new();
+    FillTessellator::new()
+        .tessellate_path(&path, &options, &mut simple_builder(&mut expected))
+        .unwrap();
+
+    let mut tess = FillTessellator::new();
+    for i in 0..3 {
+        // Interrupt the tessellation halfway through to leave some state behind.
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        assert!(tess
+            .tessellate_path(
+                &path,
+                &options,
+                &mut simple_builder(&mut buffers).with_vertex_offset(65300),
+            )
+            .is_err());
+
+        if i == 1 {
+            tess.reset();
+        }
+
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        tess.tessellate_path(&path, &options, &mut simple_builder(&mut buffers))
+            .unwrap();
+        assert_eq!(buffers.vertices, expected.vertices);
+        assert_eq!(buffers.indices, expected.indices);
+    }
+
+    let mut stroke_tess = StrokeTessellator::new();
+    let stroke_options = StrokeOptions::tolerance(0.05);
+    let mut expected: VertexBuffers<Point, u16> = VertexBuffers::new();
+    stroke_tess
+        .tessellate_path(&path, &stroke_options, &mut simple_builder(&mut expected))
+        .unwrap();
+    stroke_tess.reset();
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    stroke_tess
+        .tessellate_path(&path, &stroke_options, &mut simple_builder(&mut buffers))
+        .unwrap();
+    assert_eq!(buffers.vertices, expected.vertices);
+    assert_eq!(buffers.indices, expected.indices);
+}
+
 #[cfg(test)]
 fn test_path(path: PathSlice) {
     test_path_internal(path, FillRule::EvenOdd, None);
@@ -2501,3 +2604,1262 @@ fn test_triangle_winding() {
     )
     .unwrap();
 }
+
+#[test]
+fn test_anti_alias_band() {
+    use crate::{GeometryBuilder, Orientation};
+
+    struct Builder {
+        vertices: Vec<(Point, f32)>,
+        area: f32,
+    }
+
+    impl GeometryBuilder for Builder {
+        fn add_triangle(&mut self, a: VertexId, b: VertexId, c: VertexId) {
+            let a = self.vertices[a.to_usize()].0;
+            let b = self.vertices[b.to_usize()].0;
+            let c = self.vertices[c.to_usize()].0;
+            let cross = (b - a).cross(c - b);
+            assert!(cross <= 0.0);
+            self.area -= cross * 0.5;
+        }
+    }
+
+    impl FillGeometryBuilder for Builder {
+        fn add_fill_vertex(&mut self, v: FillVertex) -> Result<VertexId, GeometryBuilderError> {
+            let id = VertexId(self.vertices.len() as u32);
+            self.vertices.push((v.position(), v.coverage()));
+
+            Ok(id)
+        }
+    }
+
+    // A 10x10 square with a 4x4 hole.
+    let mut path = Path::builder();
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(10.0, 0.0));
+    path.line_to(point(10.0, 10.0));
+    path.line_to(point(0.0, 10.0));
+    path.close();
+    path.begin(point(3.0, 3.0));
+    path.line_to(point(7.0, 3.0));
+    path.line_to(point(7.0, 7.0));
+    path.line_to(point(3.0, 7.0));
+    path.close();
+    let path = path.build();
+
+    for orientation in [Orientation::Vertical, Orientation::Horizontal] {
+        let mut builder = Builder {
+            vertices: Vec::new(),
+            area: 0.0,
+        };
+
+        let options = FillOptions::even_odd()
+            .with_sweep_orientation(orientation)
+            .with_anti_alias(0.5);
+        FillTessellator::new()
+            .tessellate(&path, &options, &mut builder)
+            .unwrap();
+
+        let outer: Vec<Point> = builder
+            .vertices
+            .iter()
+            .filter(|(_, coverage)| *coverage == 0.0)
+            .map(|(p, _)| *p)
+            .collect();
+        let inner_count = builder.vertices.len() - outer.len();
+        assert_eq!(inner_count, 8);
+        assert_eq!(outer.len(), 16);
+
+        // The band is outside of the filled area.
+        for p in &outer {
+            let in_square = p.x > 0.0 && p.x < 10.0 && p.y > 0.0 && p.y < 10.0;
+            let in_hole = p.x >= 3.0 && p.x <= 7.0 && p.y >= 3.0 && p.y <= 7.0;
+            assert!(!in_square || in_hole, "{:?}", p);
+        }
+
+        // Filled area + bands + bevel joins.
+        let expected = (100.0 - 16.0) + (40.0 + 16.0) * 0.5 + 8.0 * 0.125;
+        assert!((builder.area - expected).abs() < 1e-3, "{:?}", builder.area);
+    }
+
+    // Without the option, there is no band.
+    let mut builder = Builder {
+        vertices: Vec::new(),
+        area: 0.0,
+    };
+    FillTessellator::new()
+        .tessellate(&path, &FillOptions::even_odd(), &mut builder)
+        .unwrap();
+    assert!(builder
+        .vertices
+        .iter()
+        .all(|(_, coverage)| *coverage == 1.0));
+    assert!((builder.area - 84.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_circle_and_annulus_sectors() {
+    use crate::path::Winding;
+    use crate::{StrokeOptions, StrokeTessellator};
+
+    fn area(buffers: &VertexBuffers<Point, u16>) -> f32 {
+        let mut area = 0.0;
+        for tri in buffers.indices.chunks(3) {
+            let a = buffers.vertices[tri[0] as usize];
+            let b = buffers.vertices[tri[1] as usize];
+            let c = buffers.vertices[tri[2] as usize];
+            area += (b - a).cross(c - a).abs() * 0.5;
+        }
+        area
+    }
+
+    let mut tess = FillTessellator::new();
+    let options = FillOptions::tolerance(0.001);
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    let mut output = simple_builder(&mut buffers);
+    let mut builder = tess.builder(&options, &mut output);
+    builder.add_circle_sector(
+        point(5.0, 5.0),
+        2.0,
+        Angle::degrees(30.0),
+        Angle::degrees(90.0),
+        Winding::Positive,
+    );
+    builder.build().unwrap();
+    assert!((area(&buffers) - PI).abs() < 0.01);
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    let mut output = simple_builder(&mut buffers);
+    let mut builder = tess.builder(&options, &mut output);
+    builder.add_annulus_sector(
+        point(5.0, 5.0),
+        1.0,
+        2.0,
+        Angle::degrees(-45.0),
+        Angle::degrees(-180.0),
+        Winding::Negative,
+    );
+    builder.build().unwrap();
+    assert!((area(&buffers) - PI * 1.5).abs() < 0.01);
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    let mut output = simple_builder(&mut buffers);
+    let mut stroke_tess = StrokeTessellator::new();
+    let stroke_options = StrokeOptions::tolerance(0.001);
+    let mut builder = stroke_tess.builder(&stroke_options, &mut output);
+    builder.add_annulus_sector(
+        point(5.0, 5.0),
+        1.0,
+        2.0,
+        Angle::degrees(0.0),
+        Angle::degrees(90.0),
+        Winding::Positive,
+    );
+    builder.build().unwrap();
+    assert!(!buffers.indices.is_empty());
+}
+
+#[test]
+fn test_vertex_uv() {
+    use crate::Orientation;
+
+    let mut tess = FillTessellator::new();
+
+    let mut path = Path::builder();
+    path.begin(point(10.0, 20.0));
+    path.line_to(point(30.0, 20.0));
+    path.quadratic_bezier_to(point(50.0, 30.0), point(30.0, 40.0));
+    path.line_to(point(10.0, 40.0));
+    path.end(true);
+    let path = path.build();
+
+    for options in [
+        FillOptions::tolerance(0.05),
+        FillOptions::tolerance(0.05).with_sweep_orientation(Orientation::Horizontal),
+    ] {
+        let mut buffers: VertexBuffers<(Point, Point), u16> = VertexBuffers::new();
+        tess.tessellate_path(
+            &path,
+            &options,
+            &mut BuffersBuilder::new(&mut buffers, |v: FillVertex| {
+                assert_eq!(v.bounds().min, point(10.0, 20.0));
+                assert_eq!(v.bounds().max.y, 40.0);
+                assert!((v.bounds().max.x - 40.0).abs() < 0.1);
+                (v.position(), v.uv())
+            }),
+        )
+        .unwrap();
+
+        for (position, uv) in &buffers.vertices {
+            assert!(uv.x >= 0.0 && uv.x <= 1.0);
+            assert!(uv.y >= 0.0 && uv.y <= 1.0);
+            if *position == point(10.0, 40.0) {
+                assert_eq!(*uv, point(0.0, 1.0));
+            }
+        }
+    }
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    tess.tessellate_circle(
+        point(0.0, 0.0),
+        2.0,
+        &FillOptions::tolerance(0.05),
+        &mut BuffersBuilder::new(&mut buffers, |v: FillVertex| v.uv()),
+    )
+    .unwrap();
+    assert!(buffers.vertices.iter().any(|uv| *uv == point(0.5, 0.0)));
+    assert!(buffers.vertices.iter().all(|uv| uv.x >= 0.0 && uv.x <= 1.0));
+}
+
+#[test]
+fn test_output_winding() {
+    use crate::path::math::Transform;
+    use crate::path::Winding;
+    use crate::{Orientation, StrokeOptions, StrokeTessellator};
+
+    fn winding_sign(buffers: &VertexBuffers<Point, u16>) -> f32 {
+        let mut sign = 0.0;
+        for tri in buffers.indices.chunks(3) {
+            let a = buffers.vertices[tri[0] as usize];
+            let b = buffers.vertices[tri[1] as usize];
+            let c = buffers.vertices[tri[2] as usize];
+            let cross = (b - a).cross(c - b);
+            if cross.abs() > 0.0001 {
+                assert!(sign * cross >= 0.0, "{:?} {:?} {:?}", a, b, c);
+                sign = cross.signum();
+            }
+        }
+
+        sign
+    }
+
+    let mut path = Path::builder().with_svg();
+    build_logo_path(&mut path);
+    let path = path.build();
+    // The same path, mirrored to invert the orientation of its sub-paths.
+    let mirrored = path.clone().transformed(&Transform::scale(-1.0, 1.0));
+
+    let mut tess = FillTessellator::new();
+    for path in [&path, &mirrored] {
+        for orientation in [Orientation::Vertical, Orientation::Horizontal] {
+            for anti_alias in [None, Some(0.5)] {
+                let mut options = FillOptions::tolerance(0.05)
+                    .with_fill_rule(FillRule::NonZero)
+                    .with_sweep_orientation(orientation);
+                options.anti_alias = anti_alias;
+
+                let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+                tess.tessellate_path(path, &options, &mut simple_builder(&mut buffers))
+                    .unwrap();
+                assert_eq!(winding_sign(&buffers), -1.0);
+
+                let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+                tess.tessellate_path(
+                    path,
+                    &options,
+                    &mut simple_builder(&mut buffers).with_inverted_winding(),
+                )
+                .unwrap();
+                assert_eq!(winding_sign(&buffers), 1.0);
+            }
+        }
+    }
+
+    let mut stroke_tess = StrokeTessellator::new();
+    for winding in [Winding::Positive, Winding::Negative] {
+        let mut path = Path::builder();
+        path.add_rounded_rectangle(
+            &Box2D {
+                min: point(0.0, 0.0),
+                max: point(100.0, 50.0),
+            },
+            &crate::path::builder::BorderRadii::new(10.0),
+            winding,
+        );
+        let path = path.build();
+
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        stroke_tess
+            .tessellate_path(
+                &path,
+                &StrokeOptions::tolerance(0.05).with_line_width(2.0),
+                &mut simple_builder(&mut buffers),
+            )
+            .unwrap();
+        assert_eq!(winding_sign(&buffers), -1.0);
+
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        stroke_tess
+            .tessellate_path(
+                &path,
+                &StrokeOptions::tolerance(0.05).with_line_width(2.0),
+                &mut InvertWinding::new(simple_builder(&mut buffers)),
+            )
+            .unwrap();
+        assert_eq!(winding_sign(&buffers), 1.0);
+    }
+}
+
+#[test]
+fn test_tessellate_batch() {
+    let mut glyph = Path::builder();
+    glyph.begin(point(0.0, 0.0));
+    glyph.line_to(point(1.0, 0.0));
+    glyph.line_to(point(1.0, 1.0));
+    glyph.line_to(point(0.0, 1.0));
+    glyph.end(true);
+    let glyph = glyph.build();
+
+    let mut shape = Path::builder_with_attributes(1);
+    shape.begin(point(1.0, 0.5), &[1.0]);
+    shape.quadratic_bezier_to(point(1.0, 1.0), point(0.5, 1.0), &[2.0]);
+    shape.line_to(point(0.5, 0.5), &[3.0]);
+    shape.end(true);
+    let shape = shape.build();
+
+    let items = [
+        (glyph.as_slice(), Transform::translation(0.0, 0.0)),
+        (shape.as_slice(), Transform::translation(10.0, 0.0)),
+        (
+            glyph.as_slice(),
+            Transform::scale(2.0, 2.0).then_translate(vector(20.0, 0.0)),
+        ),
+    ];
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    let mut tess = FillTessellator::new();
+    let ranges = tess
+        .tessellate_batch(
+            &items,
+            &FillOptions::tolerance(0.05),
+            &mut simple_builder(&mut buffers),
+        )
+        .unwrap();
+
+    assert_eq!(ranges.len(), 3);
+    assert_eq!(ranges[0].start, 0);
+    assert_eq!(ranges[0].end, ranges[1].start);
+    assert_eq!(ranges[1].end, ranges[2].start);
+    assert_eq!(ranges[2].end as usize, buffers.indices.len());
+
+    let expected_x = [0.0..1.0, 10.5..11.0, 20.0..22.0];
+    for (range, x) in ranges.iter().zip(expected_x.iter()) {
+        assert!(!range.is_empty());
+        for idx in &buffers.indices[range.start as usize..range.end as usize] {
+            let p = buffers.vertices[*idx as usize];
+            assert!(p.x >= x.start - 1e-4 && p.x <= x.end + 1e-4);
+        }
+    }
+}
+
+#[test]
+fn test_annulus() {
+    use crate::path::Winding;
+
+    fn area(buffers: &VertexBuffers<Point, u16>) -> f32 {
+        let mut area = 0.0;
+        for tri in buffers.indices.chunks(3) {
+            let a = buffers.vertices[tri[0] as usize];
+            let b = buffers.vertices[tri[1] as usize];
+            let c = buffers.vertices[tri[2] as usize];
+            area += (b - a).cross(c - a).abs() * 0.5;
+        }
+        area
+    }
+
+    let mut tess = FillTessellator::new();
+    for fill_rule in [FillRule::EvenOdd, FillRule::NonZero] {
+        for winding in [Winding::Positive, Winding::Negative] {
+            let options = FillOptions::tolerance(0.001).with_fill_rule(fill_rule);
+
+            let mut path = Path::builder();
+            path.add_annulus(point(5.0, 5.0), 1.0, 2.0, winding);
+            let path = path.build();
+
+            let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+            tess.tessellate_path(&path, &options, &mut simple_builder(&mut buffers))
+                .unwrap();
+            assert!((area(&buffers) - PI * 3.0).abs() < 0.01);
+
+            // Same thing, directly in the fill tessellator's builder which approximates
+            // circles with quadratic bézier curves.
+            let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+            let mut output = simple_builder(&mut buffers);
+            let mut builder = tess.builder(&options, &mut output);
+            builder.add_annulus(point(5.0, 5.0), 1.0, 2.0, winding);
+            builder.build().unwrap();
+            assert!((area(&buffers) - PI * 3.0).abs() < 0.05);
+        }
+    }
+
+    // Two circles with the same winding don't make a hole with the non-zero fill rule.
+    let mut path = Path::builder();
+    path.add_circle(point(5.0, 5.0), 2.0, Winding::Positive);
+    path.add_circle(point(5.0, 5.0), 1.0, Winding::Positive);
+    let path = path.build();
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    tess.tessellate_path(
+        &path,
+        &FillOptions::tolerance(0.001).with_fill_rule(FillRule::NonZero),
+        &mut simple_builder(&mut buffers),
+    )
+    .unwrap();
+    assert!((area(&buffers) - PI * 4.0).abs() < 0.01);
+}
+
+#[test]
+fn test_convex_polygon() {
+    // Returns the area, checking that all triangles have the expected orientation.
+    fn area(buffers: &VertexBuffers<Point, u16>) -> f32 {
+        let mut area = 0.0;
+        for tri in buffers.indices.chunks(3) {
+            let a = buffers.vertices[tri[0] as usize];
+            let b = buffers.vertices[tri[1] as usize];
+            let c = buffers.vertices[tri[2] as usize];
+            let cross = (b - a).cross(c - b);
+            assert!(cross <= 0.0);
+            area -= cross * 0.5;
+        }
+        area
+    }
+
+    let hexagon = [
+        point(0.0, 0.0),
+        point(2.0, 0.0),
+        point(3.0, 1.0),
+        point(3.0, 2.0),
+        point(1.0, 2.0),
+        point(0.0, 1.0),
+    ];
+    let mut reversed = hexagon;
+    reversed.reverse();
+
+    let mut tess = FillTessellator::new();
+    for points in [&hexagon, &reversed] {
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        tess.tessellate_convex_polygon(
+            points,
+            &FillOptions::DEFAULT,
+            &mut simple_builder(&mut buffers),
+        )
+        .unwrap();
+
+        assert_eq!(buffers.vertices.len(), 6);
+        assert_eq!(buffers.indices.len(), 4 * 3);
+        assert_eq!(area(&buffers), 5.0);
+    }
+
+    // Falls back to the general algorithm with anti-aliasing.
+    let mut options = FillOptions::DEFAULT;
+    options.anti_alias = Some(0.5);
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    tess.tessellate_convex_polygon(&hexagon, &options, &mut simple_builder(&mut buffers))
+        .unwrap();
+    assert!(!buffers.indices.is_empty());
+
+    // Degenerate input produces no geometry.
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    tess.tessellate_convex_polygon(
+        &hexagon[..2],
+        &FillOptions::DEFAULT,
+        &mut simple_builder(&mut buffers),
+    )
+    .unwrap();
+    assert!(buffers.indices.is_empty());
+}
+
+#[test]
+fn test_clip_rect() {
+    use crate::path::Winding;
+    use crate::Orientation;
+
+    // Returns the area and checks that the vertices are in the clip rectangle.
+    fn clipped_area(path: &Path, options: &FillOptions) -> f32 {
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        FillTessellator::new()
+            .tessellate(path, options, &mut simple_builder(&mut buffers))
+            .unwrap();
+
+        let clip = options.clip_rect.unwrap();
+        for v in &buffers.vertices {
+            assert!(
+                v.x >= clip.min.x && v.x <= clip.max.x && v.y >= clip.min.y && v.y <= clip.max.y,
+                "{:?}",
+                v
+            );
+        }
+
+        let mut area = 0.0;
+        for tri in buffers.indices.chunks(3) {
+            let a = buffers.vertices[tri[0] as usize];
+            let b = buffers.vertices[tri[1] as usize];
+            let c = buffers.vertices[tri[2] as usize];
+            area += (b - a).cross(c - a).abs() * 0.5;
+        }
+        area
+    }
+
+    let mut builder = Path::builder();
+    builder.add_rectangle(
+        &Box2D::new(point(0.0, 0.0), point(100.0, 100.0)),
+        Winding::Positive,
+    );
+    builder.add_rectangle(
+        &Box2D::new(point(40.0, 40.0), point(60.0, 60.0)),
+        Winding::Negative,
+    );
+    let square_with_hole = builder.build();
+
+    let mut builder = Path::builder();
+    builder.add_circle(point(0.0, 0.0), 50.0, Winding::Positive);
+    let circle = builder.build();
+
+    let mut builder = Path::builder().with_svg();
+    build_logo_path(&mut builder);
+    let logo = builder.build();
+
+    for orientation in [Orientation::Vertical, Orientation::Horizontal] {
+        let options = FillOptions::tolerance(0.01).with_sweep_orientation(orientation);
+
+        let clip = Box2D::new(point(30.0, 30.0), point(70.0, 70.0));
+        for fill_rule in [FillRule::EvenOdd, FillRule::NonZero] {
+            let options = options.with_clip_rect(clip).with_fill_rule(fill_rule);
+            let area = clipped_area(&square_with_hole, &options);
+            assert!((area - 1200.0).abs() < 0.01, "{}", area);
+        }
+
+        // A quarter of the circle.
+        let clip = Box2D::new(point(0.0, 0.0), point(100.0, 100.0));
+        let area = clipped_area(&circle, &options.with_clip_rect(clip));
+        assert!((area - PI * 2500.0 / 4.0).abs() < 1.0, "{}", area);
+
+        // Outside of the circle.
+        let clip = Box2D::new(point(40.0, 40.0), point(60.0, 60.0));
+        let area = clipped_area(&circle, &options.with_clip_rect(clip));
+        assert!(area < 0.01, "{}", area);
+
+        // The tiles cover the whole logo.
+        let logo_area = clipped_area(
+            &logo,
+            &options.with_clip_rect(Box2D::new(point(0.0, 0.0), point(200.0, 200.0))),
+        );
+        let mut tiles_area = 0.0;
+        for x in 0..4 {
+            for y in 0..4 {
+                let min = point(x as f32 * 50.0, y as f32 * 50.0);
+                let clip = Box2D::new(min, min + vector(50.0, 50.0));
+                tiles_area += clipped_area(&logo, &options.with_clip_rect(clip));
+            }
+        }
+        assert!(
+            (logo_area - tiles_area).abs() < 0.5,
+            "{} {}",
+            logo_area,
+            tiles_area
+        );
+    }
+
+    // The fast path for rectangles is not used when clipping.
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    FillTessellator::new()
+        .tessellate_rectangle(
+            &Box2D::new(point(0.0, 0.0), point(100.0, 100.0)),
+            &FillOptions::DEFAULT.with_clip_rect(Box2D::new(point(10.0, 10.0), point(20.0, 20.0))),
+            &mut simple_builder(&mut buffers),
+        )
+        .unwrap();
+    for v in &buffers.vertices {
+        assert!(v.x >= 10.0 && v.x <= 20.0 && v.y >= 10.0 && v.y <= 20.0);
+    }
+}
+
+#[test]
+fn test_open_sub_paths() {
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.0));
+    builder.line_to(point(1.0, 1.0));
+    builder.close();
+    // Ends at its first point, so it isn't considered open.
+    builder.begin(point(2.0, 0.0));
+    builder.line_to(point(3.0, 0.0));
+    builder.line_to(point(3.0, 1.0));
+    builder.line_to(point(2.0, 0.0));
+    builder.end(false);
+    let closed = builder.build();
+
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.0));
+    builder.line_to(point(1.0, 1.0));
+    builder.end(false);
+    let open = builder.build();
+
+    let mut tess = FillTessellator::new();
+    let strict = FillOptions::DEFAULT.with_open_sub_paths(false);
+    let tessellate = |tess: &mut FillTessellator, path: &Path, options: &FillOptions| {
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        let result = tess.tessellate(path, options, &mut simple_builder(&mut buffers));
+        result.map(|_| buffers.indices.len() / 3)
+    };
+
+    // Open sub-paths are implicitly closed by default.
+    assert_eq!(tessellate(&mut tess, &open, &FillOptions::DEFAULT), Ok(1));
+    assert_eq!(tessellate(&mut tess, &closed, &strict), Ok(2));
+    assert_eq!(
+        tessellate(&mut tess, &open, &strict),
+        Err(TessellationError::UnsupportedParamater(
+            UnsupportedParamater::OpenSubPath
+        ))
+    );
+    // The state doesn't leak into the next tessellation.
+    assert_eq!(tessellate(&mut tess, &closed, &strict), Ok(2));
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    let result = tess.tessellate_path(&open, &strict, &mut simple_builder(&mut buffers));
+    assert!(result.is_err());
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    let mut output = simple_builder(&mut buffers);
+    let mut builder = tess.builder(&strict, &mut output);
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(1.0, 0.0));
+    builder.line_to(point(1.0, 1.0));
+    builder.end(false);
+    assert!(builder.build().is_err());
+}
+
+#[test]
+fn test_simple_polygon() {
+    use crate::path::Winding;
+
+    // Returns the area, checking that all triangles have the expected orientation.
+    fn area(buffers: &VertexBuffers<Point, u16>) -> f32 {
+        let mut area = 0.0;
+        for tri in buffers.indices.chunks(3) {
+            let a = buffers.vertices[tri[0] as usize];
+            let b = buffers.vertices[tri[1] as usize];
+            let c = buffers.vertices[tri[2] as usize];
+            let cross = (b - a).cross(c - b);
+            assert!(cross <= 0.0);
+            area -= cross * 0.5;
+        }
+        area
+    }
+
+    // A comb with three teeth.
+    let comb = [
+        point(0.0, 0.0),
+        point(5.0, 0.0),
+        point(5.0, 3.0),
+        point(4.0, 3.0),
+        point(4.0, 1.0),
+        point(3.0, 1.0),
+        point(3.0, 3.0),
+        point(2.0, 3.0),
+        point(2.0, 1.0),
+        point(1.0, 1.0),
+        point(1.0, 3.0),
+        point(0.0, 3.0),
+    ];
+    // A square with aligned points on its sides and a notch.
+    let notched_square = [
+        point(0.0, 0.0),
+        point(1.0, 0.0),
+        point(2.0, 0.0),
+        point(2.0, 2.0),
+        point(1.0, 1.0),
+        point(0.0, 2.0),
+        point(0.0, 1.0),
+    ];
+
+    let mut tess = FillTessellator::new();
+    for (polygon, expected_area) in [(&comb[..], 11.0), (&notched_square[..], 3.0)] {
+        let mut reversed = polygon.to_vec();
+        reversed.reverse();
+        for (points, winding) in [
+            (polygon, Winding::Positive),
+            (&reversed[..], Winding::Negative),
+        ] {
+            let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+            tess.tessellate_simple_polygon(
+                points,
+                winding,
+                &FillOptions::DEFAULT,
+                &mut simple_builder(&mut buffers),
+            )
+            .unwrap();
+
+            assert_eq!(buffers.vertices.len(), points.len());
+            assert!(buffers.indices.len() <= (points.len() - 2) * 3);
+            assert!((area(&buffers) - expected_area).abs() < 1e-5);
+        }
+    }
+
+    // Falls back to the general algorithm with anti-aliasing.
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    tess.tessellate_simple_polygon(
+        &comb,
+        Winding::Positive,
+        &FillOptions::DEFAULT.with_anti_alias(0.5),
+        &mut simple_builder(&mut buffers),
+    )
+    .unwrap();
+    assert!(buffers.vertices.len() > comb.len());
+
+    // Degenerate input produces no geometry.
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    tess.tessellate_simple_polygon(
+        &comb[..2],
+        Winding::Positive,
+        &FillOptions::DEFAULT,
+        &mut simple_builder(&mut buffers),
+    )
+    .unwrap();
+    assert!(buffers.indices.is_empty());
+}
+
+#[test]
+fn test_vertex_source_kinds() {
+    use crate::VertexSource;
+
+    // A bow tie with a self-intersection at (1, 1), and a quadratic curve.
+    let mut builder = Path::builder();
+    let a = builder.begin(point(0.0, 0.0));
+    let b = builder.line_to(point(2.0, 2.0));
+    let c = builder.line_to(point(2.0, 0.0));
+    let d = builder.line_to(point(0.0, 2.0));
+    builder.end(true);
+    let e = builder.begin(point(10.0, 0.0));
+    let f = builder.quadratic_bezier_to(point(12.0, 4.0), point(14.0, 0.0));
+    builder.end(true);
+    let path = builder.build();
+
+    let mut buffers: VertexBuffers<(Point, Vec<VertexSource>), u16> = VertexBuffers::new();
+    FillTessellator::new()
+        .tessellate_path(
+            &path,
+            &FillOptions::tolerance(0.05),
+            &mut BuffersBuilder::new(&mut buffers, |v: FillVertex| {
+                (v.position(), v.sources().collect::<Vec<_>>())
+            }),
+        )
+        .unwrap();
+
+    for (position, sources) in &buffers.vertices {
+        assert!(!sources.is_empty());
+        if *position == point(1.0, 1.0) {
+            // The intersection is on the two crossing edges.
+            assert_eq!(sources.len(), 2);
+            for src in sources {
+                match *src {
+                    VertexSource::Edge { from, to, t } => {
+                        assert!((from, to) == (a, b) || (from, to) == (c, d));
+                        assert!((t - 0.5).abs() < 1e-5);
+                    }
+                    _ => panic!("{:?}", src),
+                }
+            }
+        } else if position.x < 5.0 {
+            assert_eq!(sources.len(), 1);
+            let id = match sources[0] {
+                VertexSource::Endpoint { id } => id,
+                _ => panic!("{:?}", sources[0]),
+            };
+            assert_eq!(path[id], *position);
+        } else if *position == point(10.0, 0.0) {
+            assert_eq!(sources, &[VertexSource::Endpoint { id: e }]);
+        } else if *position == point(14.0, 0.0) {
+            assert_eq!(sources, &[VertexSource::Endpoint { id: f }]);
+        } else {
+            // Vertices produced by flattening the curve.
+            assert_eq!(sources.len(), 1);
+            match sources[0] {
+                VertexSource::Edge { from, to, t } => {
+                    assert_eq!((from, to), (e, f));
+                    assert!(t > 0.0 && t < 1.0);
+                }
+                _ => panic!("{:?}", sources[0]),
+            }
+        }
+    }
+
+    // The fast paths don't produce sources.
+    let mut buffers: VertexBuffers<usize, u16> = VertexBuffers::new();
+    FillTessellator::new()
+        .tessellate_rectangle(
+            &Box2D {
+                min: point(0.0, 0.0),
+                max: point(1.0, 1.0),
+            },
+            &FillOptions::DEFAULT,
+            &mut BuffersBuilder::new(&mut buffers, |v: FillVertex| v.sources().count()),
+        )
+        .unwrap();
+    assert!(buffers.vertices.iter().all(|n| *n == 0));
+}
+
+#[test]
+fn test_tessellate_events() {
+    use crate::path::{AttributeStore, Attributes, EndpointId, PathEvent};
+    use crate::VertexSource;
+
+    // One attribute per endpoint, equal to ten times the index of the endpoint.
+    struct Store([f32; 4]);
+    impl AttributeStore for Store {
+        fn get(&self, id: EndpointId) -> Attributes<'_> {
+            core::slice::from_ref(&self.0[id.to_usize()])
+        }
+        fn num_attributes(&self) -> usize {
+            1
+        }
+    }
+    let store = Store([0.0, 10.0, 20.0, 30.0]);
+
+    let endpoints = [
+        point(0.0, 0.0),
+        point(4.0, 0.0),
+        point(4.0, 4.0),
+        point(0.0, 4.0),
+    ];
+    let events = (0..4)
+        .map(|i| {
+            if i == 0 {
+                PathEvent::Begin { at: endpoints[0] }
+            } else if i == 2 {
+                PathEvent::Quadratic {
+                    from: endpoints[1],
+                    ctrl: point(6.0, 2.0),
+                    to: endpoints[2],
+                }
+            } else {
+                PathEvent::Line {
+                    from: endpoints[i - 1],
+                    to: endpoints[i],
+                }
+            }
+        })
+        .chain(core::iter::once(PathEvent::End {
+            last: endpoints[3],
+            first: endpoints[0],
+            close: true,
+        }));
+
+    let mut buffers: VertexBuffers<(Point, Vec<VertexSource>, f32), u16> = VertexBuffers::new();
+    FillTessellator::new()
+        .tessellate_events(
+            events,
+            Some(&store),
+            &FillOptions::tolerance(0.05),
+            &mut BuffersBuilder::new(&mut buffers, |mut v: FillVertex| {
+                let attribute = v.interpolated_attributes()[0];
+                (v.position(), v.sources().collect::<Vec<_>>(), attribute)
+            }),
+        )
+        .unwrap();
+
+    assert!(buffers.vertices.len() > 4);
+    for (position, sources, attribute) in &buffers.vertices {
+        assert_eq!(sources.len(), 1);
+        match sources[0] {
+            VertexSource::Endpoint { id } => {
+                assert_eq!(endpoints[id.to_usize()], *position);
+                assert_eq!(*attribute, id.to_usize() as f32 * 10.0);
+            }
+            VertexSource::Edge { from, to, t } => {
+                // Vertices produced by flattening the curve.
+                assert_eq!((from, to), (EndpointId(1), EndpointId(2)));
+                assert!((attribute - (10.0 + 10.0 * t)).abs() < 1e-4);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sweep_inspector() {
+    use crate::{FillInspector, SweepEvent, SweepEventKind};
+
+    struct Inspector {
+        kinds: Vec<SweepEventKind>,
+        positions: Vec<Point>,
+        max_active_edges: usize,
+        saw_merge_edge: bool,
+    }
+
+    impl FillInspector for Inspector {
+        fn sweep_event(&mut self, event: &SweepEvent) {
+            self.kinds.push(event.kind);
+            self.positions.push(event.position);
+            self.max_active_edges = self.max_active_edges.max(event.active_edges.len());
+            self.saw_merge_edge |= event.active_edges.iter().any(|e| e.is_merge);
+        }
+    }
+
+    fn inspect(points: &[Point]) -> Inspector {
+        let mut builder = Path::builder();
+        builder.add_polygon(crate::path::Polygon {
+            points,
+            closed: true,
+        });
+        let path = builder.build();
+
+        let mut inspector = Inspector {
+            kinds: Vec::new(),
+            positions: Vec::new(),
+            max_active_edges: 0,
+            saw_merge_edge: false,
+        };
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        FillTessellator::new()
+            .tessellate_with_inspector(
+                &path,
+                &FillOptions::default(),
+                &mut inspector,
+                &mut simple_builder(&mut buffers),
+            )
+            .unwrap();
+
+        assert_eq!(buffers.indices.len(), 9);
+
+        inspector
+    }
+
+    // A square with a notch at the bottom.
+    let split = inspect(&[
+        point(0.0, 0.0),
+        point(10.0, 0.0),
+        point(10.0, 10.0),
+        point(5.0, 5.0),
+        point(0.0, 10.0),
+    ]);
+
+    assert_eq!(
+        split.positions,
+        &[
+            point(0.0, 0.0),
+            point(10.0, 0.0),
+            point(5.0, 5.0),
+            point(0.0, 10.0),
+            point(10.0, 10.0),
+        ]
+    );
+    assert_eq!(
+        split.kinds,
+        &[
+            SweepEventKind::Start,
+            SweepEventKind::Regular,
+            SweepEventKind::Split,
+            SweepEventKind::End,
+            SweepEventKind::End,
+        ]
+    );
+    assert_eq!(split.max_active_edges, 4);
+    assert!(!split.saw_merge_edge);
+
+    // A square with a notch at the top.
+    let merge = inspect(&[
+        point(0.0, 0.0),
+        point(5.0, 5.0),
+        point(10.0, 0.0),
+        point(10.0, 10.0),
+        point(0.0, 10.0),
+    ]);
+
+    assert_eq!(merge.kinds[0], SweepEventKind::Start);
+    assert_eq!(merge.kinds[1], SweepEventKind::Start);
+    assert_eq!(merge.kinds[2], SweepEventKind::Merge);
+    assert_eq!(merge.kinds[4], SweepEventKind::End);
+    assert!(merge.saw_merge_edge);
+}
+
+#[test]
+fn test_boundary_edges() {
+    use crate::Orientation;
+
+    // A 10x10 square with a 4x4 hole.
+    let mut builder = Path::builder();
+    builder.add_rectangle(
+        &Box2D {
+            min: point(0.0, 0.0),
+            max: point(10.0, 10.0),
+        },
+        crate::path::Winding::Positive,
+    );
+    builder.add_rectangle(
+        &Box2D {
+            min: point(3.0, 3.0),
+            max: point(7.0, 7.0),
+        },
+        crate::path::Winding::Positive,
+    );
+    let square_with_hole = builder.build();
+
+    // Two overlapping squares.
+    let mut builder = Path::builder();
+    builder.add_rectangle(
+        &Box2D {
+            min: point(0.0, 0.0),
+            max: point(10.0, 10.0),
+        },
+        crate::path::Winding::Positive,
+    );
+    builder.add_rectangle(
+        &Box2D {
+            min: point(5.0, 5.0),
+            max: point(15.0, 15.0),
+        },
+        crate::path::Winding::Positive,
+    );
+    let overlapping_squares = builder.build();
+
+    let mut tess = FillTessellator::new();
+    for orientation in [Orientation::Vertical, Orientation::Horizontal] {
+        for (path, fill_rule, perimeter) in [
+            (&square_with_hole, FillRule::EvenOdd, 56.0),
+            (&overlapping_squares, FillRule::NonZero, 60.0),
+        ] {
+            let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+            let mut boundary: Vec<u16> = Vec::new();
+            let options = FillOptions::default()
+                .with_fill_rule(fill_rule)
+                .with_sweep_orientation(orientation)
+                .with_boundary_edges(true);
+            tess.tessellate_path(
+                path,
+                &options,
+                &mut simple_builder(&mut buffers).with_boundary_buffer(&mut boundary),
+            )
+            .unwrap();
+
+            let mut length = 0.0;
+            for edge in boundary.chunks(2) {
+                let (a, b) = (edge[0], edge[1]);
+                length += (buffers.vertices[b as usize] - buffers.vertices[a as usize]).length();
+
+                // The edge has the same orientation as the triangle it belongs to.
+                let found = buffers
+                    .indices
+                    .chunks(3)
+                    .any(|tri| (0..3).any(|i| tri[i] == a && tri[(i + 1) % 3] == b));
+                assert!(found, "{:?}", edge);
+            }
+            assert!((length - perimeter).abs() < 0.001, "{}", length);
+        }
+    }
+
+    // The fast paths are skipped when the boundary edges are requested.
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    let mut boundary: Vec<u16> = Vec::new();
+    tess.tessellate_rectangle(
+        &Box2D {
+            min: point(0.0, 0.0),
+            max: point(1.0, 1.0),
+        },
+        &FillOptions::default().with_boundary_edges(true),
+        &mut simple_builder(&mut buffers).with_boundary_buffer(&mut boundary),
+    )
+    .unwrap();
+    assert_eq!(boundary.len(), 8);
+
+    // Boundary edges are not reported by default.
+    boundary.clear();
+    tess.tessellate_path(
+        &square_with_hole,
+        &FillOptions::default(),
+        &mut simple_builder(&mut buffers).with_boundary_buffer(&mut boundary),
+    )
+    .unwrap();
+    assert!(boundary.is_empty());
+}
+
+#[test]
+fn test_intersection_epsilon() {
+    // The edge of the triangle crosses the left side of the square very close to
+    // its bottom-left corner.
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(100.0, 0.0));
+    builder.line_to(point(100.0, 100.0));
+    builder.line_to(point(0.0, 100.0));
+    builder.close();
+    builder.begin(point(-10.0, 50.0));
+    builder.line_to(point(10.0, 149.8));
+    builder.line_to(point(-10.0, 149.8));
+    builder.close();
+
+    let mut tess = FillTessellator::new();
+    let mut num_vertices = Vec::new();
+    for offset in [0.0, 1_000_000.0] {
+        let path = builder
+            .clone()
+            .build()
+            .transformed(&Translation::new(offset, offset));
+        for epsilon in [FillOptions::DEFAULT_INTERSECTION_EPSILON, 1.0] {
+            let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+            tess.tessellate_path(
+                &path,
+                &FillOptions::non_zero().with_intersection_epsilon(epsilon),
+                &mut simple_builder(&mut buffers),
+            )
+            .unwrap();
+            num_vertices.push(buffers.vertices.len());
+        }
+    }
+
+    // With a large enough epsilon the intersection is snapped to the corner instead of
+    // producing an extra vertex.
+    assert_eq!(num_vertices, [8, 7, 8, 7]);
+}
+
+#[test]
+fn test_polygon() {
+    use crate::path::polygon::Polygon;
+
+    let points = [
+        point(0.0, 0.0),
+        point(10.0, 0.0),
+        point(5.0, 5.0),
+        point(10.0, 10.0),
+        point(0.0, 10.0),
+    ];
+
+    let mut path = Path::builder();
+    path.add_polygon(Polygon {
+        points: &points,
+        closed: true,
+    });
+    let path = path.build();
+
+    let mut tess = FillTessellator::new();
+    let options = FillOptions::default();
+
+    let mut expected: VertexBuffers<Point, u16> = VertexBuffers::new();
+    tess.tessellate_path(&path, &options, &mut simple_builder(&mut expected))
+        .unwrap();
+
+    // Open polygons are filled as if they were closed.
+    for closed in [true, false] {
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        tess.tessellate_polygon(
+            Polygon {
+                points: &points,
+                closed,
+            },
+            &options,
+            &mut simple_builder(&mut buffers),
+        )
+        .unwrap();
+
+        assert_eq!(buffers.vertices, expected.vertices);
+        assert_eq!(buffers.indices, expected.indices);
+    }
+}
+
+#[test]
+fn test_estimate_tessellation_counts() {
+    use crate::estimate_tessellation_counts;
+
+    let mut logo = Path::builder().with_svg();
+    build_logo_path(&mut logo);
+    let logo = logo.build();
+
+    let mut circle = Path::builder();
+    circle.add_circle(point(0.0, 0.0), 10.0, crate::path::Winding::Positive);
+    let circle = circle.build();
+
+    let mut square = Path::builder();
+    square.begin(point(0.0, 0.0));
+    square.line_to(point(1.0, 0.0));
+    square.line_to(point(1.0, 1.0));
+    square.line_to(point(0.0, 1.0));
+    square.end(true);
+    let square = square.build();
+
+    let mut tess = FillTessellator::new();
+    for path in [&logo, &circle, &square] {
+        for options in [
+            FillOptions::tolerance(0.05),
+            FillOptions::tolerance(0.5),
+            FillOptions::tolerance(0.05).with_anti_alias(1.0),
+        ] {
+            let estimate = estimate_tessellation_counts(path.iter(), &options);
+
+            let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+            tess.tessellate_path(path, &options, &mut simple_builder(&mut buffers))
+                .unwrap();
+
+            assert!(buffers.vertices.len() as u32 <= estimate.vertices);
+            assert!(buffers.indices.len() as u32 <= estimate.indices);
+            // The estimate should not be wildly off.
+            assert!(estimate.vertices <= buffers.vertices.len() as u32 * 4);
+        }
+    }
+}
+
+#[test]
+fn test_no_steiner_points() {
+    let tessellate = |path: &Path, options: &FillOptions| {
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        let mut tess = FillTessellator::new();
+        tess.tessellate_path(path, options, &mut simple_builder(&mut buffers))
+            .map(|_| buffers)
+    };
+    let strict = FillOptions::DEFAULT.with_steiner_points(false);
+
+    // A concave shape with a hole and a vertex touching another edge.
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.line_to(point(10.0, 10.0));
+    builder.line_to(point(5.0, 4.0));
+    builder.line_to(point(0.0, 10.0));
+    builder.close();
+    builder.begin(point(2.0, 1.0));
+    builder.line_to(point(2.0, 3.0));
+    builder.line_to(point(4.0, 3.0));
+    builder.line_to(point(4.0, 1.0));
+    builder.close();
+    builder.begin(point(10.0, 2.0));
+    builder.line_to(point(12.0, 2.0));
+    builder.line_to(point(12.0, 4.0));
+    builder.close();
+    let path = builder.build();
+
+    let input: Vec<Point> = path
+        .iter()
+        .filter_map(|evt| match evt {
+            crate::path::PathEvent::Begin { at } => Some(at),
+            crate::path::PathEvent::Line { to, .. } => Some(to),
+            _ => None,
+        })
+        .collect();
+
+    let buffers = tessellate(&path, &strict).unwrap();
+    assert!(!buffers.indices.is_empty());
+    for v in &buffers.vertices {
+        assert!(input.contains(v), "{:?}", v);
+    }
+
+    // A self-intersecting bow tie.
+    let mut builder = Path::builder();
+    builder.begin(point(0.0, 0.0));
+    builder.line_to(point(10.0, 10.0));
+    builder.line_to(point(10.0, 0.0));
+    builder.line_to(point(0.0, 10.0));
+    builder.close();
+    let bow_tie = builder.build();
+
+    let expected = Err(TessellationError::UnsupportedParamater(
+        UnsupportedParamater::Intersection,
+    ));
+    assert!(tessellate(&bow_tie, &FillOptions::DEFAULT).is_ok());
+    assert_eq!(tessellate(&bow_tie, &strict).map(|_| ()), expected);
+    // Intersections are detected even if they are not handled.
+    assert_eq!(
+        tessellate(&bow_tie, &strict.with_intersections(false)).map(|_| ()),
+        expected
+    );
+}
@@ -35,6 +35,13 @@
 //!   This separates the construction of vertex values from the assembly of the vertex buffers.
 //!   Another, simpler example of vertex constructor is the [`Positions`](struct.Positions.html)
 //!   constructor which just returns the vertex position untransformed.
+//! * The struct [`DedupBuffersBuilder`](struct.DedupBuffersBuilder.html) which wraps a
+//!   `BuffersBuilder` and reuses the indices of identical vertices instead of adding them
+//!   again, using a [`DedupKey`](trait.DedupKey.html) to decide which vertices are identical.
+//! * The struct [`StripBuffersBuilder`](struct.StripBuffersBuilder.html) which wraps a
+//!   `BuffersBuilder` and writes triangle strips instead of a triangle list.
+//! * The struct [`AdjacencyBuffersBuilder`](struct.AdjacencyBuffersBuilder.html) which wraps
+//!   a `BuffersBuilder` and also records the neighbors of each triangle.
 //!
 //! Geometry builders are a practical way to add one last step to the tessellation pipeline,
 //! such as applying a transform or clipping the geometry.
@@ -190,12 +197,17 @@
 //!
 
 pub use crate::error::GeometryBuilderError;
-use crate::math::Point;
+use crate::math::{Box2D, Point};
 use crate::{FillVertex, Index, StrokeVertex, VertexId};
 
+use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 use core::convert::From;
-use core::ops::Add;
+use core::ops::{Add, Range};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use num_traits::ToPrimitive;
 
 /// An interface separating tessellators and other geometry generation algorithms from the
 /// actual vertex construction.
@@ -221,6 +233,13 @@ pub trait GeometryBuilder {
     /// Insert a triangle made of vertices that were added after the last call to begin_geometry.
     ///
     /// This method can only be called between begin_geometry and end_geometry.
+    ///
+    /// The fill and stroke tessellators produce triangles with a consistent winding that
+    /// does not depend on the orientation of the input path: `(b - a).cross(c - b)` is
+    /// negative, which is counter-clockwise in a y-down coordinate system and clockwise in
+    /// a y-up coordinate system. The only exception is when a stroke overlaps itself,
+    /// for example on the inner side of a sharp curve.
+    /// Use [`InvertWinding`](struct.InvertWinding.html) to get the opposite winding.
     fn add_triangle(&mut self, a: VertexId, b: VertexId, c: VertexId);
 
     /// abort_geometry is called instead of end_geometry if an error occurred while producing
@@ -228,6 +247,12 @@ pub trait GeometryBuilder {
     ///
     /// The implementation is expected to discard the geometry that was generated since the last
     /// time begin_geometry was called, and to remain in a usable state.
+    ///
+    /// In particular, returning an error from `add_fill_vertex` or `add_stroke_vertex` stops
+    /// the tessellation: the tessellator calls abort_geometry and returns the error. This
+    /// allows custom builders to fail without leaving partially written geometry behind, as
+    /// long as they implement this method. `BuffersBuilder` truncates its buffers to their
+    /// size at the time of the last call to begin_geometry.
     fn abort_geometry(&mut self) {}
 }
 
@@ -239,7 +264,18 @@ pub trait FillGeometryBuilder: GeometryBuilder {
     /// Returns a vertex id that is only valid between begin_geometry and end_geometry.
     ///
     /// This method can only be called between begin_geometry and end_geometry.
+    /// If an error is returned, the tessellation is aborted (see `abort_geometry`).
     fn add_fill_vertex(&mut self, vertex: FillVertex) -> Result<VertexId, GeometryBuilderError>;
+
+    /// Called for each edge on the boundary of the filled area if
+    /// `FillOptions::emit_boundary` is set.
+    ///
+    /// The edges are oriented like the triangles they belong to (see
+    /// `GeometryBuilder::add_triangle`): the filled area is on the side where
+    /// `(to - from).cross(p - from)` is negative.
+    ///
+    /// This method can only be called between begin_geometry and end_geometry.
+    fn add_boundary_edge(&mut self, _from: VertexId, _to: VertexId) {}
 }
 
 /// A Geometry builder to interface with the [`StrokeTessellator`](../struct.StrokeTessellator.html).
@@ -250,10 +286,18 @@ pub trait StrokeGeometryBuilder: GeometryBuilder {
     /// Returns a vertex id that is only valid between begin_geometry and end_geometry.
     ///
     /// This method can only be called between begin_geometry and end_geometry.
+    /// If an error is returned, the tessellation is aborted (see `abort_geometry`).
     fn add_stroke_vertex(&mut self, vertex: StrokeVertex)
         -> Result<VertexId, GeometryBuilderError>;
 }
 
+/// Number of vertices and indices.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Count {
+    pub vertices: u32,
+    pub indices: u32,
+}
+
 /// Structure that holds the vertex and index data.
 ///
 /// Usually written into though temporary `BuffersBuilder` objects.
@@ -285,6 +329,36 @@ impl<OutputVertex, OutputIndex> VertexBuffers<OutputVertex, OutputIndex> {
     }
 }
 
+/// Computes the sum of the signed areas of the triangles of a triangle list.
+///
+/// The `position` callback extracts the position of each vertex.
+///
+/// The sign of each triangle's area follows the same convention as
+/// `lyon_algorithms::area::approximate_signed_area`. Since the tessellators produce
+/// triangles with a consistent winding (see `GeometryBuilder::add_triangle`), the
+/// result is negative unless the winding was inverted. Overlapping triangles are
+/// counted multiple times.
+pub fn triangulated_area<OutputVertex, OutputIndex, F>(
+    buffers: &VertexBuffers<OutputVertex, OutputIndex>,
+    position: F,
+) -> f32
+where
+    OutputIndex: ToPrimitive,
+    F: Fn(&OutputVertex) -> Point,
+{
+    let vertex = |idx: &OutputIndex| position(&buffers.vertices[idx.to_usize().unwrap()]);
+
+    let mut double_area = 0.0;
+    for tri in buffers.indices.chunks_exact(3) {
+        let a = vertex(&tri[0]);
+        let b = vertex(&tri[1]);
+        let c = vertex(&tri[2]);
+        double_area += (b - a).cross(c - a);
+    }
+
+    double_area * 0.5
+}
+
 /// A temporary view on a `VertexBuffers` object which facilitate the population of vertex and index
 /// data.
 ///
@@ -303,6 +377,9 @@ pub struct BuffersBuilder<'l, OutputVertex: 'l, OutputIndex: 'l, Ctor> {
     first_index: Index,
     vertex_offset: Index,
     vertex_constructor: Ctor,
+    boundary: Option<(&'l mut Vec<OutputIndex>, Index)>,
+    bounds: Option<Box2D>,
+    first_bounds: Option<Box2D>,
 }
 
 impl<'l, OutputVertex: 'l, OutputIndex: 'l, Ctor>
@@ -317,28 +394,139 @@ impl<'l, OutputVertex: 'l, OutputIndex: 'l, Ctor>
             first_index,
             vertex_offset: 0,
             vertex_constructor: ctor,
+            boundary: None,
+            bounds: None,
+            first_bounds: None,
         }
     }
 
+    /// Adds an offset to the indices written in the index buffer.
+    ///
+    /// Without offset, indices refer to the position of the vertices in the vertex
+    /// buffer, including the vertices that were added before the builder was created.
+    /// The offset is useful when the vertex buffer is later copied after other vertices,
+    /// for example into a larger GPU buffer.
+    ///
+    /// The offset is taken into account when checking that the indices fit in
+    /// the index type.
     pub fn with_vertex_offset(mut self, offset: Index) -> Self {
         self.vertex_offset = offset;
 
         self
     }
 
+    /// Writes the boundary edges reported by the fill tessellator into a separate index
+    /// buffer, as a line list.
+    ///
+    /// The fill tessellator only reports boundary edges if `FillOptions::emit_boundary`
+    /// is set. The indices are offset like the ones of the triangles.
+    pub fn with_boundary_buffer(mut self, indices: &'l mut Vec<OutputIndex>) -> Self {
+        let first_index = indices.len() as Index;
+        self.boundary = Some((indices, first_index));
+
+        self
+    }
+
     /// Consumes self and returns a builder with opposite triangle face winding.
     pub fn with_inverted_winding(self) -> InvertWinding<Self> {
         InvertWinding(self)
     }
 
+    /// Consumes self and returns a builder that reuses existing vertices instead of adding
+    /// identical ones.
+    ///
+    /// See `DedupBuffersBuilder`.
+    pub fn with_dedup<Key>(
+        self,
+        epsilon: f32,
+        key: Key,
+    ) -> DedupBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor, Key>
+    where
+        Key: DedupKey<OutputVertex>,
+    {
+        DedupBuffersBuilder {
+            builder: self,
+            key,
+            epsilon,
+            vertices: BTreeMap::new(),
+        }
+    }
+
+    /// Consumes self and returns a builder that writes triangle strips instead of a
+    /// triangle list.
+    ///
+    /// See `StripBuffersBuilder`.
+    pub fn with_strips(
+        self,
+        separator: StripSeparator,
+    ) -> StripBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor> {
+        let first_strip_index = self.buffers.indices.len() as Index;
+        StripBuffersBuilder {
+            builder: self,
+            separator,
+            triangles: Vec::new(),
+            strips: Vec::new(),
+            first_strip_index,
+            last_vertex: VertexId::INVALID,
+        }
+    }
+
+    /// Consumes self and returns a builder that also writes the neighbors of each
+    /// triangle into `adjacency`.
+    ///
+    /// See `AdjacencyBuffersBuilder`.
+    pub fn with_adjacency(
+        self,
+        adjacency: &'l mut Vec<[i32; 3]>,
+    ) -> AdjacencyBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor> {
+        AdjacencyBuffersBuilder {
+            builder: self,
+            adjacency,
+            triangles: Vec::new(),
+        }
+    }
+
     pub fn buffers<'a, 'b: 'a>(&'b self) -> &'a VertexBuffers<OutputVertex, OutputIndex> {
         self.buffers
     }
+
+    /// Returns the bounding box of the positions of the vertices added through this
+    /// builder, or `None` if no vertex was added.
+    ///
+    /// The bounds accumulate over all of the geometry added with the builder. The
+    /// vertices of aborted geometry are not taken into account.
+    pub fn bounds(&self) -> Option<Box2D> {
+        self.bounds
+    }
+
+    fn add_to_bounds(&mut self, position: Point) {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => Box2D {
+                min: bounds.min.min(position),
+                max: bounds.max.max(position),
+            },
+            None => Box2D {
+                min: position,
+                max: position,
+            },
+        });
+    }
 }
 
 /// A wrapper for stroke and fill geometry builders that inverts the triangle face winding.
+///
+/// See `GeometryBuilder::add_triangle` for the default winding.
 pub struct InvertWinding<B>(B);
 
+impl<B> InvertWinding<B> {
+    /// Wraps a geometry builder, inverting the winding of the triangles added to it.
+    ///
+    /// `BuffersBuilder::with_inverted_winding` is a shorthand for this.
+    pub fn new(builder: B) -> Self {
+        InvertWinding(builder)
+    }
+}
+
 impl<B: GeometryBuilder> GeometryBuilder for InvertWinding<B> {
     fn begin_geometry(&mut self) {
         self.0.begin_geometry();
@@ -363,6 +551,10 @@ impl<B: FillGeometryBuilder> FillGeometryBuilder for InvertWinding<B> {
     fn add_fill_vertex(&mut self, vertex: FillVertex) -> Result<VertexId, GeometryBuilderError> {
         self.0.add_fill_vertex(vertex)
     }
+
+    fn add_boundary_edge(&mut self, from: VertexId, to: VertexId) {
+        self.0.add_boundary_edge(to, from);
+    }
 }
 
 impl<B: StrokeGeometryBuilder> StrokeGeometryBuilder for InvertWinding<B> {
@@ -431,6 +623,26 @@ pub fn simple_builder(buffers: &mut VertexBuffers<Point, u16>) -> SimpleBuffersB
         first_index,
         vertex_offset: 0,
         vertex_constructor: Positions,
+        boundary: None,
+        bounds: None,
+        first_bounds: None,
+    }
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor> BuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>
+where
+    OutputVertex: 'l,
+    OutputIndex: MaxIndex,
+{
+    // Returns the id of the next vertex, or an error if its index, once offset, would not
+    // fit in the index type.
+    fn next_vertex_id(&self) -> Result<VertexId, GeometryBuilderError> {
+        let idx = self.buffers.vertices.len();
+        if idx + self.vertex_offset as usize >= OutputIndex::MAX {
+            return Err(GeometryBuilderError::TooManyVertices);
+        }
+
+        Ok(VertexId(idx as Index))
     }
 }
 
@@ -443,6 +655,10 @@ where
     fn begin_geometry(&mut self) {
         self.first_vertex = self.buffers.vertices.len() as Index;
         self.first_index = self.buffers.indices.len() as Index;
+        if let Some((indices, first_index)) = &mut self.boundary {
+            *first_index = indices.len() as Index;
+        }
+        self.first_bounds = self.bounds;
     }
 
     fn add_triangle(&mut self, a: VertexId, b: VertexId, c: VertexId) {
@@ -464,6 +680,10 @@ where
     fn abort_geometry(&mut self) {
         self.buffers.vertices.truncate(self.first_vertex as usize);
         self.buffers.indices.truncate(self.first_index as usize);
+        if let Some((indices, first_index)) = &mut self.boundary {
+            indices.truncate(*first_index as usize);
+        }
+        self.bounds = self.first_bounds;
     }
 }
 
@@ -475,14 +695,19 @@ where
     Ctor: FillVertexConstructor<OutputVertex>,
 {
     fn add_fill_vertex(&mut self, vertex: FillVertex) -> Result<VertexId, GeometryBuilderError> {
+        let id = self.next_vertex_id()?;
+        self.add_to_bounds(vertex.position());
         self.buffers
             .vertices
             .push(self.vertex_constructor.new_vertex(vertex));
-        let len = self.buffers.vertices.len();
-        if len > OutputIndex::MAX {
-            return Err(GeometryBuilderError::TooManyVertices);
+        Ok(id)
+    }
+
+    fn add_boundary_edge(&mut self, from: VertexId, to: VertexId) {
+        if let Some((indices, _)) = &mut self.boundary {
+            indices.push((from + self.vertex_offset).into());
+            indices.push((to + self.vertex_offset).into());
         }
-        Ok(VertexId((len - 1) as Index))
     }
 }
 
@@ -494,14 +719,572 @@ where
     Ctor: StrokeVertexConstructor<OutputVertex>,
 {
     fn add_stroke_vertex(&mut self, v: StrokeVertex) -> Result<VertexId, GeometryBuilderError> {
+        let id = self.next_vertex_id()?;
+        self.add_to_bounds(v.position());
         self.buffers
             .vertices
             .push(self.vertex_constructor.new_vertex(v));
-        let len = self.buffers.vertices.len();
-        if len > OutputIndex::MAX {
-            return Err(GeometryBuilderError::TooManyVertices);
+        Ok(id)
+    }
+}
+
+/// Computes the part of the identity of the output vertices that does not depend on their
+/// position, for `DedupBuffersBuilder`.
+///
+/// This trait is implemented for closures with signature `FnMut(&OutputVertex) -> Key`.
+pub trait DedupKey<OutputVertex> {
+    type Key: Ord;
+    fn key(&mut self, vertex: &OutputVertex) -> Self::Key;
+}
+
+/// A `DedupKey` that only considers the position of the vertices.
+pub struct PositionOnly;
+
+impl<OutputVertex> DedupKey<OutputVertex> for PositionOnly {
+    type Key = ();
+    fn key(&mut self, _: &OutputVertex) {}
+}
+
+impl<F, OutputVertex, Key> DedupKey<OutputVertex> for F
+where
+    F: FnMut(&OutputVertex) -> Key,
+    Key: Ord,
+{
+    type Key = Key;
+    fn key(&mut self, vertex: &OutputVertex) -> Key {
+        self(vertex)
+    }
+}
+
+/// A `BuffersBuilder` that merges identical vertices.
+///
+/// Two vertices are considered identical if their positions snap to the same point
+/// on a grid of size `epsilon` and if the keys computed from the output vertices
+/// by the `DedupKey` are equal. If `epsilon` is zero, positions must be exactly equal.
+/// For example, a `DedupKey` returning the color of the vertex allows merging vertices
+/// that have the same position and color, regardless of their other attributes.
+///
+/// Vertices that were in the buffers before the builder was created are not merged.
+/// Triangles that become degenerate because some of their vertices were merged are
+/// discarded.
+///
+/// Created with `BuffersBuilder::with_dedup`.
+///
+/// # Example
+///
+/// ```
+/// use lyon_tessellation::{BuffersBuilder, FillOptions, FillTessellator, FillVertex, VertexBuffers};
+/// use lyon_tessellation::geometry_builder::PositionOnly;
+/// use lyon_tessellation::math::{Box2D, Point, point};
+///
+/// let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+/// let mut builder = BuffersBuilder::new(&mut buffers, |v: FillVertex| v.position())
+///     .with_dedup(0.001, PositionOnly);
+///
+/// let mut tessellator = FillTessellator::new();
+/// let options = FillOptions::default();
+/// let a = Box2D { min: point(0.0, 0.0), max: point(1.0, 1.0) };
+/// let b = Box2D { min: point(1.0, 0.0), max: point(2.0, 1.0) };
+/// tessellator.tessellate_rectangle(&a, &options, &mut builder).unwrap();
+/// tessellator.tessellate_rectangle(&b, &options, &mut builder).unwrap();
+///
+/// // The two rectangles share two vertices.
+/// assert_eq!(buffers.vertices.len(), 6);
+/// ```
+pub struct DedupBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor, Key>
+where
+    Key: DedupKey<OutputVertex>,
+{
+    builder: BuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>,
+    key: Key,
+    epsilon: f32,
+    vertices: BTreeMap<(i64, i64, Key::Key), VertexId>,
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor, Key>
+    DedupBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor, Key>
+where
+    OutputVertex: 'l,
+    OutputIndex: MaxIndex,
+    Key: DedupKey<OutputVertex>,
+{
+    pub fn buffers<'a, 'b: 'a>(&'b self) -> &'a VertexBuffers<OutputVertex, OutputIndex> {
+        self.builder.buffers
+    }
+
+    /// See `BuffersBuilder::bounds`.
+    pub fn bounds(&self) -> Option<Box2D> {
+        self.builder.bounds
+    }
+
+    fn snap(&self, position: Point) -> (i64, i64) {
+        if self.epsilon > 0.0 {
+            (
+                (position.x / self.epsilon).round() as i64,
+                (position.y / self.epsilon).round() as i64,
+            )
+        } else {
+            // Adding zero turns negative zeros into positive ones.
+            (
+                (position.x + 0.0).to_bits() as i64,
+                (position.y + 0.0).to_bits() as i64,
+            )
+        }
+    }
+
+    fn add_vertex(
+        &mut self,
+        position: Point,
+        vertex: OutputVertex,
+    ) -> Result<VertexId, GeometryBuilderError> {
+        let (x, y) = self.snap(position);
+        let key = (x, y, self.key.key(&vertex));
+        if let Some(id) = self.vertices.get(&key) {
+            return Ok(*id);
+        }
+
+        let id = self.builder.next_vertex_id()?;
+        self.builder.add_to_bounds(position);
+        self.builder.buffers.vertices.push(vertex);
+        self.vertices.insert(key, id);
+
+        Ok(id)
+    }
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor, Key> GeometryBuilder
+    for DedupBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor, Key>
+where
+    OutputVertex: 'l,
+    OutputIndex: Add + From<VertexId> + MaxIndex,
+    Key: DedupKey<OutputVertex>,
+{
+    fn begin_geometry(&mut self) {
+        self.builder.begin_geometry();
+    }
+
+    fn end_geometry(&mut self) {
+        self.builder.end_geometry();
+    }
+
+    fn add_triangle(&mut self, a: VertexId, b: VertexId, c: VertexId) {
+        if a == b || a == c || b == c {
+            return;
+        }
+        self.builder.add_triangle(a, b, c);
+    }
+
+    fn abort_geometry(&mut self) {
+        let first_vertex = self.builder.first_vertex;
+        self.vertices.retain(|_, id| id.0 < first_vertex);
+        self.builder.abort_geometry();
+    }
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor, Key> FillGeometryBuilder
+    for DedupBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor, Key>
+where
+    OutputVertex: 'l,
+    OutputIndex: Add + From<VertexId> + MaxIndex,
+    Ctor: FillVertexConstructor<OutputVertex>,
+    Key: DedupKey<OutputVertex>,
+{
+    fn add_fill_vertex(&mut self, vertex: FillVertex) -> Result<VertexId, GeometryBuilderError> {
+        let position = vertex.position();
+        let vertex = self.builder.vertex_constructor.new_vertex(vertex);
+        self.add_vertex(position, vertex)
+    }
+
+    fn add_boundary_edge(&mut self, from: VertexId, to: VertexId) {
+        if from != to {
+            self.builder.add_boundary_edge(from, to);
+        }
+    }
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor, Key> StrokeGeometryBuilder
+    for DedupBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor, Key>
+where
+    OutputVertex: 'l,
+    OutputIndex: Add + From<VertexId> + MaxIndex,
+    Ctor: StrokeVertexConstructor<OutputVertex>,
+    Key: DedupKey<OutputVertex>,
+{
+    fn add_stroke_vertex(&mut self, v: StrokeVertex) -> Result<VertexId, GeometryBuilderError> {
+        let position = v.position();
+        let vertex = self.builder.vertex_constructor.new_vertex(v);
+        self.add_vertex(position, vertex)
+    }
+}
+
+/// How consecutive strips are separated in the index buffer of a `StripBuffersBuilder`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StripSeparator {
+    /// Strips are separated by the maximum value of the index type (for example
+    /// `0xFFFF` with `u16` indices and `0xFFFF_FFFF` with `u32` indices), which most
+    /// graphics APIs interpret as a primitive restart.
+    PrimitiveRestart,
+    /// Strips are joined with degenerate triangles.
+    DegenerateTriangles,
+}
+
+/// A `BuffersBuilder` that writes triangle strips instead of a triangle list.
+///
+/// The triangles of each geometry are stitched into strips along their shared edges.
+/// Arbitrary tessellations don't form a single strip, so the output is made of
+/// several strips separated according to the `StripSeparator`. All of the indices
+/// written by the builder can be rendered in a single draw call, starting at the
+/// length of the index buffer when the builder was created.
+///
+/// Strips follow the usual convention where every other triangle has its winding
+/// reversed, so the triangles keep the winding produced by the tessellators.
+///
+/// Created with `BuffersBuilder::with_strips`.
+///
+/// # Example
+///
+/// ```
+/// use lyon_tessellation::{BuffersBuilder, FillOptions, FillTessellator, FillVertex, VertexBuffers};
+/// use lyon_tessellation::geometry_builder::StripSeparator;
+/// use lyon_tessellation::math::{Box2D, Point, point};
+///
+/// let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+/// let mut builder = BuffersBuilder::new(&mut buffers, |v: FillVertex| v.position())
+///     .with_strips(StripSeparator::PrimitiveRestart);
+///
+/// let mut tessellator = FillTessellator::new();
+/// let rect = Box2D { min: point(0.0, 0.0), max: point(1.0, 1.0) };
+/// tessellator.tessellate_rectangle(&rect, &FillOptions::default(), &mut builder).unwrap();
+///
+/// // The two triangles of the rectangle form a single strip.
+/// assert_eq!(builder.strips(), &[0..4]);
+/// assert_eq!(buffers.indices.len(), 4);
+/// ```
+pub struct StripBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor> {
+    builder: BuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>,
+    separator: StripSeparator,
+    triangles: Vec<[VertexId; 3]>,
+    strips: Vec<Range<u32>>,
+    first_strip_index: Index,
+    last_vertex: VertexId,
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor> StripBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>
+where
+    OutputVertex: 'l,
+    OutputIndex: Add + From<VertexId> + MaxIndex,
+{
+    pub fn buffers<'a, 'b: 'a>(&'b self) -> &'a VertexBuffers<OutputVertex, OutputIndex> {
+        self.builder.buffers
+    }
+
+    /// See `BuffersBuilder::bounds`.
+    pub fn bounds(&self) -> Option<Box2D> {
+        self.builder.bounds
+    }
+
+    /// Returns the ranges of the index buffer containing each of the strips, not
+    /// including separators.
+    pub fn strips(&self) -> &[Range<u32>] {
+        &self.strips
+    }
+
+    fn push_index(&mut self, id: VertexId) {
+        let index = (id + self.builder.vertex_offset).into();
+        self.builder.buffers.indices.push(index);
+    }
+
+    fn build_strips(&mut self) {
+        let triangles = core::mem::take(&mut self.triangles);
+
+        // Map each directed edge to the triangle it belongs to and the position of the
+        // edge in the triangle.
+        let mut edges = BTreeMap::new();
+        for (i, tri) in triangles.iter().enumerate() {
+            for j in 0..3 {
+                edges
+                    .entry((tri[j].0, tri[(j + 1) % 3].0))
+                    .or_insert((i, j));
+            }
+        }
+
+        let mut visited = alloc::vec![false; triangles.len()];
+
+        // Returns the vertex that extends the strip, if any. Triangle k of a strip is
+        // (s[k], s[k + 1], s[k + 2]) if k is even and (s[k + 1], s[k], s[k + 2]) if k
+        // is odd, which keeps the winding of the original triangles.
+        let next_vertex = |strip: &[VertexId], visited: &[bool]| {
+            let n = strip.len();
+            let (a, b) = if n % 2 == 1 {
+                (strip[n - 1], strip[n - 2])
+            } else {
+                (strip[n - 2], strip[n - 1])
+            };
+            edges
+                .get(&(a.0, b.0))
+                .filter(|(tri, _)| !visited[*tri])
+                .map(|(tri, edge)| (*tri, triangles[*tri][(edge + 2) % 3]))
+        };
+
+        let mut strip = Vec::new();
+        for (i, tri) in triangles.iter().enumerate() {
+            if visited[i] {
+                continue;
+            }
+            visited[i] = true;
+
+            // Pick the rotation of the first triangle that lets the strip continue.
+            strip.clear();
+            strip.extend_from_slice(tri);
+            for rotation in 0..3 {
+                let candidate = [
+                    tri[rotation],
+                    tri[(rotation + 1) % 3],
+                    tri[(rotation + 2) % 3],
+                ];
+                if next_vertex(&candidate, &visited).is_some() {
+                    strip.clear();
+                    strip.extend_from_slice(&candidate);
+                    break;
+                }
+            }
+
+            while let Some((next, vertex)) = next_vertex(&strip, &visited) {
+                visited[next] = true;
+                strip.push(vertex);
+            }
+
+            self.add_strip(&strip);
         }
-        Ok(VertexId((len - 1) as Index))
+    }
+
+    fn add_strip(&mut self, strip: &[VertexId]) {
+        let has_previous_strip = !self.strips.is_empty();
+        match self.separator {
+            StripSeparator::PrimitiveRestart => {
+                if has_previous_strip {
+                    let restart = OutputIndex::from(VertexId(OutputIndex::MAX as Index));
+                    self.builder.buffers.indices.push(restart);
+                }
+            }
+            StripSeparator::DegenerateTriangles => {
+                if has_previous_strip {
+                    self.push_index(self.last_vertex);
+                    self.push_index(strip[0]);
+                    // The strip must start at an even position to keep its winding.
+                    let len = self.builder.buffers.indices.len() as Index - self.first_strip_index;
+                    if len % 2 == 1 {
+                        self.push_index(strip[0]);
+                    }
+                }
+            }
+        }
+
+        let start = self.builder.buffers.indices.len() as u32;
+        for id in strip {
+            self.push_index(*id);
+        }
+        let end = self.builder.buffers.indices.len() as u32;
+        self.strips.push(start..end);
+        self.last_vertex = strip[strip.len() - 1];
+    }
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor> GeometryBuilder
+    for StripBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>
+where
+    OutputVertex: 'l,
+    OutputIndex: Add + From<VertexId> + MaxIndex,
+{
+    fn begin_geometry(&mut self) {
+        self.triangles.clear();
+        self.builder.begin_geometry();
+    }
+
+    fn end_geometry(&mut self) {
+        self.build_strips();
+        self.builder.end_geometry();
+    }
+
+    fn add_triangle(&mut self, a: VertexId, b: VertexId, c: VertexId) {
+        debug_assert!(a != b);
+        debug_assert!(a != c);
+        debug_assert!(b != c);
+        self.triangles.push([a, b, c]);
+    }
+
+    fn abort_geometry(&mut self) {
+        self.triangles.clear();
+        self.builder.abort_geometry();
+    }
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor> FillGeometryBuilder
+    for StripBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>
+where
+    OutputVertex: 'l,
+    OutputIndex: Add + From<VertexId> + MaxIndex,
+    Ctor: FillVertexConstructor<OutputVertex>,
+{
+    fn add_fill_vertex(&mut self, vertex: FillVertex) -> Result<VertexId, GeometryBuilderError> {
+        self.builder.add_fill_vertex(vertex)
+    }
+
+    fn add_boundary_edge(&mut self, from: VertexId, to: VertexId) {
+        self.builder.add_boundary_edge(from, to);
+    }
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor> StrokeGeometryBuilder
+    for StripBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>
+where
+    OutputVertex: 'l,
+    OutputIndex: Add + From<VertexId> + MaxIndex,
+    Ctor: StrokeVertexConstructor<OutputVertex>,
+{
+    fn add_stroke_vertex(&mut self, v: StrokeVertex) -> Result<VertexId, GeometryBuilderError> {
+        self.builder.add_stroke_vertex(v)
+    }
+}
+
+/// A `BuffersBuilder` that also records which triangles share an edge.
+///
+/// For each triangle added to the index buffer, an entry is pushed to the adjacency
+/// buffer containing the triangles on the other side of each of its edges. Edge `i`
+/// goes from the `i`-th to the `(i + 1) % 3`-th vertex of the triangle. Triangles are
+/// referred to by the position of their entry in the adjacency buffer, and edges on
+/// the boundary of the geometry are marked with `-1`.
+///
+/// Triangles are adjacent if they share the two vertices of an edge, so only the
+/// triangles of a single geometry are connected. The stroke tessellator doesn't share
+/// vertices between all adjacent triangles, for example at the joins.
+///
+/// Created with `BuffersBuilder::with_adjacency`.
+///
+/// # Example
+///
+/// ```
+/// use lyon_tessellation::{BuffersBuilder, FillOptions, FillTessellator, FillVertex, VertexBuffers};
+/// use lyon_tessellation::math::{Box2D, Point, point};
+///
+/// let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+/// let mut adjacency = Vec::new();
+/// let mut builder = BuffersBuilder::new(&mut buffers, |v: FillVertex| v.position())
+///     .with_adjacency(&mut adjacency);
+///
+/// let mut tessellator = FillTessellator::new();
+/// let rect = Box2D { min: point(0.0, 0.0), max: point(1.0, 1.0) };
+/// tessellator.tessellate_rectangle(&rect, &FillOptions::default(), &mut builder).unwrap();
+///
+/// // The two triangles of the rectangle are each other's only neighbor.
+/// assert_eq!(adjacency.len(), 2);
+/// assert_eq!(adjacency[0].iter().filter(|n| **n == 1).count(), 1);
+/// assert_eq!(adjacency[1].iter().filter(|n| **n == 0).count(), 1);
+/// assert_eq!(adjacency.iter().flatten().filter(|n| **n == -1).count(), 4);
+/// ```
+pub struct AdjacencyBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor> {
+    builder: BuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>,
+    adjacency: &'l mut Vec<[i32; 3]>,
+    triangles: Vec<[VertexId; 3]>,
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor>
+    AdjacencyBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>
+where
+    OutputVertex: 'l,
+{
+    pub fn buffers<'a, 'b: 'a>(&'b self) -> &'a VertexBuffers<OutputVertex, OutputIndex> {
+        self.builder.buffers
+    }
+
+    /// See `BuffersBuilder::bounds`.
+    pub fn bounds(&self) -> Option<Box2D> {
+        self.builder.bounds
+    }
+
+    /// Returns the neighbors of the triangles added so far.
+    pub fn adjacency(&self) -> &[[i32; 3]] {
+        self.adjacency
+    }
+
+    fn build_adjacency(&mut self) {
+        let triangles = core::mem::take(&mut self.triangles);
+        let first = self.adjacency.len();
+
+        // Map each directed edge to the triangle it belongs to.
+        let mut edges = BTreeMap::new();
+        for (i, tri) in triangles.iter().enumerate() {
+            for j in 0..3 {
+                edges.insert((tri[j].0, tri[(j + 1) % 3].0), (first + i) as i32);
+            }
+        }
+
+        // Consistently oriented neighbors traverse the shared edge in the opposite
+        // direction.
+        for tri in &triangles {
+            let mut neighbors = [-1; 3];
+            for (j, neighbor) in neighbors.iter_mut().enumerate() {
+                if let Some(other) = edges.get(&(tri[(j + 1) % 3].0, tri[j].0)) {
+                    *neighbor = *other;
+                }
+            }
+            self.adjacency.push(neighbors);
+        }
+    }
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor> GeometryBuilder
+    for AdjacencyBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>
+where
+    OutputVertex: 'l,
+    OutputIndex: Add + From<VertexId> + MaxIndex,
+{
+    fn begin_geometry(&mut self) {
+        self.triangles.clear();
+        self.builder.begin_geometry();
+    }
+
+    fn end_geometry(&mut self) {
+        self.build_adjacency();
+        self.builder.end_geometry();
+    }
+
+    fn add_triangle(&mut self, a: VertexId, b: VertexId, c: VertexId) {
+        self.triangles.push([a, b, c]);
+        self.builder.add_triangle(a, b, c);
+    }
+
+    fn abort_geometry(&mut self) {
+        self.triangles.clear();
+        self.builder.abort_geometry();
+    }
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor> FillGeometryBuilder
+    for AdjacencyBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>
+where
+    OutputVertex: 'l,
+    OutputIndex: Add + From<VertexId> + MaxIndex,
+    Ctor: FillVertexConstructor<OutputVertex>,
+{
+    fn add_fill_vertex(&mut self, vertex: FillVertex) -> Result<VertexId, GeometryBuilderError> {
+        self.builder.add_fill_vertex(vertex)
+    }
+
+    fn add_boundary_edge(&mut self, from: VertexId, to: VertexId) {
+        self.builder.add_boundary_edge(from, to);
+    }
+}
+
+impl<'l, OutputVertex, OutputIndex, Ctor> StrokeGeometryBuilder
+    for AdjacencyBuffersBuilder<'l, OutputVertex, OutputIndex, Ctor>
+where
+    OutputVertex: 'l,
+    OutputIndex: Add + From<VertexId> + MaxIndex,
+    Ctor: StrokeVertexConstructor<OutputVertex>,
+{
+    fn add_stroke_vertex(&mut self, v: StrokeVertex) -> Result<VertexId, GeometryBuilderError> {
+        self.builder.add_stroke_vertex(v)
     }
 }
 
@@ -592,3 +1375,482 @@ impl MaxIndex for usize {
 impl MaxIndex for isize {
     const MAX: usize = u32::MAX as usize;
 }
+
+#[test]
+fn dedup_buffers_builder() {
+    use crate::math::{point, Box2D};
+    use crate::{FillOptions, FillTessellator};
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    struct Vertex {
+        position: Point,
+        color: u32,
+    }
+
+    let mut tessellator = FillTessellator::new();
+    let options = FillOptions::default();
+    let a = Box2D {
+        min: point(0.0, 0.0),
+        max: point(1.0, 1.0),
+    };
+    let b = Box2D {
+        min: point(1.00001, 0.0),
+        max: point(2.0, 1.0),
+    };
+
+    let mut buffers: VertexBuffers<Vertex, u16> = VertexBuffers::new();
+    let mut builder = BuffersBuilder::new(&mut buffers, |v: FillVertex| Vertex {
+        position: v.position(),
+        color: 0,
+    })
+    .with_dedup(0.001, PositionOnly);
+    tessellator
+        .tessellate_rectangle(&a, &options, &mut builder)
+        .unwrap();
+    tessellator
+        .tessellate_rectangle(&b, &options, &mut builder)
+        .unwrap();
+    assert_eq!(buffers.vertices.len(), 6);
+    assert_eq!(buffers.indices.len(), 12);
+    for idx in &buffers.indices {
+        assert!((*idx as usize) < buffers.vertices.len());
+    }
+
+    // With an exact comparison, the positions are different.
+    let mut buffers: VertexBuffers<Vertex, u16> = VertexBuffers::new();
+    let mut builder = BuffersBuilder::new(&mut buffers, |v: FillVertex| Vertex {
+        position: v.position(),
+        color: 0,
+    })
+    .with_dedup(0.0, PositionOnly);
+    tessellator
+        .tessellate_rectangle(&a, &options, &mut builder)
+        .unwrap();
+    tessellator
+        .tessellate_rectangle(&b, &options, &mut builder)
+        .unwrap();
+    assert_eq!(buffers.vertices.len(), 8);
+
+    // Vertices with different colors are not merged.
+    let color = core::cell::Cell::new(0);
+    let mut buffers: VertexBuffers<Vertex, u16> = VertexBuffers::new();
+    let mut builder = BuffersBuilder::new(&mut buffers, |v: FillVertex| Vertex {
+        position: v.position(),
+        color: color.get(),
+    })
+    .with_dedup(0.001, |v: &Vertex| v.color);
+    for (rect, c) in [(a, 1), (b, 2), (a, 1)] {
+        color.set(c);
+        tessellator
+            .tessellate_rectangle(&rect, &options, &mut builder)
+            .unwrap();
+    }
+    assert_eq!(buffers.vertices.len(), 8);
+    assert_eq!(buffers.indices.len(), 18);
+}
+
+#[test]
+fn interleave_with_existing_geometry() {
+    use crate::math::{point, Box2D, Point};
+    use crate::{FillOptions, FillTessellator};
+
+    let mut tessellator = FillTessellator::new();
+    let options = FillOptions::default();
+    let rect = Box2D {
+        min: point(0.0, 0.0),
+        max: point(1.0, 1.0),
+    };
+
+    // Some hand-written geometry.
+    let mut buffers: VertexBuffers<Point, u32> = VertexBuffers::new();
+    buffers
+        .vertices
+        .extend_from_slice(&[point(0.0, 0.0), point(1.0, 0.0), point(0.0, 1.0)]);
+    buffers.indices.extend_from_slice(&[0, 1, 2]);
+
+    tessellator
+        .tessellate_rectangle(
+            &rect,
+            &options,
+            &mut BuffersBuilder::new(&mut buffers, Positions),
+        )
+        .unwrap();
+    assert_eq!(buffers.vertices.len(), 7);
+    assert!(buffers.indices[3..].iter().all(|idx| (3..7).contains(idx)));
+
+    buffers.vertices.push(point(5.0, 5.0));
+
+    let mut builder = BuffersBuilder::new(&mut buffers, Positions).with_vertex_offset(100);
+    tessellator
+        .tessellate_rectangle(&rect, &options, &mut builder)
+        .unwrap();
+    assert_eq!(buffers.vertices.len(), 12);
+    assert!(buffers.indices[9..]
+        .iter()
+        .all(|idx| (108..112).contains(idx)));
+}
+
+#[test]
+fn strip_buffers_builder() {
+    use crate::math::Point;
+    use crate::path::Path;
+    use crate::{FillOptions, FillTessellator, StrokeOptions, StrokeTessellator};
+
+    // Converts strips back into a sorted list of triangles, rotating each triangle so
+    // that its smallest index comes first.
+    fn strips_to_triangles(indices: &[u16], restart: bool) -> Vec<[u16; 3]> {
+        let mut triangles = Vec::new();
+        let strips: Vec<&[u16]> = if restart {
+            indices.split(|idx| *idx == u16::MAX).collect()
+        } else {
+            alloc::vec![indices]
+        };
+        for strip in strips {
+            for k in 0..(strip.len().max(2) - 2) {
+                let (a, b, c) = if k % 2 == 0 {
+                    (strip[k], strip[k + 1], strip[k + 2])
+                } else {
+                    (strip[k + 1], strip[k], strip[k + 2])
+                };
+                if a != b && a != c && b != c {
+                    triangles.push(normalize([a, b, c]));
+                }
+            }
+        }
+        triangles.sort();
+        triangles
+    }
+
+    fn normalize(tri: [u16; 3]) -> [u16; 3] {
+        let min = (0..3).min_by_key(|i| tri[*i]).unwrap();
+        [tri[min], tri[(min + 1) % 3], tri[(min + 2) % 3]]
+    }
+
+    let mut path = Path::builder().with_svg();
+    crate::extra::rust_logo::build_logo_path(&mut path);
+    let path = path.build();
+
+    let mut fill_tess = FillTessellator::new();
+    let mut stroke_tess = StrokeTessellator::new();
+    let fill_options = FillOptions::tolerance(0.05);
+    let stroke_options = StrokeOptions::tolerance(0.05);
+
+    let mut expected: VertexBuffers<Point, u16> = VertexBuffers::new();
+    let mut builder = BuffersBuilder::new(&mut expected, Positions);
+    fill_tess
+        .tessellate_path(&path, &fill_options, &mut builder)
+        .unwrap();
+    stroke_tess
+        .tessellate_path(&path, &stroke_options, &mut builder)
+        .unwrap();
+    let mut expected_triangles: Vec<[u16; 3]> = expected
+        .indices
+        .chunks(3)
+        .map(|tri| normalize([tri[0], tri[1], tri[2]]))
+        .collect();
+    expected_triangles.sort();
+
+    for separator in [
+        StripSeparator::PrimitiveRestart,
+        StripSeparator::DegenerateTriangles,
+    ] {
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        let mut builder = BuffersBuilder::new(&mut buffers, Positions).with_strips(separator);
+        fill_tess
+            .tessellate_path(&path, &fill_options, &mut builder)
+            .unwrap();
+        stroke_tess
+            .tessellate_path(&path, &stroke_options, &mut builder)
+            .unwrap();
+
+        let strips = builder.strips().to_vec();
+        assert!(strips.len() < expected_triangles.len());
+        let mut prev_end = 0;
+        for strip in &strips {
+            assert!(strip.end - strip.start >= 3);
+            assert!(strip.start >= prev_end);
+            prev_end = strip.end;
+        }
+        assert_eq!(prev_end as usize, buffers.indices.len());
+
+        assert_eq!(buffers.vertices, expected.vertices);
+        let restart = separator == StripSeparator::PrimitiveRestart;
+        assert_eq!(
+            strips_to_triangles(&buffers.indices, restart),
+            expected_triangles
+        );
+    }
+}
+
+#[test]
+fn adjacency_buffers_builder() {
+    use crate::math::{point, Point};
+    use crate::path::Path;
+    use crate::{FillOptions, FillTessellator};
+
+    let mut path = Path::builder().with_svg();
+    crate::extra::rust_logo::build_logo_path(&mut path);
+    let path = path.build();
+
+    let mut fill_tess = FillTessellator::new();
+    let options = FillOptions::tolerance(0.05);
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    let mut adjacency = Vec::new();
+    let mut builder = BuffersBuilder::new(&mut buffers, Positions).with_adjacency(&mut adjacency);
+    fill_tess
+        .tessellate_path(&path, &options, &mut builder)
+        .unwrap();
+    // A second geometry isn't connected to the first one.
+    let first_len = builder.adjacency().len();
+    fill_tess
+        .tessellate_rectangle(
+            &Box2D {
+                min: point(0.0, 0.0),
+                max: point(1.0, 1.0),
+            },
+            &options,
+            &mut builder,
+        )
+        .unwrap();
+
+    assert_eq!(adjacency.len() * 3, buffers.indices.len());
+    assert_eq!(
+        adjacency[first_len..]
+            .iter()
+            .flatten()
+            .filter(|n| **n >= 0)
+            .count(),
+        2
+    );
+    assert!(adjacency[first_len..]
+        .iter()
+        .flatten()
+        .all(|n| *n < 0 || *n as usize >= first_len));
+
+    let triangle = |t: usize| &buffers.indices[t * 3..t * 3 + 3];
+    let mut num_neighbors = 0;
+    for (t, neighbors) in adjacency.iter().enumerate() {
+        let tri = triangle(t);
+        for j in 0..3 {
+            let (a, b) = (tri[j], tri[(j + 1) % 3]);
+            let n = neighbors[j];
+            if n < 0 {
+                // No other triangle has the reversed edge.
+                assert!((0..adjacency.len()).all(|other| {
+                    let o = triangle(other);
+                    (0..3).all(|k| (o[k], o[(k + 1) % 3]) != (b, a))
+                }));
+                continue;
+            }
+
+            // The neighbor has the reversed edge and refers back to this triangle.
+            num_neighbors += 1;
+            let n = n as usize;
+            let o = triangle(n);
+            let k = (0..3).find(|k| (o[*k], o[(*k + 1) % 3]) == (b, a)).unwrap();
+            assert_eq!(adjacency[n][k], t as i32);
+        }
+    }
+    assert!(num_neighbors > adjacency.len());
+}
+
+#[test]
+fn triangulated_area_of_buffers() {
+    use crate::math::{point, Box2D, Point};
+    use crate::{FillOptions, FillTessellator};
+
+    let mut tessellator = FillTessellator::new();
+    let rect = Box2D {
+        min: point(0.0, 0.0),
+        max: point(2.0, 3.0),
+    };
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    tessellator
+        .tessellate_rectangle(
+            &rect,
+            &FillOptions::default(),
+            &mut simple_builder(&mut buffers),
+        )
+        .unwrap();
+    assert_eq!(triangulated_area(&buffers, |p| *p), -6.0);
+
+    let mut buffers: VertexBuffers<[f32; 2], u32> = VertexBuffers::new();
+    tessellator
+        .tessellate_circle(
+            point(1.0, 1.0),
+            2.0,
+            &FillOptions::tolerance(0.001),
+            &mut BuffersBuilder::new(&mut buffers, |v: FillVertex| v.position().to_array())
+                .with_inverted_winding(),
+        )
+        .unwrap();
+    let area = triangulated_area(&buffers, |p| Point::from(*p));
+    assert!((area - core::f32::consts::PI * 4.0).abs() < 0.05);
+
+    let buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    assert_eq!(triangulated_area(&buffers, |p| *p), 0.0);
+}
+
+#[test]
+fn buffers_builder_bounds() {
+    use crate::math::{point, Box2D};
+    use crate::path::Path;
+    use crate::{FillOptions, FillTessellator, StrokeOptions, StrokeTessellator};
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    let mut builder = simple_builder(&mut buffers);
+    assert_eq!(builder.bounds(), None);
+
+    let rect = Box2D {
+        min: point(1.0, 2.0),
+        max: point(3.0, 5.0),
+    };
+    FillTessellator::new()
+        .tessellate_rectangle(&rect, &FillOptions::default(), &mut builder)
+        .unwrap();
+    assert_eq!(builder.bounds(), Some(rect));
+
+    // The bounds include the stroke width.
+    let mut path = Path::builder();
+    path.begin(point(-1.0, 0.0));
+    path.line_to(point(1.0, 0.0));
+    path.end(false);
+    let path = path.build();
+    StrokeTessellator::new()
+        .tessellate_path(
+            &path,
+            &StrokeOptions::default().with_line_width(2.0),
+            &mut builder,
+        )
+        .unwrap();
+    let expected = Box2D {
+        min: point(-1.0, -1.0),
+        max: point(3.0, 5.0),
+    };
+    assert_eq!(builder.bounds(), Some(expected));
+
+    // Aborted geometry does not contribute to the bounds.
+    let mut path = Path::builder();
+    path.begin(point(10.0, 10.0));
+    path.line_to(point(20.0, 10.0));
+    path.line_to(point(20.0, 20.0));
+    path.end(false);
+    let path = path.build();
+    let result = FillTessellator::new().tessellate_path(
+        &path,
+        &FillOptions::default().with_open_sub_paths(false),
+        &mut builder,
+    );
+    assert!(result.is_err());
+    assert_eq!(builder.bounds(), Some(expected));
+}
+
+#[test]
+fn abort_geometry_on_vertex_error() {
+    use crate::math::{point, Box2D};
+    use crate::path::{Path, Winding};
+    use crate::{
+        FillOptions, FillTessellator, StrokeOptions, StrokeTessellator, TessellationResult,
+    };
+
+    // Fails after a given number of vertices.
+    struct Failing<'l> {
+        builder: SimpleBuffersBuilder<'l>,
+        remaining: u32,
+    }
+
+    impl<'l> Failing<'l> {
+        fn check(&mut self) -> Result<(), GeometryBuilderError> {
+            if self.remaining == 0 {
+                return Err(GeometryBuilderError::InvalidVertex);
+            }
+            self.remaining -= 1;
+            Ok(())
+        }
+    }
+
+    impl<'l> GeometryBuilder for Failing<'l> {
+        fn begin_geometry(&mut self) {
+            self.builder.begin_geometry();
+        }
+        fn end_geometry(&mut self) {
+            self.builder.end_geometry();
+        }
+        fn add_triangle(&mut self, a: VertexId, b: VertexId, c: VertexId) {
+            self.builder.add_triangle(a, b, c);
+        }
+        fn abort_geometry(&mut self) {
+            self.builder.abort_geometry();
+        }
+    }
+
+    impl<'l> FillGeometryBuilder for Failing<'l> {
+        fn add_fill_vertex(
+            &mut self,
+            vertex: FillVertex,
+        ) -> Result<VertexId, GeometryBuilderError> {
+            self.check()?;
+            self.builder.add_fill_vertex(vertex)
+        }
+    }
+
+    impl<'l> StrokeGeometryBuilder for Failing<'l> {
+        fn add_stroke_vertex(
+            &mut self,
+            vertex: StrokeVertex,
+        ) -> Result<VertexId, GeometryBuilderError> {
+            self.check()?;
+            self.builder.add_stroke_vertex(vertex)
+        }
+    }
+
+    let mut path = Path::builder();
+    path.add_circle(point(0.0, 0.0), 10.0, Winding::Positive);
+    let path = path.build();
+    let rect = Box2D {
+        min: point(0.0, 0.0),
+        max: point(1.0, 1.0),
+    };
+    let polygon = [
+        point(0.0, 0.0),
+        point(10.0, 0.0),
+        point(10.0, 10.0),
+        point(5.0, 5.0),
+        point(0.0, 10.0),
+    ];
+
+    let mut fill = FillTessellator::new();
+    let mut stroke = StrokeTessellator::new();
+    let fill_options = FillOptions::default();
+    let stroke_options = StrokeOptions::default();
+
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    // Some existing geometry that must be preserved.
+    fill.tessellate_rectangle(&rect, &fill_options, &mut simple_builder(&mut buffers))
+        .unwrap();
+    let expected = buffers.clone();
+
+    let mut check = |tessellate: &mut dyn FnMut(&mut Failing) -> TessellationResult| {
+        for remaining in [0, 1, 2] {
+            let mut builder = Failing {
+                builder: simple_builder(&mut buffers),
+                remaining,
+            };
+            assert!(tessellate(&mut builder).is_err());
+            assert_eq!(buffers.vertices, expected.vertices);
+            assert_eq!(buffers.indices, expected.indices);
+        }
+    };
+
+    check(&mut |builder| fill.tessellate_path(&path, &fill_options, builder));
+    check(&mut |builder| fill.tessellate_rectangle(&rect, &fill_options, builder));
+    check(&mut |builder| fill.tessellate_circle(point(0.0, 0.0), 10.0, &fill_options, builder));
+    check(&mut |builder| fill.tessellate_convex_polygon(&polygon[..3], &fill_options, builder));
+    check(&mut |builder| {
+        fill.tessellate_simple_polygon(&polygon, Winding::Positive, &fill_options, builder)
+    });
+    check(&mut |builder| stroke.tessellate_path(&path, &stroke_options, builder));
+    check(&mut |builder| stroke.tessellate_rectangle(&rect, &stroke_options, builder));
+}
@@ -171,6 +171,62 @@
 //!
 //! More explanation about flattening and tolerance in the [lyon_geom crate](https://docs.rs/lyon_geom/#flattening).
 //!
+//! ### Precision and large coordinates
+//!
+//! The tessellators work with 32 bits floating point coordinates (like `lyon_path`'s path
+//! types), even though the `lyon_geom` primitives are generic over the scalar type.
+//! The precision of `f32` decreases as the magnitude of the coordinates grows: at ten
+//! million units the gap between two consecutive values is already a whole unit.
+//!
+//! When the input geometry is stored with 64 bits coordinates far from the origin (for
+//! example geographic data), most of the precision can be preserved by expressing it
+//! relative to a local origin close to the geometry before converting it to `f32`, and
+//! adding the origin back to the generated vertices in `f64`:
+//!
+//! ```
+//! use lyon_tessellation::geom::euclid::default::Point2D;
+//! use lyon_tessellation::geometry_builder::{BuffersBuilder, VertexBuffers};
+//! use lyon_tessellation::math::point;
+//! use lyon_tessellation::path::Path;
+//! use lyon_tessellation::{FillOptions, FillTessellator, FillVertex};
+//!
+//! let polygon: [Point2D<f64>; 4] = [
+//!     Point2D::new(10_000_000.25, 20_000_000.0),
+//!     Point2D::new(10_000_001.0, 20_000_000.0),
+//!     Point2D::new(10_000_001.0, 20_000_001.0),
+//!     Point2D::new(10_000_000.25, 20_000_001.0),
+//! ];
+//!
+//! // Any point close to the geometry works, for example a corner of its bounding box.
+//! let origin = polygon[0];
+//!
+//! let mut builder = Path::builder();
+//! for (i, p) in polygon.iter().enumerate() {
+//!     let local = (*p - origin).cast::<f32>();
+//!     if i == 0 {
+//!         builder.begin(point(local.x, local.y));
+//!     } else {
+//!         builder.line_to(point(local.x, local.y));
+//!     }
+//! }
+//! builder.end(true);
+//! let path = builder.build();
+//!
+//! let mut buffers: VertexBuffers<Point2D<f64>, u16> = VertexBuffers::new();
+//! FillTessellator::new()
+//!     .tessellate_path(
+//!         &path,
+//!         &FillOptions::default(),
+//!         &mut BuffersBuilder::new(&mut buffers, |vertex: FillVertex| {
+//!             origin + vertex.position().to_vector().cast::<f64>()
+//!         }),
+//!     )
+//!     .unwrap();
+//!
+//! // The fractional coordinates survived the round trip.
+//! assert!(buffers.vertices.contains(&Point2D::new(10_000_000.25, 20_000_001.0)));
+//! ```
+//!
 //! ## Examples
 //!
 //! - [Tessellating path fills](fill/struct.FillTessellator.html#examples).
@@ -229,15 +285,20 @@ pub use crate::stroke::*;
 
 #[doc(inline)]
 pub use crate::geometry_builder::{
-    BuffersBuilder, FillGeometryBuilder, FillVertexConstructor, GeometryBuilder,
-    GeometryBuilderError, StrokeGeometryBuilder, StrokeVertexConstructor, VertexBuffers,
+    AdjacencyBuffersBuilder, BuffersBuilder, Count, DedupBuffersBuilder, FillGeometryBuilder,
+    FillVertexConstructor, GeometryBuilder, GeometryBuilderError, StripBuffersBuilder,
+    StrokeGeometryBuilder, StrokeVertexConstructor, VertexBuffers,
 };
 
 #[doc(inline)]
 pub use crate::error::*;
 
-pub use crate::path::{AttributeIndex, Attributes, FillRule, LineCap, LineJoin, Side};
+pub use crate::path::{
+    AttributeIndex, Attributes, DashPattern, FillRule, LineCap, LineJoin, Side, StrokeAlignment,
+    StrokeOptions, Tolerance,
+};
 
+use crate::math::Box2D;
 use crate::path::EndpointId;
 
 use core::ops::{Add, Sub};
@@ -307,136 +368,6 @@ pub enum Orientation {
     Vertical,
 }
 
-/// Parameters for the tessellator.
-#[derive(Copy, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
-#[non_exhaustive]
-pub struct StrokeOptions {
-    /// What cap to use at the start of each sub-path.
-    ///
-    /// Default value: `LineCap::Butt`.
-    pub start_cap: LineCap,
-
-    /// What cap to use at the end of each sub-path.
-    ///
-    /// Default value: `LineCap::Butt`.
-    pub end_cap: LineCap,
-
-    /// See the SVG specification.
-    ///
-    /// Default value: `LineJoin::Miter`.
-    pub line_join: LineJoin,
-
-    /// Line width
-    ///
-    /// Default value: `StrokeOptions::DEFAULT_LINE_WIDTH`.
-    pub line_width: f32,
-
-    /// Index of a custom attribute defining a per-vertex
-    /// factor to modulate the line width.
-    ///
-    /// Default value: `None`.
-    pub variable_line_width: Option<AttributeIndex>,
-
-    /// See the SVG specification.
-    ///
-    /// Must be greater than or equal to 1.0.
-    /// Default value: `StrokeOptions::DEFAULT_MITER_LIMIT`.
-    pub miter_limit: f32,
-
-    /// Maximum allowed distance to the path when building an approximation.
-    ///
-    /// See [Flattening and tolerance](index.html#flattening-and-tolerance).
-    /// Default value: `StrokeOptions::DEFAULT_TOLERANCE`.
-    pub tolerance: f32,
-}
-
-impl StrokeOptions {
-    /// Minimum miter limit as defined by the SVG specification.
-    ///
-    /// See [StrokeMiterLimitProperty](https://svgwg.org/specs/strokes/#StrokeMiterlimitProperty)
-    pub const MINIMUM_MITER_LIMIT: f32 = 1.0;
-    /// Default miter limit as defined by the SVG specification.
-    ///
-    /// See [StrokeMiterLimitProperty](https://svgwg.org/specs/strokes/#StrokeMiterlimitProperty)
-    pub const DEFAULT_MITER_LIMIT: f32 = 4.0;
-    pub const DEFAULT_LINE_CAP: LineCap = LineCap::Butt;
-    pub const DEFAULT_LINE_JOIN: LineJoin = LineJoin::Miter;
-    pub const DEFAULT_LINE_WIDTH: f32 = 1.0;
-    pub const DEFAULT_TOLERANCE: f32 = 0.1;
-
-    pub const DEFAULT: Self = StrokeOptions {
-        start_cap: Self::DEFAULT_LINE_CAP,
-        end_cap: Self::DEFAULT_LINE_CAP,
-        line_join: Self::DEFAULT_LINE_JOIN,
-        line_width: Self::DEFAULT_LINE_WIDTH,
-        variable_line_width: None,
-        miter_limit: Self::DEFAULT_MITER_LIMIT,
-        tolerance: Self::DEFAULT_TOLERANCE,
-    };
-
-    #[inline]
-    pub fn tolerance(tolerance: f32) -> Self {
-        Self::DEFAULT.with_tolerance(tolerance)
-    }
-
-    #[inline]
-    pub const fn with_tolerance(mut self, tolerance: f32) -> Self {
-        self.tolerance = tolerance;
-        self
-    }
-
-    #[inline]
-    pub const fn with_line_cap(mut self, cap: LineCap) -> Self {
-        self.start_cap = cap;
-        self.end_cap = cap;
-        self
-    }
-
-    #[inline]
-    pub const fn with_start_cap(mut self, cap: LineCap) -> Self {
-        self.start_cap = cap;
-        self
-    }
-
-    #[inline]
-    pub const fn with_end_cap(mut self, cap: LineCap) -> Self {
-        self.end_cap = cap;
-        self
-    }
-
-    #[inline]
-    pub const fn with_line_join(mut self, join: LineJoin) -> Self {
-        self.line_join = join;
-        self
-    }
-
-    #[inline]
-    pub const fn with_line_width(mut self, width: f32) -> Self {
-        self.line_width = width;
-        self
-    }
-
-    #[inline]
-    pub fn with_miter_limit(mut self, limit: f32) -> Self {
-        assert!(limit >= Self::MINIMUM_MITER_LIMIT);
-        self.miter_limit = limit;
-        self
-    }
-
-    #[inline]
-    pub const fn with_variable_line_width(mut self, idx: AttributeIndex) -> Self {
-        self.variable_line_width = Some(idx);
-        self
-    }
-}
-
-impl Default for StrokeOptions {
-    fn default() -> Self {
-        Self::DEFAULT
-    }
-}
-
 /// Parameters for the fill tessellator.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
@@ -470,6 +401,83 @@ pub struct FillOptions {
     ///
     /// Default value: `true`.
     pub handle_intersections: bool,
+
+    /// Distance under which an intersection is snapped to the endpoint of one of the
+    /// intersecting edges.
+    ///
+    /// The default value works well with coordinates up to a few thousand units. The
+    /// precision of 32 bits floating point numbers decreases as their magnitude grows, so
+    /// paths with much larger coordinates (for example map data) need a larger epsilon to
+    /// be tessellated robustly. A value around `1e-7` times the magnitude of the
+    /// coordinates is a good starting point. The `tolerance` is also used when detecting
+    /// vertices that are on an edge, so it should be scaled accordingly.
+    ///
+    /// Default value: `FillOptions::DEFAULT_INTERSECTION_EPSILON`.
+    pub intersection_epsilon: f32,
+
+    /// Width of an optional anti-aliasing band along the boundary of the shape.
+    ///
+    /// If set, the tessellator adds a thin band of triangles around the shape by
+    /// offsetting its boundary outward. The vertices of the band are marked with
+    /// a coverage of zero (see `FillVertex::coverage`) so that the coverage can be
+    /// interpolated from 1.0 to 0.0 across the band and used to anti-alias the edges
+    /// without MSAA.
+    ///
+    /// Corners are joined with bevels, and no join is generated at vertices where more
+    /// than two boundary edges meet.
+    ///
+    /// Default value: `None`.
+    pub anti_alias: Option<f32>,
+
+    /// Whether to report the edges on the boundary of the filled area.
+    ///
+    /// If set, the tessellator calls `FillGeometryBuilder::add_boundary_edge` for each
+    /// edge between the inside and the outside of the shape, which can be used to draw an
+    /// outline as a line list without running the stroke tessellator.
+    ///
+    /// Default value: `false`.
+    pub emit_boundary: bool,
+
+    /// Whether to accept sub-paths that are not closed.
+    ///
+    /// Like in SVG, the fill tessellator always fills sub-paths as if they were closed:
+    /// an open sub-path that doesn't end at its first point gets an implicit straight edge
+    /// from its last point back to its first point. This edge is treated like any other
+    /// edge of the path (for example it is reported by `emit_boundary`).
+    ///
+    /// If set to `false`, the tessellation fails with `UnsupportedParamater::OpenSubPath`
+    /// instead when a sub-path isn't closed and doesn't end at its first point. This is
+    /// useful to catch bugs in procedurally generated geometry.
+    ///
+    /// Default value: `true`.
+    pub allow_open_sub_paths: bool,
+
+    /// An optional axis-aligned rectangle outside of which no geometry is generated.
+    ///
+    /// The edges of the path are split where they cross the sides of the rectangle, and
+    /// the parts outside are projected onto its sides. This is useful to tessellate the
+    /// portion of a large path that covers a tile. The sides of the rectangle become part
+    /// of the boundary of the shape (for example for `anti_alias` and `emit_boundary`).
+    ///
+    /// Default value: `None`.
+    pub clip_rect: Option<Box2D>,
+
+    /// Whether the tessellator may add vertices that are not in the input.
+    ///
+    /// The fill tessellator splits edges where they intersect, which adds vertices
+    /// (Steiner points) at positions that are not in the flattened input path. If set to
+    /// `false`, the tessellation fails with `UnsupportedParamater::Intersection` instead
+    /// when an intersection would require such a vertex, which guarantees that the
+    /// positions of the generated vertices are a subset of the positions of the flattened
+    /// path. Intersections are always detected in this mode, even if
+    /// `handle_intersections` is `false`. Intersections at a vertex of the path, and
+    /// overlapping edges, don't require new positions and are supported.
+    ///
+    /// `anti_alias` and `clip_rect` generate vertices of their own, so they should not be
+    /// used in combination with this option.
+    ///
+    /// Default value: `true`.
+    pub allow_steiner_points: bool,
 }
 
 impl FillOptions {
@@ -479,12 +487,20 @@ impl FillOptions {
     pub const DEFAULT_FILL_RULE: FillRule = FillRule::EvenOdd;
     /// Default orientation.
     pub const DEFAULT_SWEEP_ORIENTATION: Orientation = Orientation::Vertical;
+    /// Default intersection epsilon.
+    pub const DEFAULT_INTERSECTION_EPSILON: f32 = 3.162_278e-5;
 
     pub const DEFAULT: Self = FillOptions {
         tolerance: Self::DEFAULT_TOLERANCE,
         fill_rule: Self::DEFAULT_FILL_RULE,
         sweep_orientation: Self::DEFAULT_SWEEP_ORIENTATION,
         handle_intersections: true,
+        intersection_epsilon: Self::DEFAULT_INTERSECTION_EPSILON,
+        anti_alias: None,
+        emit_boundary: false,
+        allow_open_sub_paths: true,
+        clip_rect: None,
+        allow_steiner_points: true,
     };
 
     #[inline]
@@ -510,6 +526,16 @@ impl FillOptions {
         self
     }
 
+    /// Sets the tolerance from an absolute or transformed [`Tolerance`].
+    ///
+    /// For example `options.with_tolerance_from(Tolerance::screen(0.25, &transform))`
+    /// bounds the flattening error to a quarter of a pixel after transformation.
+    #[inline]
+    pub fn with_tolerance_from(mut self, tolerance: Tolerance) -> Self {
+        self.tolerance = tolerance.to_absolute();
+        self
+    }
+
     #[inline]
     pub const fn with_fill_rule(mut self, rule: FillRule) -> Self {
         self.fill_rule = rule;
@@ -527,6 +553,42 @@ impl FillOptions {
         self.handle_intersections = intersections;
         self
     }
+
+    #[inline]
+    pub const fn with_intersection_epsilon(mut self, epsilon: f32) -> Self {
+        self.intersection_epsilon = epsilon;
+        self
+    }
+
+    #[inline]
+    pub const fn with_anti_alias(mut self, width: f32) -> Self {
+        self.anti_alias = Some(width);
+        self
+    }
+
+    #[inline]
+    pub const fn with_boundary_edges(mut self, emit: bool) -> Self {
+        self.emit_boundary = emit;
+        self
+    }
+
+    #[inline]
+    pub const fn with_open_sub_paths(mut self, allow: bool) -> Self {
+        self.allow_open_sub_paths = allow;
+        self
+    }
+
+    #[inline]
+    pub const fn with_clip_rect(mut self, rect: Box2D) -> Self {
+        self.clip_rect = Some(rect);
+        self
+    }
+
+    #[inline]
+    pub const fn with_steiner_points(mut self, allow: bool) -> Self {
+        self.allow_steiner_points = allow;
+        self
+    }
 }
 
 impl Default for FillOptions {
@@ -592,8 +654,10 @@ impl From<i32> for VertexId {
     }
 }
 
+/// Panics in debug builds if the id does not fit in a `u16`.
 impl From<VertexId> for u16 {
     fn from(v: VertexId) -> Self {
+        debug_assert!(v.0 <= u16::MAX as Index, "{:?} overflows u16", v);
         v.0 as u16
     }
 }
@@ -602,8 +666,10 @@ impl From<VertexId> for u32 {
         v.0
     }
 }
+/// Panics in debug builds if the id does not fit in an `i32`.
 impl From<VertexId> for i32 {
     fn from(v: VertexId) -> Self {
+        debug_assert!(v.0 <= i32::MAX as Index, "{:?} overflows i32", v);
         v.0 as i32
     }
 }
@@ -686,3 +752,23 @@ fn test_with_miter_limit() {
 fn test_with_invalid_miter_limit() {
     let _ = StrokeOptions::default().with_miter_limit(0.0);
 }
+
+#[test]
+fn test_screen_tolerance() {
+    use crate::math::Transform;
+
+    let options = FillOptions::default().with_tolerance_from(Tolerance::Absolute(0.5));
+    assert_eq!(options.tolerance, 0.5);
+
+    let transform = Transform::scale(4.0, 4.0);
+    let options = FillOptions::default().with_tolerance_from(Tolerance::screen(0.5, &transform));
+    assert!((options.tolerance - 0.125).abs() < 1e-6);
+
+    // With an anisotropic transform the largest scale factor wins.
+    let transform = Transform::scale(2.0, 8.0).then_rotate(crate::math::Angle::radians(0.7));
+    let options = StrokeOptions::default().with_tolerance_from(Tolerance::screen(1.0, &transform));
+    assert!((options.tolerance - 0.125).abs() < 1e-6);
+
+    let transform = Transform::scale(0.0, 0.0);
+    assert_eq!(Tolerance::screen(1.0, &transform).to_absolute(), 1.0);
+}
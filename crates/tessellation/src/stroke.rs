@@ -7,14 +7,16 @@ use crate::geom::{CubicBezierSegment, Line, LineSegment, QuadraticBezierSegment}
 use crate::math::*;
 use crate::math_utils::compute_normal;
 use crate::path::builder::{Build, NoAttributes, PathBuilder};
+use crate::path::path::BuilderImpl;
 use crate::path::polygon::Polygon;
-use crate::path::private::DebugValidator;
+use crate::path::private::{dash_into, DebugValidator};
 use crate::path::{
     AttributeStore, Attributes, EndpointId, IdEvent, PathEvent, PathSlice, PositionStore, Winding,
+    NO_ATTRIBUTES,
 };
 use crate::{
-    LineCap, LineJoin, Side, SimpleAttributeStore, StrokeGeometryBuilder, StrokeOptions,
-    TessellationError, TessellationResult, VertexId, VertexSource,
+    LineCap, LineJoin, Side, SimpleAttributeStore, StrokeAlignment, StrokeGeometryBuilder,
+    StrokeOptions, TessellationError, TessellationResult, VertexId, VertexSource,
 };
 
 use core::f32::consts::PI;
@@ -61,6 +63,9 @@ macro_rules! nan_check {
 /// See <https://github.com/nical/lyon/wiki/Stroke-tessellation> for some notes
 /// about how the path stroke tessellator is implemented.
 ///
+/// The tessellator keeps its internal buffers between calls, so it is best to keep it
+/// around rather than creating a new one for each path. See also `StrokeTessellator::reset`.
+///
 /// # Examples
 ///
 /// ```
@@ -118,6 +123,16 @@ impl StrokeTessellator {
         }
     }
 
+    /// Clears the internal state of the tessellator while retaining the capacity of
+    /// its buffers.
+    ///
+    /// The tessellation methods already do this, so calling `reset` is not necessary
+    /// between two tessellations.
+    pub fn reset(&mut self) {
+        self.attrib_buffer.clear();
+        self.builder_attrib_store.reset(0);
+    }
+
     /// Compute the tessellation from a path iterator.
     pub fn tessellate(
         &mut self,
@@ -130,8 +145,31 @@ impl StrokeTessellator {
             "Variable line width requires custom attributes. Try tessellate_with_ids or tessellate_path",
         );
 
-        let mut buffer = Vec::new();
-        let builder = StrokeBuilderImpl::new(options, &mut buffer, builder);
+        self.attrib_buffer.clear();
+        let builder = StrokeBuilderImpl::new(options, &mut self.attrib_buffer, builder);
+
+        builder.tessellate_fw(input)
+    }
+
+    /// Compute the tessellation from a path iterator, calling into `callbacks` at
+    /// each cap and join to let it add custom geometry.
+    ///
+    /// See [`StrokeCallbacks`].
+    pub fn tessellate_with_callbacks(
+        &mut self,
+        input: impl IntoIterator<Item = PathEvent>,
+        options: &StrokeOptions,
+        callbacks: &mut dyn StrokeCallbacks,
+        builder: &mut dyn StrokeGeometryBuilder,
+    ) -> TessellationResult {
+        debug_assert!(
+            options.variable_line_width.is_none(),
+            "Variable line width requires custom attributes. Try tessellate_with_ids or tessellate_path",
+        );
+
+        self.attrib_buffer.clear();
+        let mut builder = StrokeBuilderImpl::new(options, &mut self.attrib_buffer, builder);
+        builder.callbacks = Some(callbacks);
 
         builder.tessellate_fw(input)
     }
@@ -145,6 +183,14 @@ impl StrokeTessellator {
         options: &StrokeOptions,
         output: &mut dyn StrokeGeometryBuilder,
     ) -> TessellationResult {
+        if options.dash.is_some() {
+            // Dashes don't carry custom attributes, tessellate the path events instead.
+            let events = path
+                .into_iter()
+                .map(|evt| id_event_to_path_event(evt, positions));
+            return self.tessellate(events, options, output);
+        }
+
         let custom_attributes = custom_attributes.unwrap_or(&());
 
         self.attrib_buffer.clear();
@@ -252,6 +298,9 @@ impl StrokeTessellator {
     }
 
     /// Tessellate the stroke for a `Polygon`.
+    ///
+    /// The polygon is tessellated directly, without building a `Path`. If the polygon
+    /// is not closed, line caps are added at its first and last points.
     pub fn tessellate_polygon(
         &mut self,
         polygon: Polygon<Point>,
@@ -325,6 +374,7 @@ pub(crate) struct EndpointData {
     pub src: VertexSource,
     pub side_points: [SidePoints; 2],
     pub fold: [bool; 2],
+    pub miter_length: [f32; 2],
     pub is_flattening_step: bool,
 }
 
@@ -346,6 +396,7 @@ impl Default for EndpointData {
                 single_vertex: None,
             }; 2],
             fold: [false, false],
+            miter_length: [f32::NAN, f32::NAN],
             is_flattening_step: false,
         }
     }
@@ -360,6 +411,9 @@ pub struct StrokeBuilder<'l> {
     attrib_store: &'l mut SimpleAttributeStore,
     validator: DebugValidator,
     prev: (Point, EndpointId, f32),
+    // Dashing needs whole sub-paths, so dashed strokes are recorded and tessellated
+    // when the builder is built.
+    dashed: Option<BuilderImpl>,
 }
 
 impl<'l> StrokeBuilder<'l> {
@@ -374,6 +428,7 @@ impl<'l> StrokeBuilder<'l> {
             attrib_store,
             validator: DebugValidator::new(),
             prev: (Point::zero(), EndpointId::INVALID, 0.0),
+            dashed: options.dash.map(|_| BuilderImpl::new()),
         }
     }
 
@@ -413,6 +468,10 @@ impl<'l> PathBuilder for StrokeBuilder<'l> {
 
     fn begin(&mut self, to: Point, attributes: Attributes) -> EndpointId {
         self.validator.begin();
+        if let Some(path) = &mut self.dashed {
+            return path.begin(to, NO_ATTRIBUTES);
+        }
+
         let id = self.attrib_store.add(attributes);
         if let Some(attrib_index) = self.builder.options.variable_line_width {
             let width = self.builder.options.line_width * attributes[attrib_index];
@@ -428,12 +487,21 @@ impl<'l> PathBuilder for StrokeBuilder<'l> {
 
     fn end(&mut self, close: bool) {
         self.validator.end();
+        if let Some(path) = &mut self.dashed {
+            path.end(close);
+            return;
+        }
+
         self.builder.end(close, self.attrib_store);
     }
 
     fn line_to(&mut self, to: Point, attributes: Attributes) -> EndpointId {
-        let id = self.attrib_store.add(attributes);
         self.validator.edge();
+        if let Some(path) = &mut self.dashed {
+            return path.line_to(to, NO_ATTRIBUTES);
+        }
+
+        let id = self.attrib_store.add(attributes);
         if let Some(attrib_index) = self.builder.options.variable_line_width {
             let width = self.builder.options.line_width * attributes[attrib_index];
             self.builder.line_to(to, id, width, self.attrib_store);
@@ -453,6 +521,10 @@ impl<'l> PathBuilder for StrokeBuilder<'l> {
         attributes: Attributes,
     ) -> EndpointId {
         self.validator.edge();
+        if let Some(path) = &mut self.dashed {
+            return path.quadratic_bezier_to(ctrl, to, NO_ATTRIBUTES);
+        }
+
         let (from, from_id, start_width) = self.prev;
         let to_id = self.attrib_store.add(attributes);
 
@@ -488,6 +560,10 @@ impl<'l> PathBuilder for StrokeBuilder<'l> {
         attributes: Attributes,
     ) -> EndpointId {
         self.validator.edge();
+        if let Some(path) = &mut self.dashed {
+            return path.cubic_bezier_to(ctrl1, ctrl2, to, NO_ATTRIBUTES);
+        }
+
         let (from, from_id, start_width) = self.prev;
         let to_id = self.attrib_store.add(attributes);
 
@@ -530,6 +606,7 @@ impl<'l> PathBuilder for StrokeBuilder<'l> {
         } * self.builder.options.line_width;
 
         if self.builder.options.variable_line_width.is_none()
+            && self.dashed.is_none()
             && (rect.width().abs() < threshold || rect.height().abs() < threshold)
         {
             approximate_thin_rectangle(self, rect, attributes);
@@ -569,6 +646,10 @@ impl<'l> Build for StrokeBuilder<'l> {
     type PathType = TessellationResult;
 
     fn build(self) -> TessellationResult {
+        if let Some(path) = self.dashed {
+            return self.builder.tessellate_fw(path.build().iter());
+        }
+
         self.builder.build()
     }
 }
@@ -584,7 +665,7 @@ pub(crate) struct StrokeBuilderImpl<'l> {
     previous: Option<EndpointData>,
     sub_path_start_advancement: f32,
     square_merge_threshold: f32,
-    may_need_empty_cap: bool,
+    callbacks: Option<&'l mut dyn StrokeCallbacks>,
 }
 
 impl<'l> StrokeBuilderImpl<'l> {
@@ -616,17 +697,20 @@ impl<'l> StrokeBuilderImpl<'l> {
                 advancement: 0.0,
                 buffer: attrib_buffer,
                 side: Side::Negative,
+                stroke_alignment: options.stroke_alignment,
+                custom_center: None,
                 src: VertexSource::Endpoint {
                     id: EndpointId::INVALID,
                 },
                 buffer_is_valid: false,
+                miter_length: None,
             },
             point_buffer: PointBuffer::new(),
             firsts: ArrayVec::new(),
             previous: None,
             sub_path_start_advancement: 0.0,
             square_merge_threshold,
-            may_need_empty_cap: false,
+            callbacks: None,
         }
     }
 
@@ -671,7 +755,6 @@ impl<'l> StrokeBuilderImpl<'l> {
                     let half_width = base_width * attributes.get(at)[attrib_index] * 0.5;
                     current_endpoint = at;
                     current_position = positions.get_endpoint(at);
-                    self.may_need_empty_cap = false;
                     self.step(
                         EndpointData {
                             position: current_position,
@@ -785,7 +868,6 @@ impl<'l> StrokeBuilderImpl<'l> {
                     validator.begin();
                     current_endpoint = at;
                     current_position = positions.get_endpoint(at);
-                    self.may_need_empty_cap = false;
                     self.fixed_width_step(
                         EndpointData {
                             position: current_position,
@@ -877,6 +959,20 @@ impl<'l> StrokeBuilderImpl<'l> {
         // custom attributes to get the line width from;
         self.options.variable_line_width = None;
 
+        if let Some(dash) = self.options.dash.take() {
+            // Feed the dashes directly into the stroker, so that each dash gets its own
+            // caps without building an intermediate path.
+            let tolerance = self.options.tolerance;
+            let mut output = DashedStroke {
+                stroker: &mut self,
+                id: EndpointId(0),
+                current_position: point(f32::NAN, f32::NAN),
+            };
+            dash_into(input, dash.values(), dash.offset, tolerance, &mut output);
+
+            return self.build();
+        }
+
         let mut validator = DebugValidator::new();
 
         let mut id = EndpointId(0);
@@ -958,7 +1054,6 @@ impl<'l> StrokeBuilderImpl<'l> {
         width: f32,
         attributes: &dyn AttributeStore,
     ) {
-        self.may_need_empty_cap = false;
         let half_width = width * 0.5;
         self.step(
             EndpointData {
@@ -1002,33 +1097,63 @@ impl<'l> StrokeBuilderImpl<'l> {
         end_width: f32,
         attributes: &dyn AttributeStore,
     ) {
-        flatten_quad(
-            curve,
-            self.options.tolerance,
-            &mut |position, t, is_flattening_step| {
-                let src = if t == 1.0 {
-                    VertexSource::Endpoint { id: to_id }
-                } else {
-                    VertexSource::Edge {
-                        from: from_id,
-                        to: to_id,
-                        t,
-                    }
-                };
-
+        let tolerance = self.options.tolerance;
+        let width_at = |t: f32| start_width * (1.0 - t) + end_width * t;
+        let mut prev_t = 0.0;
+        let mut prev_position = curve.from;
+        flatten_quad(curve, tolerance, &mut |position, t, is_flattening_step| {
+            let n = num_width_steps(
+                &LineSegment {
+                    from: prev_position,
+                    to: position,
+                },
+                curve.sample((prev_t + t) * 0.5),
+                width_at(t) - width_at(prev_t),
+                tolerance,
+            );
+            for i in 1..n {
+                let sub_t = prev_t + (t - prev_t) * i as f32 / n as f32;
                 self.step(
                     EndpointData {
-                        position,
-                        half_width: (start_width * (1.0 - t) + end_width * t) * 0.5,
+                        position: curve.sample(sub_t),
+                        half_width: width_at(sub_t) * 0.5,
                         line_join: self.options.line_join,
-                        src,
-                        is_flattening_step,
+                        src: VertexSource::Edge {
+                            from: from_id,
+                            to: to_id,
+                            t: sub_t,
+                        },
+                        is_flattening_step: true,
                         ..Default::default()
                     },
                     attributes,
                 );
-            },
-        );
+            }
+            prev_t = t;
+            prev_position = position;
+
+            let src = if t == 1.0 {
+                VertexSource::Endpoint { id: to_id }
+            } else {
+                VertexSource::Edge {
+                    from: from_id,
+                    to: to_id,
+                    t,
+                }
+            };
+
+            self.step(
+                EndpointData {
+                    position,
+                    half_width: width_at(t) * 0.5,
+                    line_join: self.options.line_join,
+                    src,
+                    is_flattening_step,
+                    ..Default::default()
+                },
+                attributes,
+            );
+        });
     }
 
     pub(crate) fn cubic_bezier_to(
@@ -1040,7 +1165,34 @@ impl<'l> StrokeBuilderImpl<'l> {
         end_width: f32,
         attributes: &dyn AttributeStore,
     ) {
-        curve.for_each_flattened_with_t(self.options.tolerance, &mut |line, t| {
+        let tolerance = self.options.tolerance;
+        let width_at = |t: f32| start_width * (1.0 - t) + end_width * t;
+        curve.for_each_flattened_with_t(tolerance, &mut |line, t| {
+            let n = num_width_steps(
+                line,
+                curve.sample((t.start + t.end) * 0.5),
+                width_at(t.end) - width_at(t.start),
+                tolerance,
+            );
+            for i in 1..n {
+                let sub_t = t.start + (t.end - t.start) * i as f32 / n as f32;
+                self.step(
+                    EndpointData {
+                        position: curve.sample(sub_t),
+                        half_width: width_at(sub_t) * 0.5,
+                        line_join: self.options.line_join,
+                        src: VertexSource::Edge {
+                            from: from_id,
+                            to: to_id,
+                            t: sub_t,
+                        },
+                        is_flattening_step: true,
+                        ..Default::default()
+                    },
+                    attributes,
+                );
+            }
+
             let is_flattening_step = t.end != 1.0;
             let src = if is_flattening_step {
                 VertexSource::Edge {
@@ -1055,7 +1207,7 @@ impl<'l> StrokeBuilderImpl<'l> {
             self.step(
                 EndpointData {
                     position: line.to,
-                    half_width: (start_width * (1.0 - t.end) + end_width * t.end) * 0.5,
+                    half_width: width_at(t.end) * 0.5,
                     line_join: self.options.line_join,
                     src,
                     is_flattening_step,
@@ -1072,7 +1224,6 @@ impl<'l> StrokeBuilderImpl<'l> {
         endpoint: EndpointId,
         attributes: &dyn AttributeStore,
     ) {
-        self.may_need_empty_cap = false;
         self.fixed_width_step(
             EndpointData {
                 position,
@@ -1177,7 +1328,6 @@ impl<'l> StrokeBuilderImpl<'l> {
     }
 
     pub(crate) fn end(&mut self, close: bool, attributes: &dyn AttributeStore) {
-        self.may_need_empty_cap |= close && self.point_buffer.count() == 1;
         let e = if close && self.point_buffer.count() > 2 {
             self.close(attributes)
         } else {
@@ -1322,7 +1472,9 @@ impl<'l> StrokeBuilderImpl<'l> {
     fn end_with_caps(&mut self, attributes: &dyn AttributeStore) -> Result<(), TessellationError> {
         let count = self.point_buffer.count();
 
-        if self.may_need_empty_cap && count == 1 {
+        if count == 1 {
+            // The sub-path has zero length, which is rendered as a dot for square and
+            // round caps.
             return self.tessellate_empty_cap(attributes);
         }
 
@@ -1358,6 +1510,32 @@ impl<'l> StrokeBuilderImpl<'l> {
 
             self.sub_path_start_advancement = p1.advancement;
 
+            if let Some(callbacks) = &mut self.callbacks {
+                let direction = (p1.position - p0.position).normalize();
+                let cap = StrokeCap {
+                    position: aligned_cap_center(
+                        p1.position,
+                        direction,
+                        p1.half_width,
+                        self.options.stroke_alignment,
+                    ),
+                    direction,
+                    line_width: p1.half_width * 2.0,
+                    is_start: false,
+                    positive_vertex: p1.side_points[SIDE_POSITIVE].prev_vertex,
+                    negative_vertex: p1.side_points[SIDE_NEGATIVE].prev_vertex,
+                    source: p1.src,
+                };
+                tessellate_custom_cap(
+                    &mut **callbacks,
+                    p1.position,
+                    &cap,
+                    &mut self.vertex,
+                    attributes,
+                    self.output,
+                )?;
+            }
+
             if count > 2 {
                 p0 = self.firsts[0];
                 p1 = self.firsts[1];
@@ -1372,6 +1550,32 @@ impl<'l> StrokeBuilderImpl<'l> {
                 attributes,
                 self.output,
             )?;
+
+            if let Some(callbacks) = &mut self.callbacks {
+                let direction = (p0.position - p1.position).normalize();
+                let cap = StrokeCap {
+                    position: aligned_cap_center(
+                        p0.position,
+                        -direction,
+                        p0.half_width,
+                        self.options.stroke_alignment,
+                    ),
+                    direction,
+                    line_width: p0.half_width * 2.0,
+                    is_start: true,
+                    positive_vertex: p0.side_points[SIDE_POSITIVE].next_vertex,
+                    negative_vertex: p0.side_points[SIDE_NEGATIVE].next_vertex,
+                    source: p0.src,
+                };
+                tessellate_custom_cap(
+                    &mut **callbacks,
+                    p0.position,
+                    &cap,
+                    &mut self.vertex,
+                    attributes,
+                    self.output,
+                )?;
+            }
         }
 
         Ok(())
@@ -1388,11 +1592,6 @@ impl<'l> StrokeBuilderImpl<'l> {
 
         if count > 0 && self.points_are_too_close(self.point_buffer.last().position, next.position)
         {
-            if count == 1 {
-                // move-to followed by empty segment and end of paths means we have to generate
-                // an empty cap.
-                self.may_need_empty_cap = true;
-            }
             // TODO: should do something like:
             // - add the endpoint
             // - only allow two consecutive endpoints at the same position
@@ -1495,11 +1694,27 @@ impl<'l> StrokeBuilderImpl<'l> {
                     self.output,
                 )?;
 
+                if let Some(callbacks) = &mut self.callbacks {
+                    if !join.is_flattening_step {
+                        tessellate_custom_join(
+                            &mut **callbacks,
+                            prev,
+                            join,
+                            &next,
+                            &mut self.vertex,
+                            attributes,
+                            self.output,
+                        )?;
+                    }
+                }
+
                 if count == 2 {
                     self.firsts.push(*prev);
                     self.firsts.push(*join);
                 }
             }
+
+            self.vertex.miter_length = None;
         }
 
         if skip {
@@ -1529,9 +1744,6 @@ impl<'l> StrokeBuilderImpl<'l> {
 
         if count > 0 {
             if self.points_are_too_close(self.point_buffer.last().position, next.position) {
-                if count == 1 {
-                    self.may_need_empty_cap = true;
-                }
                 return Ok(false);
             }
 
@@ -1620,10 +1832,26 @@ impl<'l> StrokeBuilderImpl<'l> {
                 self.output,
             )?;
 
+            if let Some(callbacks) = &mut self.callbacks {
+                if !join.is_flattening_step {
+                    tessellate_custom_join(
+                        &mut **callbacks,
+                        prev,
+                        join,
+                        &next,
+                        &mut self.vertex,
+                        attributes,
+                        self.output,
+                    )?;
+                }
+            }
+
             if count == 2 {
                 self.firsts.push(*prev);
                 self.firsts.push(*join);
             }
+
+            self.vertex.miter_length = None;
         }
 
         self.point_buffer.push(next);
@@ -1639,6 +1867,127 @@ impl<'l> StrokeBuilderImpl<'l> {
     }
 }
 
+// Receives the dashes of a path and tessellates them.
+struct DashedStroke<'a, 'l> {
+    stroker: &'a mut StrokeBuilderImpl<'l>,
+    id: EndpointId,
+    current_position: Point,
+}
+
+impl<'a, 'l> DashedStroke<'a, 'l> {
+    fn next_id(&mut self, to: Point) -> EndpointId {
+        let id = self.id;
+        self.id.0 += 1;
+        self.current_position = to;
+        id
+    }
+}
+
+impl<'a, 'l> PathBuilder for DashedStroke<'a, 'l> {
+    fn num_attributes(&self) -> usize {
+        0
+    }
+
+    fn begin(&mut self, at: Point, _attributes: Attributes) -> EndpointId {
+        let id = self.next_id(at);
+        if self.stroker.error.is_none() {
+            self.stroker.begin_fw(at, id, &());
+        }
+        id
+    }
+
+    fn end(&mut self, close: bool) {
+        if self.stroker.error.is_none() {
+            self.stroker.end(close, &());
+        }
+    }
+
+    fn line_to(&mut self, to: Point, _attributes: Attributes) -> EndpointId {
+        let id = self.next_id(to);
+        if self.stroker.error.is_none() {
+            self.stroker.line_to_fw(to, id, &());
+        }
+        id
+    }
+
+    fn quadratic_bezier_to(
+        &mut self,
+        ctrl: Point,
+        to: Point,
+        _attributes: Attributes,
+    ) -> EndpointId {
+        let from = self.current_position;
+        let id = self.next_id(to);
+        if self.stroker.error.is_none() {
+            self.stroker.quadratic_bezier_to_fw(
+                &QuadraticBezierSegment { from, ctrl, to },
+                EndpointId(id.0 - 1),
+                id,
+                &(),
+            );
+        }
+        id
+    }
+
+    fn cubic_bezier_to(
+        &mut self,
+        ctrl1: Point,
+        ctrl2: Point,
+        to: Point,
+        _attributes: Attributes,
+    ) -> EndpointId {
+        let from = self.current_position;
+        let id = self.next_id(to);
+        if self.stroker.error.is_none() {
+            self.stroker.cubic_bezier_to_fw(
+                &CubicBezierSegment {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                },
+                EndpointId(id.0 - 1),
+                id,
+                &(),
+            );
+        }
+        id
+    }
+}
+
+fn id_event_to_path_event(evt: IdEvent, positions: &impl PositionStore) -> PathEvent {
+    match evt {
+        IdEvent::Begin { at } => PathEvent::Begin {
+            at: positions.get_endpoint(at),
+        },
+        IdEvent::Line { from, to } => PathEvent::Line {
+            from: positions.get_endpoint(from),
+            to: positions.get_endpoint(to),
+        },
+        IdEvent::Quadratic { from, ctrl, to } => PathEvent::Quadratic {
+            from: positions.get_endpoint(from),
+            ctrl: positions.get_control_point(ctrl),
+            to: positions.get_endpoint(to),
+        },
+        IdEvent::Cubic {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        } => PathEvent::Cubic {
+            from: positions.get_endpoint(from),
+            ctrl1: positions.get_control_point(ctrl1),
+            ctrl2: positions.get_control_point(ctrl2),
+            to: positions.get_endpoint(to),
+        },
+        IdEvent::End { last, first, close } => PathEvent::End {
+            last: positions.get_endpoint(last),
+            first: positions.get_endpoint(first),
+            close,
+        },
+    }
+}
+
 #[cfg_attr(feature = "profiling", inline(never))]
 fn compute_join_side_positions_fixed_width(
     prev: &EndpointData,
@@ -1662,6 +2011,7 @@ fn compute_join_side_positions_fixed_width(
     vertex.advancement = join.advancement;
 
     let normal = compute_normal(prev_tangent, next_tangent);
+    join.miter_length = [normal.length(); 2];
     let (front_side, front_normal) = if prev_tangent.cross(next_tangent) >= 0.0 {
         (SIDE_NEGATIVE, -normal)
     } else {
@@ -1723,7 +2073,7 @@ fn compute_join_side_positions_fixed_width(
             let n0 = join.side_points[front_side].prev - join.position;
             let n1 = join.side_points[front_side].next - join.position;
             let (prev_normal, next_normal) =
-                get_clip_intersections(n0, n1, front_normal, miter_limit * 0.5 * vertex.half_width);
+                get_clip_intersections(n0, n1, front_normal, miter_limit * vertex.half_width);
             join.side_points[front_side].prev = join.position + prev_normal;
             join.side_points[front_side].next = join.position + next_normal;
         }
@@ -1976,6 +2326,7 @@ fn tessellate_round_join(
     let center = join.position;
     let radius = join.half_width;
     let start_normal = join.side_points[side].prev - center;
+    vertex.miter_length = Some(join.miter_length[side]);
     let end_normal = join.side_points[side].next - center;
 
     let mut start_vertex = join.side_points[side].prev_vertex;
@@ -1998,10 +2349,7 @@ fn tessellate_round_join(
         core::mem::swap(&mut start_vertex, &mut end_vertex);
     }
 
-    // Compute the required number of subdivisions,
-    let step = circle_flattening_step(radius, options.tolerance);
-    let num_segments = (diff.radians.abs() / step).ceil();
-    let num_subdivisions = num_segments.log2().round() as u32;
+    let num_subdivisions = arc_subdivisions(radius, diff.radians, options.tolerance);
 
     vertex.side = if side == SIDE_POSITIVE {
         Side::Positive
@@ -2020,6 +2368,120 @@ fn tessellate_round_join(
     )
 }
 
+fn tessellate_custom_join(
+    callbacks: &mut dyn StrokeCallbacks,
+    prev: &EndpointData,
+    join: &EndpointData,
+    next: &EndpointData,
+    vertex: &mut StrokeVertexData,
+    attributes: &dyn AttributeStore,
+    output: &mut dyn StrokeGeometryBuilder,
+) -> Result<(), TessellationError> {
+    // Same as in tessellate_join: only the sides that have a gap between the
+    // vertices of the two edges get a join.
+    let side_needs_join = [
+        join.side_points[SIDE_POSITIVE].single_vertex.is_none() && !join.fold[SIDE_NEGATIVE],
+        join.side_points[SIDE_NEGATIVE].single_vertex.is_none() && !join.fold[SIDE_POSITIVE],
+    ];
+
+    let sides = [Side::Positive, Side::Negative];
+    for side in 0..2 {
+        if !side_needs_join[side] {
+            continue;
+        }
+
+        let incoming = (join.position - prev.position).normalize();
+        let outgoing = (next.position - join.position).normalize();
+        // Offset the join along the miter of the two edges, where the center lines of
+        // the aligned strokes meet.
+        let n0 = vector(-incoming.y, incoming.x);
+        let n1 = vector(-outgoing.y, outgoing.x);
+        let d = 1.0 + n0.dot(n1);
+        let miter = if d > 1e-3 { (n0 + n1) / d } else { n0 };
+        let center =
+            join.position + miter * join.half_width * alignment_sign(vertex.stroke_alignment);
+
+        let info = StrokeJoin {
+            position: center,
+            incoming,
+            outgoing,
+            line_width: join.half_width * 2.0,
+            side: sides[side],
+            prev_vertex: join.side_points[side].prev_vertex,
+            next_vertex: join.side_points[side].next_vertex,
+            source: join.src,
+        };
+
+        vertex.src = join.src;
+        vertex.position_on_path = join.position;
+        vertex.custom_center = Some(center);
+        vertex.half_width = join.half_width;
+        vertex.advancement = join.advancement;
+        vertex.buffer_is_valid = false;
+
+        let result = callbacks.join(
+            &info,
+            &mut CustomStrokeGeometry {
+                vertex,
+                attributes,
+                output,
+            },
+        );
+        vertex.custom_center = None;
+        result?;
+    }
+
+    Ok(())
+}
+
+// Returns 1.0 or -1.0 if the stroke is offset towards the positive or negative side of
+// the path, and 0.0 if it is centered.
+fn alignment_sign(alignment: StrokeAlignment) -> f32 {
+    match alignment {
+        StrokeAlignment::Center => 0.0,
+        StrokeAlignment::Inner => 1.0,
+        StrokeAlignment::Outer => -1.0,
+    }
+}
+
+// The center of a cap at the end of an edge going along `tangent`.
+fn aligned_cap_center(
+    position: Point,
+    tangent: Vector,
+    half_width: f32,
+    alignment: StrokeAlignment,
+) -> Point {
+    let normal = vector(-tangent.y, tangent.x);
+    position + normal * half_width * alignment_sign(alignment)
+}
+
+fn tessellate_custom_cap(
+    callbacks: &mut dyn StrokeCallbacks,
+    position_on_path: Point,
+    cap: &StrokeCap,
+    vertex: &mut StrokeVertexData,
+    attributes: &dyn AttributeStore,
+    output: &mut dyn StrokeGeometryBuilder,
+) -> Result<(), TessellationError> {
+    vertex.src = cap.source;
+    vertex.position_on_path = position_on_path;
+    vertex.custom_center = Some(cap.position);
+    vertex.half_width = cap.line_width * 0.5;
+    vertex.buffer_is_valid = false;
+
+    let result = callbacks.cap(
+        cap,
+        &mut CustomStrokeGeometry {
+            vertex,
+            attributes,
+            output,
+        },
+    );
+    vertex.custom_center = None;
+
+    result
+}
+
 #[cfg_attr(feature = "profiling", inline(never))]
 fn add_join_base_vertices(
     join: &mut EndpointData,
@@ -2035,6 +2497,8 @@ fn add_join_base_vertices(
         Side::Negative => SIDE_NEGATIVE,
     };
 
+    vertex.miter_length = Some(join.miter_length[side]);
+
     if let Some(pos) = join.side_points[side].single_vertex {
         vertex.normal = (pos - join.position) / join.half_width;
         let vertex = output.add_stroke_vertex(StrokeVertex(vertex, attributes))?;
@@ -2075,6 +2539,7 @@ fn compute_join_side_positions(
     let forward = v0.dot(v1) > 0.0;
 
     let normal = compute_normal(v0, v1) * sign;
+    join.miter_length[side] = normal.length();
     let path_v0 = (join.position - prev.position).normalize();
     let path_v1 = (next.position - join.position).normalize();
 
@@ -2118,7 +2583,7 @@ fn compute_join_side_positions(
         let n0 = join.side_points[side].prev - join.position;
         let n1 = join.side_points[side].next - join.position;
         let (prev_normal, next_normal) =
-            get_clip_intersections(n0, n1, normal, miter_limit * 0.5 * join.half_width);
+            get_clip_intersections(n0, n1, normal, miter_limit * join.half_width);
         join.side_points[side].prev = join.position + prev_normal;
         join.side_points[side].next = join.position + next_normal;
         nan_check!(n0, n1, prev_normal, next_normal);
@@ -2316,9 +2781,12 @@ fn get_clip_intersections(
 
 // Derived from:
 // miter_limit = miter_length / stroke_width
-// miter_limit = (normal.length() * half_width) / (2.0 * half_width)
+// miter_limit = (2.0 * normal.length() * half_width) / (2.0 * half_width)
+//
+// The miter length is the distance between the inner and outer corners of the join,
+// which is twice the distance between the join and the outer corner.
 fn miter_limit_is_exceeded(normal: Vector, miter_limit: f32) -> bool {
-    normal.square_length() > miter_limit * miter_limit * 4.0
+    normal.square_length() > miter_limit * miter_limit
 }
 
 fn side_sign(side: usize) -> f32 {
@@ -2485,9 +2953,7 @@ pub(crate) fn tessellate_round_cap(
     let end_angle = mid_angle + diff;
 
     // Compute the required number of subdivisions on each side,
-    let step = circle_flattening_step(radius, options.tolerance);
-    let num_segments = (diff.radians.abs() / step).ceil();
-    let num_subdivisions = num_segments.log2().round() as u32;
+    let num_subdivisions = arc_subdivisions(radius, diff.radians, options.tolerance);
 
     vertex.position_on_path = center;
     vertex.half_width = radius;
@@ -2608,6 +3074,28 @@ pub(crate) fn tessellate_empty_round_cap(
     Ok(())
 }
 
+/// Maximum number of recursive subdivisions of arcs in round joins and caps.
+///
+/// Each arc is made of at most 2^MAX_ARC_SUBDIVISIONS segments.
+const MAX_ARC_SUBDIVISIONS: u32 = 8;
+
+// Returns the number of recursive subdivisions required to approximate an arc within
+// the tolerance threshold.
+fn arc_subdivisions(radius: f32, sweep_angle: f32, tolerance: f32) -> u32 {
+    let step = circle_flattening_step(radius, tolerance);
+    if step.is_nan() || step <= 0.0 {
+        // The tolerance is too small relative to the radius to be represented.
+        return MAX_ARC_SUBDIVISIONS;
+    }
+
+    let num_segments = (sweep_angle.abs() / step).ceil();
+    if num_segments <= 1.0 {
+        return 0;
+    }
+
+    (num_segments.log2().ceil() as u32).min(MAX_ARC_SUBDIVISIONS)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn tessellate_arc(
     angle: (f32, f32),
@@ -2622,6 +3110,14 @@ pub(crate) fn tessellate_arc(
         return Ok(());
     }
 
+    // Stop before the vertices get so close to each other that the triangles become
+    // degenerate.
+    let center = vertex.position_on_path;
+    let min_length = (center.x.abs() + center.y.abs() + vertex.half_width) * 1e-6;
+    if (angle.1 - angle.0).abs() * vertex.half_width < min_length {
+        return Ok(());
+    }
+
     let mid_angle = (angle.0 + angle.1) * 0.5;
 
     let normal = vector(mid_angle.cos(), mid_angle.sin());
@@ -2652,6 +3148,132 @@ pub(crate) fn tessellate_arc(
     )
 }
 
+/// Hooks to add custom geometry at the caps and joins of a stroke.
+///
+/// See `StrokeTessellator::tessellate_with_callbacks`.
+///
+/// The callbacks are invoked after the tessellator has produced the cap or join
+/// specified in the `StrokeOptions`, and add geometry on top of it. To replace the
+/// built-in shapes entirely, use `LineCap::Butt` and `LineJoin::Bevel`: the custom
+/// geometry can then be attached to the vertices at the end of the edges, which
+/// are provided in `StrokeCap` and `StrokeJoin`.
+///
+/// The default implementations don't add anything.
+pub trait StrokeCallbacks {
+    /// Called at the start and at the end of each sub-path that is not closed.
+    ///
+    /// Sub-paths that are reduced to a single point don't invoke this callback.
+    fn cap(&mut self, _cap: &StrokeCap, _output: &mut CustomStrokeGeometry) -> TessellationResult {
+        Ok(())
+    }
+
+    /// Called at each join between two edges of the path, for the outer side of the
+    /// join.
+    ///
+    /// This is only invoked when the two edges are not connected by a single vertex
+    /// on that side, for example with bevel and round joins, or with miter joins
+    /// exceeding the miter limit. Joins between the segments of a flattened curve
+    /// don't invoke this callback.
+    fn join(
+        &mut self,
+        _join: &StrokeJoin,
+        _output: &mut CustomStrokeGeometry,
+    ) -> TessellationResult {
+        Ok(())
+    }
+}
+
+/// Information about a cap, passed to `StrokeCallbacks::cap`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct StrokeCap {
+    /// The center of the cap.
+    ///
+    /// This is the point of the path at which the cap is placed, offset according to
+    /// the stroke alignment.
+    pub position: Point,
+    /// Unit vector along the path, pointing away from the stroke.
+    pub direction: Vector,
+    /// The line width at the cap.
+    pub line_width: f32,
+    /// Whether this is the cap at the start of the sub-path.
+    pub is_start: bool,
+    /// The vertex at the end of the edge on the positive side.
+    pub positive_vertex: VertexId,
+    /// The vertex at the end of the edge on the negative side.
+    pub negative_vertex: VertexId,
+    /// Where the cap comes from in the original path.
+    pub source: VertexSource,
+}
+
+/// Information about a join, passed to `StrokeCallbacks::join`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct StrokeJoin {
+    /// The center of the join.
+    ///
+    /// This is the point of the path at which the join is placed, offset according to
+    /// the stroke alignment.
+    pub position: Point,
+    /// Unit tangent of the edge before the join.
+    pub incoming: Vector,
+    /// Unit tangent of the edge after the join.
+    pub outgoing: Vector,
+    /// The line width at the join.
+    pub line_width: f32,
+    /// The outer side of the join.
+    pub side: Side,
+    /// The vertex at the end of the incoming edge, on the outer side.
+    pub prev_vertex: VertexId,
+    /// The vertex at the start of the outgoing edge, on the outer side.
+    pub next_vertex: VertexId,
+    /// Where the join comes from in the original path.
+    pub source: VertexSource,
+}
+
+/// Adds custom geometry to the output of the stroke tessellator.
+///
+/// See `StrokeCallbacks`.
+pub struct CustomStrokeGeometry<'a, 'b> {
+    vertex: &'b mut StrokeVertexData<'a>,
+    attributes: &'b dyn AttributeStore,
+    output: &'b mut dyn StrokeGeometryBuilder,
+}
+
+impl<'a, 'b> CustomStrokeGeometry<'a, 'b> {
+    /// Adds a vertex at the given position.
+    ///
+    /// The vertex is passed to the geometry builder with the cap or join's position
+    /// on the path and line width, and a normal that points from the center of the cap
+    /// or join to `position`.
+    pub fn add_vertex(
+        &mut self,
+        position: Point,
+        side: Side,
+    ) -> Result<VertexId, TessellationError> {
+        let center = self
+            .vertex
+            .custom_center
+            .unwrap_or(self.vertex.position_on_path);
+        let offset = position - center;
+        self.vertex.normal = if self.vertex.half_width > 0.0 {
+            offset / self.vertex.half_width
+        } else {
+            offset
+        };
+        self.vertex.side = side;
+
+        Ok(self
+            .output
+            .add_stroke_vertex(StrokeVertex(self.vertex, self.attributes))?)
+    }
+
+    /// Adds a triangle.
+    pub fn add_triangle(&mut self, a: VertexId, b: VertexId, c: VertexId) {
+        self.output.add_triangle(a, b, c);
+    }
+}
+
 /// Extra vertex information from the `StrokeTessellator`.
 pub(crate) struct StrokeVertexData<'l> {
     pub(crate) position_on_path: Point,
@@ -2659,9 +3281,13 @@ pub(crate) struct StrokeVertexData<'l> {
     pub(crate) normal: Vector,
     pub(crate) advancement: f32,
     pub(crate) side: Side,
+    pub(crate) stroke_alignment: StrokeAlignment,
+    // The center of custom caps and joins, which the normal is relative to.
+    pub(crate) custom_center: Option<Point>,
     pub(crate) src: VertexSource,
     pub(crate) buffer: &'l mut [f32],
     pub(crate) buffer_is_valid: bool,
+    pub(crate) miter_length: Option<f32>,
 }
 
 /// Extra vertex information from the `StrokeTessellator` accessible when building vertices.
@@ -2672,9 +3298,23 @@ pub struct StrokeVertex<'a, 'b>(
 
 impl<'a, 'b> StrokeVertex<'a, 'b> {
     /// The vertex position.
+    ///
+    /// With the `Inner` and `Outer` stroke alignments, the stroke is offset by half of
+    /// the line width towards one side of the path.
     #[inline]
     pub fn position(&self) -> Point {
-        self.0.position_on_path + self.0.normal * self.0.half_width
+        let offset = self.0.normal * self.0.half_width;
+        if let Some(center) = self.0.custom_center {
+            return center + offset;
+        }
+
+        match (self.0.stroke_alignment, self.0.side) {
+            (StrokeAlignment::Center, _) => self.0.position_on_path + offset,
+            (StrokeAlignment::Inner, Side::Positive) | (StrokeAlignment::Outer, Side::Negative) => {
+                self.0.position_on_path + offset * 2.0
+            }
+            _ => self.0.position_on_path,
+        }
     }
 
     /// Normal at this vertex.
@@ -2720,6 +3360,20 @@ impl<'a, 'b> StrokeVertex<'a, 'b> {
         self.0.src
     }
 
+    /// The length of the miter at the join this vertex belongs to, if any.
+    ///
+    /// The length is the distance between the join's position on the path and the
+    /// tip of the miter, divided by half of the line width. It is directly comparable
+    /// with `StrokeOptions::miter_limit` and is provided regardless of the line join
+    /// (it can be used to fade out or clip bevel, round and miter joins in a shader).
+    ///
+    /// Returns `None` for vertices that are not part of a join, for example caps and
+    /// vertices produced when flattening curves.
+    #[inline]
+    pub fn miter_length(&self) -> Option<f32> {
+        self.0.miter_length
+    }
+
     /// Computes and returns the custom attributes for this vertex.
     ///
     /// The attributes are interpolated along the edges on which this vertex is.
@@ -2752,6 +3406,40 @@ pub(crate) fn circle_flattening_step(radius: f32, mut tolerance: f32) -> f32 {
     2.0 * ((radius - tolerance) / radius).acos()
 }
 
+// Maximum number of steps a flattened segment is subdivided into by `num_width_steps`.
+const MAX_WIDTH_STEPS: u32 = 16;
+
+// The line width is interpolated linearly between the points produced by the flattening,
+// while it should follow the parameterization of the curve, which isn't uniform along
+// the flattened segment. Returns the number of steps to subdivide a flattened segment
+// into so that the error on the outline of the stroke stays under the tolerance, up to
+// `MAX_WIDTH_STEPS`.
+//
+// The error is estimated from the position on the segment of the point of the curve
+// halfway through the segment's parameter range. It decreases roughly quadratically with
+// the number of steps.
+fn num_width_steps(
+    segment: &LineSegment<f32>,
+    mid: Point,
+    width_delta: f32,
+    tolerance: f32,
+) -> u32 {
+    let v = segment.to_vector();
+    let square_length = v.square_length();
+    if square_length == 0.0 {
+        return 1;
+    }
+
+    let fraction = (mid - segment.from).dot(v) / square_length;
+    let error = width_delta.abs() * 0.5 * (fraction - 0.5).abs();
+    let n = (error / tolerance).sqrt().ceil();
+    if n.is_nan() {
+        return 1;
+    }
+
+    (n as u32).clamp(1, MAX_WIDTH_STEPS)
+}
+
 fn flatten_quad<F>(curve: &QuadraticBezierSegment<f32>, tolerance: f32, cb: &mut F)
 where
     F: FnMut(Point, f32, bool),
@@ -2943,7 +3631,7 @@ fn test_empty_caps() {
     builder.line_to(point(3.0, 0.0), &[1.0]);
     builder.end(true);
 
-    // moveto then end (not closed): no empty cap.
+    // moveto then end (not closed): empty cap.
     builder.begin(point(4.0, 0.0), &[1.0]);
     builder.end(false);
 
@@ -2963,7 +3651,7 @@ fn test_empty_caps() {
         test_path(
             path.as_slice(),
             &options.with_line_cap(LineCap::Square),
-            Some(8),
+            Some(10),
         );
         test_path(
             path.as_slice(),
@@ -2973,6 +3661,101 @@ fn test_empty_caps() {
     }
 }
 
+#[test]
+fn test_empty_cap_geometry() {
+    let mut builder = Path::builder();
+    builder.begin(point(10.0, 20.0));
+    builder.end(false);
+    let path = builder.build();
+
+    let mut tess = StrokeTessellator::new();
+    let options = StrokeOptions::tolerance(0.01).with_line_width(4.0);
+
+    for cap in [LineCap::Butt, LineCap::Square, LineCap::Round] {
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        tess.tessellate_path(
+            &path,
+            &options.with_line_cap(cap),
+            &mut simple_builder(&mut buffers),
+        )
+        .unwrap();
+
+        let center = point(10.0, 20.0);
+        let mut max_distance: f32 = 0.0;
+        for &p in &buffers.indices {
+            let v = buffers.vertices[p as usize] - center;
+            match cap {
+                LineCap::Square => {
+                    assert!((v.x.abs() - 2.0).abs() < 0.001);
+                    assert!((v.y.abs() - 2.0).abs() < 0.001);
+                }
+                _ => {
+                    assert!(v.length() < 2.001);
+                }
+            }
+            max_distance = max_distance.max(v.length());
+        }
+
+        match cap {
+            LineCap::Butt => assert!(buffers.indices.is_empty()),
+            LineCap::Square => assert_eq!(buffers.indices.len(), 6),
+            LineCap::Round => {
+                assert!(buffers.indices.len() > 12);
+                assert!((max_distance - 2.0).abs() < 0.001);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_miter_length() {
+    struct MiterCtor;
+    impl StrokeVertexConstructor<(Point, Option<f32>)> for MiterCtor {
+        fn new_vertex(&mut self, vertex: StrokeVertex) -> (Point, Option<f32>) {
+            (vertex.position_on_path(), vertex.miter_length())
+        }
+    }
+
+    let mut builder = Path::builder_with_attributes(1);
+    builder.begin(point(0.0, 0.0), &[1.0]);
+    builder.line_to(point(10.0, 0.0), &[1.0]);
+    builder.line_to(point(10.0, 10.0), &[1.0]);
+    builder.end(false);
+    let path = builder.build();
+
+    let mut tess = StrokeTessellator::new();
+    for options in [
+        StrokeOptions::default(),
+        StrokeOptions::default().with_variable_line_width(0),
+    ] {
+        for join in [LineJoin::Miter, LineJoin::Bevel, LineJoin::Round] {
+            let mut buffers: VertexBuffers<(Point, Option<f32>), u16> = VertexBuffers::new();
+            tess.tessellate_path(
+                &path,
+                &options
+                    .with_line_width(2.0)
+                    .with_line_join(join)
+                    .with_line_cap(LineCap::Round),
+                &mut BuffersBuilder::new(&mut buffers, MiterCtor),
+            )
+            .unwrap();
+
+            let mut num_join_vertices = 0;
+            for &(position, miter_length) in &buffers.vertices {
+                if position == point(10.0, 0.0) {
+                    let miter_length = miter_length.unwrap();
+                    assert!((miter_length - 2.0f32.sqrt()).abs() < 0.001);
+                    num_join_vertices += 1;
+                } else {
+                    assert_eq!(miter_length, None);
+                }
+            }
+
+            assert!(num_join_vertices >= 2);
+        }
+    }
+}
+
 #[test]
 fn test_too_many_vertices() {
     /// This test checks that the tessellator returns the proper error when
@@ -3376,3 +4159,537 @@ fn issue_894() {
         &mut BuffersBuilder::new(&mut geometry, VariableWidthStrokeCtor),
     );
 }
+
+#[test]
+fn stroke_alignment() {
+    let mut tess = StrokeTessellator::new();
+    let rect = Box2D {
+        min: point(0.0, 0.0),
+        max: point(10.0, 10.0),
+    };
+
+    let stroke = |tess: &mut StrokeTessellator, alignment, join| {
+        let mut path = Path::builder();
+        path.add_rectangle(&rect, Winding::Positive);
+        let path = path.build();
+
+        let options = StrokeOptions::default()
+            .with_line_width(2.0)
+            .with_line_join(join)
+            .with_stroke_alignment(alignment);
+        let mut output: VertexBuffers<Point, u16> = VertexBuffers::new();
+        tess.tessellate_path(&path, &options, &mut simple_builder(&mut output))
+            .unwrap();
+        assert!(!output.indices.is_empty());
+
+        output.vertices
+    };
+
+    let inside = |r: Box2D, v: Point| r.inflate(1e-5, 1e-5).contains(v);
+    let strictly_inside = |r: Box2D, v: Point| {
+        let r = r.inflate(-1e-5, -1e-5);
+        v.x > r.min.x && v.x < r.max.x && v.y > r.min.y && v.y < r.max.y
+    };
+
+    let deflated = rect.inflate(-2.0, -2.0);
+    for join in [LineJoin::Miter, LineJoin::Round, LineJoin::Bevel] {
+        // The stroke lies between the rectangle and the rectangle deflated by the
+        // line width.
+        let inner = stroke(&mut tess, StrokeAlignment::Inner, join);
+        for v in &inner {
+            assert!(inside(rect, *v), "{:?} {:?}", join, v);
+            assert!(!strictly_inside(deflated, *v), "{:?} {:?}", join, v);
+        }
+        assert!(inner.iter().any(|v| v.x == 2.0));
+
+        let outer = stroke(&mut tess, StrokeAlignment::Outer, join);
+        for v in &outer {
+            assert!(inside(rect.inflate(2.0, 2.0), *v), "{:?} {:?}", join, v);
+            assert!(!strictly_inside(rect, *v), "{:?} {:?}", join, v);
+        }
+        assert!(outer.iter().any(|v| v.x == -2.0));
+    }
+
+    // Center alignment straddles the path.
+    let center = stroke(&mut tess, StrokeAlignment::Center, LineJoin::Miter);
+    assert!(center.iter().any(|v| v.x == -1.0));
+    assert!(center.iter().any(|v| v.x == 1.0));
+
+    // The alignment does not affect the position on the path.
+    let mut path = Path::builder();
+    path.add_rectangle(&rect, Winding::Positive);
+    let path = path.build();
+    for alignment in [StrokeAlignment::Inner, StrokeAlignment::Outer] {
+        let mut output: VertexBuffers<(Point, Point), u16> = VertexBuffers::new();
+        tess.tessellate_path(
+            &path,
+            &StrokeOptions::default()
+                .with_line_width(2.0)
+                .with_stroke_alignment(alignment),
+            &mut BuffersBuilder::new(&mut output, |v: StrokeVertex| {
+                (v.position(), v.position_on_path())
+            }),
+        )
+        .unwrap();
+        for &(_, on_path) in &output.vertices {
+            let on_edge = on_path.x == rect.min.x
+                || on_path.x == rect.max.x
+                || on_path.y == rect.min.y
+                || on_path.y == rect.max.y;
+            assert!(on_edge, "{:?}", on_path);
+        }
+        assert!(output.vertices.iter().any(|(p, on_path)| p != on_path));
+    }
+}
+
+#[test]
+fn miter_clip_join() {
+    // Stroke a corner and return the distance of the vertices from the join, measured
+    // along the outward bisector.
+    fn join_distances(angle: f32, join: LineJoin) -> Vec<f32> {
+        let angle = angle.to_radians();
+        let corner = point(100.0, 0.0);
+        let mut path = Path::builder();
+        path.begin(point(0.0, 0.0));
+        path.line_to(corner);
+        path.line_to(corner + vector(-angle.cos(), angle.sin()) * 100.0);
+        path.end(false);
+        let path = path.build();
+
+        let options = StrokeOptions::default()
+            .with_line_width(4.0)
+            .with_miter_limit(2.0)
+            .with_line_join(join);
+        let mut output: VertexBuffers<Point, u16> = VertexBuffers::new();
+        StrokeTessellator::new()
+            .tessellate_path(&path, &options, &mut simple_builder(&mut output))
+            .unwrap();
+
+        let bisector = (vector(1.0, 0.0) - vector(-angle.cos(), angle.sin())).normalize();
+        output
+            .vertices
+            .iter()
+            .map(|v| (*v - corner).dot(bisector))
+            .collect()
+    }
+
+    fn max(distances: &[f32]) -> f32 {
+        distances.iter().cloned().fold(f32::MIN, f32::max)
+    }
+
+    // A 10 degrees corner exceeds the miter limit. The miter is clipped at
+    // miter_limit * line_width / 2 from the join.
+    let distances = join_distances(10.0, LineJoin::MiterClip);
+    assert!((max(&distances) - 4.0).abs() < 0.001);
+    let clipped = distances.iter().filter(|d| (**d - 4.0).abs() < 0.001);
+    assert_eq!(clipped.count(), 2);
+
+    // With a regular miter join, the miter is replaced with a bevel.
+    let distances = join_distances(10.0, LineJoin::Miter);
+    assert!(max(&distances) < 2.0);
+
+    // A 90 degrees corner doesn't exceed the limit, so both joins produce the same miter.
+    let expected = 2.0 * 2.0f32.sqrt();
+    assert!((max(&join_distances(90.0, LineJoin::MiterClip)) - expected).abs() < 0.001);
+    assert!((max(&join_distances(90.0, LineJoin::Miter)) - expected).abs() < 0.001);
+
+    // Right above the miter limit (60 degrees), the clipped miter is almost as long as the
+    // unclipped one.
+    let distances = join_distances(59.0, LineJoin::MiterClip);
+    assert!((max(&distances) - 4.0).abs() < 0.001);
+    let distances = join_distances(61.0, LineJoin::Miter);
+    assert!(max(&distances) > 3.9);
+}
+
+#[test]
+fn round_cap_small_tolerance() {
+    let mut path = Path::builder();
+    path.begin(point(1000.0, 1000.0));
+    path.line_to(point(1010.0, 1000.0));
+    path.end(false);
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(0.0, 10.0));
+    path.line_to(point(10.0, 10.0));
+    path.end(false);
+    let path = path.build();
+
+    let mut tess = StrokeTessellator::new();
+    let mut num_vertices = 0;
+    for tolerance in [0.1, 0.01, 1e-4, 1e-6, 1e-9] {
+        let options = StrokeOptions::tolerance(tolerance)
+            .with_line_width(4.0)
+            .with_line_cap(LineCap::Round)
+            .with_line_join(LineJoin::Round);
+        let mut output: VertexBuffers<Point, u16> = VertexBuffers::new();
+        tess.tessellate_path(&path, &options, &mut simple_builder(&mut output))
+            .unwrap();
+
+        // Smaller tolerances produce more vertices, up to a limit.
+        assert!(output.vertices.len() >= num_vertices);
+        assert!(output.vertices.len() < 3000);
+        num_vertices = output.vertices.len();
+
+        for tri in output.indices.chunks(3) {
+            let a = output.vertices[tri[0] as usize];
+            let b = output.vertices[tri[1] as usize];
+            let c = output.vertices[tri[2] as usize];
+            assert!(a != b && b != c && a != c, "{:?} {:?} {:?}", a, b, c);
+        }
+
+        // The cap vertices approximate the circle within the tolerance threshold.
+        let center = point(1010.0, 1000.0);
+        let mut cap: Vec<Point> = output
+            .vertices
+            .iter()
+            .cloned()
+            .filter(|v| v.x > center.x + 1e-3)
+            .collect();
+        cap.sort_by(|a, b| a.y.partial_cmp(&b.y).unwrap());
+        for pair in cap.windows(2) {
+            let mid = pair[0].lerp(pair[1], 0.5);
+            assert!(2.0 - (mid - center).length() <= tolerance.max(1e-4));
+        }
+    }
+}
+
+#[test]
+fn stroke_callbacks() {
+    // Arrowheads at the caps and a spike at each join.
+    struct Callbacks {
+        caps: u32,
+        joins: u32,
+    }
+
+    impl StrokeCallbacks for Callbacks {
+        fn cap(
+            &mut self,
+            cap: &StrokeCap,
+            output: &mut CustomStrokeGeometry,
+        ) -> TessellationResult {
+            self.caps += 1;
+            let tip = output.add_vertex(
+                cap.position + cap.direction * cap.line_width,
+                Side::Positive,
+            )?;
+            output.add_triangle(cap.positive_vertex, tip, cap.negative_vertex);
+
+            Ok(())
+        }
+
+        fn join(
+            &mut self,
+            join: &StrokeJoin,
+            output: &mut CustomStrokeGeometry,
+        ) -> TessellationResult {
+            self.joins += 1;
+            let dir = (join.incoming - join.outgoing).normalize();
+            let tip = output.add_vertex(join.position + dir * join.line_width, join.side)?;
+            output.add_triangle(join.prev_vertex, tip, join.next_vertex);
+
+            Ok(())
+        }
+    }
+
+    let mut path = Path::builder();
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(10.0, 0.0));
+    path.line_to(point(10.0, 10.0));
+    path.quadratic_bezier_to(point(10.0, 20.0), point(0.0, 20.0));
+    path.end(false);
+    let path = path.build();
+
+    let options = StrokeOptions::default()
+        .with_line_width(2.0)
+        .with_line_join(LineJoin::Bevel);
+
+    let mut tess = StrokeTessellator::new();
+
+    let mut reference: VertexBuffers<Point, u16> = VertexBuffers::new();
+    tess.tessellate(path.iter(), &options, &mut simple_builder(&mut reference))
+        .unwrap();
+
+    let mut output: VertexBuffers<Point, u16> = VertexBuffers::new();
+    let mut callbacks = Callbacks { caps: 0, joins: 0 };
+    tess.tessellate_with_callbacks(
+        path.iter(),
+        &options,
+        &mut callbacks,
+        &mut simple_builder(&mut output),
+    )
+    .unwrap();
+
+    // The joins between the segments of the flattened curve don't count.
+    assert_eq!(callbacks.caps, 2);
+    assert_eq!(callbacks.joins, 2);
+    assert_eq!(output.vertices.len(), reference.vertices.len() + 4);
+    assert_eq!(output.indices.len(), reference.indices.len() + 4 * 3);
+
+    assert!(output.vertices.contains(&point(-2.0, 0.0)));
+    // The end of the curve isn't exactly horizontal after flattening.
+    assert!(output
+        .vertices
+        .iter()
+        .any(|v| (*v - point(-2.0, 20.0)).length() < 0.5));
+    let spike = point(10.0, 0.0) + vector(1.0, -1.0).normalize() * 2.0;
+    assert!(output.vertices.iter().any(|v| (*v - spike).length() < 1e-5));
+
+    // The default callbacks don't add anything.
+    struct Nothing;
+    impl StrokeCallbacks for Nothing {}
+    let mut output: VertexBuffers<Point, u16> = VertexBuffers::new();
+    tess.tessellate_with_callbacks(
+        path.iter(),
+        &options,
+        &mut Nothing,
+        &mut simple_builder(&mut output),
+    )
+    .unwrap();
+    assert_eq!(output.vertices, reference.vertices);
+    assert_eq!(output.indices, reference.indices);
+}
+
+#[test]
+fn variable_width_along_curves() {
+    // The line width is interpolated at each flattened point of the curves, rather
+    // than only at the endpoints.
+    let mut builder = Path::builder_with_attributes(1);
+    builder.begin(point(0.0, 0.0), &[1.0]);
+    builder.cubic_bezier_to(
+        point(30.0, 50.0),
+        point(70.0, -50.0),
+        point(100.0, 0.0),
+        &[10.0],
+    );
+    builder.quadratic_bezier_to(point(150.0, 50.0), point(200.0, 0.0), &[2.0]);
+    builder.end(false);
+    let path = builder.build();
+
+    let mut tess = StrokeTessellator::new();
+    let mut output: VertexBuffers<(Point, f32), u16> = VertexBuffers::new();
+    tess.tessellate_path(
+        &path,
+        &StrokeOptions::default()
+            .with_variable_line_width(0)
+            .with_tolerance(0.01),
+        &mut BuffersBuilder::new(&mut output, |v: StrokeVertex| {
+            (v.position_on_path(), v.line_width())
+        }),
+    )
+    .unwrap();
+
+    let mut samples: Vec<(Point, f32)> = output.vertices.clone();
+    samples.sort_by(|a, b| a.0.x.partial_cmp(&b.0.x).unwrap());
+    samples.dedup_by(|a, b| a.0 == b.0);
+
+    // Many intermediate widths, increasing along the cubic then decreasing along
+    // the quadratic curve.
+    let cubic: Vec<f32> = samples
+        .iter()
+        .filter(|s| s.0.x <= 100.0)
+        .map(|s| s.1)
+        .collect();
+    let quad: Vec<f32> = samples
+        .iter()
+        .filter(|s| s.0.x >= 100.0)
+        .map(|s| s.1)
+        .collect();
+    assert!(cubic.len() > 10);
+    assert!(quad.len() > 10);
+    assert_eq!(cubic[0], 1.0);
+    assert_eq!(*cubic.last().unwrap(), 10.0);
+    assert_eq!(quad[0], 10.0);
+    assert_eq!(*quad.last().unwrap(), 2.0);
+    for w in cubic.windows(2) {
+        assert!(w[0] < w[1], "{:?}", cubic);
+    }
+    for w in quad.windows(2) {
+        assert!(w[0] > w[1], "{:?}", quad);
+    }
+
+    // A straight curve is flattened into few segments, but the width follows the
+    // parameterization of the curve rather than the distance along it.
+    let curve = CubicBezierSegment {
+        from: point(0.0, 0.0),
+        ctrl1: point(1.0, 0.0),
+        ctrl2: point(2.0, 0.0),
+        to: point(100.0, 0.0),
+    };
+    let mut builder = Path::builder_with_attributes(1);
+    builder.begin(curve.from, &[1.0]);
+    builder.cubic_bezier_to(curve.ctrl1, curve.ctrl2, curve.to, &[10.0]);
+    builder.end(false);
+    let path = builder.build();
+
+    let tolerance = 0.1;
+    let mut output: VertexBuffers<(Point, f32), u16> = VertexBuffers::new();
+    tess.tessellate_path(
+        &path,
+        &StrokeOptions::default()
+            .with_variable_line_width(0)
+            .with_tolerance(tolerance),
+        &mut BuffersBuilder::new(&mut output, |v: StrokeVertex| {
+            (v.position_on_path(), v.line_width())
+        }),
+    )
+    .unwrap();
+
+    let mut samples: Vec<(Point, f32)> = output.vertices.clone();
+    samples.sort_by(|a, b| a.0.x.partial_cmp(&b.0.x).unwrap());
+    samples.dedup_by(|a, b| a.0 == b.0);
+
+    // Between two vertices the width is interpolated linearly, compare it with the
+    // width at the corresponding parameter of the curve.
+    for pair in samples.windows(2) {
+        for i in 1..10 {
+            let f = i as f32 / 10.0;
+            let x = pair[0].0.x + (pair[1].0.x - pair[0].0.x) * f;
+            let interpolated = pair[0].1 + (pair[1].1 - pair[0].1) * f;
+            let (mut t0, mut t1) = (0.0, 1.0);
+            for _ in 0..30 {
+                let t = (t0 + t1) * 0.5;
+                if curve.x(t) < x {
+                    t0 = t;
+                } else {
+                    t1 = t;
+                }
+            }
+            let expected = 1.0 + 9.0 * (t0 + t1) * 0.5;
+            let error = (expected - interpolated).abs() * 0.5;
+            assert!(error <= tolerance, "{:?} {:?}", x, error);
+        }
+    }
+}
+
+#[test]
+fn stroke_polygon() {
+    let points = [point(0.0, 0.0), point(10.0, 0.0), point(10.0, 10.0)];
+    let options = StrokeOptions::default()
+        .with_line_width(2.0)
+        .with_line_join(LineJoin::Bevel)
+        .with_line_cap(LineCap::Square);
+    let mut tess = StrokeTessellator::new();
+
+    for closed in [true, false] {
+        let polygon = || Polygon {
+            points: &points,
+            closed,
+        };
+
+        let mut path = Path::builder();
+        path.add_polygon(polygon());
+        let path = path.build();
+
+        let mut expected: VertexBuffers<Point, u16> = VertexBuffers::new();
+        tess.tessellate_path(&path, &options, &mut simple_builder(&mut expected))
+            .unwrap();
+
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        tess.tessellate_polygon(polygon(), &options, &mut simple_builder(&mut buffers))
+            .unwrap();
+
+        assert_eq!(buffers.vertices, expected.vertices);
+        assert_eq!(buffers.indices, expected.indices);
+
+        // The square caps extend past the endpoints of open polygons.
+        let min_x = buffers
+            .vertices
+            .iter()
+            .map(|p| p.x)
+            .fold(f32::MAX, f32::min);
+        if closed {
+            assert!(min_x > -0.9);
+        } else {
+            assert!(min_x < -0.9);
+        }
+    }
+}
+
+#[test]
+fn dashed_stroke() {
+    use crate::path::private::dash_into;
+    use crate::DashPattern;
+
+    let mut tess = StrokeTessellator::new();
+    let stroke = |tess: &mut StrokeTessellator, path: &Path, options: &StrokeOptions| {
+        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+        tess.tessellate_path(path, options, &mut simple_builder(&mut buffers))
+            .unwrap();
+        buffers
+    };
+
+    let options = StrokeOptions::default()
+        .with_line_width(2.0)
+        .with_line_cap(LineCap::Square);
+
+    let mut path = Path::builder();
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(10.0, 0.0));
+    path.end(false);
+    let line = path.build();
+
+    // Dashes [0, 3], [4, 7] and [8, 10], each with its own caps.
+    let mut path = Path::builder();
+    for (start, end) in [(0.0, 3.0), (4.0, 7.0), (8.0, 10.0)] {
+        path.begin(point(start, 0.0));
+        path.line_to(point(end, 0.0));
+        path.end(false);
+    }
+    let dashes = path.build();
+
+    let dashed = options.with_dash(DashPattern::new(&[3.0, 1.0], 0.0));
+    let expected = stroke(&mut tess, &dashes, &options);
+    let buffers = stroke(&mut tess, &line, &dashed);
+    assert_eq!(buffers.vertices, expected.vertices);
+    assert_eq!(buffers.indices, expected.indices);
+
+    // The pattern wraps around closed sub-paths, and curves are split at the dash
+    // boundaries.
+    let mut path = Path::builder();
+    path.begin(point(0.0, 0.0));
+    path.line_to(point(10.0, 0.0));
+    path.quadratic_bezier_to(point(10.0, 10.0), point(0.0, 10.0));
+    path.end(true);
+    let shape = path.build();
+
+    let dash = DashPattern::new(&[6.0, 2.0], 4.0);
+    let mut path = Path::builder();
+    dash_into(shape.iter(), dash.values(), dash.offset, 0.1, &mut path);
+    let dashes = path.build();
+
+    let expected = stroke(&mut tess, &dashes, &options);
+    let buffers = stroke(&mut tess, &shape, &options.with_dash(dash));
+    assert_eq!(buffers.vertices, expected.vertices);
+    assert_eq!(buffers.indices, expected.indices);
+
+    // Invalid patterns are ignored.
+    let expected = stroke(&mut tess, &line, &options);
+    let dashed = options.with_dash(DashPattern::new(&[], 0.0));
+    let buffers = stroke(&mut tess, &line, &dashed);
+    assert_eq!(buffers.vertices, expected.vertices);
+
+    // The shape helpers, the stroke builder and paths with custom attributes are
+    // dashed as well.
+    let dashed = options.with_dash(DashPattern::new(&[3.0, 1.0], 0.0));
+    let mut path = Path::builder();
+    path.add_circle(point(5.0, 5.0), 4.0, Winding::Positive);
+    let expected = stroke(&mut tess, &path.build(), &dashed);
+    let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
+    tess.tessellate_circle(
+        point(5.0, 5.0),
+        4.0,
+        &dashed,
+        &mut simple_builder(&mut buffers),
+    )
+    .unwrap();
+    assert_eq!(buffers.vertices, expected.vertices);
+    assert_eq!(buffers.indices, expected.indices);
+
+    let expected = stroke(&mut tess, &line, &dashed);
+    let mut path = Path::builder_with_attributes(1);
+    path.begin(point(0.0, 0.0), &[1.0]);
+    path.line_to(point(10.0, 0.0), &[2.0]);
+    path.end(false);
+    let buffers = stroke(&mut tess, &path.build(), &dashed);
+    assert_eq!(buffers.vertices, expected.vertices);
+    assert_eq!(buffers.indices, expected.indices);
+}